@@ -0,0 +1,57 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminBasis;
+use num_traits::{One, Zero};
+
+impl<T, const N: usize> ArgminBasis for [T; N]
+where
+    T: Zero + One,
+{
+    fn basis_like(&self) -> Vec<[T; N]> {
+        (0..N)
+            .map(|i| std::array::from_fn(|j| if i == j { T::one() } else { T::zero() }))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_basis_like_ $t>]() {
+                    let a = [5 as $t, 6 as $t, 7 as $t];
+                    let basis = a.basis_like();
+                    assert_eq!(basis.len(), 3);
+                    for (i, e) in basis.iter().enumerate() {
+                        for (j, x) in e.iter().enumerate() {
+                            let expected = if i == j { 1 as $t } else { 0 as $t };
+                            assert_relative_eq!(*x as f64, expected as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
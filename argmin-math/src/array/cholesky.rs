@@ -0,0 +1,71 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::dense;
+use crate::{ArgminCholesky, Error};
+
+macro_rules! make_cholesky {
+    ($t:ty) => {
+        impl<const N: usize> ArgminCholesky<[[$t; N]; N]> for [[$t; N]; N] {
+            #[inline]
+            fn cholesky(&self) -> Result<[[$t; N]; N], Error> {
+                let mut l = [[0.0; N]; N];
+                dense::cholesky_into(self, &mut l)?;
+                Ok(l)
+            }
+        }
+    };
+}
+
+make_cholesky!(f32);
+make_cholesky!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_cholesky_ $t>]() {
+                    let a = [
+                        [4 as $t, 12 as $t, -16 as $t],
+                        [12 as $t, 37 as $t, -43 as $t],
+                        [-16 as $t, -43 as $t, 98 as $t],
+                    ];
+                    let target = [
+                        [2 as $t, 0 as $t, 0 as $t],
+                        [6 as $t, 1 as $t, 0 as $t],
+                        [-8 as $t, 5 as $t, 3 as $t],
+                    ];
+                    let res = <[[$t; 3]; 3] as ArgminCholesky<[[$t; 3]; 3]>>::cholesky(&a).unwrap();
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert!(((res[i][j] - target[i][j]) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_error_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 1 as $t],
+                    ];
+                    let res = <[[$t; 2]; 2] as ArgminCholesky<[[$t; 2]; 2]>>::cholesky(&a);
+                    assert_eq!(format!("{}", res.unwrap_err()), "Matrix is not positive definite");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
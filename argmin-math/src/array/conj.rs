@@ -0,0 +1,60 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::ArgminConj;
+
+impl<T, const N: usize> ArgminConj for [T; N]
+where
+    T: ArgminConj,
+{
+    #[inline]
+    fn conj(&self) -> [T; N] {
+        std::array::from_fn(|i| self[i].conj())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use num_complex::Complex;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_conj_ $t>]() {
+                    let a = [1 as $t, -4 as $t, 8 as $t];
+                    let res = <[$t; 3] as ArgminConj>::conj(&a);
+                    for i in 0..3 {
+                        assert_relative_eq!(a[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_conj_complex_mat_ $t>]() {
+                    let a = [
+                        [Complex::new(1 as $t, 2 as $t), Complex::new(4 as $t, -3 as $t)],
+                        [Complex::new(-8 as $t, 4 as $t), Complex::new(2 as $t, 0 as $t)],
+                    ];
+                    let res = <[[Complex<$t>; 2]; 2] as ArgminConj>::conj(&a);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert_relative_eq!(a[i][j].re as f64, res[i][j].re as f64, epsilon = f64::EPSILON);
+                            assert_relative_eq!(a[i][j].im as f64, -res[i][j].im as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,180 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiv;
+
+macro_rules! make_div {
+    ($t:ty) => {
+        impl<const N: usize> ArgminDiv<$t, [$t; N]> for [$t; N] {
+            #[inline]
+            fn div(&self, other: &$t) -> [$t; N] {
+                std::array::from_fn(|i| self[i] / other)
+            }
+        }
+
+        impl<const N: usize> ArgminDiv<[$t; N], [$t; N]> for $t {
+            #[inline]
+            fn div(&self, other: &[$t; N]) -> [$t; N] {
+                std::array::from_fn(|i| self / other[i])
+            }
+        }
+
+        impl<const N: usize> ArgminDiv<[$t; N], [$t; N]> for [$t; N] {
+            #[inline]
+            fn div(&self, other: &[$t; N]) -> [$t; N] {
+                std::array::from_fn(|i| self[i] / other[i])
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminDiv<[[$t; N]; M], [[$t; N]; M]>
+            for [[$t; N]; M]
+        {
+            #[inline]
+            fn div(&self, other: &[[$t; N]; M]) -> [[$t; N]; M] {
+                std::array::from_fn(|i| {
+                    <[$t; N] as ArgminDiv<[$t; N], [$t; N]>>::div(&self[i], &other[i])
+                })
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminDiv<$t, [[$t; N]; M]> for [[$t; N]; M] {
+            #[inline]
+            fn div(&self, other: &$t) -> [[$t; N]; M] {
+                std::array::from_fn(|i| <[$t; N] as ArgminDiv<$t, [$t; N]>>::div(&self[i], other))
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminDiv<[[$t; N]; M], [[$t; N]; M]> for $t {
+            #[inline]
+            fn div(&self, other: &[[$t; N]; M]) -> [[$t; N]; M] {
+                std::array::from_fn(|i| <$t as ArgminDiv<[$t; N], [$t; N]>>::div(self, &other[i]))
+            }
+        }
+    };
+}
+
+make_div!(f32);
+make_div!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_div_vec_scalar_ $t>]() {
+                    let a = [1 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target: [$t; 3] = [0.5, 2 as $t, 4 as $t];
+                    let res = <[$t; 3] as ArgminDiv<$t, [$t; 3]>>::div(&a, &b);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_scalar_vec_ $t>]() {
+                    let a = 2 as $t;
+                    let b = [1 as $t, 4 as $t, 8 as $t];
+                    let target: [$t; 3] = [2 as $t, 0.5, 0.25];
+                    let res = <$t as ArgminDiv<[$t; 3], [$t; 3]>>::div(&a, &b);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_vec_vec_ $t>]() {
+                    let a = [1 as $t, 4 as $t, 8 as $t];
+                    let b = [2 as $t, 2 as $t, 4 as $t];
+                    let target: [$t; 3] = [0.5, 2 as $t, 2 as $t];
+                    let res = <[$t; 3] as ArgminDiv<[$t; 3], [$t; 3]>>::div(&a, &b);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_mat_mat_ $t>]() {
+                    let a = [
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 16 as $t, 32 as $t],
+                    ];
+                    let b = [
+                        [2 as $t, 2 as $t, 4 as $t],
+                        [4 as $t, 8 as $t, 0.5],
+                    ];
+                    let target: [[$t; 3]; 2] = [
+                        [0.5, 2 as $t, 2 as $t],
+                        [0.5, 2 as $t, 64 as $t],
+                    ];
+                    let res = <[[$t; 3]; 2] as ArgminDiv<[[$t; 3]; 2], [[$t; 3]; 2]>>::div(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, res[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_mat_scalar_ $t>]() {
+                    let a = [
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 16 as $t, 32 as $t],
+                    ];
+                    let b = 2 as $t;
+                    let target: [[$t; 3]; 2] = [
+                        [0.5, 2 as $t, 4 as $t],
+                        [1 as $t, 8 as $t, 16 as $t],
+                    ];
+                    let res = <[[$t; 3]; 2] as ArgminDiv<$t, [[$t; 3]; 2]>>::div(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, res[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_scalar_mat_ $t>]() {
+                    let a = 2 as $t;
+                    let b = [
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 16 as $t, 32 as $t],
+                    ];
+                    let target: [[$t; 3]; 2] = [
+                        [2 as $t, 0.5, 0.25],
+                        [1 as $t, 0.125, 0.0625],
+                    ];
+                    let res = <$t as ArgminDiv<[[$t; 3]; 2], [[$t; 3]; 2]>>::div(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, res[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
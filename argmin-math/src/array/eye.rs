@@ -0,0 +1,81 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::ArgminEye;
+
+macro_rules! make_eye {
+    ($t:ty) => {
+        impl<const N: usize> ArgminEye for [[$t; N]; N] {
+            #[inline]
+            fn eye_like(&self) -> [[$t; N]; N] {
+                Self::eye(N)
+            }
+
+            // Panics if `n` does not match the size `N` of the array.
+            #[inline]
+            fn eye(n: usize) -> [[$t; N]; N] {
+                assert_eq!(n, N);
+                std::array::from_fn(|i| std::array::from_fn(|j| if i == j { 1.0 } else { 0.0 }))
+            }
+        }
+    };
+}
+
+make_eye!(f32);
+make_eye!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_eye_ $t>]() {
+                    let e = <[[$t; 3]; 3] as ArgminEye>::eye(3);
+                    let res = [
+                        [1 as $t, 0 as $t, 0 as $t],
+                        [0 as $t, 1 as $t, 0 as $t],
+                        [0 as $t, 0 as $t, 1 as $t],
+                    ];
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[i][j] as f64, e[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_eye_panic_ $t>]() {
+                    let _ = <[[$t; 3]; 3] as ArgminEye>::eye(2);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_eye_like_ $t>]() {
+                    let a = [[5 as $t, 2 as $t], [3 as $t, 4 as $t]];
+                    let e = a.eye_like();
+                    let res = [[1 as $t, 0 as $t], [0 as $t, 1 as $t]];
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert_relative_eq!(res[i][j] as f64, e[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
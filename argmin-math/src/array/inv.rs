@@ -0,0 +1,81 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::dense;
+use crate::{ArgminInv, Error};
+
+macro_rules! make_inv {
+    ($t:ty) => {
+        impl<const N: usize> ArgminInv<[[$t; N]; N]> for [[$t; N]; N] {
+            #[inline]
+            fn inv(&self) -> Result<[[$t; N]; N], Error> {
+                let mut lu = *self;
+                let mut perm: [usize; N] = std::array::from_fn(|i| i);
+                dense::lu_decompose_in_place(&mut lu, &mut perm)?;
+                let mut out = [[0.0; N]; N];
+                let mut e = [0.0; N];
+                let mut x = [0.0; N];
+                for j in 0..N {
+                    e[j] = 1.0;
+                    dense::lu_solve_in_place(&lu, &perm, &e, &mut x);
+                    e[j] = 0.0;
+                    for i in 0..N {
+                        out[i][j] = x[i];
+                    }
+                }
+                Ok(out)
+            }
+        }
+    };
+}
+
+make_inv!(f32);
+make_inv!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_inv_ $t>]() {
+                    let a = [
+                        [2 as $t, 5 as $t],
+                        [1 as $t, 3 as $t],
+                    ];
+                    let target = [
+                        [3 as $t, -5 as $t],
+                        [-1 as $t, 2 as $t],
+                    ];
+                    let res = <[[$t; 2]; 2] as ArgminInv<[[$t; 2]; 2]>>::inv(&a).unwrap();
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!(((res[i][j] - target[i][j]) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_inv_error_ $t>]() {
+                    let a = [
+                        [2 as $t, 5 as $t],
+                        [4 as $t, 10 as $t],
+                    ];
+                    let res = <[[$t; 2]; 2] as ArgminInv<[[$t; 2]; 2]>>::inv(&a);
+                    assert_eq!(format!("{}", res.unwrap_err()), "Singular matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,45 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::ArgminL2Norm;
+
+macro_rules! make_norm {
+    ($t:ty) => {
+        impl<const N: usize> ArgminL2Norm<$t> for [$t; N] {
+            #[inline]
+            fn l2_norm(&self) -> $t {
+                self.iter().map(|a| a.powi(2)).sum::<$t>().sqrt()
+            }
+        }
+    };
+}
+
+make_norm!(f32);
+make_norm!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_norm_ $t>]() {
+                    let a = [4 as $t, -3 as $t];
+                    let res = <[$t; 2] as ArgminL2Norm<$t>>::l2_norm(&a);
+                    let target = 5 as $t;
+                    assert_relative_eq!(target as f64, res as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
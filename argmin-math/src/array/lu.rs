@@ -0,0 +1,80 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::dense;
+use crate::{ArgminLU, Error};
+
+macro_rules! make_lu {
+    ($t:ty) => {
+        impl<const N: usize> ArgminLU<[[$t; N]; N]> for [[$t; N]; N] {
+            #[inline]
+            fn lu(&self) -> Result<([[$t; N]; N], [[$t; N]; N], [[$t; N]; N]), Error> {
+                let mut lu = *self;
+                let mut perm: [usize; N] = std::array::from_fn(|i| i);
+                dense::lu_decompose_in_place(&mut lu, &mut perm)?;
+                let mut p = [[0.0; N]; N];
+                let mut l = [[0.0; N]; N];
+                let mut u = [[0.0; N]; N];
+                for i in 0..N {
+                    p[i][perm[i]] = 1.0;
+                    l[i][i] = 1.0;
+                    l[i][..i].copy_from_slice(&lu[i][..i]);
+                    u[i][i..].copy_from_slice(&lu[i][i..]);
+                }
+                Ok((p, l, u))
+            }
+        }
+    };
+}
+
+make_lu!(f32);
+make_lu!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_lu_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t],
+                        [3 as $t, 4 as $t],
+                    ];
+                    let (p, l, u) = <[[$t; 2]; 2] as ArgminLU<[[$t; 2]; 2]>>::lu(&a).unwrap();
+                    let p_target = [[0 as $t, 1 as $t], [1 as $t, 0 as $t]];
+                    let l_target = [[1 as $t, 0 as $t], [1.0 as $t / 3.0, 1 as $t]];
+                    let u_target = [[3 as $t, 4 as $t], [0 as $t, 2.0 as $t / 3.0]];
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!(((p[i][j] - p_target[i][j]) as f64).abs() < 1e-5);
+                            assert!(((l[i][j] - l_target[i][j]) as f64).abs() < 1e-5);
+                            assert!(((u[i][j] - u_target[i][j]) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_lu_error_ $t>]() {
+                    let a = [
+                        [2 as $t, 5 as $t],
+                        [4 as $t, 10 as $t],
+                    ];
+                    let res = <[[$t; 2]; 2] as ArgminLU<[[$t; 2]; 2]>>::lu(&a);
+                    assert_eq!(format!("{}", res.unwrap_err()), "Singular matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,74 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod add;
+mod addassign;
+mod basis;
+mod cholesky;
+mod conj;
+mod div;
+mod dot;
+mod eye;
+mod inv;
+mod l1norm;
+mod l2norm;
+mod linfnorm;
+mod lu;
+mod minmax;
+mod mul;
+mod mulassign;
+mod outer;
+mod outofbounds;
+mod qr;
+mod random;
+mod rankoneupdate;
+mod ranktwoupdate;
+mod scaledadd;
+mod scaledaddassign;
+mod scaledsub;
+mod scaledsubassign;
+mod signum;
+mod solve;
+mod sub;
+mod subassign;
+mod transpose;
+mod weightedl2norm;
+mod zero;
+
+pub use add::*;
+pub use addassign::*;
+pub use basis::*;
+pub use cholesky::*;
+pub use conj::*;
+pub use div::*;
+pub use dot::*;
+pub use eye::*;
+pub use inv::*;
+pub use l1norm::*;
+pub use l2norm::*;
+pub use linfnorm::*;
+pub use lu::*;
+pub use minmax::*;
+pub use mul::*;
+pub use mulassign::*;
+pub use outer::*;
+pub use outofbounds::*;
+pub use qr::*;
+pub use random::*;
+pub use rankoneupdate::*;
+pub use ranktwoupdate::*;
+pub use scaledadd::*;
+pub use scaledaddassign::*;
+pub use scaledsub::*;
+pub use scaledsubassign::*;
+pub use signum::*;
+pub use solve::*;
+pub use sub::*;
+pub use subassign::*;
+pub use transpose::*;
+pub use weightedl2norm::*;
+pub use zero::*;
@@ -0,0 +1,118 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMulAssign;
+
+macro_rules! make_mulassign {
+    ($t:ty) => {
+        impl<const N: usize> ArgminMulAssign<$t> for [$t; N] {
+            #[inline]
+            fn mul_assign(&mut self, other: &$t) {
+                self.iter_mut().for_each(|a| *a *= *other);
+            }
+        }
+
+        impl<const N: usize> ArgminMulAssign<[$t; N]> for [$t; N] {
+            #[inline]
+            fn mul_assign(&mut self, other: &[$t; N]) {
+                self.iter_mut()
+                    .zip(other.iter())
+                    .for_each(|(a, b)| *a *= *b);
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminMulAssign<$t> for [[$t; N]; M] {
+            #[inline]
+            fn mul_assign(&mut self, other: &$t) {
+                self.iter_mut()
+                    .for_each(|a| <[$t; N] as ArgminMulAssign<$t>>::mul_assign(a, other));
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminMulAssign<[[$t; N]; M]> for [[$t; N]; M] {
+            #[inline]
+            fn mul_assign(&mut self, other: &[[$t; N]; M]) {
+                self.iter_mut()
+                    .zip(other.iter())
+                    .for_each(|(a, b)| <[$t; N] as ArgminMulAssign<[$t; N]>>::mul_assign(a, b));
+            }
+        }
+    };
+}
+
+make_mulassign!(f32);
+make_mulassign!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_mulassign_vec_scalar_ $t>]() {
+                    let mut a = [1 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    <[$t; 3] as ArgminMulAssign<$t>>::mul_assign(&mut a, &b);
+                    let target = [2 as $t, 8 as $t, 16 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mulassign_vec_vec_ $t>]() {
+                    let mut a = [1 as $t, 4 as $t, 8 as $t];
+                    let b = [2 as $t, 3 as $t, 4 as $t];
+                    <[$t; 3] as ArgminMulAssign<[$t; 3]>>::mul_assign(&mut a, &b);
+                    let target = [2 as $t, 12 as $t, 32 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mulassign_mat_scalar_ $t>]() {
+                    let mut a = [[1 as $t, 4 as $t, 8 as $t], [2 as $t, 5 as $t, 9 as $t]];
+                    let b = 2 as $t;
+                    <[[$t; 3]; 2] as ArgminMulAssign<$t>>::mul_assign(&mut a, &b);
+                    let target = [[2 as $t, 8 as $t, 16 as $t], [4 as $t, 10 as $t, 18 as $t]];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mulassign_mat_mat_ $t>]() {
+                    let mut a = [[1 as $t, 4 as $t, 8 as $t], [2 as $t, 5 as $t, 9 as $t]];
+                    let b = [[2 as $t, 3 as $t, 4 as $t], [1 as $t, 2 as $t, 3 as $t]];
+                    <[[$t; 3]; 2] as ArgminMulAssign<[[$t; 3]; 2]>>::mul_assign(&mut a, &b);
+                    let target = [[2 as $t, 12 as $t, 32 as $t], [2 as $t, 10 as $t, 27 as $t]];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
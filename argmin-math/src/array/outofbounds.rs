@@ -0,0 +1,55 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminOutOfBounds;
+
+macro_rules! make_out_of_bounds {
+    ($t:ty) => {
+        impl<const N: usize> ArgminOutOfBounds for [$t; N] {
+            fn out_of_bounds(&self, lower: &Self, upper: &Self) -> Self {
+                std::array::from_fn(|i| {
+                    if self[i] < lower[i] || self[i] > upper[i] {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                })
+            }
+        }
+    };
+}
+
+make_out_of_bounds!(f32);
+make_out_of_bounds!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_out_of_bounds_vec_ $t>]() {
+                    let x = [-1.5 as $t, -1.0 as $t, 0.5 as $t, 2.0 as $t, 2.5 as $t];
+                    let lower = [-1.0 as $t; 5];
+                    let upper = [2.0 as $t; 5];
+                    let target = [1.0, 0.0, 0.0, 0.0, 1.0];
+                    let res = <[$t; 5] as ArgminOutOfBounds>::out_of_bounds(&x, &lower, &upper);
+                    for i in 0..5 {
+                        assert_relative_eq!(res[i] as f64, target[i], epsilon = f64::EPSILON);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,113 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::dense::LinalgError;
+use crate::{ArgminQR, Error};
+
+macro_rules! make_qr {
+    ($t:ty) => {
+        // Householder QR which accumulates the full `M x M` orthogonal matrix on the stack and
+        // returns its first `N` columns. Requires `M >= N`.
+        impl<const N: usize, const M: usize> ArgminQR<[[$t; N]; M], [[$t; N]; N]> for [[$t; N]; M] {
+            #[allow(clippy::needless_range_loop)]
+            fn qr(&self) -> Result<([[$t; N]; M], [[$t; N]; N]), Error> {
+                if M < N {
+                    return Err(LinalgError::DimensionMismatch.into());
+                }
+                let mut r = *self;
+                let mut q: [[$t; M]; M] = std::array::from_fn(|i| {
+                    std::array::from_fn(|j| if i == j { 1.0 } else { 0.0 })
+                });
+                let mut v = [0.0; M];
+                for c in 0..N {
+                    for i in c..M {
+                        v[i] = r[i][c];
+                    }
+                    let norm_x = v[c..].iter().map(|x| x * x).sum::<$t>().sqrt();
+                    let alpha = if v[c] >= 0.0 { -norm_x } else { norm_x };
+                    v[c] -= alpha;
+                    let norm_v = v[c..].iter().map(|x| x * x).sum::<$t>().sqrt();
+                    if norm_v <= 0.0 || norm_v.is_nan() {
+                        continue;
+                    }
+                    v[c..].iter_mut().for_each(|x| *x /= norm_v);
+                    for j in c..N {
+                        let s = 2.0 * (c..M).map(|i| v[i] * r[i][j]).sum::<$t>();
+                        for i in c..M {
+                            r[i][j] -= s * v[i];
+                        }
+                    }
+                    for row in q.iter_mut() {
+                        let s = 2.0 * (c..M).map(|i| row[i] * v[i]).sum::<$t>();
+                        for i in c..M {
+                            row[i] -= s * v[i];
+                        }
+                    }
+                }
+                let q = std::array::from_fn(|i| std::array::from_fn(|j| q[i][j]));
+                let r = std::array::from_fn(|i| {
+                    std::array::from_fn(|j| if j < i { 0.0 } else { r[i][j] })
+                });
+                Ok((q, r))
+            }
+        }
+    };
+}
+
+make_qr!(f32);
+make_qr!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_qr_ $t>]() {
+                    let a = [
+                        [3 as $t, 1 as $t],
+                        [4 as $t, 2 as $t],
+                        [0 as $t, 5 as $t],
+                    ];
+                    let (q, r) =
+                        <[[$t; 2]; 3] as ArgminQR<[[$t; 2]; 3], [[$t; 2]; 2]>>::qr(&a).unwrap();
+                    assert!((r[1][0] as f64).abs() < 1e-5);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            let qr_ij = q[i][0] * r[0][j] + q[i][1] * r[1][j];
+                            assert!(((qr_ij - a[i][j]) as f64).abs() < 1e-5);
+                        }
+                    }
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            let qtq_ij = (0..3).map(|k| q[k][i] * q[k][j]).sum::<$t>();
+                            let target = if i == j { 1.0 } else { 0.0 };
+                            assert!(((qtq_ij - target) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_qr_error_ $t>]() {
+                    let a = [
+                        [3 as $t, 1 as $t, 4 as $t],
+                        [4 as $t, 2 as $t, 5 as $t],
+                    ];
+                    let res = <[[$t; 3]; 2] as ArgminQR<[[$t; 3]; 2], [[$t; 3]; 3]>>::qr(&a);
+                    assert_eq!(format!("{}", res.unwrap_err()), "Dimensions do not match");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
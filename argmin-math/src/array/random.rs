@@ -0,0 +1,68 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::ArgminRandom;
+use rand::Rng;
+
+impl<T, const N: usize> ArgminRandom for [T; N]
+where
+    T: ArgminRandom,
+{
+    #[inline]
+    fn rand_from_range(min: &Self, max: &Self) -> Self {
+        Self::rand_from_range_with_rng(min, max, &mut rand::thread_rng())
+    }
+
+    fn rand_from_range_with_rng<R: Rng + ?Sized>(min: &Self, max: &Self, rng: &mut R) -> Self {
+        std::array::from_fn(|i| T::rand_from_range_with_rng(&min[i], &max[i], rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_random_vec_ $t>]() {
+                    let a = [1 as $t, 2 as $t, 4 as $t];
+                    let b = [2 as $t, 3 as $t, 5 as $t];
+                    let random = <[$t; 3] as ArgminRandom>::rand_from_range(&a, &b);
+                    for i in 0..3 {
+                        assert!(random[i] >= a[i]);
+                        assert!(random[i] <= b[i]);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_random_mat_with_rng_ $t>]() {
+                    let a = [[1 as $t, 3 as $t], [2 as $t, 4 as $t]];
+                    let b = [[2 as $t, 4 as $t], [3 as $t, 5 as $t]];
+                    let mut rng = StdRng::seed_from_u64(42);
+                    let random = <[[$t; 2]; 2] as ArgminRandom>::rand_from_range_with_rng(&a, &b, &mut rng);
+                    let mut rng = StdRng::seed_from_u64(42);
+                    let again = <[[$t; 2]; 2] as ArgminRandom>::rand_from_range_with_rng(&a, &b, &mut rng);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!(random[i][j] >= a[i][j]);
+                            assert!(random[i][j] <= b[i][j]);
+                            assert_eq!(random[i][j].to_ne_bytes(), again[i][j].to_ne_bytes());
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,57 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminRankOneUpdate;
+
+macro_rules! make_rankoneupdate {
+    ($t:ty) => {
+        impl<const N: usize> ArgminRankOneUpdate<[$t; N], $t> for [[$t; N]; N] {
+            #[inline]
+            fn rank_one_update(&mut self, alpha: &$t, x: &[$t; N], y: &[$t; N]) {
+                for (row, xi) in self.iter_mut().zip(x.iter()) {
+                    let axi = *alpha * *xi;
+                    row.iter_mut()
+                        .zip(y.iter())
+                        .for_each(|(a, yj)| *a += axi * *yj);
+                }
+            }
+        }
+    };
+}
+
+make_rankoneupdate!(f32);
+make_rankoneupdate!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_rankoneupdate_ $t>]() {
+                    let mut a = [[1 as $t, 2 as $t, 3 as $t], [4 as $t, 5 as $t, 6 as $t], [7 as $t, 8 as $t, 9 as $t]];
+                    let x = [1 as $t, 2 as $t, 3 as $t];
+                    let y = [4 as $t, 5 as $t, 6 as $t];
+                    <[[$t; 3]; 3] as ArgminRankOneUpdate<[$t; 3], $t>>::rank_one_update(&mut a, &(2 as $t), &x, &y);
+                    let target = [[9 as $t, 12 as $t, 15 as $t], [20 as $t, 25 as $t, 30 as $t], [31 as $t, 38 as $t, 45 as $t]];
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
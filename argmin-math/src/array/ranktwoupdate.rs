@@ -0,0 +1,56 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminRankTwoUpdate;
+
+macro_rules! make_ranktwoupdate {
+    ($t:ty) => {
+        impl<const N: usize> ArgminRankTwoUpdate<[$t; N], $t> for [[$t; N]; N] {
+            #[inline]
+            fn rank_two_update(&mut self, alpha: &$t, x: &[$t; N], y: &[$t; N]) {
+                for (i, row) in self.iter_mut().enumerate() {
+                    for (j, a) in row.iter_mut().enumerate() {
+                        *a += *alpha * (x[i] * y[j] + y[i] * x[j]);
+                    }
+                }
+            }
+        }
+    };
+}
+
+make_ranktwoupdate!(f32);
+make_ranktwoupdate!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_ranktwoupdate_ $t>]() {
+                    let mut a = [[1 as $t, 2 as $t, 3 as $t], [4 as $t, 5 as $t, 6 as $t], [7 as $t, 8 as $t, 9 as $t]];
+                    let x = [1 as $t, 2 as $t, 3 as $t];
+                    let y = [4 as $t, 5 as $t, 6 as $t];
+                    <[[$t; 3]; 3] as ArgminRankTwoUpdate<[$t; 3], $t>>::rank_two_update(&mut a, &(2 as $t), &x, &y);
+                    let target = [[17 as $t, 28 as $t, 39 as $t], [30 as $t, 45 as $t, 60 as $t], [43 as $t, 62 as $t, 81 as $t]];
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,102 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminScaledAddAssign;
+
+macro_rules! make_scaledaddassign {
+    ($t:ty) => {
+        impl<const N: usize> ArgminScaledAddAssign<[$t; N], $t> for [$t; N] {
+            #[inline]
+            fn scaled_add_assign(&mut self, factor: &$t, vec: &[$t; N]) {
+                self.iter_mut()
+                    .zip(vec.iter())
+                    .for_each(|(a, b)| *a += *factor * *b);
+            }
+        }
+
+        impl<const N: usize> ArgminScaledAddAssign<[$t; N], [$t; N]> for [$t; N] {
+            #[inline]
+            fn scaled_add_assign(&mut self, factor: &[$t; N], vec: &[$t; N]) {
+                self.iter_mut()
+                    .zip(factor.iter().zip(vec.iter()))
+                    .for_each(|(a, (f, b))| *a += *f * *b);
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminScaledAddAssign<[[$t; N]; M], $t>
+            for [[$t; N]; M]
+        {
+            #[inline]
+            fn scaled_add_assign(&mut self, factor: &$t, vec: &[[$t; N]; M]) {
+                self.iter_mut().zip(vec.iter()).for_each(|(a, b)| {
+                    <[$t; N] as ArgminScaledAddAssign<[$t; N], $t>>::scaled_add_assign(a, factor, b)
+                });
+            }
+        }
+    };
+}
+
+make_scaledaddassign!(f32);
+make_scaledaddassign!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scaledaddassign_vec_ $t>]() {
+                    let mut a = [1 as $t, 2 as $t, 3 as $t];
+                    let f = 2 as $t;
+                    let b = [4 as $t, 5 as $t, 6 as $t];
+                    <[$t; 3] as ArgminScaledAddAssign<[$t; 3], $t>>::scaled_add_assign(&mut a, &f, &b);
+                    let target = [9 as $t, 12 as $t, 15 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledaddassign_vec_vec_ $t>]() {
+                    let mut a = [1 as $t, 2 as $t, 3 as $t];
+                    let f = [3 as $t, 2 as $t, 1 as $t];
+                    let b = [4 as $t, 5 as $t, 6 as $t];
+                    <[$t; 3] as ArgminScaledAddAssign<[$t; 3], [$t; 3]>>::scaled_add_assign(&mut a, &f, &b);
+                    let target = [13 as $t, 12 as $t, 9 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledaddassign_mat_ $t>]() {
+                    let mut a = [[1 as $t, 2 as $t, 3 as $t], [4 as $t, 5 as $t, 6 as $t]];
+                    let f = 2 as $t;
+                    let b = [[4 as $t, 5 as $t, 6 as $t], [7 as $t, 8 as $t, 9 as $t]];
+                    <[[$t; 3]; 2] as ArgminScaledAddAssign<[[$t; 3]; 2], $t>>::scaled_add_assign(&mut a, &f, &b);
+                    let target = [[9 as $t, 12 as $t, 15 as $t], [18 as $t, 21 as $t, 24 as $t]];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
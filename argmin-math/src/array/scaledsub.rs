@@ -0,0 +1,64 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#[cfg(test)]
+mod tests {
+    use crate::ArgminScaledSub;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scaledsub_vec_ $t>]() {
+                    let a = [1 as $t, 2 as $t, 3 as $t];
+                    let b = 2 as $t;
+                    let c = [4 as $t, 5 as $t, 6 as $t];
+                    let res = a.scaled_sub(&b, &c);
+                    let target = [-7 as $t, -8 as $t, -9 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledsub_vec_vec_ $t>]() {
+                    let a = [1 as $t, 2 as $t, 3 as $t];
+                    let b = [3 as $t, 2 as $t, 1 as $t];
+                    let c = [4 as $t, 5 as $t, 6 as $t];
+                    let res = a.scaled_sub(&b, &c);
+                    let target = [-11 as $t, -8 as $t, -3 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledsub_mat_ $t>]() {
+                    let a = [[1 as $t, 2 as $t], [3 as $t, 4 as $t]];
+                    let b = 2 as $t;
+                    let c = [[4 as $t, 5 as $t], [6 as $t, 7 as $t]];
+                    let res = a.scaled_sub(&b, &c);
+                    let target = [[-7 as $t, -8 as $t], [-9 as $t, -10 as $t]];
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert_relative_eq!(res[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
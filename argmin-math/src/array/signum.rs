@@ -0,0 +1,72 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::ArgminSignum;
+
+macro_rules! make_signum {
+    ($t:ty) => {
+        impl<const N: usize> ArgminSignum for [$t; N] {
+            fn signum(mut self) -> Self {
+                for x in &mut self {
+                    *x = x.signum();
+                }
+                self
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminSignum for [[$t; N]; M] {
+            fn signum(mut self) -> Self {
+                for row in &mut self {
+                    *row = row.signum();
+                }
+                self
+            }
+        }
+    };
+}
+
+make_signum!(f32);
+make_signum!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_signum_ $t>]() {
+                    let a = [3 as $t, -4 as $t, -8 as $t];
+                    let b = [1 as $t, -1 as $t, -1 as $t];
+                    let res = <[$t; 3] as ArgminSignum>::signum(a);
+                    for i in 0..3 {
+                        assert_relative_eq!(b[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_signum_mat_ $t>]() {
+                    let a = [[3 as $t, -4 as $t], [-8 as $t, 2 as $t]];
+                    let b = [[1 as $t, -1 as $t], [-1 as $t, 1 as $t]];
+                    let res = <[[$t; 2]; 2] as ArgminSignum>::signum(a);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert_relative_eq!(b[i][j] as f64, res[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
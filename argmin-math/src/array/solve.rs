@@ -0,0 +1,69 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::dense;
+use crate::{ArgminSolve, Error};
+
+macro_rules! make_solve {
+    ($t:ty) => {
+        impl<const N: usize> ArgminSolve<[$t; N], [$t; N]> for [[$t; N]; N] {
+            #[inline]
+            fn solve(&self, b: &[$t; N]) -> Result<[$t; N], Error> {
+                let mut lu = *self;
+                let mut perm: [usize; N] = std::array::from_fn(|i| i);
+                dense::lu_decompose_in_place(&mut lu, &mut perm)?;
+                let mut x = [0.0; N];
+                dense::lu_solve_in_place(&lu, &perm, b, &mut x);
+                Ok(x)
+            }
+        }
+    };
+}
+
+make_solve!(f32);
+make_solve!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_solve_ $t>]() {
+                    let a = [
+                        [2 as $t, 5 as $t],
+                        [1 as $t, 3 as $t],
+                    ];
+                    let b = [1 as $t, 2 as $t];
+                    let res = <[[$t; 2]; 2] as ArgminSolve<[$t; 2], [$t; 2]>>::solve(&a, &b).unwrap();
+                    let target = [-7 as $t, 3 as $t];
+                    for i in 0..2 {
+                        assert!(((res[i] - target[i]) as f64).abs() < 1e-5);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_error_ $t>]() {
+                    let a = [
+                        [2 as $t, 5 as $t],
+                        [4 as $t, 10 as $t],
+                    ];
+                    let b = [1 as $t, 2 as $t];
+                    let res = <[[$t; 2]; 2] as ArgminSolve<[$t; 2], [$t; 2]>>::solve(&a, &b);
+                    assert_eq!(format!("{}", res.unwrap_err()), "Singular matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,63 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::ArgminTranspose;
+
+macro_rules! make_transpose {
+    ($t:ty) => {
+        impl<const N: usize, const M: usize> ArgminTranspose<[[$t; M]; N]> for [[$t; N]; M] {
+            #[inline]
+            fn t(self) -> [[$t; M]; N] {
+                std::array::from_fn(|i| std::array::from_fn(|j| self[j][i]))
+            }
+        }
+    };
+}
+
+make_transpose!(f32);
+make_transpose!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_transpose_ $t>]() {
+                    let a = [[1 as $t, 4 as $t], [8 as $t, 7 as $t]];
+                    let target = [[1 as $t, 8 as $t], [4 as $t, 7 as $t]];
+                    let res: [[$t; 2]; 2] = a.t();
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[i][j] as f64, res[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_transpose_non_square_ $t>]() {
+                    let a = [[1 as $t, 4 as $t, 6 as $t], [8 as $t, 7 as $t, 9 as $t]];
+                    let target = [[1 as $t, 8 as $t], [4 as $t, 7 as $t], [6 as $t, 9 as $t]];
+                    let res: [[$t; 2]; 3] = a.t();
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[i][j] as f64, res[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
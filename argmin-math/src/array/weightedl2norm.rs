@@ -0,0 +1,50 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminWeightedL2Norm;
+
+macro_rules! make_norm {
+    ($t:ty) => {
+        impl<const N: usize> ArgminWeightedL2Norm<[$t; N], $t> for [$t; N] {
+            #[inline]
+            fn weighted_l2_norm(&self, w: &[$t; N]) -> $t {
+                self.iter()
+                    .zip(w.iter())
+                    .map(|(a, w)| w * a.powi(2))
+                    .sum::<$t>()
+                    .sqrt()
+            }
+        }
+    };
+}
+
+make_norm!(f32);
+make_norm!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_weightedl2norm_ $t>]() {
+                    let a = [1 as $t, -2 as $t, 3 as $t];
+                    let w = [4 as $t, 1 as $t, 0.5 as $t];
+                    let res = <[$t; 3] as ArgminWeightedL2Norm<[$t; 3], $t>>::weighted_l2_norm(&a, &w);
+                    assert_relative_eq!((12.5 as f64).sqrt(), res as f64, epsilon = 1e-6);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,75 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::{ArgminZero, ArgminZeroLike};
+
+impl<T, const N: usize> ArgminZero for [T; N]
+where
+    T: ArgminZero,
+{
+    #[inline]
+    fn zero() -> [T; N] {
+        std::array::from_fn(|_| T::zero())
+    }
+}
+
+impl<T, const N: usize> ArgminZeroLike for [T; N]
+where
+    T: ArgminZeroLike,
+{
+    #[inline]
+    fn zero_like(&self) -> [T; N] {
+        std::array::from_fn(|i| self[i].zero_like())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_zero_ $t>]() {
+                    let a = <[$t; 4] as ArgminZero>::zero();
+                    for i in 0..4 {
+                        assert_relative_eq!(a[i] as f64, 0 as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_zero_like_ $t>]() {
+                    let t = [1 as $t, 2 as $t, 3 as $t];
+                    let a = t.zero_like();
+                    for i in 0..3 {
+                        assert_relative_eq!(a[i] as f64, 0 as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_zero_like_mat_ $t>]() {
+                    let t = [[1 as $t, 2 as $t, 3 as $t], [4 as $t, 5 as $t, 6 as $t]];
+                    let a = t.zero_like();
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(a[i][j] as f64, 0 as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,434 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Conformance tests shared by all backends.
+//!
+//! The properties below are written generically over the vector and matrix types and checked on
+//! randomly generated inputs (with a fixed seed) for every backend via `make_vector_tests!` and
+//! `make_matrix_tests!`. This ensures that all backends agree on algebraic identities as well as
+//! on the errors returned by the fallible `ArgminTry*` operations.
+
+// Matrix and length mismatch properties are only checked for backends with dynamically sized
+// vectors and matrices.
+#![cfg_attr(
+    not(any(feature = "vec", feature = "ndarray_all", feature = "nalgebra_all")),
+    allow(dead_code, unused_macros)
+)]
+
+use crate::*;
+use approx::assert_relative_eq;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Number of random inputs each property is checked on
+const CASES: usize = 64;
+
+/// Conversion between backend vectors and `Vec<f64>`
+trait TestVector: Sized {
+    fn from_data(data: Vec<f64>) -> Self;
+    fn data(&self) -> Vec<f64>;
+}
+
+/// Conversion between backend matrices and row-major `Vec<Vec<f64>>`
+trait TestMatrix: Sized {
+    fn from_rows(rows: Vec<Vec<f64>>) -> Self;
+    fn rows(&self) -> Vec<Vec<f64>>;
+}
+
+fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+fn random_data(rng: &mut StdRng, n: usize) -> Vec<f64> {
+    (0..n).map(|_| rng.gen_range(-10.0..10.0)).collect()
+}
+
+fn random_rows(rng: &mut StdRng, r: usize, c: usize) -> Vec<Vec<f64>> {
+    (0..r).map(|_| random_data(rng, c)).collect()
+}
+
+fn assert_close(a: &[f64], b: &[f64]) {
+    assert_eq!(a.len(), b.len());
+    for (x, y) in a.iter().zip(b.iter()) {
+        assert_relative_eq!(x, y, epsilon = 1e-9, max_relative = 1e-9);
+    }
+}
+
+fn assert_close_rows(a: &[Vec<f64>], b: &[Vec<f64>]) {
+    assert_eq!(a.len(), b.len());
+    for (x, y) in a.iter().zip(b.iter()) {
+        assert_close(x, y);
+    }
+}
+
+fn assert_incompatible<T>(res: Result<T, Error>, op: &str) {
+    match res.map_err(|e| e.downcast::<ShapeError>()) {
+        Err(Ok(ShapeError::Incompatible { op: o, .. })) => assert_eq!(o, op),
+        Err(Ok(e)) => panic!("unexpected shape error: {e}"),
+        Err(Err(e)) => panic!("unexpected error: {e}"),
+        Ok(_) => panic!("expected `{op}` to fail"),
+    }
+}
+
+fn prop_pointwise<V>(a: &V, b: &V, c: &V, s: f64)
+where
+    V: TestVector + ArgminAdd<V, V> + ArgminSub<V, V> + ArgminMul<V, V> + ArgminMul<f64, V>,
+{
+    // commutativity of the addition
+    assert_close(&a.add(b).data(), &b.add(a).data());
+    // subtraction is the inverse of the addition
+    assert_close(&a.add(b).sub(b).data(), &a.data());
+    // distributivity of the pointwise multiplication
+    let lhs: V = a.mul(&b.add(c));
+    let rhs: V = ArgminMul::<V, V>::mul(a, b).add(&ArgminMul::<V, V>::mul(a, c));
+    assert_close(&lhs.data(), &rhs.data());
+    // distributivity of the multiplication with a scalar
+    let lhs: V = a.add(b).mul(&s);
+    let rhs: V = ArgminMul::<f64, V>::mul(a, &s).add(&ArgminMul::<f64, V>::mul(b, &s));
+    assert_close(&lhs.data(), &rhs.data());
+}
+
+fn prop_norms<V>(a: &V, b: &V, s: f64)
+where
+    V: TestVector
+        + ArgminAdd<V, V>
+        + ArgminMul<f64, V>
+        + ArgminDot<V, f64>
+        + ArgminL1Norm<f64>
+        + ArgminL2Norm<f64>
+        + ArgminLInfNorm<f64>,
+{
+    let tol = 1e-9;
+    let n = a.data().len() as f64;
+    let ab = a.add(b);
+    // triangle inequality
+    assert!(ab.l1_norm() <= a.l1_norm() + b.l1_norm() + tol);
+    assert!(ab.l2_norm() <= a.l2_norm() + b.l2_norm() + tol);
+    assert!(ab.linf_norm() <= a.linf_norm() + b.linf_norm() + tol);
+    // equivalence of norms
+    assert!(a.linf_norm() <= a.l2_norm() + tol);
+    assert!(a.l2_norm() <= a.l1_norm() + tol);
+    assert!(a.l1_norm() <= n * a.linf_norm() + tol);
+    // Cauchy-Schwarz inequality
+    assert!(a.dot(b).abs() <= a.l2_norm() * b.l2_norm() + tol);
+    // consistency of the l2-norm and the dot product
+    assert_relative_eq!(a.l2_norm().powi(2), a.dot(a), max_relative = 1e-9);
+    // absolute homogeneity
+    let sa: V = a.mul(&s);
+    assert_relative_eq!(sa.l2_norm(), s.abs() * a.l2_norm(), max_relative = 1e-9);
+}
+
+fn prop_try_ops<V>(a: &V, b: &V)
+where
+    V: TestVector
+        + ArgminAdd<V, V>
+        + ArgminSub<V, V>
+        + ArgminMul<V, V>
+        + ArgminDiv<V, V>
+        + ArgminDot<V, f64>
+        + ArgminTryAdd<V, V>
+        + ArgminTrySub<V, V>
+        + ArgminTryMul<V, V>
+        + ArgminTryDiv<V, V>
+        + ArgminTryDot<V, f64>,
+{
+    assert_close(&a.try_add(b).unwrap().data(), &a.add(b).data());
+    assert_close(&a.try_sub(b).unwrap().data(), &a.sub(b).data());
+    assert_close(&a.try_mul(b).unwrap().data(), &a.mul(b).data());
+    assert_close(&a.try_div(b).unwrap().data(), &a.div(b).data());
+    assert_relative_eq!(a.try_dot(b).unwrap(), a.dot(b), max_relative = 1e-9);
+}
+
+fn prop_try_ops_mismatch<V>(a: &V, b: &V)
+where
+    V: ArgminTryAdd<V, V>
+        + ArgminTrySub<V, V>
+        + ArgminTryMul<V, V>
+        + ArgminTryDiv<V, V>
+        + ArgminTryDot<V, f64>,
+{
+    assert_incompatible(a.try_add(b), "add");
+    assert_incompatible(a.try_sub(b), "sub");
+    assert_incompatible(a.try_mul(b), "mul");
+    assert_incompatible(a.try_div(b), "div");
+    assert_incompatible(a.try_dot(b), "dot");
+}
+
+fn prop_transpose<M>(a: &M, b: &M)
+where
+    M: TestMatrix + Clone + ArgminTranspose<M> + ArgminDot<M, M>,
+{
+    // involution
+    assert_close_rows(&a.clone().t().t().rows(), &a.rows());
+    // transpose of a product
+    let lhs: M = a.dot(b).t();
+    let rhs: M = b.clone().t().dot(&a.clone().t());
+    assert_close_rows(&lhs.rows(), &rhs.rows());
+}
+
+fn prop_eye<M>(a: &M)
+where
+    M: TestMatrix + ArgminEye + ArgminDot<M, M>,
+{
+    let rows = a.rows();
+    let (r, c) = (rows.len(), rows[0].len());
+    assert_close_rows(&M::eye(r).dot(a).rows(), &rows);
+    assert_close_rows(&a.dot(&M::eye(c)).rows(), &rows);
+}
+
+fn prop_matrix_distributivity<M, V>(a: &M, b: &M, x: &V, y: &V)
+where
+    M: TestMatrix + ArgminAdd<M, M> + ArgminDot<V, V>,
+    V: TestVector + ArgminAdd<V, V>,
+{
+    let lhs: V = a.dot(&x.add(y));
+    let rhs: V = a.dot(x).add(&a.dot(y));
+    assert_close(&lhs.data(), &rhs.data());
+    let lhs: V = a.add(b).dot(x);
+    let rhs: V = a.dot(x).add(&b.dot(x));
+    assert_close(&lhs.data(), &rhs.data());
+}
+
+fn prop_matrix_try_ops<M, V>(a: &M, x: &V, x_wrong: &V, b_wrong: &M)
+where
+    M: TestMatrix + ArgminTryDot<V, V> + ArgminTryDot<M, M> + ArgminTryAdd<M, M>,
+    V: TestVector,
+{
+    assert!(ArgminTryDot::<V, V>::try_dot(a, x).is_ok());
+    assert_incompatible(ArgminTryDot::<V, V>::try_dot(a, x_wrong), "dot");
+    assert_incompatible(ArgminTryDot::<M, M>::try_dot(a, b_wrong), "dot");
+    assert_incompatible(a.try_add(b_wrong), "add");
+}
+
+/// Checks the vector properties for vectors of type `$v` with at most `$max_dim` elements.
+macro_rules! make_vector_tests {
+    ($v:ty, $max_dim:expr) => {
+        #[test]
+        fn test_pointwise() {
+            let mut rng = rng(1);
+            for _ in 0..CASES {
+                let n = rng.gen_range(1..=$max_dim);
+                let a = <$v as TestVector>::from_data(random_data(&mut rng, n));
+                let b = <$v as TestVector>::from_data(random_data(&mut rng, n));
+                let c = <$v as TestVector>::from_data(random_data(&mut rng, n));
+                prop_pointwise(&a, &b, &c, rng.gen_range(-10.0..10.0));
+            }
+        }
+
+        #[test]
+        fn test_norms() {
+            let mut rng = rng(2);
+            for _ in 0..CASES {
+                let n = rng.gen_range(1..=$max_dim);
+                let a = <$v as TestVector>::from_data(random_data(&mut rng, n));
+                let b = <$v as TestVector>::from_data(random_data(&mut rng, n));
+                prop_norms(&a, &b, rng.gen_range(-10.0..10.0));
+            }
+        }
+
+        #[test]
+        fn test_try_ops() {
+            let mut rng = rng(3);
+            for _ in 0..CASES {
+                let n = rng.gen_range(1..=$max_dim);
+                let a = <$v as TestVector>::from_data(random_data(&mut rng, n));
+                let b = <$v as TestVector>::from_data(random_data(&mut rng, n));
+                prop_try_ops(&a, &b);
+            }
+        }
+    };
+}
+
+/// Checks the error behavior of vectors of type `$v` with mismatching lengths.
+macro_rules! make_vector_mismatch_tests {
+    ($v:ty) => {
+        #[test]
+        fn test_try_ops_mismatch() {
+            let mut rng = rng(4);
+            for _ in 0..CASES {
+                let n = rng.gen_range(1..8);
+                let m = n + rng.gen_range(1..4);
+                let a = <$v as TestVector>::from_data(random_data(&mut rng, n));
+                let b = <$v as TestVector>::from_data(random_data(&mut rng, m));
+                prop_try_ops_mismatch(&a, &b);
+                prop_try_ops_mismatch(&b, &a);
+            }
+        }
+    };
+}
+
+/// Checks the matrix properties for matrices of type `$m` and vectors of type `$v`.
+macro_rules! make_matrix_tests {
+    ($m:ty, $v:ty) => {
+        #[test]
+        fn test_transpose() {
+            let mut rng = rng(5);
+            for _ in 0..CASES {
+                let (r, k, c) = (
+                    rng.gen_range(1..6),
+                    rng.gen_range(1..6),
+                    rng.gen_range(1..6),
+                );
+                let a = <$m as TestMatrix>::from_rows(random_rows(&mut rng, r, k));
+                let b = <$m as TestMatrix>::from_rows(random_rows(&mut rng, k, c));
+                prop_transpose(&a, &b);
+            }
+        }
+
+        #[test]
+        fn test_eye() {
+            let mut rng = rng(6);
+            for _ in 0..CASES {
+                let (r, c) = (rng.gen_range(1..6), rng.gen_range(1..6));
+                prop_eye(&<$m as TestMatrix>::from_rows(random_rows(&mut rng, r, c)));
+            }
+        }
+
+        #[test]
+        fn test_distributivity() {
+            let mut rng = rng(7);
+            for _ in 0..CASES {
+                let (r, c) = (rng.gen_range(1..6), rng.gen_range(1..6));
+                let a = <$m as TestMatrix>::from_rows(random_rows(&mut rng, r, c));
+                let b = <$m as TestMatrix>::from_rows(random_rows(&mut rng, r, c));
+                let x = <$v as TestVector>::from_data(random_data(&mut rng, c));
+                let y = <$v as TestVector>::from_data(random_data(&mut rng, c));
+                prop_matrix_distributivity(&a, &b, &x, &y);
+            }
+        }
+
+        #[test]
+        fn test_try_ops_mismatch_matrix() {
+            let mut rng = rng(8);
+            for _ in 0..CASES {
+                let (r, c) = (rng.gen_range(1..6), rng.gen_range(1..6));
+                let a = <$m as TestMatrix>::from_rows(random_rows(&mut rng, r, c));
+                let x = <$v as TestVector>::from_data(random_data(&mut rng, c));
+                let x_wrong = <$v as TestVector>::from_data(random_data(&mut rng, c + 1));
+                let b_wrong = <$m as TestMatrix>::from_rows(random_rows(&mut rng, c + 1, r));
+                prop_matrix_try_ops(&a, &x, &x_wrong, &b_wrong);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "primitives")]
+mod primitives_backend {
+    use super::*;
+
+    impl TestVector for f64 {
+        fn from_data(data: Vec<f64>) -> Self {
+            data[0]
+        }
+
+        fn data(&self) -> Vec<f64> {
+            vec![*self]
+        }
+    }
+
+    make_vector_tests!(f64, 1);
+}
+
+#[cfg(feature = "vec")]
+mod vec_backend {
+    use super::*;
+
+    impl TestVector for Vec<f64> {
+        fn from_data(data: Vec<f64>) -> Self {
+            data
+        }
+
+        fn data(&self) -> Vec<f64> {
+            self.clone()
+        }
+    }
+
+    impl TestMatrix for Vec<Vec<f64>> {
+        fn from_rows(rows: Vec<Vec<f64>>) -> Self {
+            rows
+        }
+
+        fn rows(&self) -> Vec<Vec<f64>> {
+            self.clone()
+        }
+    }
+
+    make_vector_tests!(Vec<f64>, 8);
+    make_vector_mismatch_tests!(Vec<f64>);
+    make_matrix_tests!(Vec<Vec<f64>>, Vec<f64>);
+
+    #[test]
+    fn test_try_ops_ragged() {
+        let a = vec![vec![1.0, 2.0], vec![3.0]];
+        let b = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+        let err = a.try_add(&b).unwrap_err();
+        assert_eq!(err.downcast_ref::<ShapeError>(), Some(&ShapeError::Ragged));
+    }
+}
+
+#[cfg(feature = "ndarray_all")]
+mod ndarray_backend {
+    use super::*;
+    use ndarray::{Array1, Array2};
+
+    impl TestVector for Array1<f64> {
+        fn from_data(data: Vec<f64>) -> Self {
+            Array1::from_vec(data)
+        }
+
+        fn data(&self) -> Vec<f64> {
+            self.to_vec()
+        }
+    }
+
+    impl TestMatrix for Array2<f64> {
+        fn from_rows(rows: Vec<Vec<f64>>) -> Self {
+            let (r, c) = (rows.len(), rows[0].len());
+            Array2::from_shape_vec((r, c), rows.concat()).unwrap()
+        }
+
+        fn rows(&self) -> Vec<Vec<f64>> {
+            self.outer_iter().map(|row| row.to_vec()).collect()
+        }
+    }
+
+    make_vector_tests!(Array1<f64>, 8);
+    make_vector_mismatch_tests!(Array1<f64>);
+    make_matrix_tests!(Array2<f64>, Array1<f64>);
+}
+
+#[cfg(feature = "nalgebra_all")]
+mod nalgebra_backend {
+    use super::*;
+    use nalgebra::{DMatrix, DVector};
+
+    impl TestVector for DVector<f64> {
+        fn from_data(data: Vec<f64>) -> Self {
+            DVector::from_vec(data)
+        }
+
+        fn data(&self) -> Vec<f64> {
+            self.iter().copied().collect()
+        }
+    }
+
+    impl TestMatrix for DMatrix<f64> {
+        fn from_rows(rows: Vec<Vec<f64>>) -> Self {
+            let (r, c) = (rows.len(), rows[0].len());
+            DMatrix::from_row_slice(r, c, &rows.concat())
+        }
+
+        fn rows(&self) -> Vec<Vec<f64>> {
+            self.row_iter()
+                .map(|row| row.iter().copied().collect())
+                .collect()
+        }
+    }
+
+    make_vector_tests!(DVector<f64>, 8);
+    make_vector_mismatch_tests!(DVector<f64>);
+    make_matrix_tests!(DMatrix<f64>, DVector<f64>);
+}
@@ -0,0 +1,495 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Dense linear algebra routines on row-major `Vec<Vec<F>>` matrices.
+//!
+//! These are shared by the backends which do not come with their own implementation of the
+//! factorizations (`vec`, `array` and `ndarray` without `ndarray-linalg`). The `*_in_place` and
+//! `*_into` variants operate on any slice of rows and do not allocate, which is what the `array`
+//! backend relies on. Each routine is only compiled for the backends which use it; with
+//! `ndarray-linalg`, the `ndarray` backend only uses the LU decomposition.
+
+#[cfg(any(feature = "vec", feature = "array", feature = "ndarray_all"))]
+use crate::Error;
+#[cfg(any(feature = "vec", feature = "array", feature = "ndarray_all"))]
+use num_traits::Float;
+
+/// Errors of the dense linear algebra routines
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub(crate) enum LinalgError {
+    /// Matrix is not square
+    #[cfg(any(
+        feature = "vec",
+        feature = "ndarray_all",
+        feature = "nalgebra_all",
+        feature = "faer_all"
+    ))]
+    #[error("Matrix must be square")]
+    NotSquare,
+    /// Dimensions of the inputs do not match
+    #[cfg(any(
+        feature = "vec",
+        feature = "array",
+        feature = "ndarray_all",
+        feature = "nalgebra_all",
+        feature = "faer_all"
+    ))]
+    #[error("Dimensions do not match")]
+    DimensionMismatch,
+    /// Matrix is singular
+    #[error("Singular matrix")]
+    Singular,
+    /// Matrix is not positive definite
+    #[cfg(any(
+        feature = "vec",
+        feature = "array",
+        all(
+            feature = "ndarray_all",
+            not(any(
+                feature = "ndarray-linalg_0_12",
+                feature = "ndarray-linalg_0_13",
+                feature = "ndarray-linalg_0_16"
+            ))
+        ),
+        feature = "nalgebra_all",
+        feature = "faer_all"
+    ))]
+    #[error("Matrix is not positive definite")]
+    NotPositiveDefinite,
+}
+
+/// Returns the number of columns if all rows are of the same length.
+#[cfg(any(feature = "vec", feature = "ndarray_all"))]
+fn num_cols<F>(a: &[Vec<F>]) -> Result<usize, Error> {
+    let n = a.first().map(|row| row.len()).unwrap_or(0);
+    if a.iter().any(|row| row.len() != n) {
+        return Err(LinalgError::DimensionMismatch.into());
+    }
+    Ok(n)
+}
+
+/// Returns the dimension of a square matrix.
+#[cfg(any(feature = "vec", feature = "ndarray_all"))]
+fn dim_square<F>(a: &[Vec<F>]) -> Result<usize, Error> {
+    if num_cols(a)? != a.len() {
+        return Err(LinalgError::NotSquare.into());
+    }
+    Ok(a.len())
+}
+
+/// LU decomposition with partial pivoting.
+///
+/// Returns the strictly lower part of `L` (the unit diagonal is implied) and `U` combined in a
+/// single matrix as well as the row permutation, where `perm[i]` is the row of `a` which ends up
+/// in row `i`.
+#[cfg(any(feature = "vec", feature = "ndarray_all"))]
+pub(crate) fn lu_decompose<F: Float>(
+    mut a: Vec<Vec<F>>,
+) -> Result<(Vec<Vec<F>>, Vec<usize>), Error> {
+    let n = dim_square(&a)?;
+    let mut perm: Vec<usize> = (0..n).collect();
+    lu_decompose_in_place(&mut a, &mut perm)?;
+    Ok((a, perm))
+}
+
+/// In-place variant of [`lu_decompose`] for any square matrix stored as a slice of rows.
+///
+/// `perm` must be of the same length as `a` and hold the identity permutation. Neither the rows
+/// nor the permutation are checked for consistent dimensions.
+#[cfg(any(feature = "vec", feature = "array", feature = "ndarray_all"))]
+pub(crate) fn lu_decompose_in_place<F, R>(a: &mut [R], perm: &mut [usize]) -> Result<(), Error>
+where
+    F: Float,
+    R: AsRef<[F]> + AsMut<[F]>,
+{
+    let n = a.len();
+    for k in 0..n {
+        let mut p = k;
+        for i in (k + 1)..n {
+            if a[i].as_ref()[k].abs() > a[p].as_ref()[k].abs() {
+                p = i;
+            }
+        }
+        let pivot = a[p].as_ref()[k];
+        if pivot.abs() <= F::zero() || pivot.is_nan() {
+            return Err(LinalgError::Singular.into());
+        }
+        a.swap(k, p);
+        perm.swap(k, p);
+
+        let (top, bottom) = a.split_at_mut(k + 1);
+        let pivot_row = top[k].as_ref();
+        for row in bottom.iter_mut() {
+            let row = row.as_mut();
+            let factor = row[k] / pivot_row[k];
+            row[k] = factor;
+            for j in (k + 1)..n {
+                row[j] = row[j] - factor * pivot_row[j];
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Solve `A * x = b` given the LU decomposition of `A` as returned by [`lu_decompose`].
+#[cfg(any(
+    feature = "vec",
+    all(
+        feature = "ndarray_all",
+        not(any(
+            feature = "ndarray-linalg_0_12",
+            feature = "ndarray-linalg_0_13",
+            feature = "ndarray-linalg_0_16"
+        ))
+    )
+))]
+pub(crate) fn lu_solve<F: Float>(lu: &[Vec<F>], perm: &[usize], b: &[F]) -> Vec<F> {
+    let mut x = vec![F::zero(); lu.len()];
+    lu_solve_in_place(lu, perm, b, &mut x);
+    x
+}
+
+/// Variant of [`lu_solve`] which writes the solution into `x` instead of allocating it.
+#[cfg(any(
+    feature = "vec",
+    feature = "array",
+    all(
+        feature = "ndarray_all",
+        not(any(
+            feature = "ndarray-linalg_0_12",
+            feature = "ndarray-linalg_0_13",
+            feature = "ndarray-linalg_0_16"
+        ))
+    )
+))]
+pub(crate) fn lu_solve_in_place<F, R>(lu: &[R], perm: &[usize], b: &[F], x: &mut [F])
+where
+    F: Float,
+    R: AsRef<[F]>,
+{
+    let n = lu.len();
+    for (xi, &p) in x.iter_mut().zip(perm.iter()) {
+        *xi = b[p];
+    }
+    for i in 0..n {
+        let row = lu[i].as_ref();
+        for j in 0..i {
+            x[i] = x[i] - row[j] * x[j];
+        }
+    }
+    for i in (0..n).rev() {
+        let row = lu[i].as_ref();
+        for j in (i + 1)..n {
+            x[i] = x[i] - row[j] * x[j];
+        }
+        x[i] = x[i] / row[i];
+    }
+}
+
+/// Solve `A * x = b` for a square matrix `A`.
+#[cfg(any(
+    feature = "vec",
+    all(
+        feature = "ndarray_all",
+        not(any(
+            feature = "ndarray-linalg_0_12",
+            feature = "ndarray-linalg_0_13",
+            feature = "ndarray-linalg_0_16"
+        ))
+    )
+))]
+pub(crate) fn solve<F: Float>(a: Vec<Vec<F>>, b: &[F]) -> Result<Vec<F>, Error> {
+    if a.len() != b.len() {
+        return Err(LinalgError::DimensionMismatch.into());
+    }
+    let (lu, perm) = lu_decompose(a)?;
+    Ok(lu_solve(&lu, &perm, b))
+}
+
+/// LU decomposition `P * A = L * U` with partial pivoting, returned as `(P, L, U)`.
+#[cfg(any(feature = "vec", feature = "ndarray_all"))]
+#[allow(clippy::type_complexity)]
+pub(crate) fn lu<F: Float>(
+    a: Vec<Vec<F>>,
+) -> Result<(Vec<Vec<F>>, Vec<Vec<F>>, Vec<Vec<F>>), Error> {
+    let (lu, perm) = lu_decompose(a)?;
+    let n = lu.len();
+    let mut p = vec![vec![F::zero(); n]; n];
+    let mut l = vec![vec![F::zero(); n]; n];
+    let mut u = vec![vec![F::zero(); n]; n];
+    for i in 0..n {
+        p[i][perm[i]] = F::one();
+        l[i][i] = F::one();
+        l[i][..i].copy_from_slice(&lu[i][..i]);
+        u[i][i..].copy_from_slice(&lu[i][i..]);
+    }
+    Ok((p, l, u))
+}
+
+/// Cholesky decomposition `A = L * L^T` of a symmetric positive definite matrix.
+///
+/// Only the lower triangular part of `a` is accessed.
+#[cfg(any(
+    feature = "vec",
+    all(
+        feature = "ndarray_all",
+        not(any(
+            feature = "ndarray-linalg_0_12",
+            feature = "ndarray-linalg_0_13",
+            feature = "ndarray-linalg_0_16"
+        ))
+    )
+))]
+pub(crate) fn cholesky<F: Float>(a: &[Vec<F>]) -> Result<Vec<Vec<F>>, Error> {
+    let n = dim_square(a)?;
+    let mut l = vec![vec![F::zero(); n]; n];
+    cholesky_into(a, &mut l)?;
+    Ok(l)
+}
+
+/// Variant of [`cholesky`] which writes `L` into `l`.
+///
+/// `l` must be a zero matrix of the same size as the square matrix `a`.
+#[cfg(any(
+    feature = "vec",
+    feature = "array",
+    all(
+        feature = "ndarray_all",
+        not(any(
+            feature = "ndarray-linalg_0_12",
+            feature = "ndarray-linalg_0_13",
+            feature = "ndarray-linalg_0_16"
+        ))
+    )
+))]
+pub(crate) fn cholesky_into<F, R>(a: &[R], l: &mut [R]) -> Result<(), Error>
+where
+    F: Float,
+    R: AsRef<[F]> + AsMut<[F]>,
+{
+    let n = a.len();
+    for j in 0..n {
+        let lj = l[j].as_ref();
+        let d = a[j].as_ref()[j] - lj[..j].iter().fold(F::zero(), |acc, &x| acc + x * x);
+        if d <= F::zero() || d.is_nan() {
+            return Err(LinalgError::NotPositiveDefinite.into());
+        }
+        l[j].as_mut()[j] = d.sqrt();
+        let (top, bottom) = l.split_at_mut(j + 1);
+        let lj = top[j].as_ref();
+        for (ai, li) in a[(j + 1)..].iter().zip(bottom.iter_mut()) {
+            let li = li.as_mut();
+            let s = li[..j]
+                .iter()
+                .zip(lj[..j].iter())
+                .fold(F::zero(), |acc, (&x, &y)| acc + x * y);
+            li[j] = (ai.as_ref()[j] - s) / lj[j];
+        }
+    }
+    Ok(())
+}
+
+/// Reduced QR decomposition `A = Q * R` via Householder reflections.
+///
+/// For a `m x n` matrix `A`, `Q` is of size `m x min(m, n)` and `R` is of size `min(m, n) x n`.
+#[cfg(any(
+    feature = "vec",
+    all(
+        feature = "ndarray_all",
+        not(any(
+            feature = "ndarray-linalg_0_12",
+            feature = "ndarray-linalg_0_13",
+            feature = "ndarray-linalg_0_16"
+        ))
+    )
+))]
+#[allow(clippy::type_complexity, clippy::needless_range_loop)]
+pub(crate) fn qr<F: Float>(a: &[Vec<F>]) -> Result<(Vec<Vec<F>>, Vec<Vec<F>>), Error> {
+    let m = a.len();
+    let n = num_cols(a)?;
+    let k = m.min(n);
+    let two = F::one() + F::one();
+
+    let mut r = a.to_vec();
+    let mut reflectors: Vec<(usize, Vec<F>)> = Vec::with_capacity(k);
+    for c in 0..k {
+        let mut v: Vec<F> = (c..m).map(|i| r[i][c]).collect();
+        let norm_x = v.iter().fold(F::zero(), |acc, &x| acc + x * x).sqrt();
+        let alpha = if v[0] >= F::zero() { -norm_x } else { norm_x };
+        v[0] = v[0] - alpha;
+        let norm_v = v.iter().fold(F::zero(), |acc, &x| acc + x * x).sqrt();
+        if norm_v <= F::zero() || norm_v.is_nan() {
+            continue;
+        }
+        v.iter_mut().for_each(|x| *x = *x / norm_v);
+        for j in c..n {
+            let s = two * (c..m).fold(F::zero(), |acc, i| acc + v[i - c] * r[i][j]);
+            for i in c..m {
+                r[i][j] = r[i][j] - s * v[i - c];
+            }
+        }
+        reflectors.push((c, v));
+    }
+
+    let mut q = vec![vec![F::zero(); k]; m];
+    for (i, row) in q.iter_mut().enumerate().take(k) {
+        row[i] = F::one();
+    }
+    for (c, v) in reflectors.iter().rev() {
+        for j in 0..k {
+            let s = two * (*c..m).fold(F::zero(), |acc, i| acc + v[i - c] * q[i][j]);
+            for i in *c..m {
+                q[i][j] = q[i][j] - s * v[i - c];
+            }
+        }
+    }
+
+    let mut r: Vec<Vec<F>> = r.into_iter().take(k).collect();
+    for (i, row) in r.iter_mut().enumerate() {
+        row[..i].iter_mut().for_each(|x| *x = F::zero());
+    }
+    Ok((q, r))
+}
+
+#[cfg(all(test, feature = "vec"))]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn matmul(a: &[Vec<f64>], b: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        a.iter()
+            .map(|row| {
+                (0..b[0].len())
+                    .map(|j| row.iter().zip(b.iter()).map(|(x, r)| x * r[j]).sum())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn transpose(a: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        (0..a[0].len())
+            .map(|j| a.iter().map(|row| row[j]).collect())
+            .collect()
+    }
+
+    fn assert_mat_eq(a: &[Vec<f64>], b: &[Vec<f64>]) {
+        assert_eq!(a.len(), b.len());
+        for (ra, rb) in a.iter().zip(b.iter()) {
+            assert_eq!(ra.len(), rb.len());
+            for (x, y) in ra.iter().zip(rb.iter()) {
+                assert_relative_eq!(x, y, epsilon = 1e-12);
+            }
+        }
+    }
+
+    fn test_matrix() -> Vec<Vec<f64>> {
+        vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 10.0],
+        ]
+    }
+
+    #[test]
+    fn test_solve() {
+        let a = test_matrix();
+        let x = [1.0, -2.0, 0.5];
+        let b: Vec<f64> = a
+            .iter()
+            .map(|row| row.iter().zip(x.iter()).map(|(a, b)| a * b).sum())
+            .collect();
+        let res = solve(a, &b).unwrap();
+        for (r, x) in res.iter().zip(x.iter()) {
+            assert_relative_eq!(r, x, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        let singular = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+        let err = solve(singular, &[1.0, 1.0]).unwrap_err();
+        assert_eq!(
+            err.downcast::<LinalgError>().unwrap(),
+            LinalgError::Singular
+        );
+
+        let err = solve(test_matrix(), &[1.0, 1.0]).unwrap_err();
+        assert_eq!(
+            err.downcast::<LinalgError>().unwrap(),
+            LinalgError::DimensionMismatch
+        );
+
+        let err = solve(vec![vec![1.0, 2.0]], &[1.0]).unwrap_err();
+        assert_eq!(
+            err.downcast::<LinalgError>().unwrap(),
+            LinalgError::NotSquare
+        );
+    }
+
+    #[test]
+    fn test_lu() {
+        let a = test_matrix();
+        let (p, l, u) = lu(a.clone()).unwrap();
+        assert_mat_eq(&matmul(&p, &a), &matmul(&l, &u));
+        for i in 0..3 {
+            assert_relative_eq!(l[i][i], 1.0);
+            for j in (i + 1)..3 {
+                assert_relative_eq!(l[i][j], 0.0);
+                assert_relative_eq!(u[j][i], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cholesky() {
+        let a = vec![
+            vec![4.0, 12.0, -16.0],
+            vec![12.0, 37.0, -43.0],
+            vec![-16.0, -43.0, 98.0],
+        ];
+        let l = cholesky(&a).unwrap();
+        assert_mat_eq(
+            &l,
+            &[
+                vec![2.0, 0.0, 0.0],
+                vec![6.0, 1.0, 0.0],
+                vec![-8.0, 5.0, 3.0],
+            ],
+        );
+        assert_mat_eq(&matmul(&l, &transpose(&l)), &a);
+
+        let err = cholesky(&test_matrix()).unwrap_err();
+        assert_eq!(
+            err.downcast::<LinalgError>().unwrap(),
+            LinalgError::NotPositiveDefinite
+        );
+    }
+
+    #[test]
+    fn test_qr() {
+        for a in [
+            test_matrix(),
+            vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]],
+            vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]],
+        ] {
+            let (q, r) = qr(&a).unwrap();
+            let k = a.len().min(a[0].len());
+            assert_eq!(q.len(), a.len());
+            assert_eq!(r.len(), k);
+            assert_mat_eq(&matmul(&q, &r), &a);
+            let identity: Vec<Vec<f64>> = (0..k)
+                .map(|i| (0..k).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+                .collect();
+            assert_mat_eq(&matmul(&transpose(&q), &q), &identity);
+            for (i, row) in r.iter().enumerate() {
+                for x in row.iter().take(i) {
+                    assert_relative_eq!(*x, 0.0);
+                }
+            }
+        }
+    }
+}
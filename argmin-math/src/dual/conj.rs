@@ -0,0 +1,23 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminConj, Dual};
+use num_traits::Float;
+
+impl<F: Float> ArgminConj for Dual<F> {
+    #[inline]
+    fn conj(&self) -> Dual<F> {
+        *self
+    }
+}
+
+impl<F: Float> ArgminConj for Vec<Dual<F>> {
+    #[inline]
+    fn conj(&self) -> Vec<Dual<F>> {
+        self.clone()
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminDiv, Dual};
+use num_traits::Float;
+
+impl<F: Float> ArgminDiv<Dual<F>, Dual<F>> for Dual<F> {
+    #[inline]
+    fn div(&self, other: &Dual<F>) -> Dual<F> {
+        *self / *other
+    }
+}
+
+impl<F: Float> ArgminDiv<Dual<F>, Vec<Dual<F>>> for Vec<Dual<F>> {
+    #[inline]
+    fn div(&self, other: &Dual<F>) -> Vec<Dual<F>> {
+        self.iter().map(|a| *a / *other).collect()
+    }
+}
+
+impl<F: Float> ArgminDiv<Vec<Dual<F>>, Vec<Dual<F>>> for Dual<F> {
+    #[inline]
+    fn div(&self, other: &Vec<Dual<F>>) -> Vec<Dual<F>> {
+        other.iter().map(|a| *self / *a).collect()
+    }
+}
+
+impl<F: Float> ArgminDiv<Vec<Dual<F>>, Vec<Dual<F>>> for Vec<Dual<F>> {
+    #[inline]
+    fn div(&self, other: &Vec<Dual<F>>) -> Vec<Dual<F>> {
+        assert_eq!(self.len(), other.len());
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| *a / *b)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_div_vec_vec_ $t>]() {
+                    let a = vec![Dual::new(1 as $t, 1 as $t), Dual::new(2 as $t, 0 as $t)];
+                    let b = vec![Dual::new(3 as $t, 2 as $t), Dual::new(4 as $t, 1 as $t)];
+                    let res = <Vec<Dual<$t>> as ArgminDiv<Vec<Dual<$t>>, Vec<Dual<$t>>>>::div(&a, &b);
+                    let target = [(1.0 / 3.0 as $t, 1.0 / 9.0 as $t), (0.5 as $t, -0.125 as $t)];
+                    for i in 0..2 {
+                        assert_relative_eq!(res[i].re, target[i].0, epsilon = <$t>::EPSILON);
+                        assert_relative_eq!(res[i].eps, target[i].1, epsilon = <$t>::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_vec_scalar_ $t>]() {
+                    let a = vec![Dual::new(1 as $t, 1 as $t), Dual::new(2 as $t, 0 as $t)];
+                    let b = Dual::new(2 as $t, 1 as $t);
+                    let res = <Vec<Dual<$t>> as ArgminDiv<Dual<$t>, Vec<Dual<$t>>>>::div(&a, &b);
+                    let target = [(0.5 as $t, 0.25 as $t), (1 as $t, -0.5 as $t)];
+                    for i in 0..2 {
+                        assert_relative_eq!(res[i].re, target[i].0, epsilon = <$t>::EPSILON);
+                        assert_relative_eq!(res[i].eps, target[i].1, epsilon = <$t>::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_scalar_vec_ $t>]() {
+                    let a = vec![Dual::new(1 as $t, 1 as $t), Dual::new(2 as $t, 0 as $t)];
+                    let b = Dual::new(2 as $t, 1 as $t);
+                    let res = <Dual<$t> as ArgminDiv<Vec<Dual<$t>>, Vec<Dual<$t>>>>::div(&b, &a);
+                    let target = [(2 as $t, -1 as $t), (1 as $t, 0.5 as $t)];
+                    for i in 0..2 {
+                        assert_relative_eq!(res[i].re, target[i].0, epsilon = <$t>::EPSILON);
+                        assert_relative_eq!(res[i].eps, target[i].1, epsilon = <$t>::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_div_vec_vec_panic_ $t>]() {
+                    let a = vec![Dual::new(1 as $t, 1 as $t)];
+                    let b = vec![Dual::new(3 as $t, 2 as $t), Dual::new(4 as $t, 1 as $t)];
+                    <Vec<Dual<$t>> as ArgminDiv<Vec<Dual<$t>>, Vec<Dual<$t>>>>::div(&a, &b);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,92 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminDot, Dual};
+use num_traits::Float;
+
+impl<F: Float> ArgminDot<Dual<F>, Dual<F>> for Dual<F> {
+    #[inline]
+    fn dot(&self, other: &Dual<F>) -> Dual<F> {
+        *self * *other
+    }
+}
+
+impl<F: Float> ArgminDot<Vec<Dual<F>>, Dual<F>> for Vec<Dual<F>> {
+    #[inline]
+    fn dot(&self, other: &Vec<Dual<F>>) -> Dual<F> {
+        assert_eq!(self.len(), other.len());
+        self.iter().zip(other.iter()).map(|(a, b)| *a * *b).sum()
+    }
+}
+
+impl<F: Float> ArgminDot<Dual<F>, Vec<Dual<F>>> for Vec<Dual<F>> {
+    #[inline]
+    fn dot(&self, other: &Dual<F>) -> Vec<Dual<F>> {
+        self.iter().map(|a| *a * *other).collect()
+    }
+}
+
+impl<F: Float> ArgminDot<Vec<Dual<F>>, Vec<Dual<F>>> for Dual<F> {
+    #[inline]
+    fn dot(&self, other: &Vec<Dual<F>>) -> Vec<Dual<F>> {
+        other.iter().map(|a| *self * *a).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_vec_vec_ $t>]() {
+                    // d/dx (x * 3 + 2 * x^2) at x = 1 is 3 + 4 x = 7
+                    let x = Dual::variable(1 as $t);
+                    let a = vec![x, Dual::constant(2 as $t)];
+                    let b = vec![Dual::constant(3 as $t), x * x];
+                    let res = <Vec<Dual<$t>> as ArgminDot<Vec<Dual<$t>>, Dual<$t>>>::dot(&a, &b);
+                    assert_relative_eq!(res.re, 5 as $t, epsilon = <$t>::EPSILON);
+                    assert_relative_eq!(res.eps, 7 as $t, epsilon = <$t>::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_vec_scalar_ $t>]() {
+                    let a = vec![Dual::new(1 as $t, 1 as $t), Dual::new(2 as $t, 0 as $t)];
+                    let b = Dual::new(2 as $t, 1 as $t);
+                    let res = <Vec<Dual<$t>> as ArgminDot<Dual<$t>, Vec<Dual<$t>>>>::dot(&a, &b);
+                    let res2 = <Dual<$t> as ArgminDot<Vec<Dual<$t>>, Vec<Dual<$t>>>>::dot(&b, &a);
+                    let target = [(2 as $t, 3 as $t), (4 as $t, 2 as $t)];
+                    for i in 0..2 {
+                        assert_relative_eq!(res[i].re, target[i].0, epsilon = <$t>::EPSILON);
+                        assert_relative_eq!(res[i].eps, target[i].1, epsilon = <$t>::EPSILON);
+                        assert_relative_eq!(res2[i].re, target[i].0, epsilon = <$t>::EPSILON);
+                        assert_relative_eq!(res2[i].eps, target[i].1, epsilon = <$t>::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_vec_vec_panic_ $t>]() {
+                    let a = vec![Dual::constant(1 as $t)];
+                    let b = vec![Dual::constant(1 as $t), Dual::constant(2 as $t)];
+                    <Vec<Dual<$t>> as ArgminDot<Vec<Dual<$t>>, Dual<$t>>>::dot(&a, &b);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,57 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminL2Norm, Dual};
+use num_traits::Float;
+
+impl<F: Float> ArgminL2Norm<Dual<F>> for Dual<F> {
+    #[inline]
+    fn l2_norm(&self) -> Dual<F> {
+        self.abs()
+    }
+}
+
+impl<F: Float> ArgminL2Norm<Dual<F>> for Vec<Dual<F>> {
+    #[inline]
+    fn l2_norm(&self) -> Dual<F> {
+        self.iter().map(|a| *a * *a).sum::<Dual<F>>().sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_l2_norm_ $t>]() {
+                    let a = vec![Dual::new(3 as $t, -1 as $t), Dual::new(-4 as $t, 1 as $t)];
+                    let res = <Vec<Dual<$t>> as ArgminL2Norm<Dual<$t>>>::l2_norm(&a);
+                    assert_relative_eq!(res.re, 5 as $t, epsilon = <$t>::EPSILON);
+                    assert_relative_eq!(res.eps, -1.4 as $t, epsilon = 4.0 * <$t>::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_l2_norm_scalar_ $t>]() {
+                    let a = Dual::new(-2 as $t, 1 as $t);
+                    let res = <Dual<$t> as ArgminL2Norm<Dual<$t>>>::l2_norm(&a);
+                    assert_relative_eq!(res.re, 2 as $t, epsilon = <$t>::EPSILON);
+                    assert_relative_eq!(res.eps, -1 as $t, epsilon = <$t>::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
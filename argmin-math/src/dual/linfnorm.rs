@@ -0,0 +1,57 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminLInfNorm, Dual};
+use num_traits::{Float, Zero};
+
+impl<F: Float> ArgminLInfNorm<Dual<F>> for Dual<F> {
+    #[inline]
+    fn linf_norm(&self) -> Dual<F> {
+        self.abs()
+    }
+}
+
+impl<F: Float> ArgminLInfNorm<Dual<F>> for Vec<Dual<F>> {
+    #[inline]
+    fn linf_norm(&self) -> Dual<F> {
+        self.iter().fold(Dual::zero(), |acc, a| acc.max(a.abs()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_linf_norm_ $t>]() {
+                    let a = vec![Dual::new(3 as $t, -1 as $t), Dual::new(-4 as $t, 1 as $t)];
+                    let res = <Vec<Dual<$t>> as ArgminLInfNorm<Dual<$t>>>::linf_norm(&a);
+                    assert_relative_eq!(res.re, 4 as $t, epsilon = <$t>::EPSILON);
+                    assert_relative_eq!(res.eps, -1 as $t, epsilon = <$t>::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_linf_norm_scalar_ $t>]() {
+                    let a = Dual::new(-2 as $t, 1 as $t);
+                    let res = <Dual<$t> as ArgminLInfNorm<Dual<$t>>>::linf_norm(&a);
+                    assert_relative_eq!(res.re, 2 as $t, epsilon = <$t>::EPSILON);
+                    assert_relative_eq!(res.eps, -1 as $t, epsilon = <$t>::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,888 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use num_traits::{Float, FloatConst, FromPrimitive, Num, NumCast, One, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::num::FpCategory;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+#[cfg(feature = "primitives-serde")]
+use serde::{Deserialize, Serialize};
+
+/// Dual number `re + eps * ε` with `ε² = 0`
+///
+/// Evaluating a function `f` at `Dual::new(x, 1)` yields `f(x) + f'(x) ε`, which makes it possible
+/// to compute exact derivatives of any function written generically over a [`Float`] type
+/// (forward-mode automatic differentiation). Since `Dual<F>` implements [`Float`] itself, duals can
+/// be nested (`Dual<Dual<F>>`) in order to obtain second order derivatives.
+///
+/// Comparisons (`PartialEq` and `PartialOrd`) only take the real part into account, such that
+/// branches in the differentiated function behave exactly as for the underlying float type.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "primitives-serde", derive(Serialize, Deserialize))]
+pub struct Dual<F> {
+    /// Real part
+    pub re: F,
+    /// Dual (derivative) part
+    pub eps: F,
+}
+
+impl<F> Dual<F> {
+    /// Construct a dual number from its real and dual part.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin_math::Dual;
+    /// let x = Dual::new(2.0f64, 1.0);
+    /// let y = x * x;
+    /// assert_eq!(y.re, 4.0);
+    /// assert_eq!(y.eps, 4.0);
+    /// ```
+    #[inline]
+    pub const fn new(re: F, eps: F) -> Self {
+        Dual { re, eps }
+    }
+}
+
+impl<F: Zero> Dual<F> {
+    /// Construct a constant, i.e. a dual number with a dual part of zero.
+    #[inline]
+    pub fn constant(re: F) -> Self {
+        Dual::new(re, F::zero())
+    }
+}
+
+impl<F: Zero + One> Dual<F> {
+    /// Construct a variable, i.e. a dual number with a dual part of one.
+    #[inline]
+    pub fn variable(re: F) -> Self {
+        Dual::new(re, F::one())
+    }
+}
+
+impl<F: Float> Dual<F> {
+    /// Apply a function with value `f` and derivative `df` at `self.re` via the chain rule.
+    #[inline]
+    fn chain(self, f: F, df: F) -> Self {
+        Dual::new(f, df * self.eps)
+    }
+}
+
+impl<F: Zero> From<F> for Dual<F> {
+    #[inline]
+    fn from(re: F) -> Self {
+        Dual::constant(re)
+    }
+}
+
+impl<F: fmt::Display> fmt::Display for Dual<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}ε", self.re, self.eps)
+    }
+}
+
+/// Only the real parts are compared, the dual parts are ignored.
+///
+/// ```
+/// # use argmin_math::Dual;
+/// assert_eq!(Dual::new(1.0f64, 2.0), Dual::new(1.0, 3.0));
+/// ```
+impl<F: PartialEq> PartialEq for Dual<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.re == other.re
+    }
+}
+
+impl<F: PartialOrd> PartialOrd for Dual<F> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.re.partial_cmp(&other.re)
+    }
+}
+
+impl<F: Float> Neg for Dual<F> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Dual::new(-self.re, -self.eps)
+    }
+}
+
+impl<F: Float> Add for Dual<F> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Dual::new(self.re + other.re, self.eps + other.eps)
+    }
+}
+
+impl<F: Float> Sub for Dual<F> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Dual::new(self.re - other.re, self.eps - other.eps)
+    }
+}
+
+impl<F: Float> Mul for Dual<F> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Dual::new(
+            self.re * other.re,
+            self.re * other.eps + self.eps * other.re,
+        )
+    }
+}
+
+impl<F: Float> Div for Dual<F> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Dual::new(
+            self.re / other.re,
+            (self.eps * other.re - self.re * other.eps) / (other.re * other.re),
+        )
+    }
+}
+
+impl<F: Float> Rem for Dual<F> {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, other: Self) -> Self {
+        Dual::new(
+            self.re % other.re,
+            self.eps - other.eps * (self.re / other.re).trunc(),
+        )
+    }
+}
+
+impl<F: Float> Add<F> for Dual<F> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: F) -> Self {
+        Dual::new(self.re + other, self.eps)
+    }
+}
+
+impl<F: Float> Sub<F> for Dual<F> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: F) -> Self {
+        Dual::new(self.re - other, self.eps)
+    }
+}
+
+impl<F: Float> Mul<F> for Dual<F> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: F) -> Self {
+        Dual::new(self.re * other, self.eps * other)
+    }
+}
+
+impl<F: Float> Div<F> for Dual<F> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: F) -> Self {
+        Dual::new(self.re / other, self.eps / other)
+    }
+}
+
+macro_rules! make_ref_ops {
+    ($trait:ident, $method:ident) => {
+        impl<'a, F: Float> $trait<&'a Dual<F>> for Dual<F> {
+            type Output = Dual<F>;
+
+            #[inline]
+            fn $method(self, other: &'a Dual<F>) -> Dual<F> {
+                $trait::$method(self, *other)
+            }
+        }
+
+        impl<'a, F: Float> $trait<Dual<F>> for &'a Dual<F> {
+            type Output = Dual<F>;
+
+            #[inline]
+            fn $method(self, other: Dual<F>) -> Dual<F> {
+                $trait::$method(*self, other)
+            }
+        }
+
+        impl<'a, 'b, F: Float> $trait<&'b Dual<F>> for &'a Dual<F> {
+            type Output = Dual<F>;
+
+            #[inline]
+            fn $method(self, other: &'b Dual<F>) -> Dual<F> {
+                $trait::$method(*self, *other)
+            }
+        }
+    };
+}
+
+make_ref_ops!(Add, add);
+make_ref_ops!(Sub, sub);
+make_ref_ops!(Mul, mul);
+make_ref_ops!(Div, div);
+make_ref_ops!(Rem, rem);
+
+macro_rules! make_assign_ops {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<F: Float> $trait for Dual<F> {
+            #[inline]
+            fn $method(&mut self, other: Dual<F>) {
+                *self = *self $op other;
+            }
+        }
+
+        impl<F: Float> $trait<F> for Dual<F> {
+            #[inline]
+            fn $method(&mut self, other: F) {
+                *self = *self $op Dual::constant(other);
+            }
+        }
+    };
+}
+
+make_assign_ops!(AddAssign, add_assign, +);
+make_assign_ops!(SubAssign, sub_assign, -);
+make_assign_ops!(MulAssign, mul_assign, *);
+make_assign_ops!(DivAssign, div_assign, /);
+make_assign_ops!(RemAssign, rem_assign, %);
+
+macro_rules! make_primitive_ops {
+    ($t:ty) => {
+        impl Add<Dual<$t>> for $t {
+            type Output = Dual<$t>;
+
+            #[inline]
+            fn add(self, other: Dual<$t>) -> Dual<$t> {
+                Dual::constant(self) + other
+            }
+        }
+
+        impl Sub<Dual<$t>> for $t {
+            type Output = Dual<$t>;
+
+            #[inline]
+            fn sub(self, other: Dual<$t>) -> Dual<$t> {
+                Dual::constant(self) - other
+            }
+        }
+
+        impl Mul<Dual<$t>> for $t {
+            type Output = Dual<$t>;
+
+            #[inline]
+            fn mul(self, other: Dual<$t>) -> Dual<$t> {
+                other * self
+            }
+        }
+
+        impl Div<Dual<$t>> for $t {
+            type Output = Dual<$t>;
+
+            #[inline]
+            fn div(self, other: Dual<$t>) -> Dual<$t> {
+                Dual::constant(self) / other
+            }
+        }
+    };
+}
+
+make_primitive_ops!(f32);
+make_primitive_ops!(f64);
+
+impl<F: Float> Sum for Dual<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Dual::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<'a, F: Float> Sum<&'a Dual<F>> for Dual<F> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Dual::zero(), |acc, x| acc + *x)
+    }
+}
+
+impl<F: Float> Product for Dual<F> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Dual::one(), |acc, x| acc * x)
+    }
+}
+
+impl<'a, F: Float> Product<&'a Dual<F>> for Dual<F> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Dual::one(), |acc, x| acc * *x)
+    }
+}
+
+impl<F: Float> Zero for Dual<F> {
+    #[inline]
+    fn zero() -> Self {
+        Dual::constant(F::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.eps.is_zero()
+    }
+}
+
+impl<F: Float> One for Dual<F> {
+    #[inline]
+    fn one() -> Self {
+        Dual::constant(F::one())
+    }
+}
+
+impl<F: Float> Num for Dual<F> {
+    type FromStrRadixErr = F::FromStrRadixErr;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        F::from_str_radix(s, radix).map(Dual::constant)
+    }
+}
+
+impl<F: ToPrimitive> ToPrimitive for Dual<F> {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.re.to_i64()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.re.to_u64()
+    }
+
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        self.re.to_f32()
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        self.re.to_f64()
+    }
+}
+
+impl<F: FromPrimitive + Zero> FromPrimitive for Dual<F> {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        F::from_i64(n).map(Dual::constant)
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        F::from_u64(n).map(Dual::constant)
+    }
+
+    #[inline]
+    fn from_f32(n: f32) -> Option<Self> {
+        F::from_f32(n).map(Dual::constant)
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        F::from_f64(n).map(Dual::constant)
+    }
+}
+
+impl<F: NumCast + Zero> NumCast for Dual<F> {
+    #[inline]
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        <F as NumCast>::from(n).map(Dual::constant)
+    }
+}
+
+macro_rules! make_float_const {
+    ($($c:ident),*) => {
+        impl<F: Float + FloatConst> FloatConst for Dual<F> {
+            $(
+                #[inline]
+                fn $c() -> Self {
+                    Dual::constant(F::$c())
+                }
+            )*
+        }
+    };
+}
+
+make_float_const!(
+    E,
+    FRAC_1_PI,
+    FRAC_1_SQRT_2,
+    FRAC_2_PI,
+    FRAC_2_SQRT_PI,
+    FRAC_PI_2,
+    FRAC_PI_3,
+    FRAC_PI_4,
+    FRAC_PI_6,
+    FRAC_PI_8,
+    LN_10,
+    LN_2,
+    LOG10_E,
+    LOG2_E,
+    PI,
+    SQRT_2,
+    TAU,
+    LOG10_2,
+    LOG2_10
+);
+
+impl<F: Float> Float for Dual<F> {
+    #[inline]
+    fn nan() -> Self {
+        Dual::constant(F::nan())
+    }
+
+    #[inline]
+    fn infinity() -> Self {
+        Dual::constant(F::infinity())
+    }
+
+    #[inline]
+    fn neg_infinity() -> Self {
+        Dual::constant(F::neg_infinity())
+    }
+
+    #[inline]
+    fn neg_zero() -> Self {
+        Dual::constant(F::neg_zero())
+    }
+
+    #[inline]
+    fn min_value() -> Self {
+        Dual::constant(F::min_value())
+    }
+
+    #[inline]
+    fn min_positive_value() -> Self {
+        Dual::constant(F::min_positive_value())
+    }
+
+    #[inline]
+    fn epsilon() -> Self {
+        Dual::constant(F::epsilon())
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Dual::constant(F::max_value())
+    }
+
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.re.is_nan() || self.eps.is_nan()
+    }
+
+    #[inline]
+    fn is_infinite(self) -> bool {
+        self.re.is_infinite() || self.eps.is_infinite()
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.re.is_finite() && self.eps.is_finite()
+    }
+
+    #[inline]
+    fn is_normal(self) -> bool {
+        self.re.is_normal()
+    }
+
+    #[inline]
+    fn classify(self) -> FpCategory {
+        self.re.classify()
+    }
+
+    #[inline]
+    fn floor(self) -> Self {
+        Dual::constant(self.re.floor())
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        Dual::constant(self.re.ceil())
+    }
+
+    #[inline]
+    fn round(self) -> Self {
+        Dual::constant(self.re.round())
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        Dual::constant(self.re.trunc())
+    }
+
+    #[inline]
+    fn fract(self) -> Self {
+        Dual::new(self.re.fract(), self.eps)
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        if self.re.is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn signum(self) -> Self {
+        Dual::constant(self.re.signum())
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        self.re.is_sign_positive()
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        self.re.is_sign_negative()
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    #[inline]
+    fn recip(self) -> Self {
+        let r = self.re.recip();
+        self.chain(r, -r * r)
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            return Dual::one();
+        }
+        let n_f = F::from(n).unwrap();
+        self.chain(self.re.powi(n), n_f * self.re.powi(n - 1))
+    }
+
+    #[inline]
+    fn powf(self, n: Self) -> Self {
+        if n.eps.is_zero() {
+            if n.re.is_zero() {
+                return Dual::one();
+            }
+            return self.chain(self.re.powf(n.re), n.re * self.re.powf(n.re - F::one()));
+        }
+        // d(x^n) = n x^(n-1) dx + x^n ln(x) dn
+        let p = self.re.powf(n.re);
+        let dx = if self.eps.is_zero() {
+            F::zero()
+        } else {
+            n.re * self.re.powf(n.re - F::one()) * self.eps
+        };
+        Dual::new(p, dx + p * self.re.ln() * n.eps)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        let s = self.re.sqrt();
+        self.chain(s, (s + s).recip())
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        let e = self.re.exp();
+        self.chain(e, e)
+    }
+
+    #[inline]
+    fn exp2(self) -> Self {
+        let e = self.re.exp2();
+        self.chain(e, e * F::from(2.0).unwrap().ln())
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        self.chain(self.re.ln(), self.re.recip())
+    }
+
+    #[inline]
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    #[inline]
+    fn log2(self) -> Self {
+        let ln2 = F::from(2.0).unwrap().ln();
+        self.chain(self.re.log2(), (self.re * ln2).recip())
+    }
+
+    #[inline]
+    fn log10(self) -> Self {
+        let ln10 = F::from(10.0).unwrap().ln();
+        self.chain(self.re.log10(), (self.re * ln10).recip())
+    }
+
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        if self.re.is_nan() || other.re > self.re {
+            other
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        if self.re.is_nan() || other.re < self.re {
+            other
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    #[allow(deprecated)]
+    fn abs_sub(self, other: Self) -> Self {
+        if self.re > other.re {
+            self - other
+        } else {
+            Dual::zero()
+        }
+    }
+
+    #[inline]
+    fn cbrt(self) -> Self {
+        let c = self.re.cbrt();
+        self.chain(c, (F::from(3.0).unwrap() * c * c).recip())
+    }
+
+    #[inline]
+    fn hypot(self, other: Self) -> Self {
+        let h = self.re.hypot(other.re);
+        Dual::new(h, (self.re * self.eps + other.re * other.eps) / h)
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        self.chain(self.re.sin(), self.re.cos())
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        self.chain(self.re.cos(), -self.re.sin())
+    }
+
+    #[inline]
+    fn tan(self) -> Self {
+        let t = self.re.tan();
+        self.chain(t, F::one() + t * t)
+    }
+
+    #[inline]
+    fn asin(self) -> Self {
+        self.chain(
+            self.re.asin(),
+            (F::one() - self.re * self.re).sqrt().recip(),
+        )
+    }
+
+    #[inline]
+    fn acos(self) -> Self {
+        self.chain(
+            self.re.acos(),
+            -(F::one() - self.re * self.re).sqrt().recip(),
+        )
+    }
+
+    #[inline]
+    fn atan(self) -> Self {
+        self.chain(self.re.atan(), (F::one() + self.re * self.re).recip())
+    }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        Dual::new(
+            self.re.atan2(other.re),
+            (self.eps * other.re - self.re * other.eps) / (self.re * self.re + other.re * other.re),
+        )
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        let (s, c) = self.re.sin_cos();
+        (self.chain(s, c), self.chain(c, -s))
+    }
+
+    #[inline]
+    fn exp_m1(self) -> Self {
+        self.chain(self.re.exp_m1(), self.re.exp())
+    }
+
+    #[inline]
+    fn ln_1p(self) -> Self {
+        self.chain(self.re.ln_1p(), (F::one() + self.re).recip())
+    }
+
+    #[inline]
+    fn sinh(self) -> Self {
+        self.chain(self.re.sinh(), self.re.cosh())
+    }
+
+    #[inline]
+    fn cosh(self) -> Self {
+        self.chain(self.re.cosh(), self.re.sinh())
+    }
+
+    #[inline]
+    fn tanh(self) -> Self {
+        let t = self.re.tanh();
+        self.chain(t, F::one() - t * t)
+    }
+
+    #[inline]
+    fn asinh(self) -> Self {
+        self.chain(
+            self.re.asinh(),
+            (self.re * self.re + F::one()).sqrt().recip(),
+        )
+    }
+
+    #[inline]
+    fn acosh(self) -> Self {
+        self.chain(
+            self.re.acosh(),
+            (self.re * self.re - F::one()).sqrt().recip(),
+        )
+    }
+
+    #[inline]
+    fn atanh(self) -> Self {
+        self.chain(self.re.atanh(), (F::one() - self.re * self.re).recip())
+    }
+
+    #[inline]
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.re.integer_decode()
+    }
+
+    #[inline]
+    fn to_degrees(self) -> Self {
+        Dual::new(self.re.to_degrees(), self.eps.to_degrees())
+    }
+
+    #[inline]
+    fn to_radians(self) -> Self {
+        Dual::new(self.re.to_radians(), self.eps.to_radians())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_arithmetic_ $t>]() {
+                    let x = Dual::variable(3.0 as $t);
+                    let y = Dual::constant(2.0 as $t);
+                    // f(x) = (x * 2 + x - 2) / x^2
+                    let f = (x * y + x - y) / (x * x);
+                    assert_relative_eq!(f.re, 7.0 as $t / 9.0 as $t, epsilon = <$t>::EPSILON);
+                    // f'(x) = -3 / x^2 + 4 / x^3
+                    assert_relative_eq!(
+                        f.eps,
+                        -3.0 as $t / 9.0 as $t + 4.0 as $t / 27.0 as $t,
+                        epsilon = <$t>::EPSILON
+                    );
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_elementary_functions_ $t>]() {
+                    let x0 = 0.5 as $t;
+                    let x = Dual::variable(x0);
+                    let cases: Vec<(Dual<$t>, $t, $t)> = vec![
+                        (x.sin(), x0.sin(), x0.cos()),
+                        (x.cos(), x0.cos(), -x0.sin()),
+                        (x.tan(), x0.tan(), 1.0 / (x0.cos() * x0.cos())),
+                        (x.exp(), x0.exp(), x0.exp()),
+                        (x.ln(), x0.ln(), 1.0 / x0),
+                        (x.sqrt(), x0.sqrt(), 0.5 / x0.sqrt()),
+                        (x.powi(3), x0.powi(3), 3.0 * x0 * x0),
+                        (x.powf(Dual::constant(2.5)), x0.powf(2.5), 2.5 * x0.powf(1.5)),
+                        (x.asin(), x0.asin(), 1.0 / (1.0 - x0 * x0).sqrt()),
+                        (x.atan(), x0.atan(), 1.0 / (1.0 + x0 * x0)),
+                        (x.tanh(), x0.tanh(), 1.0 - x0.tanh() * x0.tanh()),
+                        (x.recip(), 1.0 / x0, -1.0 / (x0 * x0)),
+                        (x.cbrt(), x0.cbrt(), 1.0 / (3.0 * x0.cbrt() * x0.cbrt())),
+                        (x.log10(), x0.log10(), 1.0 / (x0 * (10.0 as $t).ln())),
+                        (
+                            Dual::constant(2.0 as $t).powf(x),
+                            (2.0 as $t).powf(x0),
+                            (2.0 as $t).powf(x0) * (2.0 as $t).ln()
+                        ),
+                    ];
+                    for (f, re, eps) in cases {
+                        assert_relative_eq!(f.re, re, max_relative = 10.0 * <$t>::EPSILON);
+                        assert_relative_eq!(f.eps, eps, max_relative = 10.0 * <$t>::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_nested_ $t>]() {
+                    // f(x) = x^3, f'(x) = 3 x^2, f''(x) = 6 x
+                    let x = Dual::new(Dual::variable(2.0 as $t), Dual::constant(1.0 as $t));
+                    let f = x * x * x;
+                    assert_relative_eq!(f.re.re, 8.0 as $t, epsilon = <$t>::EPSILON);
+                    assert_relative_eq!(f.re.eps, 12.0 as $t, epsilon = <$t>::EPSILON);
+                    assert_relative_eq!(f.eps.re, 12.0 as $t, epsilon = <$t>::EPSILON);
+                    assert_relative_eq!(f.eps.eps, 12.0 as $t, epsilon = <$t>::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_comparison_ $t>]() {
+                    let a = Dual::new(1.0 as $t, 5.0);
+                    let b = Dual::new(1.0 as $t, -5.0);
+                    let c = Dual::new(2.0 as $t, 0.0);
+                    assert!(a == b);
+                    assert!(a < c);
+                    assert_relative_eq!(a.max(c).eps, 0.0 as $t, epsilon = <$t>::EPSILON);
+                    assert_relative_eq!(Dual::new(-1.0 as $t, 2.0).abs().eps, -2.0 as $t, epsilon = <$t>::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,53 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminZero, ArgminZeroLike, Dual};
+use num_traits::{Float, Zero};
+
+impl<F: Float> ArgminZero for Dual<F> {
+    #[inline]
+    fn zero() -> Dual<F> {
+        <Dual<F> as Zero>::zero()
+    }
+}
+
+impl<F: Float> ArgminZeroLike for Dual<F> {
+    #[inline]
+    fn zero_like(&self) -> Dual<F> {
+        <Dual<F> as Zero>::zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_zero_ $t>]() {
+                    let a = <Dual<$t> as ArgminZero>::zero();
+                    assert!(Zero::is_zero(&a));
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_zero_like_ $t>]() {
+                    let a = Dual::new(1 as $t, 2 as $t);
+                    let res = a.zero_like();
+                    assert!(Zero::is_zero(&res));
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,205 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminAdd;
+use faer::{Col, Mat};
+
+macro_rules! make_add {
+    ($t:ty) => {
+        impl ArgminAdd<$t, Col<$t>> for Col<$t> {
+            #[inline]
+            fn add(&self, other: &$t) -> Col<$t> {
+                Col::from_fn(self.nrows(), |i| self[i] + *other)
+            }
+        }
+
+        impl ArgminAdd<Col<$t>, Col<$t>> for $t {
+            #[inline]
+            fn add(&self, other: &Col<$t>) -> Col<$t> {
+                Col::from_fn(other.nrows(), |i| *self + other[i])
+            }
+        }
+
+        impl ArgminAdd<Col<$t>, Col<$t>> for Col<$t> {
+            #[inline]
+            fn add(&self, other: &Col<$t>) -> Col<$t> {
+                assert_eq!(self.nrows(), other.nrows());
+                self + other
+            }
+        }
+
+        impl ArgminAdd<Mat<$t>, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn add(&self, other: &Mat<$t>) -> Mat<$t> {
+                assert_eq!(self.shape(), other.shape());
+                self + other
+            }
+        }
+
+        impl ArgminAdd<$t, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn add(&self, other: &$t) -> Mat<$t> {
+                Mat::from_fn(self.nrows(), self.ncols(), |i, j| self[(i, j)] + *other)
+            }
+        }
+
+        impl ArgminAdd<Mat<$t>, Mat<$t>> for $t {
+            #[inline]
+            fn add(&self, other: &Mat<$t>) -> Mat<$t> {
+                Mat::from_fn(other.nrows(), other.ncols(), |i, j| *self + other[(i, j)])
+            }
+        }
+    };
+}
+
+make_add!(f32);
+make_add!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_add_vec_scalar_ $t>]() {
+                    let a = col![2 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = col![4 as $t, 6 as $t, 10 as $t];
+                    let res = <Col<$t> as ArgminAdd<$t, Col<$t>>>::add(&a, &b);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_scalar_vec_ $t>]() {
+                    let a = col![2 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = col![4 as $t, 6 as $t, 10 as $t];
+                    let res = <$t as ArgminAdd<Col<$t>, Col<$t>>>::add(&b, &a);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_vec_vec_ $t>]() {
+                    let a = col![2 as $t, 4 as $t, 8 as $t];
+                    let b = col![1 as $t, 2 as $t, 4 as $t];
+                    let target = col![3 as $t, 6 as $t, 12 as $t];
+                    let res = <Col<$t> as ArgminAdd<Col<$t>, Col<$t>>>::add(&a, &b);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_add_vec_vec_panic_ $t>]() {
+                    let a = col![2 as $t, 4 as $t];
+                    let b = col![1 as $t, 2 as $t, 4 as $t];
+                    <Col<$t> as ArgminAdd<Col<$t>, Col<$t>>>::add(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_mat_mat_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 16 as $t]
+                    ];
+                    let b = mat![
+                        [1 as $t, 2 as $t, 4 as $t],
+                        [2 as $t, 4 as $t, 8 as $t]
+                    ];
+                    let target = mat![
+                        [3 as $t, 6 as $t, 12 as $t],
+                        [6 as $t, 12 as $t, 24 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminAdd<Mat<$t>, Mat<$t>>>::add(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert!(((target[(i, j)] - res[(i, j)]) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_mat_scalar_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 16 as $t]
+                    ];
+                    let b = 2 as $t;
+                    let target = mat![
+                        [4 as $t, 6 as $t, 10 as $t],
+                        [6 as $t, 10 as $t, 18 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminAdd<$t, Mat<$t>>>::add(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert!(((target[(i, j)] - res[(i, j)]) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_scalar_mat_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 16 as $t]
+                    ];
+                    let b = 2 as $t;
+                    let target = mat![
+                        [4 as $t, 6 as $t, 10 as $t],
+                        [6 as $t, 10 as $t, 18 as $t]
+                    ];
+                    let res = <$t as ArgminAdd<Mat<$t>, Mat<$t>>>::add(&b, &a);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert!(((target[(i, j)] - res[(i, j)]) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_add_mat_mat_panic_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 16 as $t]
+                    ];
+                    let b = mat![
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 4 as $t]
+                    ];
+                    <Mat<$t> as ArgminAdd<Mat<$t>, Mat<$t>>>::add(&a, &b);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
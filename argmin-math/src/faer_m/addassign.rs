@@ -0,0 +1,139 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminAddAssign;
+use faer::{Col, Mat};
+
+macro_rules! make_addassign {
+    ($t:ty) => {
+        impl ArgminAddAssign<$t> for Col<$t> {
+            #[inline]
+            fn add_assign(&mut self, other: &$t) {
+                for i in 0..self.nrows() {
+                    self[i] += *other;
+                }
+            }
+        }
+
+        impl ArgminAddAssign<Col<$t>> for Col<$t> {
+            #[inline]
+            fn add_assign(&mut self, other: &Col<$t>) {
+                assert_eq!(self.nrows(), other.nrows());
+                for i in 0..self.nrows() {
+                    self[i] += other[i];
+                }
+            }
+        }
+
+        impl ArgminAddAssign<$t> for Mat<$t> {
+            #[inline]
+            fn add_assign(&mut self, other: &$t) {
+                for j in 0..self.ncols() {
+                    for i in 0..self.nrows() {
+                        self[(i, j)] += *other;
+                    }
+                }
+            }
+        }
+
+        impl ArgminAddAssign<Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn add_assign(&mut self, other: &Mat<$t>) {
+                assert_eq!(self.shape(), other.shape());
+                for j in 0..self.ncols() {
+                    for i in 0..self.nrows() {
+                        self[(i, j)] += other[(i, j)];
+                    }
+                }
+            }
+        }
+    };
+}
+
+make_addassign!(f32);
+make_addassign!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_addassign_vec_scalar_ $t>]() {
+                    let mut a = col![1 as $t, 4 as $t, 8 as $t];
+                    let b = 34 as $t;
+                    <Col<$t> as ArgminAddAssign<$t>>::add_assign(&mut a, &b);
+                    let target = [35 as $t, 38 as $t, 42 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_addassign_vec_vec_ $t>]() {
+                    let mut a = col![1 as $t, 4 as $t, 8 as $t];
+                    let b = col![41 as $t, 38 as $t, 34 as $t];
+                    <Col<$t> as ArgminAddAssign<Col<$t>>>::add_assign(&mut a, &b);
+                    let target = [42 as $t, 42 as $t, 42 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_addassign_vec_vec_panic_ $t>]() {
+                    let mut a = col![1 as $t, 4 as $t];
+                    let b = col![41 as $t, 38 as $t, 34 as $t];
+                    <Col<$t> as ArgminAddAssign<Col<$t>>>::add_assign(&mut a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_addassign_mat_mat_ $t>]() {
+                    let mut a = mat![[1 as $t, 4 as $t, 8 as $t], [2 as $t, 5 as $t, 9 as $t]];
+                    let b = mat![[41 as $t, 38 as $t, 34 as $t], [40 as $t, 37 as $t, 33 as $t]];
+                    <Mat<$t> as ArgminAddAssign<Mat<$t>>>::add_assign(&mut a, &b);
+                    let target = [[42 as $t, 42 as $t, 42 as $t], [42 as $t, 42 as $t, 42 as $t]];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_addassign_mat_scalar_ $t>]() {
+                    let mut a = mat![[1 as $t, 4 as $t, 8 as $t], [2 as $t, 5 as $t, 9 as $t]];
+                    let b = 2 as $t;
+                    <Mat<$t> as ArgminAddAssign<$t>>::add_assign(&mut a, &b);
+                    let target = [[3 as $t, 6 as $t, 10 as $t], [4 as $t, 7 as $t, 11 as $t]];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
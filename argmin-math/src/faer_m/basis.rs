@@ -0,0 +1,55 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminBasis;
+use faer::Col;
+
+macro_rules! make_basis {
+    ($t:ty) => {
+        impl ArgminBasis for Col<$t> {
+            fn basis_like(&self) -> Vec<Col<$t>> {
+                let n = self.nrows();
+                (0..n)
+                    .map(|i| Col::from_fn(n, |j| if i == j { 1.0 } else { 0.0 }))
+                    .collect()
+            }
+        }
+    };
+}
+
+make_basis!(f32);
+make_basis!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::col;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_basis_like_ $t>]() {
+                    let a = col![5 as $t, 6 as $t, 7 as $t];
+                    let basis = a.basis_like();
+                    assert_eq!(basis.len(), 3);
+                    for (i, e) in basis.iter().enumerate() {
+                        assert_eq!(e.nrows(), 3);
+                        for j in 0..3 {
+                            let expected = if i == j { 1 as $t } else { 0 as $t };
+                            assert!(((e[j] - expected) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,77 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::dense::LinalgError;
+use crate::{ArgminCholesky, Error};
+use faer::{Mat, Side};
+
+macro_rules! make_cholesky {
+    ($t:ty) => {
+        impl ArgminCholesky<Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn cholesky(&self) -> Result<Mat<$t>, Error> {
+                if self.nrows() != self.ncols() {
+                    return Err(LinalgError::NotSquare.into());
+                }
+                self.llt(Side::Lower)
+                    .map(|llt| llt.L().to_owned())
+                    .map_err(|_| LinalgError::NotPositiveDefinite.into())
+            }
+        }
+    };
+}
+
+make_cholesky!(f32);
+make_cholesky!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::mat;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_cholesky_ $t>]() {
+                    let a = mat![
+                        [4 as $t, 12 as $t, -16 as $t],
+                        [12 as $t, 37 as $t, -43 as $t],
+                        [-16 as $t, -43 as $t, 98 as $t]
+                    ];
+                    let target = mat![
+                        [2 as $t, 0 as $t, 0 as $t],
+                        [6 as $t, 1 as $t, 0 as $t],
+                        [-8 as $t, 5 as $t, 3 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminCholesky<Mat<$t>>>::cholesky(&a).unwrap();
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert!(((res[(i, j)] - target[(i, j)]) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_error_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 1 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminCholesky<Mat<$t>>>::cholesky(&a);
+                    assert_eq!(format!("{}", res.unwrap_err()), "Matrix is not positive definite");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,68 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminConj;
+use faer::{Col, Mat};
+
+macro_rules! make_conj {
+    ($t:ty) => {
+        impl ArgminConj for Col<$t> {
+            #[inline]
+            fn conj(&self) -> Col<$t> {
+                self.clone()
+            }
+        }
+
+        impl ArgminConj for Mat<$t> {
+            #[inline]
+            fn conj(&self) -> Mat<$t> {
+                self.clone()
+            }
+        }
+    };
+}
+
+make_conj!(f32);
+make_conj!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_conj_vec_ $t>]() {
+                    let a = col![1 as $t, -4 as $t, 8 as $t];
+                    let res = <Col<$t> as ArgminConj>::conj(&a);
+                    for i in 0..3 {
+                        assert!(((a[i] - res[i]) as f64).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_conj_mat_ $t>]() {
+                    let a = mat![[1 as $t, -4 as $t], [8 as $t, 3 as $t]];
+                    let res = <Mat<$t> as ArgminConj>::conj(&a);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!(((a[(i, j)] - res[(i, j)]) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,207 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiv;
+use faer::{Col, Mat};
+
+macro_rules! make_div {
+    ($t:ty) => {
+        impl ArgminDiv<$t, Col<$t>> for Col<$t> {
+            #[inline]
+            fn div(&self, other: &$t) -> Col<$t> {
+                Col::from_fn(self.nrows(), |i| self[i] / *other)
+            }
+        }
+
+        impl ArgminDiv<Col<$t>, Col<$t>> for $t {
+            #[inline]
+            fn div(&self, other: &Col<$t>) -> Col<$t> {
+                Col::from_fn(other.nrows(), |i| *self / other[i])
+            }
+        }
+
+        impl ArgminDiv<Col<$t>, Col<$t>> for Col<$t> {
+            #[inline]
+            fn div(&self, other: &Col<$t>) -> Col<$t> {
+                assert_eq!(self.nrows(), other.nrows());
+                Col::from_fn(self.nrows(), |i| self[i] / other[i])
+            }
+        }
+
+        impl ArgminDiv<Mat<$t>, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn div(&self, other: &Mat<$t>) -> Mat<$t> {
+                assert_eq!(self.shape(), other.shape());
+                Mat::from_fn(self.nrows(), self.ncols(), |i, j| {
+                    self[(i, j)] / other[(i, j)]
+                })
+            }
+        }
+
+        impl ArgminDiv<$t, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn div(&self, other: &$t) -> Mat<$t> {
+                Mat::from_fn(self.nrows(), self.ncols(), |i, j| self[(i, j)] / *other)
+            }
+        }
+
+        impl ArgminDiv<Mat<$t>, Mat<$t>> for $t {
+            #[inline]
+            fn div(&self, other: &Mat<$t>) -> Mat<$t> {
+                Mat::from_fn(other.nrows(), other.ncols(), |i, j| *self / other[(i, j)])
+            }
+        }
+    };
+}
+
+make_div!(f32);
+make_div!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_div_vec_scalar_ $t>]() {
+                    let a = col![2 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = col![1 as $t, 2 as $t, 4 as $t];
+                    let res = <Col<$t> as ArgminDiv<$t, Col<$t>>>::div(&a, &b);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_scalar_vec_ $t>]() {
+                    let a = col![2 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = col![1 as $t, 0.5 as $t, 0.25 as $t];
+                    let res = <$t as ArgminDiv<Col<$t>, Col<$t>>>::div(&b, &a);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_vec_vec_ $t>]() {
+                    let a = col![2 as $t, 4 as $t, 8 as $t];
+                    let b = col![1 as $t, 2 as $t, 4 as $t];
+                    let target = col![2 as $t, 2 as $t, 2 as $t];
+                    let res = <Col<$t> as ArgminDiv<Col<$t>, Col<$t>>>::div(&a, &b);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_div_vec_vec_panic_ $t>]() {
+                    let a = col![2 as $t, 4 as $t];
+                    let b = col![1 as $t, 2 as $t, 4 as $t];
+                    <Col<$t> as ArgminDiv<Col<$t>, Col<$t>>>::div(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_mat_mat_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 16 as $t]
+                    ];
+                    let b = mat![
+                        [1 as $t, 2 as $t, 4 as $t],
+                        [2 as $t, 4 as $t, 8 as $t]
+                    ];
+                    let target = mat![
+                        [2 as $t, 2 as $t, 2 as $t],
+                        [2 as $t, 2 as $t, 2 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminDiv<Mat<$t>, Mat<$t>>>::div(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert!(((target[(i, j)] - res[(i, j)]) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_mat_scalar_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 16 as $t]
+                    ];
+                    let b = 2 as $t;
+                    let target = mat![
+                        [1 as $t, 2 as $t, 4 as $t],
+                        [2 as $t, 4 as $t, 8 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminDiv<$t, Mat<$t>>>::div(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert!(((target[(i, j)] - res[(i, j)]) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_scalar_mat_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 16 as $t]
+                    ];
+                    let b = 2 as $t;
+                    let target = mat![
+                        [1 as $t, 0.5 as $t, 0.25 as $t],
+                        [0.5 as $t, 0.25 as $t, 0.125 as $t]
+                    ];
+                    let res = <$t as ArgminDiv<Mat<$t>, Mat<$t>>>::div(&b, &a);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert!(((target[(i, j)] - res[(i, j)]) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_div_mat_mat_panic_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 16 as $t]
+                    ];
+                    let b = mat![
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 4 as $t]
+                    ];
+                    <Mat<$t> as ArgminDiv<Mat<$t>, Mat<$t>>>::div(&a, &b);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,248 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDot;
+use faer::{Col, Mat};
+
+macro_rules! make_dot {
+    ($t:ty) => {
+        impl ArgminDot<Col<$t>, $t> for Col<$t> {
+            #[inline]
+            fn dot(&self, other: &Col<$t>) -> $t {
+                assert_eq!(self.nrows(), other.nrows());
+                self.iter().zip(other.iter()).map(|(a, b)| a * b).sum()
+            }
+        }
+
+        impl ArgminDot<$t, Col<$t>> for Col<$t> {
+            #[inline]
+            fn dot(&self, other: &$t) -> Col<$t> {
+                Col::from_fn(self.nrows(), |i| self[i] * *other)
+            }
+        }
+
+        impl ArgminDot<Col<$t>, Col<$t>> for $t {
+            #[inline]
+            fn dot(&self, other: &Col<$t>) -> Col<$t> {
+                Col::from_fn(other.nrows(), |i| *self * other[i])
+            }
+        }
+
+        impl ArgminDot<Col<$t>, Mat<$t>> for Col<$t> {
+            #[inline]
+            fn dot(&self, other: &Col<$t>) -> Mat<$t> {
+                Mat::from_fn(self.nrows(), other.nrows(), |i, j| self[i] * other[j])
+            }
+        }
+
+        impl ArgminDot<Col<$t>, Col<$t>> for Mat<$t> {
+            #[inline]
+            fn dot(&self, other: &Col<$t>) -> Col<$t> {
+                assert_eq!(self.ncols(), other.nrows());
+                self * other
+            }
+        }
+
+        impl ArgminDot<Mat<$t>, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn dot(&self, other: &Mat<$t>) -> Mat<$t> {
+                assert_eq!(self.ncols(), other.nrows());
+                self * other
+            }
+        }
+
+        // Frobenius inner product; allows to use single column `Mat`s as vectors
+        impl ArgminDot<Mat<$t>, $t> for Mat<$t> {
+            #[inline]
+            fn dot(&self, other: &Mat<$t>) -> $t {
+                assert_eq!(self.shape(), other.shape());
+                let mut sum = 0.0;
+                for j in 0..self.ncols() {
+                    for i in 0..self.nrows() {
+                        sum += self[(i, j)] * other[(i, j)];
+                    }
+                }
+                sum
+            }
+        }
+
+        impl ArgminDot<$t, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn dot(&self, other: &$t) -> Mat<$t> {
+                Mat::from_fn(self.nrows(), self.ncols(), |i, j| self[(i, j)] * *other)
+            }
+        }
+
+        impl ArgminDot<Mat<$t>, Mat<$t>> for $t {
+            #[inline]
+            fn dot(&self, other: &Mat<$t>) -> Mat<$t> {
+                Mat::from_fn(other.nrows(), other.ncols(), |i, j| *self * other[(i, j)])
+            }
+        }
+    };
+}
+
+make_dot!(f32);
+make_dot!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_vec_vec_ $t>]() {
+                    let a = col![1 as $t, 2 as $t, 3 as $t];
+                    let b = col![4 as $t, 5 as $t, 6 as $t];
+                    let res: $t = <Col<$t> as ArgminDot<Col<$t>, $t>>::dot(&a, &b);
+                    assert!((((res - 32 as $t) as f64).abs()) < f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_vec_vec_panic_ $t>]() {
+                    let a = col![1 as $t, 2 as $t];
+                    let b = col![4 as $t, 5 as $t, 6 as $t];
+                    <Col<$t> as ArgminDot<Col<$t>, $t>>::dot(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_vec_scalar_ $t>]() {
+                    let a = col![1 as $t, 2 as $t, 3 as $t];
+                    let b = 2 as $t;
+                    let product = <Col<$t> as ArgminDot<$t, Col<$t>>>::dot(&a, &b);
+                    let product2 = <$t as ArgminDot<Col<$t>, Col<$t>>>::dot(&b, &a);
+                    let res = col![2 as $t, 4 as $t, 6 as $t];
+                    for i in 0..3 {
+                        assert!((((res[i] - product[i]) as f64).abs()) < f64::EPSILON);
+                        assert!((((res[i] - product2[i]) as f64).abs()) < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_vec_ $t>]() {
+                    let a = col![1 as $t, 2 as $t, 3 as $t];
+                    let b = col![4 as $t, 5 as $t, 6 as $t];
+                    let res = mat![
+                        [4 as $t, 5 as $t, 6 as $t],
+                        [8 as $t, 10 as $t, 12 as $t],
+                        [12 as $t, 15 as $t, 18 as $t]
+                    ];
+                    let product = <Col<$t> as ArgminDot<Col<$t>, Mat<$t>>>::dot(&a, &b);
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert!((((res[(i, j)] - product[(i, j)]) as f64).abs()) < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_vec_2_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t]
+                    ];
+                    let b = col![1 as $t, 2 as $t, 3 as $t];
+                    let res = col![14 as $t, 32 as $t];
+                    let product = <Mat<$t> as ArgminDot<Col<$t>, Col<$t>>>::dot(&a, &b);
+                    for i in 0..2 {
+                        assert!((((res[i] - product[i]) as f64).abs()) < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_mat_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t]
+                    ];
+                    let b = mat![
+                        [1 as $t, 2 as $t],
+                        [3 as $t, 4 as $t],
+                        [5 as $t, 6 as $t]
+                    ];
+                    let res = mat![
+                        [22 as $t, 28 as $t],
+                        [49 as $t, 64 as $t]
+                    ];
+                    let product = <Mat<$t> as ArgminDot<Mat<$t>, Mat<$t>>>::dot(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!((((res[(i, j)] - product[(i, j)]) as f64).abs()) < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mat_mat_panic_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t]
+                    ];
+                    let b = mat![
+                        [1 as $t, 2 as $t],
+                        [3 as $t, 4 as $t]
+                    ];
+                    <Mat<$t> as ArgminDot<Mat<$t>, Mat<$t>>>::dot(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_mat_frobenius_ $t>]() {
+                    let a = mat![[1 as $t], [2 as $t], [3 as $t]];
+                    let b = mat![[4 as $t], [5 as $t], [6 as $t]];
+                    let res: $t = <Mat<$t> as ArgminDot<Mat<$t>, $t>>::dot(&a, &b);
+                    assert!((((res - 32 as $t) as f64).abs()) < f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_scalar_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t],
+                        [3 as $t, 4 as $t]
+                    ];
+                    let b = 2 as $t;
+                    let res = mat![
+                        [2 as $t, 4 as $t],
+                        [6 as $t, 8 as $t]
+                    ];
+                    let product = <Mat<$t> as ArgminDot<$t, Mat<$t>>>::dot(&a, &b);
+                    let product2 = <$t as ArgminDot<Mat<$t>, Mat<$t>>>::dot(&b, &a);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!((((res[(i, j)] - product[(i, j)]) as f64).abs()) < f64::EPSILON);
+                            assert!((((res[(i, j)] - product2[(i, j)]) as f64).abs()) < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,68 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminEye;
+use faer::Mat;
+
+macro_rules! make_eye {
+    ($t:ty) => {
+        impl ArgminEye for Mat<$t> {
+            #[inline]
+            fn eye_like(&self) -> Mat<$t> {
+                // TODO: Should return an error!
+                assert_eq!(self.nrows(), self.ncols());
+                Mat::identity(self.nrows(), self.ncols())
+            }
+
+            #[inline]
+            fn eye(n: usize) -> Mat<$t> {
+                Mat::identity(n, n)
+            }
+        }
+    };
+}
+
+make_eye!(f32);
+make_eye!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_eye_ $t>]() {
+                    let e: Mat<$t> = <Mat<$t> as ArgminEye>::eye(3);
+                    let e2: Mat<$t> = <Mat<$t> as ArgminEye>::eye_like(&Mat::zeros(3, 3));
+                    assert_eq!(e.shape(), (3, 3));
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            let target = if i == j { 1 as $t } else { 0 as $t };
+                            assert!(((e[(i, j)] - target) as f64).abs() < f64::EPSILON);
+                            assert!(((e2[(i, j)] - target) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_eye_like_panic_ $t>]() {
+                    let a: Mat<$t> = Mat::zeros(3, 2);
+                    let _ = <Mat<$t> as ArgminEye>::eye_like(&a);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,80 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::factorize;
+use crate::{ArgminInv, Error};
+use faer::linalg::solvers::DenseSolveCore;
+use faer::Mat;
+
+macro_rules! make_inv {
+    ($t:ty) => {
+        impl ArgminInv<Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn inv(&self) -> Result<Mat<$t>, Error> {
+                Ok(factorize(self)?.inverse())
+            }
+        }
+    };
+}
+
+make_inv!(f32);
+make_inv!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::mat;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_inv_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 5 as $t],
+                        [1 as $t, 3 as $t]
+                    ];
+                    let target = mat![
+                        [3 as $t, -5 as $t],
+                        [-1 as $t, 2 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminInv<Mat<$t>>>::inv(&a).unwrap();
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!((((res[(i, j)] - target[(i, j)]) as f64).abs()) < 1e-5);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_inv_error_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 5 as $t],
+                        [4 as $t, 10 as $t]
+                    ];
+                    let err = <Mat<$t> as ArgminInv<Mat<$t>>>::inv(&a).unwrap_err();
+                    assert_eq!(format!("{}", err), "Singular matrix");
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_inv_not_square_ $t>]() {
+                    let a: Mat<$t> = Mat::zeros(2, 3);
+                    let err = <Mat<$t> as ArgminInv<Mat<$t>>>::inv(&a).unwrap_err();
+                    assert_eq!(format!("{}", err), "Matrix must be square");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
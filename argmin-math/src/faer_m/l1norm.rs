@@ -0,0 +1,71 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminL1Norm;
+use faer::{Col, Mat};
+
+macro_rules! make_l1norm {
+    ($t:ty) => {
+        impl ArgminL1Norm<$t> for Col<$t> {
+            #[inline]
+            fn l1_norm(&self) -> $t {
+                self.iter().map(|a| a.abs()).sum()
+            }
+        }
+
+        // Sum of the absolute values of all entries
+        impl ArgminL1Norm<$t> for Mat<$t> {
+            #[inline]
+            fn l1_norm(&self) -> $t {
+                let mut sum = 0.0;
+                for j in 0..self.ncols() {
+                    for i in 0..self.nrows() {
+                        sum += self[(i, j)].abs();
+                    }
+                }
+                sum
+            }
+        }
+    };
+}
+
+make_l1norm!(f32);
+make_l1norm!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_norm_ $t>]() {
+                    let a = col![4 as $t, -3 as $t];
+                    let res = <Col<$t> as ArgminL1Norm<$t>>::l1_norm(&a);
+                    let target = 7 as $t;
+                    assert!(((target - res) as f64).abs() < f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_norm_mat_ $t>]() {
+                    let a = mat![[4 as $t], [-3 as $t]];
+                    let res = <Mat<$t> as ArgminL1Norm<$t>>::l1_norm(&a);
+                    let target = 7 as $t;
+                    assert!(((target - res) as f64).abs() < f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,65 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminL2Norm;
+use faer::{Col, Mat};
+
+macro_rules! make_l2norm {
+    ($t:ty) => {
+        impl ArgminL2Norm<$t> for Col<$t> {
+            #[inline]
+            fn l2_norm(&self) -> $t {
+                self.norm_l2()
+            }
+        }
+
+        // Frobenius norm
+        impl ArgminL2Norm<$t> for Mat<$t> {
+            #[inline]
+            fn l2_norm(&self) -> $t {
+                self.norm_l2()
+            }
+        }
+    };
+}
+
+make_l2norm!(f32);
+make_l2norm!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_norm_ $t>]() {
+                    let a = col![4 as $t, 3 as $t];
+                    let res = <Col<$t> as ArgminL2Norm<$t>>::l2_norm(&a);
+                    let target = 5 as $t;
+                    assert!(((target - res) as f64).abs() < 1e-6);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_norm_mat_ $t>]() {
+                    let a = mat![[4 as $t], [-3 as $t]];
+                    let res = <Mat<$t> as ArgminL2Norm<$t>>::l2_norm(&a);
+                    let target = 5 as $t;
+                    assert!(((target - res) as f64).abs() < 1e-6);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,64 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminLInfNorm;
+use faer::{Col, Mat};
+
+macro_rules! make_linfnorm {
+    ($t:ty) => {
+        impl ArgminLInfNorm<$t> for Col<$t> {
+            #[inline]
+            fn linf_norm(&self) -> $t {
+                self.norm_max()
+            }
+        }
+
+        impl ArgminLInfNorm<$t> for Mat<$t> {
+            #[inline]
+            fn linf_norm(&self) -> $t {
+                self.norm_max()
+            }
+        }
+    };
+}
+
+make_linfnorm!(f32);
+make_linfnorm!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_linfnorm_ $t>]() {
+                    let a = col![3 as $t, -4 as $t, 2 as $t];
+                    let res = <Col<$t> as ArgminLInfNorm<$t>>::linf_norm(&a);
+                    let target = 4 as $t;
+                    assert!(((target - res) as f64).abs() < 1e-6);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_linfnorm_mat_ $t>]() {
+                    let a = mat![[3 as $t, 1 as $t], [-5 as $t, 2 as $t]];
+                    let res = <Mat<$t> as ArgminLInfNorm<$t>>::linf_norm(&a);
+                    let target = 5 as $t;
+                    assert!(((target - res) as f64).abs() < 1e-6);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,75 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::factorize;
+use crate::{ArgminLU, Error};
+use faer::Mat;
+
+macro_rules! make_lu {
+    ($t:ty) => {
+        impl ArgminLU<Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn lu(&self) -> Result<(Mat<$t>, Mat<$t>, Mat<$t>), Error> {
+                let lu = factorize(self)?;
+                let (perm, _) = lu.P().arrays();
+                let n = self.nrows();
+                let p = Mat::from_fn(n, n, |i, j| if perm[i] == j { 1.0 } else { 0.0 });
+                Ok((p, lu.L().to_owned(), lu.U().to_owned()))
+            }
+        }
+    };
+}
+
+make_lu!(f32);
+make_lu!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::mat;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_lu_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t],
+                        [3 as $t, 4 as $t]
+                    ];
+                    let (p, l, u) = <Mat<$t> as ArgminLU<Mat<$t>>>::lu(&a).unwrap();
+                    let p_target = mat![[0 as $t, 1 as $t], [1 as $t, 0 as $t]];
+                    let l_target = mat![[1 as $t, 0 as $t], [1.0 as $t / 3.0, 1 as $t]];
+                    let u_target = mat![[3 as $t, 4 as $t], [0 as $t, 2.0 as $t / 3.0]];
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!(((p[(i, j)] - p_target[(i, j)]) as f64).abs() < 1e-5);
+                            assert!(((l[(i, j)] - l_target[(i, j)]) as f64).abs() < 1e-5);
+                            assert!(((u[(i, j)] - u_target[(i, j)]) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_lu_error_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 4 as $t]
+                    ];
+                    let err = <Mat<$t> as ArgminLU<Mat<$t>>>::lu(&a).unwrap_err();
+                    assert_eq!(format!("{}", err), "Singular matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,92 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod add;
+mod addassign;
+mod basis;
+mod cholesky;
+mod conj;
+mod div;
+mod dot;
+mod eye;
+mod inv;
+mod l1norm;
+mod l2norm;
+mod linfnorm;
+mod lu;
+mod minmax;
+mod mul;
+mod mulassign;
+mod outer;
+mod outofbounds;
+mod qr;
+mod random;
+mod rankoneupdate;
+mod ranktwoupdate;
+mod scaledaddassign;
+mod scaledsubassign;
+mod signum;
+mod solve;
+mod sub;
+mod subassign;
+mod transpose;
+mod weightedl2norm;
+mod zero;
+
+pub use add::*;
+pub use addassign::*;
+pub use basis::*;
+pub use cholesky::*;
+pub use conj::*;
+pub use div::*;
+pub use dot::*;
+pub use eye::*;
+pub use inv::*;
+pub use l1norm::*;
+pub use l2norm::*;
+pub use linfnorm::*;
+pub use lu::*;
+pub use minmax::*;
+pub use mul::*;
+pub use mulassign::*;
+pub use outer::*;
+pub use outofbounds::*;
+pub use qr::*;
+pub use random::*;
+pub use rankoneupdate::*;
+pub use ranktwoupdate::*;
+pub use scaledaddassign::*;
+pub use scaledsubassign::*;
+pub use signum::*;
+pub use solve::*;
+pub use sub::*;
+pub use subassign::*;
+pub use transpose::*;
+pub use weightedl2norm::*;
+pub use zero::*;
+
+use crate::dense::LinalgError;
+use crate::Error;
+use faer::linalg::solvers::PartialPivLu;
+use faer::Mat;
+use num_traits::Zero;
+
+/// LU decomposition of a square and nonsingular matrix.
+fn factorize<T>(a: &Mat<T>) -> Result<PartialPivLu<T>, Error>
+where
+    T: faer::traits::ComplexField + Zero,
+{
+    if a.nrows() != a.ncols() {
+        return Err(LinalgError::NotSquare.into());
+    }
+    let lu = a.partial_piv_lu();
+    let u = lu.U();
+    if (0..u.nrows()).any(|i| u[(i, i)].is_zero()) {
+        return Err(LinalgError::Singular.into());
+    }
+    Ok(lu)
+}
@@ -0,0 +1,207 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMul;
+use faer::{Col, Mat};
+
+macro_rules! make_mul {
+    ($t:ty) => {
+        impl ArgminMul<$t, Col<$t>> for Col<$t> {
+            #[inline]
+            fn mul(&self, other: &$t) -> Col<$t> {
+                Col::from_fn(self.nrows(), |i| self[i] * *other)
+            }
+        }
+
+        impl ArgminMul<Col<$t>, Col<$t>> for $t {
+            #[inline]
+            fn mul(&self, other: &Col<$t>) -> Col<$t> {
+                Col::from_fn(other.nrows(), |i| *self * other[i])
+            }
+        }
+
+        impl ArgminMul<Col<$t>, Col<$t>> for Col<$t> {
+            #[inline]
+            fn mul(&self, other: &Col<$t>) -> Col<$t> {
+                assert_eq!(self.nrows(), other.nrows());
+                Col::from_fn(self.nrows(), |i| self[i] * other[i])
+            }
+        }
+
+        impl ArgminMul<Mat<$t>, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn mul(&self, other: &Mat<$t>) -> Mat<$t> {
+                assert_eq!(self.shape(), other.shape());
+                Mat::from_fn(self.nrows(), self.ncols(), |i, j| {
+                    self[(i, j)] * other[(i, j)]
+                })
+            }
+        }
+
+        impl ArgminMul<$t, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn mul(&self, other: &$t) -> Mat<$t> {
+                Mat::from_fn(self.nrows(), self.ncols(), |i, j| self[(i, j)] * *other)
+            }
+        }
+
+        impl ArgminMul<Mat<$t>, Mat<$t>> for $t {
+            #[inline]
+            fn mul(&self, other: &Mat<$t>) -> Mat<$t> {
+                Mat::from_fn(other.nrows(), other.ncols(), |i, j| *self * other[(i, j)])
+            }
+        }
+    };
+}
+
+make_mul!(f32);
+make_mul!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_mul_vec_scalar_ $t>]() {
+                    let a = col![2 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = col![4 as $t, 8 as $t, 16 as $t];
+                    let res = <Col<$t> as ArgminMul<$t, Col<$t>>>::mul(&a, &b);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_scalar_vec_ $t>]() {
+                    let a = col![2 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = col![4 as $t, 8 as $t, 16 as $t];
+                    let res = <$t as ArgminMul<Col<$t>, Col<$t>>>::mul(&b, &a);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_vec_vec_ $t>]() {
+                    let a = col![2 as $t, 4 as $t, 8 as $t];
+                    let b = col![1 as $t, 2 as $t, 4 as $t];
+                    let target = col![2 as $t, 8 as $t, 32 as $t];
+                    let res = <Col<$t> as ArgminMul<Col<$t>, Col<$t>>>::mul(&a, &b);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mul_vec_vec_panic_ $t>]() {
+                    let a = col![2 as $t, 4 as $t];
+                    let b = col![1 as $t, 2 as $t, 4 as $t];
+                    <Col<$t> as ArgminMul<Col<$t>, Col<$t>>>::mul(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_mat_mat_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 16 as $t]
+                    ];
+                    let b = mat![
+                        [1 as $t, 2 as $t, 4 as $t],
+                        [2 as $t, 4 as $t, 8 as $t]
+                    ];
+                    let target = mat![
+                        [2 as $t, 8 as $t, 32 as $t],
+                        [8 as $t, 32 as $t, 128 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminMul<Mat<$t>, Mat<$t>>>::mul(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert!(((target[(i, j)] - res[(i, j)]) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_mat_scalar_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 16 as $t]
+                    ];
+                    let b = 2 as $t;
+                    let target = mat![
+                        [4 as $t, 8 as $t, 16 as $t],
+                        [8 as $t, 16 as $t, 32 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminMul<$t, Mat<$t>>>::mul(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert!(((target[(i, j)] - res[(i, j)]) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_scalar_mat_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 16 as $t]
+                    ];
+                    let b = 2 as $t;
+                    let target = mat![
+                        [4 as $t, 8 as $t, 16 as $t],
+                        [8 as $t, 16 as $t, 32 as $t]
+                    ];
+                    let res = <$t as ArgminMul<Mat<$t>, Mat<$t>>>::mul(&b, &a);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert!(((target[(i, j)] - res[(i, j)]) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mul_mat_mat_panic_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 16 as $t]
+                    ];
+                    let b = mat![
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 4 as $t]
+                    ];
+                    <Mat<$t> as ArgminMul<Mat<$t>, Mat<$t>>>::mul(&a, &b);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,53 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminOuter;
+use faer::{Col, Mat};
+
+macro_rules! make_outer {
+    ($t:ty) => {
+        impl ArgminOuter<Col<$t>, Mat<$t>> for Col<$t> {
+            #[inline]
+            fn outer(&self, other: &Col<$t>) -> Mat<$t> {
+                Mat::from_fn(self.nrows(), other.nrows(), |i, j| self[i] * other[j])
+            }
+        }
+    };
+}
+
+make_outer!(f32);
+make_outer!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_outer_ $t>]() {
+                    let a = col![1 as $t, 2 as $t, 3 as $t];
+                    let b = col![4 as $t, 5 as $t];
+                    let res = <Col<$t> as ArgminOuter<Col<$t>, Mat<$t>>>::outer(&a, &b);
+                    let target = [[4 as $t, 5 as $t], [8 as $t, 10 as $t], [12 as $t, 15 as $t]];
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[i][j] as f64, res[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
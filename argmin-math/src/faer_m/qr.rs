@@ -0,0 +1,59 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminQR, Error};
+use faer::Mat;
+
+macro_rules! make_qr {
+    ($t:ty) => {
+        impl ArgminQR<Mat<$t>, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn qr(&self) -> Result<(Mat<$t>, Mat<$t>), Error> {
+                let qr = self.qr();
+                Ok((qr.compute_thin_Q(), qr.thin_R().to_owned()))
+            }
+        }
+    };
+}
+
+make_qr!(f32);
+make_qr!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::mat;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_qr_ $t>]() {
+                    let a = mat![
+                        [3 as $t, 1 as $t],
+                        [4 as $t, 2 as $t],
+                        [0 as $t, 5 as $t]
+                    ];
+                    let (q, r) = <Mat<$t> as ArgminQR<Mat<$t>, Mat<$t>>>::qr(&a).unwrap();
+                    assert_eq!(q.shape(), (3, 2));
+                    assert_eq!(r.shape(), (2, 2));
+                    assert!((r[(1, 0)] as f64).abs() < 1e-5);
+                    let qr = &q * &r;
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert!(((qr[(i, j)] - a[(i, j)]) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,71 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminRankOneUpdate;
+use faer::{Col, Mat};
+
+macro_rules! make_rankoneupdate {
+    ($t:ty) => {
+        impl ArgminRankOneUpdate<Col<$t>, $t> for Mat<$t> {
+            #[inline]
+            fn rank_one_update(&mut self, alpha: &$t, x: &Col<$t>, y: &Col<$t>) {
+                assert_eq!(self.shape(), (x.nrows(), y.nrows()));
+                for j in 0..self.ncols() {
+                    let ayj = *alpha * y[j];
+                    for i in 0..self.nrows() {
+                        self[(i, j)] += x[i] * ayj;
+                    }
+                }
+            }
+        }
+    };
+}
+
+make_rankoneupdate!(f32);
+make_rankoneupdate!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_rankoneupdate_ $t>]() {
+                    let mut a = mat![[1 as $t, 2 as $t, 3 as $t], [4 as $t, 5 as $t, 6 as $t], [7 as $t, 8 as $t, 9 as $t]];
+                    let x = col![1 as $t, 2 as $t, 3 as $t];
+                    let y = col![4 as $t, 5 as $t, 6 as $t];
+                    <Mat<$t> as ArgminRankOneUpdate<Col<$t>, $t>>::rank_one_update(&mut a, &(2 as $t), &x, &y);
+                    let target = [[9 as $t, 12 as $t, 15 as $t], [20 as $t, 25 as $t, 30 as $t], [31 as $t, 38 as $t, 45 as $t]];
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_rankoneupdate_panic_ $t>]() {
+                    let mut a = mat![[1 as $t, 2 as $t, 3 as $t], [4 as $t, 5 as $t, 6 as $t], [7 as $t, 8 as $t, 9 as $t]];
+                    let x = col![1 as $t, 2 as $t, 3 as $t];
+                    let y = col![4 as $t, 5 as $t];
+                    <Mat<$t> as ArgminRankOneUpdate<Col<$t>, $t>>::rank_one_update(&mut a, &(2 as $t), &x, &y);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,130 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminScaledSubAssign;
+use faer::{Col, Mat};
+
+macro_rules! make_scaledsubassign {
+    ($t:ty) => {
+        impl ArgminScaledSubAssign<Col<$t>, $t> for Col<$t> {
+            #[inline]
+            fn scaled_sub_assign(&mut self, factor: &$t, vec: &Col<$t>) {
+                assert_eq!(self.nrows(), vec.nrows());
+                for i in 0..self.nrows() {
+                    self[i] -= *factor * vec[i];
+                }
+            }
+        }
+
+        impl ArgminScaledSubAssign<Col<$t>, Col<$t>> for Col<$t> {
+            #[inline]
+            fn scaled_sub_assign(&mut self, factor: &Col<$t>, vec: &Col<$t>) {
+                assert_eq!(self.nrows(), factor.nrows());
+                assert_eq!(self.nrows(), vec.nrows());
+                for i in 0..self.nrows() {
+                    self[i] -= factor[i] * vec[i];
+                }
+            }
+        }
+
+        impl ArgminScaledSubAssign<Mat<$t>, $t> for Mat<$t> {
+            #[inline]
+            fn scaled_sub_assign(&mut self, factor: &$t, vec: &Mat<$t>) {
+                assert_eq!(self.shape(), vec.shape());
+                for j in 0..self.ncols() {
+                    for i in 0..self.nrows() {
+                        self[(i, j)] -= *factor * vec[(i, j)];
+                    }
+                }
+            }
+        }
+    };
+}
+
+make_scaledsubassign!(f32);
+make_scaledsubassign!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scaledsubassign_vec_ $t>]() {
+                    let mut a = col![20 as $t, 20 as $t, 20 as $t];
+                    let f = 2 as $t;
+                    let b = col![4 as $t, 5 as $t, 6 as $t];
+                    <Col<$t> as ArgminScaledSubAssign<Col<$t>, $t>>::scaled_sub_assign(&mut a, &f, &b);
+                    let target = [12 as $t, 10 as $t, 8 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledsubassign_vec_panic_ $t>]() {
+                    let mut a = col![20 as $t, 20 as $t];
+                    let f = 2 as $t;
+                    let b = col![4 as $t, 5 as $t, 6 as $t];
+                    <Col<$t> as ArgminScaledSubAssign<Col<$t>, $t>>::scaled_sub_assign(&mut a, &f, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledsubassign_vec_vec_ $t>]() {
+                    let mut a = col![20 as $t, 20 as $t, 20 as $t];
+                    let f = col![3 as $t, 2 as $t, 1 as $t];
+                    let b = col![4 as $t, 5 as $t, 6 as $t];
+                    <Col<$t> as ArgminScaledSubAssign<Col<$t>, Col<$t>>>::scaled_sub_assign(&mut a, &f, &b);
+                    let target = [8 as $t, 10 as $t, 14 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledsubassign_vec_vec_panic_ $t>]() {
+                    let mut a = col![20 as $t, 20 as $t];
+                    let f = col![3 as $t, 2 as $t, 1 as $t];
+                    let b = col![4 as $t, 5 as $t, 6 as $t];
+                    <Col<$t> as ArgminScaledSubAssign<Col<$t>, Col<$t>>>::scaled_sub_assign(&mut a, &f, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledsubassign_mat_ $t>]() {
+                    let mut a = mat![[20 as $t, 20 as $t, 20 as $t], [30 as $t, 30 as $t, 30 as $t]];
+                    let f = 2 as $t;
+                    let b = mat![[4 as $t, 5 as $t, 6 as $t], [7 as $t, 8 as $t, 9 as $t]];
+                    <Mat<$t> as ArgminScaledSubAssign<Mat<$t>, $t>>::scaled_sub_assign(&mut a, &f, &b);
+                    let target = [[12 as $t, 10 as $t, 8 as $t], [16 as $t, 14 as $t, 12 as $t]];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
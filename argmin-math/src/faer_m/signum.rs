@@ -0,0 +1,78 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSignum;
+use faer::{Col, Mat};
+
+macro_rules! make_signum {
+    ($t:ty) => {
+        impl ArgminSignum for Col<$t> {
+            #[inline]
+            fn signum(mut self) -> Col<$t> {
+                for i in 0..self.nrows() {
+                    self[i] = self[i].signum();
+                }
+                self
+            }
+        }
+
+        impl ArgminSignum for Mat<$t> {
+            #[inline]
+            fn signum(mut self) -> Mat<$t> {
+                for j in 0..self.ncols() {
+                    for i in 0..self.nrows() {
+                        self[(i, j)] = self[(i, j)].signum();
+                    }
+                }
+                self
+            }
+        }
+    };
+}
+
+make_signum!(f32);
+make_signum!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_signum_vec_ $t>]() {
+                    let a = col![3 as $t, -4 as $t, -8 as $t];
+                    let b = col![1 as $t, -1 as $t, -1 as $t];
+                    let res = <Col<$t> as ArgminSignum>::signum(a);
+                    for i in 0..3 {
+                        assert!(((b[i] - res[i]) as f64).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_signum_mat_ $t>]() {
+                    let a = mat![[3 as $t, -4 as $t], [-8 as $t, 2 as $t]];
+                    let b = mat![[1 as $t, -1 as $t], [-1 as $t, 1 as $t]];
+                    let res = <Mat<$t> as ArgminSignum>::signum(a);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!(((b[(i, j)] - res[(i, j)]) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,101 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::factorize;
+use crate::dense::LinalgError;
+use crate::{ArgminSolve, Error};
+use faer::linalg::solvers::Solve;
+use faer::{Col, Mat};
+
+macro_rules! make_solve {
+    ($t:ty) => {
+        impl ArgminSolve<Col<$t>, Col<$t>> for Mat<$t> {
+            #[inline]
+            fn solve(&self, b: &Col<$t>) -> Result<Col<$t>, Error> {
+                if self.nrows() != b.nrows() {
+                    return Err(LinalgError::DimensionMismatch.into());
+                }
+                Ok(factorize(self)?.solve(b))
+            }
+        }
+
+        impl ArgminSolve<Mat<$t>, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn solve(&self, b: &Mat<$t>) -> Result<Mat<$t>, Error> {
+                if self.nrows() != b.nrows() {
+                    return Err(LinalgError::DimensionMismatch.into());
+                }
+                Ok(factorize(self)?.solve(b))
+            }
+        }
+    };
+}
+
+make_solve!(f32);
+make_solve!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_solve_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 5 as $t],
+                        [1 as $t, 3 as $t]
+                    ];
+                    let b = col![1 as $t, 2 as $t];
+                    let res = <Mat<$t> as ArgminSolve<Col<$t>, Col<$t>>>::solve(&a, &b).unwrap();
+                    let target = col![-7 as $t, 3 as $t];
+                    for i in 0..2 {
+                        assert!(((res[i] - target[i]) as f64).abs() < 1e-5);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_mat_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 5 as $t],
+                        [1 as $t, 3 as $t]
+                    ];
+                    let b = mat![[1 as $t], [2 as $t]];
+                    let res = <Mat<$t> as ArgminSolve<Mat<$t>, Mat<$t>>>::solve(&a, &b).unwrap();
+                    let target = mat![[-7 as $t], [3 as $t]];
+                    for i in 0..2 {
+                        assert!(((res[(i, 0)] - target[(i, 0)]) as f64).abs() < 1e-5);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_error_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 5 as $t],
+                        [4 as $t, 10 as $t]
+                    ];
+                    let b = col![1 as $t, 2 as $t];
+                    let err = <Mat<$t> as ArgminSolve<Col<$t>, Col<$t>>>::solve(&a, &b).unwrap_err();
+                    assert_eq!(format!("{}", err), "Singular matrix");
+                    let b = col![1 as $t, 2 as $t, 3 as $t];
+                    let err = <Mat<$t> as ArgminSolve<Col<$t>, Col<$t>>>::solve(&a, &b).unwrap_err();
+                    assert_eq!(format!("{}", err), "Dimensions do not match");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,205 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSub;
+use faer::{Col, Mat};
+
+macro_rules! make_sub {
+    ($t:ty) => {
+        impl ArgminSub<$t, Col<$t>> for Col<$t> {
+            #[inline]
+            fn sub(&self, other: &$t) -> Col<$t> {
+                Col::from_fn(self.nrows(), |i| self[i] - *other)
+            }
+        }
+
+        impl ArgminSub<Col<$t>, Col<$t>> for $t {
+            #[inline]
+            fn sub(&self, other: &Col<$t>) -> Col<$t> {
+                Col::from_fn(other.nrows(), |i| *self - other[i])
+            }
+        }
+
+        impl ArgminSub<Col<$t>, Col<$t>> for Col<$t> {
+            #[inline]
+            fn sub(&self, other: &Col<$t>) -> Col<$t> {
+                assert_eq!(self.nrows(), other.nrows());
+                self - other
+            }
+        }
+
+        impl ArgminSub<Mat<$t>, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn sub(&self, other: &Mat<$t>) -> Mat<$t> {
+                assert_eq!(self.shape(), other.shape());
+                self - other
+            }
+        }
+
+        impl ArgminSub<$t, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn sub(&self, other: &$t) -> Mat<$t> {
+                Mat::from_fn(self.nrows(), self.ncols(), |i, j| self[(i, j)] - *other)
+            }
+        }
+
+        impl ArgminSub<Mat<$t>, Mat<$t>> for $t {
+            #[inline]
+            fn sub(&self, other: &Mat<$t>) -> Mat<$t> {
+                Mat::from_fn(other.nrows(), other.ncols(), |i, j| *self - other[(i, j)])
+            }
+        }
+    };
+}
+
+make_sub!(f32);
+make_sub!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sub_vec_scalar_ $t>]() {
+                    let a = col![2 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = col![0 as $t, 2 as $t, 6 as $t];
+                    let res = <Col<$t> as ArgminSub<$t, Col<$t>>>::sub(&a, &b);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_scalar_vec_ $t>]() {
+                    let a = col![2 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = col![0 as $t, -2 as $t, -6 as $t];
+                    let res = <$t as ArgminSub<Col<$t>, Col<$t>>>::sub(&b, &a);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_vec_vec_ $t>]() {
+                    let a = col![2 as $t, 4 as $t, 8 as $t];
+                    let b = col![1 as $t, 2 as $t, 4 as $t];
+                    let target = col![1 as $t, 2 as $t, 4 as $t];
+                    let res = <Col<$t> as ArgminSub<Col<$t>, Col<$t>>>::sub(&a, &b);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_sub_vec_vec_panic_ $t>]() {
+                    let a = col![2 as $t, 4 as $t];
+                    let b = col![1 as $t, 2 as $t, 4 as $t];
+                    <Col<$t> as ArgminSub<Col<$t>, Col<$t>>>::sub(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_mat_mat_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 16 as $t]
+                    ];
+                    let b = mat![
+                        [1 as $t, 2 as $t, 4 as $t],
+                        [2 as $t, 4 as $t, 8 as $t]
+                    ];
+                    let target = mat![
+                        [1 as $t, 2 as $t, 4 as $t],
+                        [2 as $t, 4 as $t, 8 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminSub<Mat<$t>, Mat<$t>>>::sub(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert!(((target[(i, j)] - res[(i, j)]) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_mat_scalar_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 16 as $t]
+                    ];
+                    let b = 2 as $t;
+                    let target = mat![
+                        [0 as $t, 2 as $t, 6 as $t],
+                        [2 as $t, 6 as $t, 14 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminSub<$t, Mat<$t>>>::sub(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert!(((target[(i, j)] - res[(i, j)]) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_scalar_mat_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 16 as $t]
+                    ];
+                    let b = 2 as $t;
+                    let target = mat![
+                        [0 as $t, -2 as $t, -6 as $t],
+                        [-2 as $t, -6 as $t, -14 as $t]
+                    ];
+                    let res = <$t as ArgminSub<Mat<$t>, Mat<$t>>>::sub(&b, &a);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert!(((target[(i, j)] - res[(i, j)]) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_sub_mat_mat_panic_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 16 as $t]
+                    ];
+                    let b = mat![
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 4 as $t]
+                    ];
+                    <Mat<$t> as ArgminSub<Mat<$t>, Mat<$t>>>::sub(&a, &b);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,78 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminTranspose;
+use faer::{Col, Mat};
+
+macro_rules! make_transpose {
+    ($t:ty) => {
+        impl ArgminTranspose<Col<$t>> for Col<$t> {
+            #[inline]
+            fn t(self) -> Col<$t> {
+                self
+            }
+        }
+
+        impl ArgminTranspose<Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn t(self) -> Mat<$t> {
+                self.transpose().to_owned()
+            }
+        }
+    };
+}
+
+make_transpose!(f32);
+make_transpose!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_transpose_vec_ $t>]() {
+                    let a = col![1 as $t, 4 as $t];
+                    let target = col![1 as $t, 4 as $t];
+                    let res = <Col<$t> as ArgminTranspose<Col<$t>>>::t(a);
+                    for i in 0..2 {
+                        assert!(((target[i] - res[i]) as f64).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_transpose_mat_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let target = mat![
+                        [1 as $t, 2 as $t],
+                        [4 as $t, 5 as $t],
+                        [8 as $t, 9 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminTranspose<Mat<$t>>>::t(a);
+                    assert_eq!(res.shape(), (3, 2));
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert!(((target[(i, j)] - res[(i, j)]) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,61 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminWeightedL2Norm;
+use faer::Col;
+
+macro_rules! make_weightedl2norm {
+    ($t:ty) => {
+        impl ArgminWeightedL2Norm<Col<$t>, $t> for Col<$t> {
+            #[inline]
+            fn weighted_l2_norm(&self, w: &Col<$t>) -> $t {
+                assert_eq!(self.nrows(), w.nrows());
+                (0..self.nrows())
+                    .map(|i| w[i] * self[i].powi(2))
+                    .sum::<$t>()
+                    .sqrt()
+            }
+        }
+    };
+}
+
+make_weightedl2norm!(f32);
+make_weightedl2norm!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::col;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_weightedl2norm_ $t>]() {
+                    let a = col![1 as $t, -2 as $t, 3 as $t];
+                    let w = col![4 as $t, 1 as $t, 0.5 as $t];
+                    let res = <Col<$t> as ArgminWeightedL2Norm<Col<$t>, $t>>::weighted_l2_norm(&a, &w);
+                    assert!((((12.5 as $t).sqrt() - res) as f64).abs() < 1e-6);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_weightedl2norm_panic_ $t>]() {
+                    let a = col![1 as $t, -2 as $t, 3 as $t];
+                    let w = col![4 as $t, 1 as $t];
+                    <Col<$t> as ArgminWeightedL2Norm<Col<$t>, $t>>::weighted_l2_norm(&a, &w);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
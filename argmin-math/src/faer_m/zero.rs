@@ -0,0 +1,68 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminZeroLike;
+use faer::{Col, Mat};
+
+macro_rules! make_zero {
+    ($t:ty) => {
+        impl ArgminZeroLike for Col<$t> {
+            #[inline]
+            fn zero_like(&self) -> Col<$t> {
+                Col::zeros(self.nrows())
+            }
+        }
+
+        impl ArgminZeroLike for Mat<$t> {
+            #[inline]
+            fn zero_like(&self) -> Mat<$t> {
+                Mat::zeros(self.nrows(), self.ncols())
+            }
+        }
+    };
+}
+
+make_zero!(f32);
+make_zero!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_zero_like_vec_ $t>]() {
+                    let a = col![42 as $t, 42 as $t].zero_like();
+                    assert_eq!(a.nrows(), 2);
+                    for i in 0..2 {
+                        assert!(((a[i] - 0 as $t) as f64).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_zero_like_mat_ $t>]() {
+                    let a = mat![[42 as $t, 42 as $t, 42 as $t], [42 as $t, 42 as $t, 42 as $t]].zero_like();
+                    assert_eq!(a.shape(), (2, 3));
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert!(((a[(i, j)] - 0 as $t) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
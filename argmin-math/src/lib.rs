@@ -35,7 +35,23 @@
 //! | Feature                | Default | Comment                                               |
 //! |------------------------|---------|-------------------------------------------------------|
 //! | `primitives`           | yes     | basic integer and floating point types                |
+//! | `primitives-serde`     | no      | serde support for `Dual`                              |
 //! | `vec`                  | yes     | `Vec`s (basic functionality)                          |
+//! | `array`                | no      | fixed-size arrays `[F; N]` and `[[F; N]; M]`          |
+//!
+//! The `primitives` feature also provides the dual number type `Dual<F>`, which implements
+//! `num_traits::Float` and can therefore be used for forward-mode automatic differentiation of
+//! functions written generically over the float type. The traits are implemented for `Dual<F>` and
+//! `Vec<Dual<F>>`. Serialization of `Dual<F>` requires the `primitives-serde` feature.
+//!
+//! The `array` backend implements the traits for `[F; N]` (vectors) and `[[F; N]; M]` (row-major
+//! matrices with `M` rows and `N` columns) with `f32` and `f64` elements. Since the dimensions are
+//! known at compile time, none of the operations allocate, including `ArgminInv`, `ArgminSolve`
+//! and the decompositions. This makes it well suited for many small problems. `ArgminEye::eye(n)`
+//! panics if `n` does not match the size of the array and `ArgminQR` requires `M >= N`. Note that
+//! the transpose of a `[[F; N]; M]` is a `[[F; M]; N]`, therefore solvers which require the
+//! transpose of a matrix to be of the same type (such as `GaussNewton` for Jacobians) can only be
+//! used with square matrices.
 //!
 //! ### `ndarray`
 //!
@@ -65,6 +81,10 @@
 //! not needed in order to keep the compilation times low and avoid problems when linking against a
 //! BLAS library.
 //!
+//! The linear system solver and factorizations (`ArgminSolve`, `ArgminCholesky`, `ArgminLU` and
+//! `ArgminQR`) are implemented in pure Rust and are therefore also available with the
+//! `*-nolinalg*` features. Solvers such as `Newton` and `GaussNewton` only require those.
+//!
 //! Using the `ndarray_*` features with `ndarray-linalg` support may require to explicitly choose
 //! the `ndarray-linalg` BLAS backend in your `Cargo.toml` (see the [`ndarray-linalg` documentation
 //! for details](https://github.com/rust-ndarray/ndarray-linalg)):
@@ -88,6 +108,39 @@
 //! | `nalgebra_v0_29`       | no      | version 0.29                             |
 //! | `nalgebra_v0_29-serde` | no      | version 0.29 + serde support             |
 //!
+//! ### `faer`
+//!
+//! | Feature             | Default | Comment                                  |
+//! |---------------------|---------|------------------------------------------|
+//! | `faer_latest`       | no      | latest supported version                 |
+//! | `faer_latest-serde` | no      | latest supported version + serde support |
+//! | `faer_v0_23`        | no      | version 0.23                             |
+//! | `faer_v0_23-serde`  | no      | version 0.23 + serde support             |
+//! | `faer_v0_22`        | no      | version 0.22                             |
+//! | `faer_v0_22-serde`  | no      | version 0.22 + serde support             |
+//!
+//! The traits are implemented for `faer::Col` (vectors) and `faer::Mat` (matrices) with `f32` and
+//! `f64` elements. Since `faer` only supports serialization of `Mat`, parameter vectors should be
+//! represented as `Mat`s with a single column if `serde` support is needed. For this reason, all
+//! vector operations are also available for `Mat`. In that case the dot product of two `Mat`s
+//! yielding a scalar is the Frobenius inner product and the norms are computed over all entries.
+//!
+//! ### `sprs`
+//!
+//! | Feature             | Default | Comment                                  |
+//! |---------------------|---------|------------------------------------------|
+//! | `sprs_latest`       | no      | latest supported version                 |
+//! | `sprs_latest-serde` | no      | latest supported version + serde support |
+//! | `sprs_v0_11`        | no      | version 0.11                             |
+//! | `sprs_v0_11-serde`  | no      | version 0.11 + serde support             |
+//!
+//! Sparse matrices (`sprs::CsMat` with `f32` and `f64` elements, in CSR or CSC storage) can be
+//! used as Jacobians and Hessians together with `Vec`s as parameter vectors. This covers the
+//! matrix-vector and matrix-matrix products, the transpose, addition and subtraction as well as
+//! scaling by a scalar, which is what solvers such as `GaussNewton` and `NewtonCG` require.
+//! `ArgminSolve` converts the matrix into a dense matrix and is therefore only suitable for small
+//! systems such as the normal equations of the Gauss-Newton method.
+//!
 //!
 //! ## Choosing a backend
 //!
@@ -199,6 +252,17 @@ cfg_if::cfg_if! {
     }
 }
 
+cfg_if::cfg_if! {
+    if #[cfg(feature = "faer_0_23")] {
+        extern crate faer_0_23 as faer;
+    } else if #[cfg(feature = "faer_0_22")] {
+        extern crate faer_0_22 as faer;
+    }
+}
+
+#[cfg(feature = "sprs_0_11")]
+extern crate sprs_0_11 as sprs;
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "num-complex_0_2")] {
         extern crate num_complex_0_2 as num_complex;
@@ -209,27 +273,57 @@ cfg_if::cfg_if! {
     }
 }
 
+#[cfg(feature = "primitives")]
+mod dense;
+
+mod shape;
+pub use crate::shape::*;
+
+#[cfg(test)]
+mod conformance;
+
+#[cfg(feature = "primitives")]
+mod dual;
+#[cfg(feature = "primitives")]
+pub use crate::dual::*;
+
 #[cfg(feature = "primitives")]
 mod primitives;
 #[cfg(feature = "primitives")]
 pub use crate::primitives::*;
 
+#[cfg(feature = "array")]
+mod array;
+#[cfg(feature = "array")]
+pub use crate::array::*;
+
 #[cfg(feature = "ndarray_all")]
 mod ndarray_m;
 #[cfg(feature = "ndarray_all")]
 pub use crate::ndarray_m::*;
 
+#[cfg(feature = "faer_all")]
+mod faer_m;
+#[cfg(feature = "faer_all")]
+pub use crate::faer_m::*;
+
 #[cfg(feature = "nalgebra_all")]
 mod nalgebra_m;
 #[cfg(feature = "nalgebra_all")]
 pub use crate::nalgebra_m::*;
 
+#[cfg(feature = "sprs_all")]
+mod sprs_m;
+#[cfg(feature = "sprs_all")]
+pub use crate::sprs_m::*;
+
 #[cfg(feature = "vec")]
 mod vec;
 #[cfg(feature = "vec")]
 pub use crate::vec::*;
 
 use anyhow::Error;
+use rand::Rng;
 
 /// Dot/scalar product of `T` and `self`
 pub trait ArgminDot<T, U> {
@@ -243,6 +337,28 @@ pub trait ArgminWeightedDot<T, U, V> {
     fn weighted_dot(&self, w: &V, vec: &T) -> U;
 }
 
+/// Outer product of `self` and `T` (`self * T^T`), resulting in a matrix `U`
+pub trait ArgminOuter<T, U> {
+    /// Outer product of `self` and `T`
+    fn outer(&self, other: &T) -> U;
+}
+
+/// In-place rank-one update `self += alpha * x * y^T` (BLAS `ger`)
+///
+/// `x` and `y` are of the same type `T`, which means that for backends which encode the dimension
+/// in the type (fixed-size arrays, static `nalgebra` matrices) this is only implemented for square
+/// matrices.
+pub trait ArgminRankOneUpdate<T, U> {
+    /// Add `alpha * x * y^T` to `self`
+    fn rank_one_update(&mut self, alpha: &U, x: &T, y: &T);
+}
+
+/// In-place symmetric rank-two update `self += alpha * (x * y^T + y * x^T)` (BLAS `syr2`)
+pub trait ArgminRankTwoUpdate<T, U> {
+    /// Add `alpha * (x * y^T + y * x^T)` to `self`
+    fn rank_two_update(&mut self, alpha: &U, x: &T, y: &T);
+}
+
 /// Return param vector of all zeros (for now, this is a hack. It should be done better)
 pub trait ArgminZero {
     /// Return zero(s)
@@ -272,6 +388,13 @@ pub trait ArgminEye {
     fn eye_like(&self) -> Self;
 }
 
+/// Standard basis
+pub trait ArgminBasis: Sized {
+    /// Returns the standard basis of the space `self` belongs to, i.e. all elements of the same
+    /// shape as `self` with a single entry equal to one and all other entries equal to zero.
+    fn basis_like(&self) -> Vec<Self>;
+}
+
 /// Add a `T` to `self`
 pub trait ArgminAdd<T, U> {
     /// Add a `T` to `self`
@@ -308,6 +431,83 @@ pub trait ArgminScaledSub<T, U, V> {
     fn scaled_sub(&self, factor: &U, vec: &T) -> V;
 }
 
+/// Add a `T` to `self` in place
+pub trait ArgminAddAssign<T> {
+    /// Add a `T` to `self` in place
+    fn add_assign(&mut self, other: &T);
+}
+
+/// Subtract a `T` from `self` in place
+pub trait ArgminSubAssign<T> {
+    /// Subtract a `T` from `self` in place
+    fn sub_assign(&mut self, other: &T);
+}
+
+/// (Pointwise) Multiply `self` with a `T` in place
+pub trait ArgminMulAssign<T> {
+    /// (Pointwise) Multiply `self` with a `T` in place
+    fn mul_assign(&mut self, other: &T);
+}
+
+/// Add a `T` scaled by an `U` to `self` in place (`axpy`)
+pub trait ArgminScaledAddAssign<T, U> {
+    /// Add a `T` scaled by an `U` to `self` in place
+    fn scaled_add_assign(&mut self, factor: &U, vec: &T);
+}
+
+/// Subtract a `T` scaled by an `U` from `self` in place
+pub trait ArgminScaledSubAssign<T, U> {
+    /// Subtract a `T` scaled by an `U` from `self` in place
+    fn scaled_sub_assign(&mut self, factor: &U, vec: &T);
+}
+
+/// Shape of scalars, vectors and matrices
+///
+/// Used by the fallible `ArgminTry*` operations to check whether the shapes of the operands are
+/// compatible before the operation is carried out.
+pub trait ArgminShape {
+    /// Number of dimensions (0 for scalars, 1 for vectors and 2 for matrices)
+    const NDIM: usize;
+
+    /// Size along each of the `NDIM` dimensions
+    ///
+    /// Fails if `self` is malformed, for instance a `Vec<Vec<T>>` whose rows differ in length.
+    fn shape(&self) -> Result<Vec<usize>, Error>;
+}
+
+/// Add a `T` to `self`, failing with a [`ShapeError`] if the shapes are incompatible
+pub trait ArgminTryAdd<T, U> {
+    /// Add a `T` to `self`
+    fn try_add(&self, other: &T) -> Result<U, Error>;
+}
+
+/// Subtract a `T` from `self`, failing with a [`ShapeError`] if the shapes are incompatible
+pub trait ArgminTrySub<T, U> {
+    /// Subtract a `T` from `self`
+    fn try_sub(&self, other: &T) -> Result<U, Error>;
+}
+
+/// (Pointwise) Multiply a `T` with `self`, failing with a [`ShapeError`] if the shapes are
+/// incompatible
+pub trait ArgminTryMul<T, U> {
+    /// (Pointwise) Multiply a `T` with `self`
+    fn try_mul(&self, other: &T) -> Result<U, Error>;
+}
+
+/// (Pointwise) Divide a `T` by `self`, failing with a [`ShapeError`] if the shapes are
+/// incompatible
+pub trait ArgminTryDiv<T, U> {
+    /// (Pointwise) Divide a `T` by `self`
+    fn try_div(&self, other: &T) -> Result<U, Error>;
+}
+
+/// Dot/scalar product of `T` and `self`, failing with a [`ShapeError`] if the shapes are
+/// incompatible
+pub trait ArgminTryDot<T, U> {
+    /// Dot/scalar product of `T` and `self`
+    fn try_dot(&self, other: &T) -> Result<U, Error>;
+}
+
 /// Compute the l1-norm (`U`) of `self`
 pub trait ArgminL1Norm<U> {
     /// Compute the l1-norm (`U`) of `self`
@@ -320,6 +520,22 @@ pub trait ArgminL2Norm<U> {
     fn l2_norm(&self) -> U;
 }
 
+/// Compute the l-infinity-norm (`U`) of `self`, i.e. the largest absolute value of its elements
+pub trait ArgminLInfNorm<U> {
+    /// Compute the l-infinity-norm (`U`) of `self`
+    fn linf_norm(&self) -> U;
+}
+
+/// Compute the l2-norm (`U`) of `self`, weighted elementwise by `W`
+///
+/// The weighted norm is `sqrt(sum_i w_i * |x_i|^2)`, which corresponds to a diagonal scaling of
+/// the parameter space. Norms induced by a general symmetric positive definite matrix `M` (e.g. a
+/// preconditioner) can be computed via [`ArgminWeightedDot`] as `sqrt(x^T M x)`.
+pub trait ArgminWeightedL2Norm<W, U> {
+    /// Compute the l2-norm (`U`) of `self`, weighted elementwise by `w`
+    fn weighted_l2_norm(&self, w: &W) -> U;
+}
+
 // Sub-optimal: self is moved. ndarray however offers array views...
 /// Return the transpose (`U`) of `self`
 pub trait ArgminTranspose<U> {
@@ -333,10 +549,60 @@ pub trait ArgminInv<T> {
     fn inv(&self) -> Result<T, Error>;
 }
 
+/// Solve the linear system `self * x = b` for `x` (`X`), where `self` is a square matrix
+///
+/// Prefer this over computing the inverse via [`ArgminInv`] and multiplying, which is slower and
+/// numerically less accurate.
+///
+/// The implementation for sparse `sprs` matrices copies the matrix into a dense matrix and uses a
+/// dense LU decomposition, which needs memory and time quadratic and cubic in the dimension,
+/// respectively. It is therefore only suitable for small systems.
+pub trait ArgminSolve<B, X> {
+    /// Solve `self * x = b` for `x`
+    fn solve(&self, b: &B) -> Result<X, Error>;
+}
+
+/// Cholesky decomposition of a symmetric (Hermitian) positive definite matrix
+pub trait ArgminCholesky<L> {
+    /// Compute the lower triangular matrix `L` such that `self = L * L^T`
+    ///
+    /// Fails if `self` is not positive definite.
+    fn cholesky(&self) -> Result<L, Error>;
+}
+
+/// LU decomposition with partial pivoting of a square matrix
+pub trait ArgminLU<T> {
+    /// Compute the permutation matrix `P`, the unit lower triangular matrix `L` and the upper
+    /// triangular matrix `U` such that `P * self = L * U`, returned as `(P, L, U)`
+    ///
+    /// Fails if `self` is singular.
+    fn lu(&self) -> Result<(T, T, T), Error>;
+}
+
+/// QR decomposition of a matrix
+pub trait ArgminQR<Q, R> {
+    /// Compute the (reduced) QR decomposition `self = Q * R` where `Q` has orthonormal columns
+    /// and `R` is upper triangular, returned as `(Q, R)`
+    fn qr(&self) -> Result<(Q, R), Error>;
+}
+
 /// Create a random number
 pub trait ArgminRandom {
     /// Get a random element between min and max,
     fn rand_from_range(min: &Self, max: &Self) -> Self;
+
+    /// Get a random element between min and max, drawn from the random number generator `rng`.
+    ///
+    /// The default implementation ignores `rng` and calls
+    /// [`rand_from_range`](`ArgminRandom::rand_from_range`), therefore results are only
+    /// reproducible for types which override this method. All types provided by `argmin-math`
+    /// do.
+    fn rand_from_range_with_rng<R: Rng + ?Sized>(min: &Self, max: &Self, _rng: &mut R) -> Self
+    where
+        Self: Sized,
+    {
+        Self::rand_from_range(min, max)
+    }
 }
 
 /// Minimum and Maximum of type `T`
@@ -347,6 +613,13 @@ pub trait ArgminMinMax {
     fn max(x: &Self, y: &Self) -> Self;
 }
 
+/// Elementwise indicator of violated bounds
+pub trait ArgminOutOfBounds {
+    /// Returns `1` for each element of `self` which lies outside of `[lower, upper]` and `0`
+    /// otherwise.
+    fn out_of_bounds(&self, lower: &Self, upper: &Self) -> Self;
+}
+
 /// Returns a number that represents the sign of `self`.
 pub trait ArgminSignum {
     /// Returns a number that represents the sign of `self`.
@@ -0,0 +1,94 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminBasis;
+
+use num_traits::{One, Zero};
+
+use nalgebra::{
+    base::{allocator::Allocator, dimension::Dim},
+    DefaultAllocator, OMatrix, Scalar,
+};
+
+impl<N, R, C> ArgminBasis for OMatrix<N, R, C>
+where
+    N: Scalar + Zero + One,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    fn basis_like(&self) -> Vec<OMatrix<N, R, C>> {
+        let (nrows, ncols) = self.shape();
+        (0..nrows * ncols)
+            .map(|i| {
+                let mut e = Self::zeros_generic(R::from_usize(nrows), C::from_usize(ncols));
+                e[i] = N::one();
+                e
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{DVector, Matrix2, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_basis_like_ $t>]() {
+                    let a = Vector3::new(5 as $t, 6 as $t, 7 as $t);
+                    let basis = a.basis_like();
+                    assert_eq!(basis.len(), 3);
+                    for (i, e) in basis.iter().enumerate() {
+                        for j in 0..3 {
+                            let expected = if i == j { 1 as $t } else { 0 as $t };
+                            assert!(((e[j] - expected) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_basis_like_dynamic_ $t>]() {
+                    let a = DVector::from_vec(vec![5 as $t, 6 as $t]);
+                    let basis = a.basis_like();
+                    assert_eq!(basis.len(), 2);
+                    assert_eq!(basis[0], DVector::from_vec(vec![1 as $t, 0 as $t]));
+                    assert_eq!(basis[1], DVector::from_vec(vec![0 as $t, 1 as $t]));
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_basis_like_matrix_ $t>]() {
+                    let a = Matrix2::new(5 as $t, 6 as $t, 7 as $t, 8 as $t);
+                    let basis = a.basis_like();
+                    assert_eq!(basis.len(), 4);
+                    // column-major order
+                    assert_eq!(basis[1], Matrix2::new(0 as $t, 0 as $t, 1 as $t, 0 as $t));
+                    assert_eq!(basis[2], Matrix2::new(0 as $t, 1 as $t, 0 as $t, 0 as $t));
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,78 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::dense::LinalgError;
+use crate::{ArgminCholesky, Error};
+use nalgebra::{
+    base::{allocator::Allocator, dimension::Dim},
+    Cholesky, ComplexField, DefaultAllocator, OMatrix,
+};
+
+impl<N, D> ArgminCholesky<OMatrix<N, D, D>> for OMatrix<N, D, D>
+where
+    N: ComplexField,
+    D: Dim,
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    #[inline]
+    fn cholesky(&self) -> Result<OMatrix<N, D, D>, Error> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare.into());
+        }
+        Cholesky::new(self.clone())
+            .map(|cholesky| cholesky.unpack())
+            .ok_or_else(|| LinalgError::NotPositiveDefinite.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{Matrix2, Matrix3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_cholesky_ $t>]() {
+                    let a = Matrix3::new(
+                        4 as $t, 12 as $t, -16 as $t,
+                        12 as $t, 37 as $t, -43 as $t,
+                        -16 as $t, -43 as $t, 98 as $t,
+                    );
+                    let target = Matrix3::new(
+                        2 as $t, 0 as $t, 0 as $t,
+                        6 as $t, 1 as $t, 0 as $t,
+                        -8 as $t, 5 as $t, 3 as $t,
+                    );
+                    let res = <Matrix3<$t> as ArgminCholesky<Matrix3<$t>>>::cholesky(&a).unwrap();
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert!(((res[(i, j)] - target[(i, j)]) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_error_ $t>]() {
+                    let a = Matrix2::new(
+                        1 as $t, 2 as $t,
+                        2 as $t, 1 as $t,
+                    );
+                    let res = <Matrix2<$t> as ArgminCholesky<Matrix2<$t>>>::cholesky(&a);
+                    assert_eq!(format!("{}", res.unwrap_err()), "Matrix is not positive definite");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
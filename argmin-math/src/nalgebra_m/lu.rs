@@ -0,0 +1,70 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::dense::LinalgError;
+use crate::{ArgminLU, Error};
+use nalgebra::{
+    base::{allocator::Allocator, dimension::DimMin},
+    ComplexField, DefaultAllocator, OMatrix, LU,
+};
+
+impl<N, D> ArgminLU<OMatrix<N, D, D>> for OMatrix<N, D, D>
+where
+    N: ComplexField,
+    D: DimMin<D, Output = D>,
+    DefaultAllocator: Allocator<N, D, D> + Allocator<(usize, usize), D>,
+{
+    #[inline]
+    fn lu(&self) -> Result<(OMatrix<N, D, D>, OMatrix<N, D, D>, OMatrix<N, D, D>), Error> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare.into());
+        }
+        let lu = LU::new(self.clone());
+        if !lu.is_invertible() {
+            return Err(LinalgError::Singular.into());
+        }
+        let (nrows, ncols) = self.shape_generic();
+        let mut p = OMatrix::identity_generic(nrows, ncols);
+        lu.p().permute_rows(&mut p);
+        Ok((p, lu.l(), lu.u()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::Matrix2;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_lu_ $t>]() {
+                    let a = Matrix2::new(
+                        1 as $t, 2 as $t,
+                        3 as $t, 4 as $t,
+                    );
+                    let (p, l, u) = <Matrix2<$t> as ArgminLU<Matrix2<$t>>>::lu(&a).unwrap();
+                    let p_target = Matrix2::new(0 as $t, 1 as $t, 1 as $t, 0 as $t);
+                    let l_target = Matrix2::new(1 as $t, 0 as $t, 1.0 as $t / 3.0, 1 as $t);
+                    let u_target = Matrix2::new(3 as $t, 4 as $t, 0 as $t, 2.0 as $t / 3.0);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!(((p[(i, j)] - p_target[(i, j)]) as f64).abs() < 1e-5);
+                            assert!(((l[(i, j)] - l_target[(i, j)]) as f64).abs() < 1e-5);
+                            assert!(((u[(i, j)] - u_target[(i, j)]) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,68 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminOuter;
+
+use nalgebra::{
+    base::{allocator::Allocator, dimension::Dim, storage::Storage, Scalar},
+    ClosedMul, DefaultAllocator, OMatrix, Vector,
+};
+
+impl<N, R1, R2, SA, SB> ArgminOuter<Vector<N, R2, SB>, OMatrix<N, R1, R2>> for Vector<N, R1, SA>
+where
+    N: Scalar + ClosedMul,
+    R1: Dim,
+    R2: Dim,
+    SA: Storage<N, R1>,
+    SB: Storage<N, R2>,
+    DefaultAllocator: Allocator<N, R1, R2>,
+{
+    #[inline]
+    fn outer(&self, other: &Vector<N, R2, SB>) -> OMatrix<N, R1, R2> {
+        OMatrix::from_fn_generic(self.shape_generic().0, other.shape_generic().0, |i, j| {
+            self[i].clone() * other[j].clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::{Matrix3x2, Vector2, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_outer_ $t>]() {
+                    let a = Vector3::new(1 as $t, 2 as $t, 3 as $t);
+                    let b = Vector2::new(4 as $t, 5 as $t);
+                    let res = <Vector3<$t> as ArgminOuter<Vector2<$t>, Matrix3x2<$t>>>::outer(&a, &b);
+                    let target = [[4 as $t, 5 as $t], [8 as $t, 10 as $t], [12 as $t, 15 as $t]];
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[i][j] as f64, res[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
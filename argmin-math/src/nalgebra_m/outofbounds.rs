@@ -0,0 +1,64 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminOutOfBounds;
+
+use nalgebra::{
+    base::{allocator::Allocator, dimension::Dim, Scalar},
+    DefaultAllocator, OMatrix,
+};
+use num_traits::{One, Zero};
+
+impl<N, R, C> ArgminOutOfBounds for OMatrix<N, R, C>
+where
+    N: Scalar + Copy + PartialOrd + Zero + One,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    #[inline]
+    fn out_of_bounds(&self, lower: &Self, upper: &Self) -> OMatrix<N, R, C> {
+        assert_eq!(self.shape(), lower.shape());
+        assert_eq!(self.shape(), upper.shape());
+        self.zip_zip_map(lower, upper, |x, l, u| {
+            if x < l || x > u {
+                N::one()
+            } else {
+                N::zero()
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::Vector5;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_out_of_bounds_vec_ $t>]() {
+                    let x = Vector5::new(-1.5 as $t, -1.0 as $t, 0.5 as $t, 2.0 as $t, 2.5 as $t);
+                    let lower = Vector5::from_element(-1.0 as $t);
+                    let upper = Vector5::from_element(2.0 as $t);
+                    let target = [1.0, 0.0, 0.0, 0.0, 1.0];
+                    let res = <Vector5<$t> as ArgminOutOfBounds>::out_of_bounds(&x, &lower, &upper);
+                    for i in 0..5 {
+                        assert_relative_eq!(res[i] as f64, target[i], epsilon = f64::EPSILON);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
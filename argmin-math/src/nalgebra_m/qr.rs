@@ -0,0 +1,76 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminQR, Error};
+use nalgebra::{
+    base::{
+        allocator::Allocator,
+        dimension::{Dim, DimMin, DimMinimum},
+    },
+    ComplexField, DefaultAllocator, OMatrix, QR,
+};
+
+impl<N, R, C> ArgminQR<OMatrix<N, R, DimMinimum<R, C>>, OMatrix<N, DimMinimum<R, C>, C>>
+    for OMatrix<N, R, C>
+where
+    N: ComplexField,
+    R: DimMin<C>,
+    C: Dim,
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, R>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<N, R, DimMinimum<R, C>>
+        + Allocator<N, DimMinimum<R, C>, C>,
+{
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn qr(
+        &self,
+    ) -> Result<
+        (
+            OMatrix<N, R, DimMinimum<R, C>>,
+            OMatrix<N, DimMinimum<R, C>, C>,
+        ),
+        Error,
+    > {
+        let qr = QR::new(self.clone());
+        Ok((qr.q(), qr.r()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{Matrix2, Matrix3x2};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_qr_ $t>]() {
+                    let a = Matrix3x2::new(
+                        3 as $t, 1 as $t,
+                        4 as $t, 2 as $t,
+                        0 as $t, 5 as $t,
+                    );
+                    let (q, r) = <Matrix3x2<$t> as ArgminQR<Matrix3x2<$t>, Matrix2<$t>>>::qr(&a).unwrap();
+                    assert!((r[(1, 0)] as f64).abs() < 1e-5);
+                    let qr = q * r;
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert!(((qr[(i, j)] - a[(i, j)]) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -22,12 +22,19 @@ where
     DefaultAllocator: Allocator<N, R, C>,
 {
     #[inline]
-    fn rand_from_range(min: &Self, max: &Self) -> OMatrix<N, R, C> {
+    fn rand_from_range(min: &Self, max: &Self) -> Self {
+        Self::rand_from_range_with_rng(min, max, &mut rand::thread_rng())
+    }
+
+    #[inline]
+    fn rand_from_range_with_rng<G: Rng + ?Sized>(
+        min: &Self,
+        max: &Self,
+        rng: &mut G,
+    ) -> OMatrix<N, R, C> {
         assert!(!min.is_empty());
         assert_eq!(min.shape(), max.shape());
 
-        let mut rng = rand::thread_rng();
-
         Self::from_iterator_generic(
             R::from_usize(min.nrows()),
             C::from_usize(min.ncols()),
@@ -53,6 +60,7 @@ mod tests {
     use super::*;
     use nalgebra::{Matrix2x3, Vector3};
     use paste::item;
+    use rand::{rngs::StdRng, SeedableRng};
 
     macro_rules! make_test {
         ($t:ty) => {
@@ -69,6 +77,23 @@ mod tests {
                 }
             }
 
+            item! {
+                #[test]
+                fn [<test_random_vec_with_rng_ $t>]() {
+                    let a = Vector3::new(1 as $t, 2 as $t, 3 as $t);
+                    let b = Vector3::new(2 as $t, 3 as $t, 4 as $t);
+                    let mut rng1 = StdRng::seed_from_u64(42);
+                    let mut rng2 = StdRng::seed_from_u64(42);
+                    let random1 = Vector3::<$t>::rand_from_range_with_rng(&a, &b, &mut rng1);
+                    let random2 = Vector3::<$t>::rand_from_range_with_rng(&a, &b, &mut rng2);
+                    for i in 0..3 {
+                        assert!(random1[i] >= a[i]);
+                        assert!(random1[i] <= b[i]);
+                    }
+                    assert_eq!(random1, random2);
+                }
+            }
+
             item! {
                 #[test]
                 fn [<test_random_vec_equal $t>]() {
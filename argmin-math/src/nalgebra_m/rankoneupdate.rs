@@ -0,0 +1,90 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminRankOneUpdate;
+
+use num_traits::{One, Zero};
+
+use nalgebra::{
+    base::{
+        constraint::{DimEq, ShapeConstraint},
+        dimension::Dim,
+        storage::{Storage, StorageMut},
+        Scalar,
+    },
+    ClosedAdd, ClosedMul, Matrix, Vector,
+};
+
+impl<N, R, C, D, S, SB> ArgminRankOneUpdate<Vector<N, D, SB>, N> for Matrix<N, R, C, S>
+where
+    N: Scalar + Zero + One + ClosedAdd + ClosedMul,
+    R: Dim,
+    C: Dim,
+    D: Dim,
+    S: StorageMut<N, R, C>,
+    SB: Storage<N, D>,
+    ShapeConstraint: DimEq<R, D> + DimEq<C, D>,
+{
+    #[inline]
+    fn rank_one_update(&mut self, alpha: &N, x: &Vector<N, D, SB>, y: &Vector<N, D, SB>) {
+        self.ger(alpha.clone(), x, y, N::one());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::{DMatrix, DVector, Matrix3, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_rankoneupdate_ $t>]() {
+                    let mut a = Matrix3::new(
+                        1 as $t, 2 as $t, 3 as $t,
+                        4 as $t, 5 as $t, 6 as $t,
+                        7 as $t, 8 as $t, 9 as $t,
+                    );
+                    let x = Vector3::new(1 as $t, 2 as $t, 3 as $t);
+                    let y = Vector3::new(4 as $t, 5 as $t, 6 as $t);
+                    <Matrix3<$t> as ArgminRankOneUpdate<Vector3<$t>, $t>>::rank_one_update(&mut a, &(2 as $t), &x, &y);
+                    let target = [[9 as $t, 12 as $t, 15 as $t], [20 as $t, 25 as $t, 30 as $t], [31 as $t, 38 as $t, 45 as $t]];
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_rankoneupdate_panic_ $t>]() {
+                    let mut a = DMatrix::<$t>::zeros(3, 3);
+                    let x = DVector::from_vec(vec![1 as $t, 2 as $t, 3 as $t]);
+                    let y = DVector::from_vec(vec![4 as $t, 5 as $t]);
+                    <DMatrix<$t> as ArgminRankOneUpdate<DVector<$t>, $t>>::rank_one_update(&mut a, &(2 as $t), &x, &y);
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
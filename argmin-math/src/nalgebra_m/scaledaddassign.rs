@@ -0,0 +1,150 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminScaledAddAssign;
+
+use nalgebra::{
+    base::{
+        constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint},
+        dimension::Dim,
+        storage::{Storage, StorageMut},
+        Scalar,
+    },
+    ClosedAdd, ClosedMul, Matrix,
+};
+
+impl<N, R1, C1, R2, C2, SA, SB> ArgminScaledAddAssign<Matrix<N, R2, C2, SB>, N>
+    for Matrix<N, R1, C1, SA>
+where
+    N: Scalar + ClosedAdd + ClosedMul + Copy,
+    R1: Dim,
+    C1: Dim,
+    R2: Dim,
+    C2: Dim,
+    SA: StorageMut<N, R1, C1>,
+    SB: Storage<N, R2, C2>,
+    ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+{
+    #[inline]
+    fn scaled_add_assign(&mut self, factor: &N, vec: &Matrix<N, R2, C2, SB>) {
+        assert_eq!(self.shape(), vec.shape());
+        self.iter_mut()
+            .zip(vec.iter())
+            .for_each(|(a, b)| *a += *factor * *b);
+    }
+}
+
+impl<N, R1, C1, R2, C2, SA, SB> ArgminScaledAddAssign<Matrix<N, R2, C2, SB>, Matrix<N, R2, C2, SB>>
+    for Matrix<N, R1, C1, SA>
+where
+    N: Scalar + ClosedAdd + ClosedMul + Copy,
+    R1: Dim,
+    C1: Dim,
+    R2: Dim,
+    C2: Dim,
+    SA: StorageMut<N, R1, C1>,
+    SB: Storage<N, R2, C2>,
+    ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+{
+    #[inline]
+    fn scaled_add_assign(&mut self, factor: &Matrix<N, R2, C2, SB>, vec: &Matrix<N, R2, C2, SB>) {
+        assert_eq!(self.shape(), factor.shape());
+        assert_eq!(self.shape(), vec.shape());
+        self.iter_mut()
+            .zip(factor.iter().zip(vec.iter()))
+            .for_each(|(a, (f, b))| *a += *f * *b);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::{DVector, Matrix2x3, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scaledaddassign_vec_ $t>]() {
+                    let mut a = Vector3::new(1 as $t, 2 as $t, 3 as $t);
+                    let f = 2 as $t;
+                    let b = Vector3::new(4 as $t, 5 as $t, 6 as $t);
+                    <Vector3<$t> as ArgminScaledAddAssign<Vector3<$t>, $t>>::scaled_add_assign(&mut a, &f, &b);
+                    let target = [9 as $t, 12 as $t, 15 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledaddassign_vec_panic_ $t>]() {
+                    let mut a = DVector::from_vec(vec![1 as $t, 2 as $t]);
+                    let f = 2 as $t;
+                    let b = DVector::from_vec(vec![4 as $t, 5 as $t, 6 as $t]);
+                    <DVector<$t> as ArgminScaledAddAssign<DVector<$t>, $t>>::scaled_add_assign(&mut a, &f, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledaddassign_vec_vec_ $t>]() {
+                    let mut a = Vector3::new(1 as $t, 2 as $t, 3 as $t);
+                    let f = Vector3::new(3 as $t, 2 as $t, 1 as $t);
+                    let b = Vector3::new(4 as $t, 5 as $t, 6 as $t);
+                    <Vector3<$t> as ArgminScaledAddAssign<Vector3<$t>, Vector3<$t>>>::scaled_add_assign(&mut a, &f, &b);
+                    let target = [13 as $t, 12 as $t, 9 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledaddassign_vec_vec_panic_ $t>]() {
+                    let mut a = DVector::from_vec(vec![1 as $t, 2 as $t]);
+                    let f = DVector::from_vec(vec![3 as $t, 2 as $t, 1 as $t]);
+                    let b = DVector::from_vec(vec![4 as $t, 5 as $t, 6 as $t]);
+                    <DVector<$t> as ArgminScaledAddAssign<DVector<$t>, DVector<$t>>>::scaled_add_assign(&mut a, &f, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledaddassign_mat_ $t>]() {
+                    let mut a = Matrix2x3::new(1 as $t, 2 as $t, 3 as $t, 4 as $t, 5 as $t, 6 as $t);
+                    let f = 2 as $t;
+                    let b = Matrix2x3::new(4 as $t, 5 as $t, 6 as $t, 7 as $t, 8 as $t, 9 as $t);
+                    <Matrix2x3<$t> as ArgminScaledAddAssign<Matrix2x3<$t>, $t>>::scaled_add_assign(&mut a, &f, &b);
+                    let target = [[9 as $t, 12 as $t, 15 as $t], [18 as $t, 21 as $t, 24 as $t]];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
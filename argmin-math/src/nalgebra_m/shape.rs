@@ -0,0 +1,45 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminShape, Error};
+
+use nalgebra::{
+    base::{dimension::Dim, storage::Storage, Scalar},
+    Matrix,
+};
+
+impl<N, R, C, S> ArgminShape for Matrix<N, R, C, S>
+where
+    N: Scalar,
+    R: Dim,
+    C: Dim,
+    S: Storage<N, R, C>,
+{
+    const NDIM: usize = 2;
+
+    #[inline]
+    fn shape(&self) -> Result<Vec<usize>, Error> {
+        Ok(vec![self.nrows(), self.ncols()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{DMatrix, Matrix2x3, Vector3};
+
+    #[test]
+    fn test_shape() {
+        let a = Vector3::new(1.0f64, 2.0, 3.0);
+        assert_eq!(<Vector3<f64> as ArgminShape>::NDIM, 2);
+        assert_eq!(ArgminShape::shape(&a).unwrap(), vec![3, 1]);
+        let b = Matrix2x3::new(1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0);
+        assert_eq!(ArgminShape::shape(&b).unwrap(), vec![2, 3]);
+        let c = DMatrix::<f64>::zeros(4, 5);
+        assert_eq!(ArgminShape::shape(&c).unwrap(), vec![4, 5]);
+    }
+}
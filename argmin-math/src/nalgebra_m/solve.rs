@@ -0,0 +1,86 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::dense::LinalgError;
+use crate::{ArgminSolve, Error};
+use nalgebra::{
+    base::{allocator::Allocator, dimension::DimMin},
+    ComplexField, DefaultAllocator, OMatrix, OVector, LU,
+};
+
+impl<N, D> ArgminSolve<OVector<N, D>, OVector<N, D>> for OMatrix<N, D, D>
+where
+    N: ComplexField,
+    D: DimMin<D, Output = D>,
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D>,
+{
+    #[inline]
+    fn solve(&self, b: &OVector<N, D>) -> Result<OVector<N, D>, Error> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare.into());
+        }
+        if self.nrows() != b.nrows() {
+            return Err(LinalgError::DimensionMismatch.into());
+        }
+        LU::new(self.clone())
+            .solve(b)
+            .ok_or_else(|| LinalgError::Singular.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{DMatrix, DVector, Matrix2, Vector2};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_solve_ $t>]() {
+                    let a = Matrix2::new(
+                        2 as $t, 5 as $t,
+                        1 as $t, 3 as $t,
+                    );
+                    let b = Vector2::new(1 as $t, 2 as $t);
+                    let res = <Matrix2<$t> as ArgminSolve<Vector2<$t>, Vector2<$t>>>::solve(&a, &b).unwrap();
+                    let target = Vector2::new(-7 as $t, 3 as $t);
+                    for i in 0..2 {
+                        assert!(((res[i] - target[i]) as f64).abs() < 1e-5);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_error_ $t>]() {
+                    let a = Matrix2::new(
+                        2 as $t, 5 as $t,
+                        4 as $t, 10 as $t,
+                    );
+                    let b = Vector2::new(1 as $t, 2 as $t);
+                    let res = <Matrix2<$t> as ArgminSolve<Vector2<$t>, Vector2<$t>>>::solve(&a, &b);
+                    assert_eq!(format!("{}", res.unwrap_err()), "Singular matrix");
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_not_square_ $t>]() {
+                    let a = DMatrix::<$t>::zeros(2, 3);
+                    let b = DVector::<$t>::zeros(2);
+                    let res = <DMatrix<$t> as ArgminSolve<DVector<$t>, DVector<$t>>>::solve(&a, &b);
+                    assert_eq!(format!("{}", res.unwrap_err()), "Matrix must be square");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,64 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminWeightedL2Norm;
+
+use nalgebra::{
+    base::{dimension::Dim, storage::Storage},
+    Matrix, RealField,
+};
+
+impl<N, R, C, S> ArgminWeightedL2Norm<Matrix<N, R, C, S>, N> for Matrix<N, R, C, S>
+where
+    N: RealField + Copy,
+    R: Dim,
+    C: Dim,
+    S: Storage<N, R, C>,
+{
+    #[inline]
+    fn weighted_l2_norm(&self, w: &Matrix<N, R, C, S>) -> N {
+        assert_eq!(self.shape(), w.shape());
+        self.iter()
+            .zip(w.iter())
+            .fold(N::zero(), |acc, (&a, &w)| acc + w * a * a)
+            .sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{DVector, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_weightedl2norm_ $t>]() {
+                    let a = Vector3::new(1 as $t, -2 as $t, 3 as $t);
+                    let w = Vector3::new(4 as $t, 1 as $t, 0.5 as $t);
+                    let res = <Vector3<$t> as ArgminWeightedL2Norm<Vector3<$t>, $t>>::weighted_l2_norm(&a, &w);
+                    assert!((((12.5 as $t).sqrt() - res) as f64).abs() < 1e-6);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_weightedl2norm_panic_ $t>]() {
+                    let a = DVector::from_vec(vec![1 as $t, -2 as $t, 3 as $t]);
+                    let w = DVector::from_vec(vec![4 as $t, 1 as $t]);
+                    <DVector<$t> as ArgminWeightedL2Norm<DVector<$t>, $t>>::weighted_l2_norm(&a, &w);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,84 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminBasis;
+use ndarray::{Array, Dimension};
+use num_traits::{One, Zero};
+
+impl<T, D> ArgminBasis for Array<T, D>
+where
+    T: Zero + One + Clone,
+    D: Dimension,
+{
+    fn basis_like(&self) -> Vec<Array<T, D>> {
+        (0..self.len())
+            .map(|i| {
+                let mut e = Array::zeros(self.raw_dim());
+                if let Some(x) = e.iter_mut().nth(i) {
+                    *x = T::one();
+                }
+                e
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{array, Array1, Array2};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_basis_like_ $t>]() {
+                    let a: Array1<$t> = array![5 as $t, 6 as $t, 7 as $t];
+                    let basis = a.basis_like();
+                    assert_eq!(basis.len(), 3);
+                    for (i, e) in basis.iter().enumerate() {
+                        assert_eq!(e.len(), 3);
+                        for j in 0..3 {
+                            let expected = if i == j { 1 as $t } else { 0 as $t };
+                            assert!(((e[j] - expected) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_basis_like_2d_ $t>]() {
+                    let a: Array2<$t> = array![[5 as $t, 6 as $t], [7 as $t, 8 as $t]];
+                    let basis = a.basis_like();
+                    assert_eq!(basis.len(), 4);
+                    for (k, e) in basis.iter().enumerate() {
+                        assert_eq!(e.dim(), (2, 2));
+                        for i in 0..2 {
+                            for j in 0..2 {
+                                let expected = if 2 * i + j == k { 1 as $t } else { 0 as $t };
+                                assert!(((e[(i, j)] - expected) as f64).abs() < f64::EPSILON);
+                            }
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,121 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#[cfg(not(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+)))]
+use crate::dense;
+use crate::{ArgminCholesky, Error};
+use ndarray::Array2;
+#[cfg(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+))]
+use ndarray_linalg::{Cholesky, UPLO};
+
+#[cfg(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+))]
+macro_rules! make_cholesky {
+    ($t:ty) => {
+        impl ArgminCholesky<Array2<$t>> for Array2<$t> {
+            #[inline]
+            fn cholesky(&self) -> Result<Array2<$t>, Error> {
+                Ok(<Self as Cholesky>::cholesky(self, UPLO::Lower)?)
+            }
+        }
+    };
+}
+
+#[cfg(not(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+)))]
+macro_rules! make_cholesky {
+    ($t:ty) => {
+        impl ArgminCholesky<Array2<$t>> for Array2<$t> {
+            #[inline]
+            fn cholesky(&self) -> Result<Array2<$t>, Error> {
+                let l = dense::cholesky(
+                    &self
+                        .outer_iter()
+                        .map(|row| row.to_vec())
+                        .collect::<Vec<_>>(),
+                )?;
+                Ok(Array2::from_shape_fn(self.raw_dim(), |(i, j)| l[i][j]))
+            }
+        }
+    };
+}
+
+make_cholesky!(f32);
+make_cholesky!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_cholesky_ $t>]() {
+                    let a = array![
+                        [4 as $t, 12 as $t, -16 as $t],
+                        [12 as $t, 37 as $t, -43 as $t],
+                        [-16 as $t, -43 as $t, 98 as $t],
+                    ];
+                    let target = array![
+                        [2 as $t, 0 as $t, 0 as $t],
+                        [6 as $t, 1 as $t, 0 as $t],
+                        [-8 as $t, 5 as $t, 3 as $t],
+                    ];
+                    let res = <Array2<$t> as ArgminCholesky<Array2<$t>>>::cholesky(&a).unwrap();
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert!(((res[(i, j)] - target[(i, j)]) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_error_ $t>]() {
+                    let a = array![
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 1 as $t],
+                    ];
+                    let res = <Array2<$t> as ArgminCholesky<Array2<$t>>>::cholesky(&a);
+                    #[cfg(not(any(
+                        feature = "ndarray-linalg_0_12",
+                        feature = "ndarray-linalg_0_13",
+                        feature = "ndarray-linalg_0_16"
+                    )))]
+                    assert_eq!(format!("{}", res.unwrap_err()), "Matrix is not positive definite");
+                    #[cfg(any(
+                        feature = "ndarray-linalg_0_12",
+                        feature = "ndarray-linalg_0_13",
+                        feature = "ndarray-linalg_0_16"
+                    ))]
+                    assert!(res.is_err());
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
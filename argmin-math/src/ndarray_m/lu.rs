@@ -0,0 +1,69 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{dense, ArgminLU, Error};
+use ndarray::Array2;
+
+// The LU factorization of `ndarray-linalg` does not expose its packed factors and pivots, therefore
+// the explicit factors are always computed with the pure-Rust implementation.
+macro_rules! make_lu {
+    ($t:ty) => {
+        impl ArgminLU<Array2<$t>> for Array2<$t> {
+            #[inline]
+            fn lu(&self) -> Result<(Array2<$t>, Array2<$t>, Array2<$t>), Error> {
+                let (p, l, u) = dense::lu(
+                    self.outer_iter()
+                        .map(|row| row.to_vec())
+                        .collect::<Vec<_>>(),
+                )?;
+                Ok((
+                    Array2::from_shape_fn(self.raw_dim(), |(i, j)| p[i][j]),
+                    Array2::from_shape_fn(self.raw_dim(), |(i, j)| l[i][j]),
+                    Array2::from_shape_fn(self.raw_dim(), |(i, j)| u[i][j]),
+                ))
+            }
+        }
+    };
+}
+
+make_lu!(f32);
+make_lu!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_lu_ $t>]() {
+                    let a = array![
+                        [1 as $t, 2 as $t],
+                        [3 as $t, 4 as $t],
+                    ];
+                    let (p, l, u) = <Array2<$t> as ArgminLU<Array2<$t>>>::lu(&a).unwrap();
+                    let p_target = array![[0 as $t, 1 as $t], [1 as $t, 0 as $t]];
+                    let l_target = array![[1 as $t, 0 as $t], [1.0 as $t / 3.0, 1 as $t]];
+                    let u_target = array![[3 as $t, 4 as $t], [0 as $t, 2.0 as $t / 3.0]];
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!(((p[(i, j)] - p_target[(i, j)]) as f64).abs() < 1e-5);
+                            assert!(((l[(i, j)] - l_target[(i, j)]) as f64).abs() < 1e-5);
+                            assert!(((u[(i, j)] - u_target[(i, j)]) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,141 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMulAssign;
+use ndarray::{Array1, Array2};
+
+macro_rules! make_mulassign {
+    ($t:ty) => {
+        impl ArgminMulAssign<$t> for Array1<$t> {
+            #[inline]
+            fn mul_assign(&mut self, other: &$t) {
+                *self *= *other;
+            }
+        }
+
+        impl ArgminMulAssign<Array1<$t>> for Array1<$t> {
+            #[inline]
+            fn mul_assign(&mut self, other: &Array1<$t>) {
+                *self *= other;
+            }
+        }
+
+        impl ArgminMulAssign<$t> for Array2<$t> {
+            #[inline]
+            fn mul_assign(&mut self, other: &$t) {
+                *self *= *other;
+            }
+        }
+
+        impl ArgminMulAssign<Array2<$t>> for Array2<$t> {
+            #[inline]
+            fn mul_assign(&mut self, other: &Array2<$t>) {
+                *self *= other;
+            }
+        }
+    };
+}
+
+make_mulassign!(i8);
+make_mulassign!(i16);
+make_mulassign!(i32);
+make_mulassign!(i64);
+make_mulassign!(u8);
+make_mulassign!(u16);
+make_mulassign!(u32);
+make_mulassign!(u64);
+make_mulassign!(f32);
+make_mulassign!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_mulassign_vec_scalar_ $t>]() {
+                    let mut a = array![1 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    <Array1<$t> as ArgminMulAssign<$t>>::mul_assign(&mut a, &b);
+                    let target = [2 as $t, 8 as $t, 16 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mulassign_vec_vec_ $t>]() {
+                    let mut a = array![1 as $t, 4 as $t, 8 as $t];
+                    let b = array![2 as $t, 3 as $t, 4 as $t];
+                    <Array1<$t> as ArgminMulAssign<Array1<$t>>>::mul_assign(&mut a, &b);
+                    let target = [2 as $t, 12 as $t, 32 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mulassign_vec_vec_panic_ $t>]() {
+                    let mut a = array![1 as $t, 4 as $t];
+                    let b = array![2 as $t, 3 as $t, 4 as $t];
+                    <Array1<$t> as ArgminMulAssign<Array1<$t>>>::mul_assign(&mut a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mulassign_mat_scalar_ $t>]() {
+                    let mut a = array![[1 as $t, 4 as $t, 8 as $t], [2 as $t, 5 as $t, 9 as $t]];
+                    let b = 2 as $t;
+                    <Array2<$t> as ArgminMulAssign<$t>>::mul_assign(&mut a, &b);
+                    let target = [[2 as $t, 8 as $t, 16 as $t], [4 as $t, 10 as $t, 18 as $t]];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mulassign_mat_mat_ $t>]() {
+                    let mut a = array![[1 as $t, 4 as $t, 8 as $t], [2 as $t, 5 as $t, 9 as $t]];
+                    let b = array![[2 as $t, 3 as $t, 4 as $t], [1 as $t, 2 as $t, 3 as $t]];
+                    <Array2<$t> as ArgminMulAssign<Array2<$t>>>::mul_assign(&mut a, &b);
+                    let target = [[2 as $t, 12 as $t, 32 as $t], [2 as $t, 10 as $t, 27 as $t]];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,73 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminOuter;
+use ndarray::{Array1, Array2};
+
+macro_rules! make_outer {
+    ($t:ty) => {
+        impl ArgminOuter<Array1<$t>, Array2<$t>> for Array1<$t> {
+            #[inline]
+            fn outer(&self, other: &Array1<$t>) -> Array2<$t> {
+                Array2::from_shape_fn((self.len(), other.len()), |(i, j)| self[i] * other[j])
+            }
+        }
+    };
+}
+
+make_outer!(i8);
+make_outer!(i16);
+make_outer!(i32);
+make_outer!(i64);
+make_outer!(isize);
+make_outer!(u8);
+make_outer!(u16);
+make_outer!(u32);
+make_outer!(u64);
+make_outer!(usize);
+make_outer!(f32);
+make_outer!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_outer_ $t>]() {
+                    let a = array![1 as $t, 2 as $t, 3 as $t];
+                    let b = array![4 as $t, 5 as $t];
+                    let res = <Array1<$t> as ArgminOuter<Array1<$t>, Array2<$t>>>::outer(&a, &b);
+                    let target = [[4 as $t, 5 as $t], [8 as $t, 10 as $t], [12 as $t, 15 as $t]];
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[i][j] as f64, res[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(isize);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(usize);
+    make_test!(f32);
+    make_test!(f64);
+}
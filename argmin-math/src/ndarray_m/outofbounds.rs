@@ -0,0 +1,69 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminOutOfBounds;
+use ndarray::Array1;
+
+macro_rules! make_out_of_bounds {
+    ($t:ty) => {
+        impl ArgminOutOfBounds for Array1<$t> {
+            #[inline]
+            fn out_of_bounds(&self, lower: &Self, upper: &Self) -> Array1<$t> {
+                assert_eq!(self.shape(), lower.shape());
+                assert_eq!(self.shape(), upper.shape());
+                self.iter()
+                    .zip(lower.iter())
+                    .zip(upper.iter())
+                    .map(|((x, l), u)| if x < l || x > u { 1.0 } else { 0.0 })
+                    .collect()
+            }
+        }
+    };
+}
+
+make_out_of_bounds!(f32);
+make_out_of_bounds!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_out_of_bounds_vec_ $t>]() {
+                    let x = array![-1.5 as $t, -1.0 as $t, 0.5 as $t, 2.0 as $t, 2.5 as $t];
+                    let lower = Array1::from_elem(5, -1.0 as $t);
+                    let upper = Array1::from_elem(5, 2.0 as $t);
+                    let target = [1.0, 0.0, 0.0, 0.0, 1.0];
+                    let res = <Array1<$t> as ArgminOutOfBounds>::out_of_bounds(&x, &lower, &upper);
+                    for i in 0..5 {
+                        assert_relative_eq!(res[i] as f64, target[i], epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_out_of_bounds_vec_panic_ $t>]() {
+                    let x = array![0.5 as $t, 0.5 as $t];
+                    let lower = Array1::from_elem(3, -1.0 as $t);
+                    let upper = Array1::from_elem(3, 2.0 as $t);
+                    <Array1<$t> as ArgminOutOfBounds>::out_of_bounds(&x, &lower, &upper);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
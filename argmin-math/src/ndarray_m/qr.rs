@@ -0,0 +1,102 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#[cfg(not(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+)))]
+use crate::dense;
+use crate::{ArgminQR, Error};
+use ndarray::Array2;
+#[cfg(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+))]
+use ndarray_linalg::QR;
+
+#[cfg(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+))]
+macro_rules! make_qr {
+    ($t:ty) => {
+        impl ArgminQR<Array2<$t>, Array2<$t>> for Array2<$t> {
+            #[inline]
+            fn qr(&self) -> Result<(Array2<$t>, Array2<$t>), Error> {
+                Ok(<Self as QR>::qr(self)?)
+            }
+        }
+    };
+}
+
+#[cfg(not(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+)))]
+macro_rules! make_qr {
+    ($t:ty) => {
+        impl ArgminQR<Array2<$t>, Array2<$t>> for Array2<$t> {
+            #[inline]
+            fn qr(&self) -> Result<(Array2<$t>, Array2<$t>), Error> {
+                let (m, n) = self.dim();
+                let k = m.min(n);
+                let (q, r) = dense::qr(
+                    &self
+                        .outer_iter()
+                        .map(|row| row.to_vec())
+                        .collect::<Vec<_>>(),
+                )?;
+                Ok((
+                    Array2::from_shape_fn((m, k), |(i, j)| q[i][j]),
+                    Array2::from_shape_fn((k, n), |(i, j)| r[i][j]),
+                ))
+            }
+        }
+    };
+}
+
+make_qr!(f32);
+make_qr!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_qr_ $t>]() {
+                    let a = array![
+                        [3 as $t, 1 as $t],
+                        [4 as $t, 2 as $t],
+                        [0 as $t, 5 as $t],
+                    ];
+                    let (q, r) = <Array2<$t> as ArgminQR<Array2<$t>, Array2<$t>>>::qr(&a).unwrap();
+                    assert_eq!(q.dim(), (3, 2));
+                    assert_eq!(r.dim(), (2, 2));
+                    assert!((r[(1, 0)] as f64).abs() < 1e-5);
+                    let qr = q.dot(&r);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert!(((qr[(i, j)] - a[(i, j)]) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
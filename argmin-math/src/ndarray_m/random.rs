@@ -12,12 +12,19 @@ use crate::ArgminRandom;
 macro_rules! make_random {
     ($t:ty) => {
         impl ArgminRandom for ndarray::Array1<$t> {
-            fn rand_from_range(min: &Self, max: &Self) -> ndarray::Array1<$t> {
+            #[inline]
+            fn rand_from_range(min: &Self, max: &Self) -> Self {
+                Self::rand_from_range_with_rng(min, max, &mut rand::thread_rng())
+            }
+
+            fn rand_from_range_with_rng<R: Rng + ?Sized>(
+                min: &Self,
+                max: &Self,
+                rng: &mut R,
+            ) -> ndarray::Array1<$t> {
                 assert!(!min.is_empty());
                 assert_eq!(min.len(), max.len());
 
-                let mut rng = rand::thread_rng();
-
                 ndarray::Array1::from_iter(min.iter().zip(max.iter()).map(|(a, b)| {
                     // Do not require a < b:
 
@@ -35,12 +42,19 @@ macro_rules! make_random {
         }
 
         impl ArgminRandom for ndarray::Array2<$t> {
-            fn rand_from_range(min: &Self, max: &Self) -> ndarray::Array2<$t> {
+            #[inline]
+            fn rand_from_range(min: &Self, max: &Self) -> Self {
+                Self::rand_from_range_with_rng(min, max, &mut rand::thread_rng())
+            }
+
+            fn rand_from_range_with_rng<R: Rng + ?Sized>(
+                min: &Self,
+                max: &Self,
+                rng: &mut R,
+            ) -> ndarray::Array2<$t> {
                 assert!(!min.is_empty());
                 assert_eq!(min.raw_dim(), max.raw_dim());
 
-                let mut rng = rand::thread_rng();
-
                 ndarray::Array2::from_shape_fn(min.raw_dim(), |(i, j)| {
                     let a = min.get((i, j)).unwrap();
                     let b = max.get((i, j)).unwrap();
@@ -78,6 +92,7 @@ mod tests {
     use super::*;
     use ndarray::{array, Array1, Array2};
     use paste::item;
+    use rand::{rngs::StdRng, SeedableRng};
 
     macro_rules! make_test {
         ($t:ty) => {
@@ -94,6 +109,23 @@ mod tests {
                 }
             }
 
+            item! {
+                #[test]
+                fn [<test_random_vec_with_rng_ $t>]() {
+                    let a = array![1 as $t, 2 as $t, 4 as $t];
+                    let b = array![2 as $t, 3 as $t, 5 as $t];
+                    let mut rng1 = StdRng::seed_from_u64(42);
+                    let mut rng2 = StdRng::seed_from_u64(42);
+                    let random1 = Array1::<$t>::rand_from_range_with_rng(&a, &b, &mut rng1);
+                    let random2 = Array1::<$t>::rand_from_range_with_rng(&a, &b, &mut rng2);
+                    for i in 0..3usize {
+                        assert!(random1[i] >= a[i]);
+                        assert!(random1[i] <= b[i]);
+                    }
+                    assert_eq!(random1, random2);
+                }
+            }
+
             item! {
                 #[test]
                 fn [<test_random_mat_ $t>]() {
@@ -0,0 +1,144 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminScaledAddAssign;
+use ndarray::{Array1, Array2};
+
+macro_rules! make_scaledaddassign {
+    ($t:ty) => {
+        impl ArgminScaledAddAssign<Array1<$t>, $t> for Array1<$t> {
+            #[inline]
+            fn scaled_add_assign(&mut self, factor: &$t, vec: &Array1<$t>) {
+                assert_eq!(self.shape(), vec.shape());
+                self.iter_mut()
+                    .zip(vec.iter())
+                    .for_each(|(a, b)| *a += *factor * *b);
+            }
+        }
+
+        impl ArgminScaledAddAssign<Array1<$t>, Array1<$t>> for Array1<$t> {
+            #[inline]
+            fn scaled_add_assign(&mut self, factor: &Array1<$t>, vec: &Array1<$t>) {
+                assert_eq!(self.shape(), factor.shape());
+                assert_eq!(self.shape(), vec.shape());
+                self.iter_mut()
+                    .zip(factor.iter().zip(vec.iter()))
+                    .for_each(|(a, (f, b))| *a += *f * *b);
+            }
+        }
+
+        impl ArgminScaledAddAssign<Array2<$t>, $t> for Array2<$t> {
+            #[inline]
+            fn scaled_add_assign(&mut self, factor: &$t, vec: &Array2<$t>) {
+                assert_eq!(self.shape(), vec.shape());
+                self.iter_mut()
+                    .zip(vec.iter())
+                    .for_each(|(a, b)| *a += *factor * *b);
+            }
+        }
+    };
+}
+
+make_scaledaddassign!(i8);
+make_scaledaddassign!(i16);
+make_scaledaddassign!(i32);
+make_scaledaddassign!(i64);
+make_scaledaddassign!(u8);
+make_scaledaddassign!(u16);
+make_scaledaddassign!(u32);
+make_scaledaddassign!(u64);
+make_scaledaddassign!(f32);
+make_scaledaddassign!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scaledaddassign_vec_ $t>]() {
+                    let mut a = array![1 as $t, 2 as $t, 3 as $t];
+                    let f = 2 as $t;
+                    let b = array![4 as $t, 5 as $t, 6 as $t];
+                    <Array1<$t> as ArgminScaledAddAssign<Array1<$t>, $t>>::scaled_add_assign(&mut a, &f, &b);
+                    let target = [9 as $t, 12 as $t, 15 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledaddassign_vec_panic_ $t>]() {
+                    let mut a = array![1 as $t, 2 as $t];
+                    let f = 2 as $t;
+                    let b = array![4 as $t, 5 as $t, 6 as $t];
+                    <Array1<$t> as ArgminScaledAddAssign<Array1<$t>, $t>>::scaled_add_assign(&mut a, &f, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledaddassign_vec_vec_ $t>]() {
+                    let mut a = array![1 as $t, 2 as $t, 3 as $t];
+                    let f = array![3 as $t, 2 as $t, 1 as $t];
+                    let b = array![4 as $t, 5 as $t, 6 as $t];
+                    <Array1<$t> as ArgminScaledAddAssign<Array1<$t>, Array1<$t>>>::scaled_add_assign(&mut a, &f, &b);
+                    let target = [13 as $t, 12 as $t, 9 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledaddassign_vec_vec_panic_ $t>]() {
+                    let mut a = array![1 as $t, 2 as $t];
+                    let f = array![3 as $t, 2 as $t, 1 as $t];
+                    let b = array![4 as $t, 5 as $t, 6 as $t];
+                    <Array1<$t> as ArgminScaledAddAssign<Array1<$t>, Array1<$t>>>::scaled_add_assign(&mut a, &f, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledaddassign_mat_ $t>]() {
+                    let mut a = array![[1 as $t, 2 as $t, 3 as $t], [4 as $t, 5 as $t, 6 as $t]];
+                    let f = 2 as $t;
+                    let b = array![[4 as $t, 5 as $t, 6 as $t], [7 as $t, 8 as $t, 9 as $t]];
+                    <Array2<$t> as ArgminScaledAddAssign<Array2<$t>, $t>>::scaled_add_assign(&mut a, &f, &b);
+                    let target = [[9 as $t, 12 as $t, 15 as $t], [18 as $t, 21 as $t, 24 as $t]];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,48 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminShape, Error};
+use ndarray::{Array1, Array2};
+
+impl<T> ArgminShape for Array1<T> {
+    const NDIM: usize = 1;
+
+    #[inline]
+    fn shape(&self) -> Result<Vec<usize>, Error> {
+        Ok(vec![self.len()])
+    }
+}
+
+impl<T> ArgminShape for Array2<T> {
+    const NDIM: usize = 2;
+
+    #[inline]
+    fn shape(&self) -> Result<Vec<usize>, Error> {
+        let (rows, cols) = self.dim();
+        Ok(vec![rows, cols])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{array, Array1, Array2};
+
+    #[test]
+    fn test_shape_vec() {
+        let a: Array1<f64> = array![1.0, 2.0, 3.0];
+        assert_eq!(<Array1<f64> as ArgminShape>::NDIM, 1);
+        assert_eq!(ArgminShape::shape(&a).unwrap(), vec![3]);
+    }
+
+    #[test]
+    fn test_shape_mat() {
+        let a: Array2<f64> = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        assert_eq!(<Array2<f64> as ArgminShape>::NDIM, 2);
+        assert_eq!(ArgminShape::shape(&a).unwrap(), vec![2, 3]);
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#[cfg(not(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+)))]
+use crate::dense;
+use crate::{ArgminSolve, Error};
+use ndarray::{Array1, Array2};
+#[cfg(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+))]
+use ndarray_linalg::Solve;
+
+#[cfg(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+))]
+macro_rules! make_solve {
+    ($t:ty) => {
+        impl ArgminSolve<Array1<$t>, Array1<$t>> for Array2<$t> {
+            #[inline]
+            fn solve(&self, b: &Array1<$t>) -> Result<Array1<$t>, Error> {
+                Ok(<Self as Solve<$t>>::solve(self, b)?)
+            }
+        }
+    };
+}
+
+#[cfg(not(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+)))]
+macro_rules! make_solve {
+    ($t:ty) => {
+        impl ArgminSolve<Array1<$t>, Array1<$t>> for Array2<$t> {
+            #[inline]
+            fn solve(&self, b: &Array1<$t>) -> Result<Array1<$t>, Error> {
+                let a = self
+                    .outer_iter()
+                    .map(|row| row.to_vec())
+                    .collect::<Vec<_>>();
+                Ok(Array1::from(dense::solve(a, &b.to_vec())?))
+            }
+        }
+    };
+}
+
+make_solve!(f32);
+make_solve!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_solve_ $t>]() {
+                    let a = array![
+                        [2 as $t, 5 as $t],
+                        [1 as $t, 3 as $t],
+                    ];
+                    let b = array![1 as $t, 2 as $t];
+                    let res = <Array2<$t> as ArgminSolve<Array1<$t>, Array1<$t>>>::solve(&a, &b).unwrap();
+                    let target = array![-7 as $t, 3 as $t];
+                    for i in 0..2 {
+                        assert!(((res[i] - target[i]) as f64).abs() < 1e-5);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_error_ $t>]() {
+                    let a = array![
+                        [2 as $t, 5 as $t],
+                        [4 as $t, 10 as $t],
+                    ];
+                    let b = array![1 as $t, 2 as $t];
+                    let res = <Array2<$t> as ArgminSolve<Array1<$t>, Array1<$t>>>::solve(&a, &b);
+                    #[cfg(not(any(
+                        feature = "ndarray-linalg_0_12",
+                        feature = "ndarray-linalg_0_13",
+                        feature = "ndarray-linalg_0_16"
+                    )))]
+                    assert_eq!(format!("{}", res.unwrap_err()), "Singular matrix");
+                    #[cfg(any(
+                        feature = "ndarray-linalg_0_12",
+                        feature = "ndarray-linalg_0_13",
+                        feature = "ndarray-linalg_0_16"
+                    ))]
+                    assert!(res.is_err());
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
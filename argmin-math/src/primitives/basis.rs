@@ -0,0 +1,65 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminBasis;
+
+macro_rules! make_basis {
+    ($t:ty) => {
+        impl ArgminBasis for $t {
+            #[inline]
+            fn basis_like(&self) -> Vec<$t> {
+                vec![1 as $t]
+            }
+        }
+    };
+}
+
+make_basis!(f32);
+make_basis!(f64);
+make_basis!(i8);
+make_basis!(i16);
+make_basis!(i32);
+make_basis!(i64);
+make_basis!(u8);
+make_basis!(u16);
+make_basis!(u32);
+make_basis!(u64);
+make_basis!(isize);
+make_basis!(usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_basis_like_ $t>]() {
+                    let basis = (42 as $t).basis_like();
+                    assert_eq!(basis.len(), 1);
+                    assert_relative_eq!(basis[0] as f64, 1.0, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(isize);
+    make_test!(usize);
+}
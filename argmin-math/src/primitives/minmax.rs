@@ -6,18 +6,26 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::ArgminMinMax;
-use num_complex::Complex;
 
 macro_rules! make_minmax {
     ($t:ty) => {
         impl ArgminMinMax for $t {
             #[inline]
             fn min(x: &Self, y: &Self) -> $t {
-                std::cmp::min(x, y)
+                if x < y {
+                    *x
+                } else {
+                    *y
+                }
             }
 
+            #[inline]
             fn max(x: &Self, y: &Self) -> $t {
-                std::cmp::max(x, y)
+                if x > y {
+                    *x
+                } else {
+                    *y
+                }
             }
         }
     };
@@ -35,17 +43,47 @@ make_minmax!(u32);
 make_minmax!(u64);
 make_minmax!(isize);
 make_minmax!(usize);
-make_minmax!(Complex<f32>);
-make_minmax!(Complex<f64>);
-make_minmax!(Complex<i8>);
-make_minmax!(Complex<i16>);
-make_minmax!(Complex<i32>);
-make_minmax!(Complex<i64>);
-make_minmax!(Complex<u8>);
-make_minmax!(Complex<u16>);
-make_minmax!(Complex<u32>);
-make_minmax!(Complex<u64>);
-make_minmax!(Complex<isize>);
-make_minmax!(Complex<usize>);
 
-// TODO: tests!!!
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_minmax_ $t>]() {
+                    let a = 3 as $t;
+                    let b = 7 as $t;
+                    for (x, y) in [(a, b), (b, a)] {
+                        assert_relative_eq!(
+                            <$t as ArgminMinMax>::min(&x, &y) as f64,
+                            3.0,
+                            epsilon = f64::EPSILON
+                        );
+                        assert_relative_eq!(
+                            <$t as ArgminMinMax>::max(&x, &y) as f64,
+                            7.0,
+                            epsilon = f64::EPSILON
+                        );
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(isize);
+    make_test!(usize);
+}
@@ -6,31 +6,55 @@
 // copied, modified, or distributed except according to those terms.
 
 mod add;
+mod addassign;
+mod basis;
 mod conj;
 mod div;
 mod dot;
 mod l1norm;
 mod l2norm;
+mod linfnorm;
+mod minmax;
 mod mul;
+mod mulassign;
+mod outofbounds;
 mod random;
 mod scaledadd;
+mod scaledaddassign;
 mod scaledsub;
+mod scaledsubassign;
+mod shape;
+mod solve;
 mod sub;
+mod subassign;
 mod transpose;
 mod weighteddot;
+mod weightedl2norm;
 mod zero;
 
 pub use add::*;
+pub use addassign::*;
+pub use basis::*;
 pub use conj::*;
 pub use div::*;
 pub use dot::*;
 pub use l1norm::*;
 pub use l2norm::*;
+pub use linfnorm::*;
+pub use minmax::*;
 pub use mul::*;
+pub use mulassign::*;
+pub use outofbounds::*;
 pub use random::*;
 pub use scaledadd::*;
+pub use scaledaddassign::*;
 pub use scaledsub::*;
+pub use scaledsubassign::*;
+pub use shape::*;
+pub use solve::*;
 pub use sub::*;
+pub use subassign::*;
 pub use transpose::*;
 pub use weighteddot::*;
+pub use weightedl2norm::*;
 pub use zero::*;
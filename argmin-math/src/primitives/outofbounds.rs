@@ -0,0 +1,52 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminOutOfBounds;
+
+macro_rules! make_out_of_bounds {
+    ($t:ty) => {
+        impl ArgminOutOfBounds for $t {
+            #[inline]
+            fn out_of_bounds(&self, lower: &Self, upper: &Self) -> $t {
+                if self < lower || self > upper {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+    };
+}
+
+make_out_of_bounds!(f32);
+make_out_of_bounds!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_out_of_bounds_ $t>]() {
+                    let lower = -1.0 as $t;
+                    let upper = 2.0 as $t;
+                    for (x, target) in [(-1.5, 1.0), (-1.0, 0.0), (0.5, 0.0), (2.0, 0.0), (2.5, 1.0)] {
+                        let res = <$t as ArgminOutOfBounds>::out_of_bounds(&(x as $t), &lower, &upper);
+                        assert_relative_eq!(res as f64, target, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
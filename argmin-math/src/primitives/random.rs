@@ -12,8 +12,17 @@ macro_rules! make_random {
     ($t:ty) => {
         impl ArgminRandom for $t {
             #[inline]
-            fn rand_from_range(min: &Self, max: &Self) -> $t {
-                rand::thread_rng().gen_range(*min..*max)
+            fn rand_from_range(min: &Self, max: &Self) -> Self {
+                Self::rand_from_range_with_rng(min, max, &mut rand::thread_rng())
+            }
+
+            #[inline]
+            fn rand_from_range_with_rng<R: Rng + ?Sized>(
+                min: &Self,
+                max: &Self,
+                rng: &mut R,
+            ) -> $t {
+                rng.gen_range(*min..*max)
             }
         }
     };
@@ -36,6 +45,7 @@ make_random!(usize);
 mod tests {
     use super::*;
     use paste::item;
+    use rand::{rngs::StdRng, SeedableRng};
 
     macro_rules! make_test {
         ($t:ty) => {
@@ -49,6 +59,21 @@ mod tests {
                     assert!(random <= b);
                 }
             }
+
+            item! {
+                #[test]
+                fn [<test_random_with_rng_ $t>]() {
+                    let a = 1 as $t;
+                    let b = 2 as $t;
+                    let mut rng1 = StdRng::seed_from_u64(42);
+                    let mut rng2 = StdRng::seed_from_u64(42);
+                    let random1 = $t::rand_from_range_with_rng(&a, &b, &mut rng1);
+                    let random2 = $t::rand_from_range_with_rng(&a, &b, &mut rng2);
+                    assert!(random1 >= a);
+                    assert!(random1 <= b);
+                    assert_eq!(random1.to_ne_bytes(), random2.to_ne_bytes());
+                }
+            }
         };
     }
 
@@ -0,0 +1,78 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminShape, Error};
+use num_complex::Complex;
+
+macro_rules! make_shape {
+    ($t:ty) => {
+        impl ArgminShape for $t {
+            const NDIM: usize = 0;
+
+            #[inline]
+            fn shape(&self) -> Result<Vec<usize>, Error> {
+                Ok(vec![])
+            }
+        }
+    };
+}
+
+make_shape!(isize);
+make_shape!(usize);
+make_shape!(i8);
+make_shape!(i16);
+make_shape!(i32);
+make_shape!(i64);
+make_shape!(u8);
+make_shape!(u16);
+make_shape!(u32);
+make_shape!(u64);
+make_shape!(f32);
+make_shape!(f64);
+make_shape!(Complex<isize>);
+make_shape!(Complex<usize>);
+make_shape!(Complex<i8>);
+make_shape!(Complex<i16>);
+make_shape!(Complex<i32>);
+make_shape!(Complex<i64>);
+make_shape!(Complex<u8>);
+make_shape!(Complex<u16>);
+make_shape!(Complex<u32>);
+make_shape!(Complex<u64>);
+make_shape!(Complex<f32>);
+make_shape!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_shape_ $t>]() {
+                    assert_eq!(<$t as ArgminShape>::NDIM, 0);
+                    assert!((1 as $t).shape().unwrap().is_empty());
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
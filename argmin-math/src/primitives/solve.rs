@@ -0,0 +1,60 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::dense::LinalgError;
+use crate::{ArgminSolve, Error};
+
+macro_rules! make_solve {
+    ($t:ty) => {
+        // solve for scalars (1d solvers)
+        impl ArgminSolve<$t, $t> for $t {
+            #[inline]
+            fn solve(&self, b: &$t) -> Result<$t, Error> {
+                if *self == 0.0 {
+                    return Err(LinalgError::Singular.into());
+                }
+                Ok(b / self)
+            }
+        }
+    };
+}
+
+make_solve!(f32);
+make_solve!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_solve_ $t>]() {
+                    let a = 4.0 as $t;
+                    let b = 2.0 as $t;
+                    let res = <$t as ArgminSolve<$t, $t>>::solve(&a, &b).unwrap();
+                    assert!(((res - 0.5) as f64).abs() < f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_error_ $t>]() {
+                    let a = 0.0 as $t;
+                    let b = 2.0 as $t;
+                    let res = <$t as ArgminSolve<$t, $t>>::solve(&a, &b);
+                    assert_eq!(format!("{}", res.unwrap_err()), "Singular matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
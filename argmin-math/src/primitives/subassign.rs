@@ -0,0 +1,79 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSubAssign;
+use num_complex::Complex;
+
+macro_rules! make_subassign {
+    ($t:ty) => {
+        impl ArgminSubAssign<$t> for $t {
+            #[inline]
+            fn sub_assign(&mut self, other: &$t) {
+                *self -= *other;
+            }
+        }
+    };
+}
+
+make_subassign!(isize);
+make_subassign!(usize);
+make_subassign!(i8);
+make_subassign!(i16);
+make_subassign!(i32);
+make_subassign!(i64);
+make_subassign!(u8);
+make_subassign!(u16);
+make_subassign!(u32);
+make_subassign!(u64);
+make_subassign!(f32);
+make_subassign!(f64);
+make_subassign!(Complex<isize>);
+make_subassign!(Complex<usize>);
+make_subassign!(Complex<i8>);
+make_subassign!(Complex<i16>);
+make_subassign!(Complex<i32>);
+make_subassign!(Complex<i64>);
+make_subassign!(Complex<u8>);
+make_subassign!(Complex<u16>);
+make_subassign!(Complex<u32>);
+make_subassign!(Complex<u64>);
+make_subassign!(Complex<f32>);
+make_subassign!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_subassign_ $t>]() {
+                    let mut a = 76 as $t;
+                    let b = 34 as $t;
+                    <$t as ArgminSubAssign<$t>>::sub_assign(&mut a, &b);
+                    assert_relative_eq!(42 as f64, a as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,46 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminWeightedL2Norm;
+
+macro_rules! make_norm {
+    ($t:ty) => {
+        impl ArgminWeightedL2Norm<$t, $t> for $t {
+            #[inline]
+            fn weighted_l2_norm(&self, w: &$t) -> $t {
+                (*w * *self * *self).sqrt()
+            }
+        }
+    };
+}
+
+make_norm!(f32);
+make_norm!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_weightedl2norm_ $t>]() {
+                    let a = -3 as $t;
+                    let w = 4 as $t;
+                    let res = <$t as ArgminWeightedL2Norm<$t, $t>>::weighted_l2_norm(&a, &w);
+                    assert_relative_eq!(6 as f64, res as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,142 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Fallible variants of the basic operations.
+//!
+//! The `ArgminTry*` traits are implemented for all types which implement the corresponding
+//! infallible operation as well as [`ArgminShape`]. The shapes of the operands are checked before
+//! the infallible operation is called, such that shape mismatches result in a [`ShapeError`]
+//! instead of a panic, independent of the backend.
+
+use crate::{
+    ArgminAdd, ArgminDiv, ArgminDot, ArgminMul, ArgminShape, ArgminSub, ArgminTryAdd, ArgminTryDiv,
+    ArgminTryDot, ArgminTryMul, ArgminTrySub, Error,
+};
+
+/// Errors of the fallible `ArgminTry*` operations
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum ShapeError {
+    /// Shapes of the operands are incompatible
+    #[error("Incompatible shapes {left:?} and {right:?} in `{op}`")]
+    Incompatible {
+        /// Name of the operation
+        op: &'static str,
+        /// Shape of the left operand
+        left: Vec<usize>,
+        /// Shape of the right operand
+        right: Vec<usize>,
+    },
+    /// Rows of a matrix differ in length
+    #[error("Rows of matrix differ in length")]
+    Ragged,
+}
+
+/// Pointwise operations require operands of the same shape, unless one of them is a scalar.
+fn check_pointwise<A, B>(op: &'static str, a: &A, b: &B) -> Result<(), Error>
+where
+    A: ArgminShape,
+    B: ArgminShape,
+{
+    let (left, right) = (a.shape()?, b.shape()?);
+    if left.is_empty() || right.is_empty() || left == right {
+        Ok(())
+    } else {
+        Err(ShapeError::Incompatible { op, left, right }.into())
+    }
+}
+
+/// Checks the shapes of the operands of a dot product with a result of dimension `ndim_out`.
+fn check_dot<A, B>(a: &A, b: &B, ndim_out: usize) -> Result<(), Error>
+where
+    A: ArgminShape,
+    B: ArgminShape,
+{
+    let (left, right) = (a.shape()?, b.shape()?);
+    let compatible = match (left.len(), right.len()) {
+        // scaling by a scalar
+        (0, _) | (_, 0) => true,
+        // scalar product
+        _ if ndim_out == 0 => left == right,
+        // outer product
+        (1, 1) => true,
+        // vector-matrix product
+        (1, _) => left[0] == right[0],
+        // matrix-vector and matrix-matrix product
+        _ => left[1] == right[0],
+    };
+    if compatible {
+        Ok(())
+    } else {
+        Err(ShapeError::Incompatible {
+            op: "dot",
+            left,
+            right,
+        }
+        .into())
+    }
+}
+
+impl<T, U, W> ArgminTryAdd<T, U> for W
+where
+    W: ArgminAdd<T, U> + ArgminShape,
+    T: ArgminShape,
+{
+    #[inline]
+    fn try_add(&self, other: &T) -> Result<U, Error> {
+        check_pointwise("add", self, other)?;
+        Ok(self.add(other))
+    }
+}
+
+impl<T, U, W> ArgminTrySub<T, U> for W
+where
+    W: ArgminSub<T, U> + ArgminShape,
+    T: ArgminShape,
+{
+    #[inline]
+    fn try_sub(&self, other: &T) -> Result<U, Error> {
+        check_pointwise("sub", self, other)?;
+        Ok(self.sub(other))
+    }
+}
+
+impl<T, U, W> ArgminTryMul<T, U> for W
+where
+    W: ArgminMul<T, U> + ArgminShape,
+    T: ArgminShape,
+{
+    #[inline]
+    fn try_mul(&self, other: &T) -> Result<U, Error> {
+        check_pointwise("mul", self, other)?;
+        Ok(self.mul(other))
+    }
+}
+
+impl<T, U, W> ArgminTryDiv<T, U> for W
+where
+    W: ArgminDiv<T, U> + ArgminShape,
+    T: ArgminShape,
+{
+    #[inline]
+    fn try_div(&self, other: &T) -> Result<U, Error> {
+        check_pointwise("div", self, other)?;
+        Ok(self.div(other))
+    }
+}
+
+impl<T, U, W> ArgminTryDot<T, U> for W
+where
+    W: ArgminDot<T, U> + ArgminShape,
+    T: ArgminShape,
+    U: ArgminShape,
+{
+    #[inline]
+    fn try_dot(&self, other: &T) -> Result<U, Error> {
+        check_dot(self, other, U::NDIM)?;
+        Ok(self.dot(other))
+    }
+}
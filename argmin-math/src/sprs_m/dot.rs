@@ -0,0 +1,142 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDot;
+use sprs::CsMat;
+
+macro_rules! make_dot {
+    ($t:ty) => {
+        impl ArgminDot<Vec<$t>, Vec<$t>> for CsMat<$t> {
+            #[inline]
+            fn dot(&self, other: &Vec<$t>) -> Vec<$t> {
+                assert_eq!(self.cols(), other.len());
+                let mut out = vec![0 as $t; self.rows()];
+                for (v, (i, j)) in self.iter() {
+                    out[i] += v * other[j];
+                }
+                out
+            }
+        }
+
+        impl ArgminDot<CsMat<$t>, CsMat<$t>> for CsMat<$t> {
+            #[inline]
+            fn dot(&self, other: &CsMat<$t>) -> CsMat<$t> {
+                assert_eq!(self.cols(), other.rows());
+                self * other
+            }
+        }
+
+        impl ArgminDot<$t, CsMat<$t>> for CsMat<$t> {
+            #[inline]
+            fn dot(&self, other: &$t) -> CsMat<$t> {
+                self.map(|v| v * other)
+            }
+        }
+
+        impl ArgminDot<CsMat<$t>, CsMat<$t>> for $t {
+            #[inline]
+            fn dot(&self, other: &CsMat<$t>) -> CsMat<$t> {
+                other.map(|v| self * v)
+            }
+        }
+    };
+}
+
+make_dot!(f32);
+make_dot!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+    use sprs::TriMat;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_mat_vec_ $t>]() {
+                    let mut a = TriMat::new((2, 3));
+                    a.add_triplet(0, 0, 1 as $t);
+                    a.add_triplet(0, 2, 3 as $t);
+                    a.add_triplet(1, 1, 5 as $t);
+                    let b = vec![1 as $t, 2 as $t, 3 as $t];
+                    let res = vec![10 as $t, 10 as $t];
+                    let csr: CsMat<$t> = a.to_csr();
+                    let csc: CsMat<$t> = a.to_csc();
+                    let product = <CsMat<$t> as ArgminDot<Vec<$t>, Vec<$t>>>::dot(&csr, &b);
+                    let product2 = <CsMat<$t> as ArgminDot<Vec<$t>, Vec<$t>>>::dot(&csc, &b);
+                    for i in 0..2 {
+                        assert!((((res[i] - product[i]) as f64).abs()) < f64::EPSILON);
+                        assert!((((res[i] - product2[i]) as f64).abs()) < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mat_vec_panic_ $t>]() {
+                    let a: CsMat<$t> = CsMat::eye(3);
+                    let b = vec![1 as $t, 2 as $t];
+                    <CsMat<$t> as ArgminDot<Vec<$t>, Vec<$t>>>::dot(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_mat_ $t>]() {
+                    let mut a = TriMat::new((2, 3));
+                    a.add_triplet(0, 0, 1 as $t);
+                    a.add_triplet(0, 2, 3 as $t);
+                    a.add_triplet(1, 1, 5 as $t);
+                    let a: CsMat<$t> = a.to_csr();
+                    let product = <CsMat<$t> as ArgminDot<CsMat<$t>, CsMat<$t>>>::dot(
+                        &a,
+                        &a.transpose_view().to_owned(),
+                    );
+                    let res = [[10 as $t, 0 as $t], [0 as $t, 25 as $t]];
+                    assert_eq!(product.shape(), (2, 2));
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            let p = product.get(i, j).cloned().unwrap_or(0 as $t);
+                            assert!((((res[i][j] - p) as f64).abs()) < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mat_mat_panic_ $t>]() {
+                    let a: CsMat<$t> = CsMat::eye(3);
+                    let b: CsMat<$t> = CsMat::eye(2);
+                    <CsMat<$t> as ArgminDot<CsMat<$t>, CsMat<$t>>>::dot(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_scalar_ $t>]() {
+                    let a: CsMat<$t> = CsMat::eye(2);
+                    let b = 2 as $t;
+                    let product = <CsMat<$t> as ArgminDot<$t, CsMat<$t>>>::dot(&a, &b);
+                    let product2 = <$t as ArgminDot<CsMat<$t>, CsMat<$t>>>::dot(&b, &a);
+                    for i in 0..2 {
+                        assert!((((product.get(i, i).unwrap() - 2 as $t) as f64).abs()) < f64::EPSILON);
+                        assert!((((product2.get(i, i).unwrap() - 2 as $t) as f64).abs()) < f64::EPSILON);
+                    }
+                    assert_eq!(product.nnz(), 2);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,58 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMul;
+use sprs::CsMat;
+
+macro_rules! make_mul {
+    ($t:ty) => {
+        impl ArgminMul<$t, CsMat<$t>> for CsMat<$t> {
+            #[inline]
+            fn mul(&self, other: &$t) -> CsMat<$t> {
+                self.map(|v| v * other)
+            }
+        }
+
+        impl ArgminMul<CsMat<$t>, CsMat<$t>> for $t {
+            #[inline]
+            fn mul(&self, other: &CsMat<$t>) -> CsMat<$t> {
+                other.map(|v| self * v)
+            }
+        }
+    };
+}
+
+make_mul!(f32);
+make_mul!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_mul_mat_scalar_ $t>]() {
+                    let a: CsMat<$t> = CsMat::eye(3);
+                    let b = 3 as $t;
+                    let res = <CsMat<$t> as ArgminMul<$t, CsMat<$t>>>::mul(&a, &b);
+                    let res2 = <$t as ArgminMul<CsMat<$t>, CsMat<$t>>>::mul(&b, &a);
+                    assert_eq!(res.nnz(), 3);
+                    for i in 0..3 {
+                        assert!((((res.get(i, i).unwrap() - 3 as $t) as f64).abs()) < f64::EPSILON);
+                        assert!((((res2.get(i, i).unwrap() - 3 as $t) as f64).abs()) < f64::EPSILON);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
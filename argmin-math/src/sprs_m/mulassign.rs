@@ -0,0 +1,47 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::ArgminMulAssign;
+use sprs::CsMat;
+
+macro_rules! make_mulassign {
+    ($t:ty) => {
+        impl ArgminMulAssign<$t> for CsMat<$t> {
+            #[inline]
+            fn mul_assign(&mut self, other: &$t) {
+                self.map_inplace(|v| v * other);
+            }
+        }
+    };
+}
+
+make_mulassign!(f32);
+make_mulassign!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_mulassign_mat_scalar_ $t>]() {
+                    let mut a: CsMat<$t> = CsMat::eye(3);
+                    <CsMat<$t> as ArgminMulAssign<$t>>::mul_assign(&mut a, &(3 as $t));
+                    assert_eq!(a.nnz(), 3);
+                    for i in 0..3 {
+                        assert!((((a.get(i, i).unwrap() - 3 as $t) as f64).abs()) < f64::EPSILON);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,66 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::ArgminScaledAddAssign;
+use sprs::CsMat;
+
+macro_rules! make_scaledaddassign {
+    ($t:ty) => {
+        impl ArgminScaledAddAssign<CsMat<$t>, $t> for CsMat<$t> {
+            #[inline]
+            fn scaled_add_assign(&mut self, factor: &$t, vec: &CsMat<$t>) {
+                assert_eq!(self.shape(), vec.shape());
+                *self = &*self + &vec.map(|v| v * factor);
+            }
+        }
+    };
+}
+
+make_scaledaddassign!(f32);
+make_scaledaddassign!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+    use sprs::TriMat;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scaledaddassign_mat_ $t>]() {
+                    let mut a: CsMat<$t> = CsMat::eye(2);
+                    let mut b = TriMat::new((2, 2));
+                    b.add_triplet(0, 0, 2 as $t);
+                    b.add_triplet(0, 1, 3 as $t);
+                    let b: CsMat<$t> = b.to_csr();
+                    <CsMat<$t> as ArgminScaledAddAssign<CsMat<$t>, $t>>::scaled_add_assign(&mut a, &(2 as $t), &b);
+                    let target = [[5 as $t, 6 as $t], [0 as $t, 1 as $t]];
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            let r = a.get(i, j).cloned().unwrap_or(0 as $t);
+                            assert!((((target[i][j] - r) as f64).abs()) < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledaddassign_mat_panic_ $t>]() {
+                    let mut a: CsMat<$t> = CsMat::eye(3);
+                    let b: CsMat<$t> = CsMat::eye(2);
+                    <CsMat<$t> as ArgminScaledAddAssign<CsMat<$t>, $t>>::scaled_add_assign(&mut a, &(2 as $t), &b);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,76 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::dense;
+use crate::{ArgminSolve, Error};
+use sprs::CsMat;
+
+macro_rules! make_solve {
+    ($t:ty) => {
+        /// Solves the system with a dense LU decomposition of the matrix.
+        ///
+        /// This is intended for small systems such as the normal equations `J^T J x = J^T r` of
+        /// the Gauss-Newton method, where `J` is sparse but `J^T J` only has the dimension of the
+        /// parameter vector.
+        impl ArgminSolve<Vec<$t>, Vec<$t>> for CsMat<$t> {
+            #[inline]
+            fn solve(&self, b: &Vec<$t>) -> Result<Vec<$t>, Error> {
+                let mut a = vec![vec![0 as $t; self.cols()]; self.rows()];
+                for (v, (i, j)) in self.iter() {
+                    a[i][j] = *v;
+                }
+                dense::solve(a, b)
+            }
+        }
+    };
+}
+
+make_solve!(f32);
+make_solve!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+    use sprs::TriMat;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_solve_ $t>]() {
+                    let mut a = TriMat::new((2, 2));
+                    a.add_triplet(0, 0, 2 as $t);
+                    a.add_triplet(0, 1, 5 as $t);
+                    a.add_triplet(1, 0, 1 as $t);
+                    a.add_triplet(1, 1, 3 as $t);
+                    let b = vec![1 as $t, 2 as $t];
+                    let target = vec![-7 as $t, 3 as $t];
+                    for a in [a.to_csr(), a.to_csc()] {
+                        let res = <CsMat<$t> as ArgminSolve<Vec<$t>, Vec<$t>>>::solve(&a, &b).unwrap();
+                        for i in 0..2 {
+                            assert!(((res[i] - target[i]) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_error_ $t>]() {
+                    let a: CsMat<$t> = CsMat::zero((2, 2));
+                    let b = vec![1 as $t, 2 as $t];
+                    let err = <CsMat<$t> as ArgminSolve<Vec<$t>, Vec<$t>>>::solve(&a, &b).unwrap_err();
+                    assert_eq!(format!("{}", err), "Singular matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,67 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSub;
+use sprs::CsMat;
+
+macro_rules! make_sub {
+    ($t:ty) => {
+        impl ArgminSub<CsMat<$t>, CsMat<$t>> for CsMat<$t> {
+            #[inline]
+            fn sub(&self, other: &CsMat<$t>) -> CsMat<$t> {
+                assert_eq!(self.shape(), other.shape());
+                self - other
+            }
+        }
+    };
+}
+
+make_sub!(f32);
+make_sub!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+    use sprs::TriMat;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sub_mat_mat_ $t>]() {
+                    let a: CsMat<$t> = CsMat::eye(2);
+                    let mut b = TriMat::new((2, 2));
+                    b.add_triplet(0, 0, 2 as $t);
+                    b.add_triplet(0, 1, 3 as $t);
+                    let b: CsMat<$t> = b.to_csc();
+                    let res = <CsMat<$t> as ArgminSub<CsMat<$t>, CsMat<$t>>>::sub(&a, &b);
+                    let target = [[-1 as $t, -3 as $t], [0 as $t, 1 as $t]];
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            let r = res.get(i, j).cloned().unwrap_or(0 as $t);
+                            assert!((((target[i][j] - r) as f64).abs()) < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_sub_mat_mat_panic_ $t>]() {
+                    let a: CsMat<$t> = CsMat::eye(3);
+                    let b: CsMat<$t> = CsMat::eye(2);
+                    <CsMat<$t> as ArgminSub<CsMat<$t>, CsMat<$t>>>::sub(&a, &b);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
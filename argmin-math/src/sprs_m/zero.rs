@@ -0,0 +1,46 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminZeroLike;
+use sprs::CsMat;
+
+macro_rules! make_zero {
+    ($t:ty) => {
+        impl ArgminZeroLike for CsMat<$t> {
+            #[inline]
+            fn zero_like(&self) -> CsMat<$t> {
+                CsMat::zero(self.shape())
+            }
+        }
+    };
+}
+
+make_zero!(f32);
+make_zero!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_zero_like_ $t>]() {
+                    let a: CsMat<$t> = CsMat::eye(3);
+                    let res = a.zero_like();
+                    assert_eq!(res.shape(), (3, 3));
+                    assert_eq!(res.nnz(), 0);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
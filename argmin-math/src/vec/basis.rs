@@ -0,0 +1,69 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminBasis;
+use num_traits::{One, Zero};
+
+impl<T> ArgminBasis for Vec<T>
+where
+    T: Zero + One + Clone,
+{
+    fn basis_like(&self) -> Vec<Vec<T>> {
+        (0..self.len())
+            .map(|i| {
+                let mut e = vec![T::zero(); self.len()];
+                e[i] = T::one();
+                e
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_basis_like_ $t>]() {
+                    let a = vec![5 as $t, 6 as $t, 7 as $t];
+                    let basis = a.basis_like();
+                    assert_eq!(basis.len(), 3);
+                    for (i, e) in basis.iter().enumerate() {
+                        assert_eq!(e.len(), 3);
+                        for (j, x) in e.iter().enumerate() {
+                            let expected = if i == j { 1 as $t } else { 0 as $t };
+                            assert!(((x - expected) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_basis_like_empty_ $t>]() {
+                    let a: Vec<$t> = vec![];
+                    assert!(a.basis_like().is_empty());
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,64 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::dense;
+use crate::{ArgminCholesky, Error};
+use num_traits::Float;
+
+impl<F: Float> ArgminCholesky<Vec<Vec<F>>> for Vec<Vec<F>> {
+    #[inline]
+    fn cholesky(&self) -> Result<Vec<Vec<F>>, Error> {
+        dense::cholesky(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_cholesky_ $t>]() {
+                    let a = vec![
+                        vec![4 as $t, 12 as $t, -16 as $t],
+                        vec![12 as $t, 37 as $t, -43 as $t],
+                        vec![-16 as $t, -43 as $t, 98 as $t],
+                    ];
+                    let target = vec![
+                        vec![2 as $t, 0 as $t, 0 as $t],
+                        vec![6 as $t, 1 as $t, 0 as $t],
+                        vec![-8 as $t, 5 as $t, 3 as $t],
+                    ];
+                    let res = <Vec<Vec<$t>> as ArgminCholesky<Vec<Vec<$t>>>>::cholesky(&a).unwrap();
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert!(((res[i][j] - target[i][j]) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_error_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t],
+                        vec![2 as $t, 1 as $t],
+                    ];
+                    let res = <Vec<Vec<$t>> as ArgminCholesky<Vec<Vec<$t>>>>::cholesky(&a);
+                    assert_eq!(format!("{}", res.unwrap_err()), "Matrix is not positive definite");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -51,8 +51,6 @@ macro_rules! make_dot_vec {
         impl ArgminDot<Vec<Vec<$t>>, Vec<Vec<$t>>> for Vec<Vec<$t>> {
             #[inline]
             fn dot(&self, other: &Vec<Vec<$t>>) -> Vec<Vec<$t>> {
-                // Would be more efficient if this wasn't necessary!
-                let other = other.clone().t();
                 let sr = self.len();
                 assert!(sr > 0);
                 let sc = self[0].len();
@@ -62,6 +60,8 @@ macro_rules! make_dot_vec {
                 let oc = other[0].len();
                 assert_eq!(sc, or);
                 assert!(oc > 0);
+                // Would be more efficient if this wasn't necessary!
+                let other = other.clone().t();
                 let v = vec![<$t>::default(); oc];
                 let mut out = vec![v; sr];
                 for i in 0..sr {
@@ -409,8 +409,7 @@ mod tests {
 
             item! {
                 #[test]
-                #[should_panic]
-                fn [<test_mat_mat_panic_4_ $t>]() {
+                fn [<test_mat_mat_non_square_ $t>]() {
                     let a = vec![
                         vec![1 as $t, 2 as $t, 3 as $t],
                         vec![4 as $t, 5 as $t, 6 as $t],
@@ -421,7 +420,19 @@ mod tests {
                         vec![6 as $t, 5 as $t],
                         vec![3 as $t, 2 as $t]
                     ];
-                    a.dot(&b);
+                    let res = vec![
+                        vec![24 as $t, 18 as $t],
+                        vec![60 as $t, 45 as $t],
+                        vec![24 as $t, 18 as $t]
+                    ];
+                    let product = a.dot(&b);
+                    assert_eq!(product.len(), 3);
+                    for i in 0..3 {
+                        assert_eq!(product[i].len(), 2);
+                        for j in 0..2 {
+                            assert!((((res[i][j] - product[i][j]) as f64).abs()) < std::f64::EPSILON);
+                        }
+                    }
                 }
             }
 
@@ -0,0 +1,73 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::dense;
+use crate::{ArgminInv, Error};
+use num_traits::Float;
+
+impl<F: Float> ArgminInv<Vec<Vec<F>>> for Vec<Vec<F>> {
+    #[inline]
+    fn inv(&self) -> Result<Vec<Vec<F>>, Error> {
+        let (lu, perm) = dense::lu_decompose(self.clone())?;
+        let n = lu.len();
+        let columns: Vec<Vec<F>> = (0..n)
+            .map(|j| {
+                let mut e = vec![F::zero(); n];
+                e[j] = F::one();
+                dense::lu_solve(&lu, &perm, &e)
+            })
+            .collect();
+        Ok((0..n)
+            .map(|i| columns.iter().map(|column| column[i]).collect())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_inv_ $t>]() {
+                    let a = vec![
+                        vec![2 as $t, 5 as $t],
+                        vec![1 as $t, 3 as $t],
+                    ];
+                    let target = vec![
+                        vec![3 as $t, -5 as $t],
+                        vec![-1 as $t, 2 as $t],
+                    ];
+                    let res = <Vec<Vec<$t>> as ArgminInv<Vec<Vec<$t>>>>::inv(&a).unwrap();
+                    for (row, target_row) in res.iter().zip(target.iter()) {
+                        for (&r, &t) in row.iter().zip(target_row.iter()) {
+                            assert!(((r - t) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_inv_error_ $t>]() {
+                    let a = vec![
+                        vec![2 as $t, 5 as $t],
+                        vec![4 as $t, 10 as $t],
+                    ];
+                    let res = <Vec<Vec<$t>> as ArgminInv<Vec<Vec<$t>>>>::inv(&a);
+                    assert_eq!(format!("{}", res.unwrap_err()), "Singular matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
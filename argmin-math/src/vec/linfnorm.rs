@@ -0,0 +1,147 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminLInfNorm;
+use num_complex::Complex;
+
+macro_rules! make_norm_float {
+    ($t:ty) => {
+        impl ArgminLInfNorm<$t> for Vec<$t> {
+            #[inline]
+            fn linf_norm(&self) -> $t {
+                self.iter().fold(0 as $t, |acc, a| acc.max(a.abs()))
+            }
+        }
+    };
+}
+
+macro_rules! make_norm_integer {
+    ($t:ty) => {
+        impl ArgminLInfNorm<$t> for Vec<$t> {
+            #[inline]
+            fn linf_norm(&self) -> $t {
+                self.iter().map(|a| a.abs()).max().unwrap_or(0)
+            }
+        }
+    };
+}
+
+macro_rules! make_norm_unsigned {
+    ($t:ty) => {
+        impl ArgminLInfNorm<$t> for Vec<$t> {
+            #[inline]
+            fn linf_norm(&self) -> $t {
+                self.iter().copied().max().unwrap_or(0)
+            }
+        }
+    };
+}
+
+macro_rules! make_norm_complex {
+    ($i: ty, $t:ty) => {
+        impl ArgminLInfNorm<$t> for Vec<$i> {
+            #[inline]
+            fn linf_norm(&self) -> $t {
+                self.iter().fold(0 as $t, |acc, a| acc.max(a.norm()))
+            }
+        }
+    };
+}
+
+make_norm_unsigned!(u8);
+make_norm_unsigned!(u16);
+make_norm_unsigned!(u32);
+make_norm_unsigned!(u64);
+make_norm_unsigned!(usize);
+make_norm_integer!(i8);
+make_norm_integer!(i16);
+make_norm_integer!(i32);
+make_norm_integer!(i64);
+make_norm_integer!(isize);
+make_norm_float!(f32);
+make_norm_float!(f64);
+make_norm_complex!(Complex<f32>, f32);
+make_norm_complex!(Complex<f64>, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_linfnorm_ $t>]() {
+                    let a = vec![3 as $t, 4 as $t, 2 as $t];
+                    let res = <Vec<$t> as ArgminLInfNorm<$t>>::linf_norm(&a);
+                    assert_relative_eq!(4 as f64, res as f64, epsilon = f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_linfnorm_empty_ $t>]() {
+                    let a: Vec<$t> = vec![];
+                    let res = <Vec<$t> as ArgminLInfNorm<$t>>::linf_norm(&a);
+                    assert_relative_eq!(0 as f64, res as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    macro_rules! make_test_signed {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_linfnorm_signed_ $t>]() {
+                    let a = vec![3 as $t, -4 as $t, 2 as $t];
+                    let res = <Vec<$t> as ArgminLInfNorm<$t>>::linf_norm(&a);
+                    assert_relative_eq!(4 as f64, res as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    macro_rules! make_test_complex {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_linfnorm_complex_ $t>]() {
+                    let a = vec![Complex::new(1 as $t, 1 as $t), Complex::new(-3 as $t, 4 as $t)];
+                    let res = <Vec<Complex<$t>> as ArgminLInfNorm<$t>>::linf_norm(&a);
+                    assert_relative_eq!(5 as f64, res as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+
+    make_test_signed!(isize);
+    make_test_signed!(i8);
+    make_test_signed!(i16);
+    make_test_signed!(i32);
+    make_test_signed!(i64);
+    make_test_signed!(f32);
+    make_test_signed!(f64);
+
+    make_test_complex!(f32);
+    make_test_complex!(f64);
+}
@@ -0,0 +1,57 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::dense;
+use crate::{ArgminLU, Error};
+
+macro_rules! make_lu {
+    ($t:ty) => {
+        impl ArgminLU<Vec<Vec<$t>>> for Vec<Vec<$t>> {
+            #[inline]
+            fn lu(&self) -> Result<(Vec<Vec<$t>>, Vec<Vec<$t>>, Vec<Vec<$t>>), Error> {
+                dense::lu(self.clone())
+            }
+        }
+    };
+}
+
+make_lu!(f32);
+make_lu!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_lu_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t],
+                        vec![3 as $t, 4 as $t],
+                    ];
+                    let (p, l, u) = <Vec<Vec<$t>> as ArgminLU<Vec<Vec<$t>>>>::lu(&a).unwrap();
+                    let p_target = vec![vec![0 as $t, 1 as $t], vec![1 as $t, 0 as $t]];
+                    let l_target = vec![vec![1 as $t, 0 as $t], vec![1.0 as $t / 3.0, 1 as $t]];
+                    let u_target = vec![vec![3 as $t, 4 as $t], vec![0 as $t, 2.0 as $t / 3.0]];
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!(((p[i][j] - p_target[i][j]) as f64).abs() < 1e-5);
+                            assert!(((l[i][j] - l_target[i][j]) as f64).abs() < 1e-5);
+                            assert!(((u[i][j] - u_target[i][j]) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
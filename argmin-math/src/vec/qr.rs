@@ -0,0 +1,60 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::dense;
+use crate::{ArgminQR, Error};
+
+macro_rules! make_qr {
+    ($t:ty) => {
+        impl ArgminQR<Vec<Vec<$t>>, Vec<Vec<$t>>> for Vec<Vec<$t>> {
+            #[inline]
+            fn qr(&self) -> Result<(Vec<Vec<$t>>, Vec<Vec<$t>>), Error> {
+                dense::qr(self)
+            }
+        }
+    };
+}
+
+make_qr!(f32);
+make_qr!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_qr_ $t>]() {
+                    let a = vec![
+                        vec![3 as $t, 1 as $t],
+                        vec![4 as $t, 2 as $t],
+                        vec![0 as $t, 5 as $t],
+                    ];
+                    let (q, r) =
+                        <Vec<Vec<$t>> as ArgminQR<Vec<Vec<$t>>, Vec<Vec<$t>>>>::qr(&a).unwrap();
+                    assert_eq!(q.len(), 3);
+                    assert_eq!(q[0].len(), 2);
+                    assert_eq!(r.len(), 2);
+                    assert_eq!(r[0].len(), 2);
+                    assert!((r[1][0] as f64).abs() < 1e-5);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            let qr_ij = q[i][0] * r[0][j] + q[i][1] * r[1][j];
+                            assert!(((qr_ij - a[i][j]) as f64).abs() < 1e-5);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
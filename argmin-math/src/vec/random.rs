@@ -11,12 +11,19 @@ use rand::Rng;
 macro_rules! make_random {
     ($t:ty) => {
         impl ArgminRandom for Vec<$t> {
-            fn rand_from_range(min: &Self, max: &Self) -> Vec<$t> {
+            #[inline]
+            fn rand_from_range(min: &Self, max: &Self) -> Self {
+                Self::rand_from_range_with_rng(min, max, &mut rand::thread_rng())
+            }
+
+            fn rand_from_range_with_rng<R: Rng + ?Sized>(
+                min: &Self,
+                max: &Self,
+                rng: &mut R,
+            ) -> Vec<$t> {
                 assert!(!min.is_empty());
                 assert_eq!(min.len(), max.len());
 
-                let mut rng = rand::thread_rng();
-
                 min.iter()
                     .zip(max.iter())
                     .map(|(a, b)| {
@@ -37,12 +44,21 @@ macro_rules! make_random {
         }
 
         impl ArgminRandom for Vec<Vec<$t>> {
-            fn rand_from_range(min: &Self, max: &Self) -> Vec<Vec<$t>> {
+            #[inline]
+            fn rand_from_range(min: &Self, max: &Self) -> Self {
+                Self::rand_from_range_with_rng(min, max, &mut rand::thread_rng())
+            }
+
+            fn rand_from_range_with_rng<R: Rng + ?Sized>(
+                min: &Self,
+                max: &Self,
+                rng: &mut R,
+            ) -> Vec<Vec<$t>> {
                 assert!(!min.is_empty());
                 assert_eq!(min.len(), max.len());
                 min.iter()
                     .zip(max.iter())
-                    .map(|(a, b)| Vec::<$t>::rand_from_range(a, b))
+                    .map(|(a, b)| Vec::<$t>::rand_from_range_with_rng(a, b, rng))
                     .collect()
             }
         }
@@ -66,6 +82,7 @@ make_random!(usize);
 mod tests {
     use super::*;
     use paste::item;
+    use rand::{rngs::StdRng, SeedableRng};
 
     macro_rules! make_test {
         ($t:ty) => {
@@ -82,6 +99,23 @@ mod tests {
                 }
             }
 
+            item! {
+                #[test]
+                fn [<test_random_vec_with_rng_ $t>]() {
+                    let a = vec![1 as $t, 2 as $t, 4 as $t];
+                    let b = vec![2 as $t, 3 as $t, 5 as $t];
+                    let mut rng1 = StdRng::seed_from_u64(42);
+                    let mut rng2 = StdRng::seed_from_u64(42);
+                    let random1 = Vec::<$t>::rand_from_range_with_rng(&a, &b, &mut rng1);
+                    let random2 = Vec::<$t>::rand_from_range_with_rng(&a, &b, &mut rng2);
+                    for i in 0..3usize {
+                        assert!(random1[i] >= a[i]);
+                        assert!(random1[i] <= b[i]);
+                    }
+                    assert_eq!(random1, random2);
+                }
+            }
+
             item! {
                 #[test]
                 fn [<test_random_mat_ $t>]() {
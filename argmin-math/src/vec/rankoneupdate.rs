@@ -0,0 +1,90 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminRankOneUpdate;
+
+macro_rules! make_rankoneupdate {
+    ($t:ty) => {
+        impl ArgminRankOneUpdate<Vec<$t>, $t> for Vec<Vec<$t>> {
+            #[inline]
+            fn rank_one_update(&mut self, alpha: &$t, x: &Vec<$t>, y: &Vec<$t>) {
+                assert_eq!(self.len(), x.len());
+                for (row, xi) in self.iter_mut().zip(x.iter()) {
+                    assert_eq!(row.len(), y.len());
+                    let axi = *alpha * *xi;
+                    row.iter_mut()
+                        .zip(y.iter())
+                        .for_each(|(a, yj)| *a += axi * *yj);
+                }
+            }
+        }
+    };
+}
+
+make_rankoneupdate!(i8);
+make_rankoneupdate!(i16);
+make_rankoneupdate!(i32);
+make_rankoneupdate!(i64);
+make_rankoneupdate!(isize);
+make_rankoneupdate!(u8);
+make_rankoneupdate!(u16);
+make_rankoneupdate!(u32);
+make_rankoneupdate!(u64);
+make_rankoneupdate!(usize);
+make_rankoneupdate!(f32);
+make_rankoneupdate!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_rankoneupdate_ $t>]() {
+                    let mut a = vec![vec![1 as $t, 2 as $t, 3 as $t], vec![4 as $t, 5 as $t, 6 as $t], vec![7 as $t, 8 as $t, 9 as $t]];
+                    let x = vec![1 as $t, 2 as $t, 3 as $t];
+                    let y = vec![4 as $t, 5 as $t, 6 as $t];
+                    <Vec<Vec<$t>> as ArgminRankOneUpdate<Vec<$t>, $t>>::rank_one_update(&mut a, &(2 as $t), &x, &y);
+                    let target = [[9 as $t, 12 as $t, 15 as $t], [20 as $t, 25 as $t, 30 as $t], [31 as $t, 38 as $t, 45 as $t]];
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_rankoneupdate_panic_ $t>]() {
+                    let mut a = vec![vec![1 as $t, 2 as $t, 3 as $t], vec![4 as $t, 5 as $t, 6 as $t], vec![7 as $t, 8 as $t, 9 as $t]];
+                    let x = vec![1 as $t, 2 as $t, 3 as $t];
+                    let y = vec![4 as $t, 5 as $t];
+                    <Vec<Vec<$t>> as ArgminRankOneUpdate<Vec<$t>, $t>>::rank_one_update(&mut a, &(2 as $t), &x, &y);
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(isize);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(usize);
+    make_test!(f32);
+    make_test!(f64);
+}
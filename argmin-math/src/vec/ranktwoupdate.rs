@@ -0,0 +1,90 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminRankTwoUpdate;
+
+macro_rules! make_ranktwoupdate {
+    ($t:ty) => {
+        impl ArgminRankTwoUpdate<Vec<$t>, $t> for Vec<Vec<$t>> {
+            #[inline]
+            fn rank_two_update(&mut self, alpha: &$t, x: &Vec<$t>, y: &Vec<$t>) {
+                assert_eq!(self.len(), x.len());
+                assert_eq!(x.len(), y.len());
+                for (i, row) in self.iter_mut().enumerate() {
+                    assert_eq!(row.len(), x.len());
+                    for (j, a) in row.iter_mut().enumerate() {
+                        *a += *alpha * (x[i] * y[j] + y[i] * x[j]);
+                    }
+                }
+            }
+        }
+    };
+}
+
+make_ranktwoupdate!(i8);
+make_ranktwoupdate!(i16);
+make_ranktwoupdate!(i32);
+make_ranktwoupdate!(i64);
+make_ranktwoupdate!(isize);
+make_ranktwoupdate!(u8);
+make_ranktwoupdate!(u16);
+make_ranktwoupdate!(u32);
+make_ranktwoupdate!(u64);
+make_ranktwoupdate!(usize);
+make_ranktwoupdate!(f32);
+make_ranktwoupdate!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_ranktwoupdate_ $t>]() {
+                    let mut a = vec![vec![1 as $t, 2 as $t, 3 as $t], vec![4 as $t, 5 as $t, 6 as $t], vec![7 as $t, 8 as $t, 9 as $t]];
+                    let x = vec![1 as $t, 2 as $t, 3 as $t];
+                    let y = vec![4 as $t, 5 as $t, 6 as $t];
+                    <Vec<Vec<$t>> as ArgminRankTwoUpdate<Vec<$t>, $t>>::rank_two_update(&mut a, &(2 as $t), &x, &y);
+                    let target = [[17 as $t, 28 as $t, 39 as $t], [30 as $t, 45 as $t, 60 as $t], [43 as $t, 62 as $t, 81 as $t]];
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_ranktwoupdate_panic_ $t>]() {
+                    let mut a = vec![vec![1 as $t, 2 as $t, 3 as $t], vec![4 as $t, 5 as $t, 6 as $t], vec![7 as $t, 8 as $t, 9 as $t]];
+                    let x = vec![1 as $t, 2 as $t, 3 as $t];
+                    let y = vec![4 as $t, 5 as $t];
+                    <Vec<Vec<$t>> as ArgminRankTwoUpdate<Vec<$t>, $t>>::rank_two_update(&mut a, &(2 as $t), &x, &y);
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(isize);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(usize);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,159 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminScaledAddAssign;
+use num_complex::Complex;
+
+macro_rules! make_scaledaddassign {
+    ($t:ty) => {
+        impl ArgminScaledAddAssign<Vec<$t>, $t> for Vec<$t> {
+            #[inline]
+            fn scaled_add_assign(&mut self, factor: &$t, vec: &Vec<$t>) {
+                assert_eq!(self.len(), vec.len());
+                self.iter_mut()
+                    .zip(vec.iter())
+                    .for_each(|(a, b)| *a += *factor * *b);
+            }
+        }
+
+        impl ArgminScaledAddAssign<Vec<$t>, Vec<$t>> for Vec<$t> {
+            #[inline]
+            fn scaled_add_assign(&mut self, factor: &Vec<$t>, vec: &Vec<$t>) {
+                assert_eq!(self.len(), factor.len());
+                assert_eq!(self.len(), vec.len());
+                self.iter_mut()
+                    .zip(factor.iter().zip(vec.iter()))
+                    .for_each(|(a, (f, b))| *a += *f * *b);
+            }
+        }
+
+        impl ArgminScaledAddAssign<Vec<Vec<$t>>, $t> for Vec<Vec<$t>> {
+            #[inline]
+            fn scaled_add_assign(&mut self, factor: &$t, vec: &Vec<Vec<$t>>) {
+                assert_eq!(self.len(), vec.len());
+                self.iter_mut().zip(vec.iter()).for_each(|(a, b)| {
+                    <Vec<$t> as ArgminScaledAddAssign<Vec<$t>, $t>>::scaled_add_assign(a, factor, b)
+                });
+            }
+        }
+    };
+}
+
+make_scaledaddassign!(isize);
+make_scaledaddassign!(usize);
+make_scaledaddassign!(i8);
+make_scaledaddassign!(i16);
+make_scaledaddassign!(i32);
+make_scaledaddassign!(i64);
+make_scaledaddassign!(u8);
+make_scaledaddassign!(u16);
+make_scaledaddassign!(u32);
+make_scaledaddassign!(u64);
+make_scaledaddassign!(f32);
+make_scaledaddassign!(f64);
+make_scaledaddassign!(Complex<isize>);
+make_scaledaddassign!(Complex<usize>);
+make_scaledaddassign!(Complex<i8>);
+make_scaledaddassign!(Complex<i16>);
+make_scaledaddassign!(Complex<i32>);
+make_scaledaddassign!(Complex<i64>);
+make_scaledaddassign!(Complex<u8>);
+make_scaledaddassign!(Complex<u16>);
+make_scaledaddassign!(Complex<u32>);
+make_scaledaddassign!(Complex<u64>);
+make_scaledaddassign!(Complex<f32>);
+make_scaledaddassign!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scaledaddassign_vec_ $t>]() {
+                    let mut a = vec![1 as $t, 2 as $t, 3 as $t];
+                    let f = 2 as $t;
+                    let b = vec![4 as $t, 5 as $t, 6 as $t];
+                    <Vec<$t> as ArgminScaledAddAssign<Vec<$t>, $t>>::scaled_add_assign(&mut a, &f, &b);
+                    let target = [9 as $t, 12 as $t, 15 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledaddassign_vec_panic_ $t>]() {
+                    let mut a = vec![1 as $t, 2 as $t];
+                    let f = 2 as $t;
+                    let b = vec![4 as $t, 5 as $t, 6 as $t];
+                    <Vec<$t> as ArgminScaledAddAssign<Vec<$t>, $t>>::scaled_add_assign(&mut a, &f, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledaddassign_vec_vec_ $t>]() {
+                    let mut a = vec![1 as $t, 2 as $t, 3 as $t];
+                    let f = vec![3 as $t, 2 as $t, 1 as $t];
+                    let b = vec![4 as $t, 5 as $t, 6 as $t];
+                    <Vec<$t> as ArgminScaledAddAssign<Vec<$t>, Vec<$t>>>::scaled_add_assign(&mut a, &f, &b);
+                    let target = [13 as $t, 12 as $t, 9 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledaddassign_vec_vec_panic_ $t>]() {
+                    let mut a = vec![1 as $t, 2 as $t];
+                    let f = vec![3 as $t, 2 as $t, 1 as $t];
+                    let b = vec![4 as $t, 5 as $t, 6 as $t];
+                    <Vec<$t> as ArgminScaledAddAssign<Vec<$t>, Vec<$t>>>::scaled_add_assign(&mut a, &f, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledaddassign_mat_ $t>]() {
+                    let mut a = vec![vec![1 as $t, 2 as $t, 3 as $t], vec![4 as $t, 5 as $t, 6 as $t]];
+                    let f = 2 as $t;
+                    let b = vec![vec![4 as $t, 5 as $t, 6 as $t], vec![7 as $t, 8 as $t, 9 as $t]];
+                    <Vec<Vec<$t>> as ArgminScaledAddAssign<Vec<Vec<$t>>, $t>>::scaled_add_assign(&mut a, &f, &b);
+                    let target = [[9 as $t, 12 as $t, 15 as $t], [18 as $t, 21 as $t, 24 as $t]];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
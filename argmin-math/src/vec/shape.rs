@@ -0,0 +1,111 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::shape::ShapeError;
+use crate::{ArgminShape, Error};
+use num_complex::Complex;
+
+macro_rules! make_shape {
+    ($t:ty) => {
+        impl ArgminShape for Vec<$t> {
+            const NDIM: usize = 1;
+
+            #[inline]
+            fn shape(&self) -> Result<Vec<usize>, Error> {
+                Ok(vec![self.len()])
+            }
+        }
+
+        impl ArgminShape for Vec<Vec<$t>> {
+            const NDIM: usize = 2;
+
+            #[inline]
+            fn shape(&self) -> Result<Vec<usize>, Error> {
+                let cols = self.first().map(|row| row.len()).unwrap_or(0);
+                if self.iter().any(|row| row.len() != cols) {
+                    return Err(ShapeError::Ragged.into());
+                }
+                Ok(vec![self.len(), cols])
+            }
+        }
+    };
+}
+
+make_shape!(isize);
+make_shape!(usize);
+make_shape!(i8);
+make_shape!(i16);
+make_shape!(i32);
+make_shape!(i64);
+make_shape!(u8);
+make_shape!(u16);
+make_shape!(u32);
+make_shape!(u64);
+make_shape!(f32);
+make_shape!(f64);
+make_shape!(Complex<isize>);
+make_shape!(Complex<usize>);
+make_shape!(Complex<i8>);
+make_shape!(Complex<i16>);
+make_shape!(Complex<i32>);
+make_shape!(Complex<i64>);
+make_shape!(Complex<u8>);
+make_shape!(Complex<u16>);
+make_shape!(Complex<u32>);
+make_shape!(Complex<u64>);
+make_shape!(Complex<f32>);
+make_shape!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_shape_vec_ $t>]() {
+                    let a = vec![1 as $t, 2 as $t, 3 as $t];
+                    assert_eq!(<Vec<$t> as ArgminShape>::NDIM, 1);
+                    assert_eq!(a.shape().unwrap(), vec![3]);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_shape_mat_ $t>]() {
+                    let a = vec![vec![1 as $t, 2 as $t, 3 as $t], vec![4 as $t, 5 as $t, 6 as $t]];
+                    assert_eq!(<Vec<Vec<$t>> as ArgminShape>::NDIM, 2);
+                    assert_eq!(a.shape().unwrap(), vec![2, 3]);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_shape_ragged_ $t>]() {
+                    let a = vec![vec![1 as $t, 2 as $t, 3 as $t], vec![4 as $t, 5 as $t]];
+                    let err = a.shape().unwrap_err();
+                    assert_eq!(err.downcast_ref::<ShapeError>(), Some(&ShapeError::Ragged));
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
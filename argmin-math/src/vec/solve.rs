@@ -0,0 +1,59 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::dense;
+use crate::{ArgminSolve, Error};
+use num_traits::Float;
+
+impl<F: Float> ArgminSolve<Vec<F>, Vec<F>> for Vec<Vec<F>> {
+    #[inline]
+    fn solve(&self, b: &Vec<F>) -> Result<Vec<F>, Error> {
+        dense::solve(self.clone(), b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_solve_ $t>]() {
+                    let a = vec![
+                        vec![2 as $t, 5 as $t],
+                        vec![1 as $t, 3 as $t],
+                    ];
+                    let b = vec![1 as $t, 2 as $t];
+                    let res = <Vec<Vec<$t>> as ArgminSolve<Vec<$t>, Vec<$t>>>::solve(&a, &b).unwrap();
+                    let target = vec![-7 as $t, 3 as $t];
+                    for i in 0..2 {
+                        assert!(((res[i] - target[i]) as f64).abs() < 1e-5);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_error_ $t>]() {
+                    let a = vec![
+                        vec![2 as $t, 5 as $t],
+                        vec![4 as $t, 10 as $t],
+                    ];
+                    let b = vec![1 as $t, 2 as $t];
+                    let res = <Vec<Vec<$t>> as ArgminSolve<Vec<$t>, Vec<$t>>>::solve(&a, &b);
+                    assert_eq!(format!("{}", res.unwrap_err()), "Singular matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,163 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSubAssign;
+use num_complex::Complex;
+
+macro_rules! make_subassign {
+    ($t:ty) => {
+        impl ArgminSubAssign<$t> for Vec<$t> {
+            #[inline]
+            fn sub_assign(&mut self, other: &$t) {
+                self.iter_mut().for_each(|a| *a -= *other);
+            }
+        }
+
+        impl ArgminSubAssign<Vec<$t>> for Vec<$t> {
+            #[inline]
+            fn sub_assign(&mut self, other: &Vec<$t>) {
+                assert_eq!(self.len(), other.len());
+                self.iter_mut()
+                    .zip(other.iter())
+                    .for_each(|(a, b)| *a -= *b);
+            }
+        }
+
+        impl ArgminSubAssign<$t> for Vec<Vec<$t>> {
+            #[inline]
+            fn sub_assign(&mut self, other: &$t) {
+                self.iter_mut()
+                    .for_each(|a| <Vec<$t> as ArgminSubAssign<$t>>::sub_assign(a, other));
+            }
+        }
+
+        impl ArgminSubAssign<Vec<Vec<$t>>> for Vec<Vec<$t>> {
+            #[inline]
+            fn sub_assign(&mut self, other: &Vec<Vec<$t>>) {
+                assert_eq!(self.len(), other.len());
+                self.iter_mut()
+                    .zip(other.iter())
+                    .for_each(|(a, b)| <Vec<$t> as ArgminSubAssign<Vec<$t>>>::sub_assign(a, b));
+            }
+        }
+    };
+}
+
+make_subassign!(isize);
+make_subassign!(usize);
+make_subassign!(i8);
+make_subassign!(i16);
+make_subassign!(i32);
+make_subassign!(i64);
+make_subassign!(u8);
+make_subassign!(u16);
+make_subassign!(u32);
+make_subassign!(u64);
+make_subassign!(f32);
+make_subassign!(f64);
+make_subassign!(Complex<isize>);
+make_subassign!(Complex<usize>);
+make_subassign!(Complex<i8>);
+make_subassign!(Complex<i16>);
+make_subassign!(Complex<i32>);
+make_subassign!(Complex<i64>);
+make_subassign!(Complex<u8>);
+make_subassign!(Complex<u16>);
+make_subassign!(Complex<u32>);
+make_subassign!(Complex<u64>);
+make_subassign!(Complex<f32>);
+make_subassign!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_subassign_vec_scalar_ $t>]() {
+                    let mut a = vec![35 as $t, 38 as $t, 42 as $t];
+                    let b = 34 as $t;
+                    <Vec<$t> as ArgminSubAssign<$t>>::sub_assign(&mut a, &b);
+                    let target = [1 as $t, 4 as $t, 8 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_subassign_vec_vec_ $t>]() {
+                    let mut a = vec![42 as $t, 42 as $t, 42 as $t];
+                    let b = vec![41 as $t, 38 as $t, 34 as $t];
+                    <Vec<$t> as ArgminSubAssign<Vec<$t>>>::sub_assign(&mut a, &b);
+                    let target = [1 as $t, 4 as $t, 8 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_subassign_vec_vec_panic_ $t>]() {
+                    let mut a = vec![42 as $t, 42 as $t];
+                    let b = vec![41 as $t, 38 as $t, 34 as $t];
+                    <Vec<$t> as ArgminSubAssign<Vec<$t>>>::sub_assign(&mut a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_subassign_mat_mat_ $t>]() {
+                    let mut a = vec![vec![42 as $t, 42 as $t, 42 as $t], vec![42 as $t, 42 as $t, 42 as $t]];
+                    let b = vec![vec![41 as $t, 38 as $t, 34 as $t], vec![40 as $t, 37 as $t, 33 as $t]];
+                    <Vec<Vec<$t>> as ArgminSubAssign<Vec<Vec<$t>>>>::sub_assign(&mut a, &b);
+                    let target = [[1 as $t, 4 as $t, 8 as $t], [2 as $t, 5 as $t, 9 as $t]];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_subassign_mat_scalar_ $t>]() {
+                    let mut a = vec![vec![3 as $t, 6 as $t, 10 as $t], vec![4 as $t, 7 as $t, 11 as $t]];
+                    let b = 2 as $t;
+                    <Vec<Vec<$t>> as ArgminSubAssign<$t>>::sub_assign(&mut a, &b);
+                    let target = [[1 as $t, 4 as $t, 8 as $t], [2 as $t, 5 as $t, 9 as $t]];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(target[i][j] as f64, a[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
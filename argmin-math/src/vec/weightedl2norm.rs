@@ -0,0 +1,61 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminWeightedL2Norm;
+
+macro_rules! make_norm {
+    ($t:ty) => {
+        impl ArgminWeightedL2Norm<Vec<$t>, $t> for Vec<$t> {
+            #[inline]
+            fn weighted_l2_norm(&self, w: &Vec<$t>) -> $t {
+                assert_eq!(self.len(), w.len());
+                self.iter()
+                    .zip(w.iter())
+                    .map(|(a, w)| w * a.powi(2))
+                    .sum::<$t>()
+                    .sqrt()
+            }
+        }
+    };
+}
+
+make_norm!(f32);
+make_norm!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_weightedl2norm_ $t>]() {
+                    let a = vec![1 as $t, -2 as $t, 3 as $t];
+                    let w = vec![4 as $t, 1 as $t, 0.5 as $t];
+                    let res = <Vec<$t> as ArgminWeightedL2Norm<Vec<$t>, $t>>::weighted_l2_norm(&a, &w);
+                    assert_relative_eq!((12.5 as f64).sqrt(), res as f64, epsilon = 1e-6);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_weightedl2norm_panic_ $t>]() {
+                    let a = vec![1 as $t, -2 as $t, 3 as $t];
+                    let w = vec![4 as $t, 1 as $t];
+                    <Vec<$t> as ArgminWeightedL2Norm<Vec<$t>, $t>>::weighted_l2_norm(&a, &w);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
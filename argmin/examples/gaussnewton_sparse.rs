@@ -0,0 +1,81 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{Error, Executor, Jacobian, Operator};
+use argmin::solver::gaussnewton::GaussNewton;
+
+use sprs::{CsMat, TriMat};
+
+// Extended Rosenbrock function written as a nonlinear least squares problem. Each residual only
+// depends on two parameters, therefore the Jacobian is sparse (two nonzero entries per row at
+// most).
+struct ExtendedRosenbrock {
+    n: usize,
+}
+
+impl Operator for ExtendedRosenbrock {
+    type Param = Vec<f64>;
+    type Output = Vec<f64>;
+
+    fn apply(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        Ok((0..self.n / 2)
+            .flat_map(|i| {
+                let (x1, x2) = (p[2 * i], p[2 * i + 1]);
+                [10.0 * (x2 - x1.powi(2)), 1.0 - x1]
+            })
+            .collect())
+    }
+}
+
+impl Jacobian for ExtendedRosenbrock {
+    type Param = Vec<f64>;
+    type Jacobian = CsMat<f64>;
+
+    fn jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+        let mut jacobian = TriMat::new((self.n, self.n));
+        for i in 0..self.n / 2 {
+            jacobian.add_triplet(2 * i, 2 * i, -20.0 * p[2 * i]);
+            jacobian.add_triplet(2 * i, 2 * i + 1, 10.0);
+            jacobian.add_triplet(2 * i + 1, 2 * i, -1.0);
+        }
+        Ok(jacobian.to_csr())
+    }
+}
+
+fn run() -> Result<(), Error> {
+    // Define cost function
+    let cost = ExtendedRosenbrock { n: 100 };
+
+    // Define initial parameter vector
+    let init_param: Vec<f64> = (0..cost.n)
+        .map(|i| if i % 2 == 0 { -1.2 } else { 1.0 })
+        .collect();
+
+    // Set up solver
+    let solver: GaussNewton<f64> = GaussNewton::new();
+
+    // Run solver
+    let res = Executor::new(cost, solver)
+        .configure(|state| state.param(init_param).max_iters(10))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Wait a second (lets the logger flush everything before printing again)
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    // Print result
+    println!("{res}");
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+        std::process::exit(1);
+    }
+}
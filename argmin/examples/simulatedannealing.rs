@@ -98,7 +98,9 @@ fn run() -> Result<(), Error> {
     // An alternative random number generator (RNG) can be provided to `new_with_rng`:
     // SimulatedAnnealing::new_with_rng(temp, Xoshiro256PlusPlus::from_entropy())?
     let solver = SimulatedAnnealing::new(temp)?
-        // Optional: Define temperature function (defaults to `SATempFunc::TemperatureFast`)
+        // Optional: Define temperature function (defaults to `SATempFunc::TemperatureFast`).
+        // Any implementation of `TemperatureSchedule` can be used, such as `LundyMees::new(0.01)`
+        // or `AdaptiveAcceptance::new()`.
         .with_temp_func(SATempFunc::Boltzmann)
         /////////////////////////
         // Stopping criteria   //
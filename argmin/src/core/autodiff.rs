@@ -0,0 +1,366 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, Hessian, Jacobian, KvValue, Operator,
+};
+use argmin_math::Dual;
+use std::marker::PhantomData;
+
+/// Cost function which is written generically over the float type
+///
+/// Implementing this trait instead of [`CostFunction`] allows [`ForwardDiff`] to evaluate the
+/// cost function with dual numbers and thereby compute exact gradients and Hessians.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{ArgminFloat, Error, GenericCostFunction};
+///
+/// struct Rosenbrock {}
+///
+/// impl GenericCostFunction for Rosenbrock {
+///     fn cost<T: ArgminFloat>(&self, p: &[T]) -> Result<T, Error> {
+///         let a = T::from_f64(1.0).unwrap();
+///         let b = T::from_f64(100.0).unwrap();
+///         Ok((a - p[0]).powi(2) + b * (p[1] - p[0].powi(2)).powi(2))
+///     }
+/// }
+/// ```
+pub trait GenericCostFunction {
+    /// Compute cost function for any float type `T`
+    fn cost<T: ArgminFloat>(&self, param: &[T]) -> Result<T, Error>;
+}
+
+/// Operator which is written generically over the float type
+///
+/// Implementing this trait instead of [`Operator`] allows [`ForwardDiff`] to evaluate the operator
+/// with dual numbers and thereby compute exact Jacobians.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{ArgminFloat, Error, GenericOperator};
+///
+/// struct Residuals {}
+///
+/// impl GenericOperator for Residuals {
+///     fn apply<T: ArgminFloat>(&self, p: &[T]) -> Result<Vec<T>, Error> {
+///         Ok(vec![p[0] * p[1], p[0].sin()])
+///     }
+/// }
+/// ```
+pub trait GenericOperator {
+    /// Apply operator for any float type `T`
+    fn apply<T: ArgminFloat>(&self, param: &[T]) -> Result<Vec<T>, Error>;
+}
+
+/// Exact derivatives via forward-mode automatic differentiation
+///
+/// Wraps a problem implementing [`GenericCostFunction`] and/or [`GenericOperator`] and implements
+/// [`CostFunction`], [`Gradient`] and [`Hessian`] respectively [`Operator`] and [`Jacobian`] for
+/// it, with `Vec<F>` as parameter vector. Derivatives are obtained by evaluating the problem with
+/// [`Dual`] numbers. The gradient and the Jacobian require `n` evaluations, the Hessian requires
+/// `n * (n + 1) / 2` evaluations with nested dual numbers, where `n` is the number of parameters.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{ArgminFloat, Error, ForwardDiff, GenericCostFunction, Gradient, Hessian};
+///
+/// struct Rosenbrock {}
+///
+/// impl GenericCostFunction for Rosenbrock {
+///     fn cost<T: ArgminFloat>(&self, p: &[T]) -> Result<T, Error> {
+///         let a = T::from_f64(1.0).unwrap();
+///         let b = T::from_f64(100.0).unwrap();
+///         Ok((a - p[0]).powi(2) + b * (p[1] - p[0].powi(2)).powi(2))
+///     }
+/// }
+///
+/// let problem = ForwardDiff::new(Rosenbrock {});
+///
+/// let gradient: Vec<f64> = problem.gradient(&vec![1.0, 1.0])?;
+/// assert_eq!(gradient, vec![0.0, 0.0]);
+///
+/// let hessian: Vec<Vec<f64>> = problem.hessian(&vec![1.0, 1.0])?;
+/// assert_eq!(hessian, vec![vec![802.0, -400.0], vec![-400.0, 200.0]]);
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct ForwardDiff<O, F> {
+    /// Wrapped problem
+    problem: O,
+    /// Float type of the parameter vector
+    float: PhantomData<F>,
+}
+
+impl<O, F> ForwardDiff<O, F> {
+    /// Construct a new instance of `ForwardDiff`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ArgminFloat, Error, ForwardDiff, GenericCostFunction};
+    /// # struct Problem {}
+    /// # impl GenericCostFunction for Problem {
+    /// #     fn cost<T: ArgminFloat>(&self, p: &[T]) -> Result<T, Error> {
+    /// #         Ok(p[0])
+    /// #     }
+    /// # }
+    /// let problem: ForwardDiff<_, f64> = ForwardDiff::new(Problem {});
+    /// ```
+    pub fn new(problem: O) -> Self {
+        ForwardDiff {
+            problem,
+            float: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the wrapped problem
+    pub fn inner(&self) -> &O {
+        &self.problem
+    }
+}
+
+/// Seed the parameter vector such that the dual parts are the `i`-th unit vector.
+fn seed<F: ArgminFloat>(param: &[F], i: usize) -> Vec<Dual<F>> {
+    param
+        .iter()
+        .enumerate()
+        .map(|(k, &x)| {
+            if k == i {
+                Dual::variable(x)
+            } else {
+                Dual::constant(x)
+            }
+        })
+        .collect()
+}
+
+impl<O, F> CostFunction for ForwardDiff<O, F>
+where
+    O: GenericCostFunction,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.problem.cost(param)
+    }
+}
+
+impl<O, F> Gradient for ForwardDiff<O, F>
+where
+    O: GenericCostFunction,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Gradient = Vec<F>;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        (0..param.len())
+            .map(|i| Ok(self.problem.cost(&seed(param, i))?.eps))
+            .collect()
+    }
+}
+
+impl<O, F> Hessian for ForwardDiff<O, F>
+where
+    O: GenericCostFunction,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Hessian = Vec<Vec<F>>;
+
+    fn hessian(&self, param: &Self::Param) -> Result<Self::Hessian, Error> {
+        let n = param.len();
+        let mut hessian: Vec<Vec<F>> = Vec::with_capacity(n);
+        for i in 0..n {
+            let inner = seed(param, i);
+            let row = (0..n)
+                .map(|j| {
+                    if j < i {
+                        // The Hessian is symmetric
+                        return Ok(hessian[j][i]);
+                    }
+                    // The inner dual parts carry the direction `i`, the outer ones the direction
+                    // `j`.
+                    let p: Vec<Dual<Dual<F>>> = inner
+                        .iter()
+                        .enumerate()
+                        .map(|(k, &x)| {
+                            let dx = if k == j { F::one() } else { F::zero() };
+                            Dual::new(x, Dual::constant(dx))
+                        })
+                        .collect();
+                    Ok(self.problem.cost(&p)?.eps.eps)
+                })
+                .collect::<Result<Vec<F>, Error>>()?;
+            hessian.push(row);
+        }
+        Ok(hessian)
+    }
+}
+
+impl<O, F> Operator for ForwardDiff<O, F>
+where
+    O: GenericOperator,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Output = Vec<F>;
+
+    fn apply(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.problem.apply(param)
+    }
+}
+
+impl<O, F> Jacobian for ForwardDiff<O, F>
+where
+    O: GenericOperator,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Jacobian = Vec<Vec<F>>;
+
+    fn jacobian(&self, param: &Self::Param) -> Result<Self::Jacobian, Error> {
+        let n = param.len();
+        let mut jacobian: Vec<Vec<F>> = vec![];
+        for i in 0..n {
+            let column = self.problem.apply(&seed(param, i))?;
+            if i == 0 {
+                jacobian = vec![vec![F::zero(); n]; column.len()];
+            }
+            for (row, x) in jacobian.iter_mut().zip(column.iter()) {
+                row[i] = x.eps;
+            }
+        }
+        Ok(jacobian)
+    }
+}
+
+/// Only the real part of a dual number is stored.
+///
+/// This makes `Dual<F>` an [`ArgminFloat`], which is required for evaluating
+/// [`GenericCostFunction`] and [`GenericOperator`] with dual numbers.
+impl<F: Into<KvValue>> From<Dual<F>> for KvValue {
+    fn from(x: Dual<F>) -> KvValue {
+        x.re.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Executor, State};
+    use crate::solver::{linesearch::MoreThuenteLineSearch, quasinewton::LBFGS};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(forward_diff, ForwardDiff<Rosenbrock, f64>);
+
+    #[derive(Clone, Debug)]
+    struct Rosenbrock {}
+
+    impl GenericCostFunction for Rosenbrock {
+        fn cost<T: ArgminFloat>(&self, p: &[T]) -> Result<T, Error> {
+            let a = T::from_f64(1.0).unwrap();
+            let b = T::from_f64(100.0).unwrap();
+            Ok(p.windows(2)
+                .map(|w| (a - w[0]).powi(2) + b * (w[1] - w[0].powi(2)).powi(2))
+                .fold(T::zero(), |acc, x| acc + x))
+        }
+    }
+
+    struct Residuals {}
+
+    impl GenericOperator for Residuals {
+        fn apply<T: ArgminFloat>(&self, p: &[T]) -> Result<Vec<T>, Error> {
+            Ok(vec![p[0] * p[1].exp(), p[0].sin() + p[1] * p[1], p[1].ln()])
+        }
+    }
+
+    #[test]
+    fn test_cost() {
+        let problem = ForwardDiff::new(Rosenbrock {});
+        let cost: f64 = problem.cost(&vec![-1.2, 1.0]).unwrap();
+        assert_relative_eq!(cost, 24.2, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_gradient() {
+        let problem = ForwardDiff::new(Rosenbrock {});
+        let (x, y) = (-1.2f64, 1.0f64);
+        let gradient = problem.gradient(&vec![x, y]).unwrap();
+        let target = [
+            -2.0 * (1.0 - x) - 400.0 * x * (y - x * x),
+            200.0 * (y - x * x),
+        ];
+        assert_eq!(gradient.len(), 2);
+        for i in 0..2 {
+            assert_relative_eq!(gradient[i], target[i], epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_gradient_f32() {
+        let problem = ForwardDiff::new(Rosenbrock {});
+        let gradient: Vec<f32> = problem.gradient(&vec![1.0f32, 1.0, 1.0]).unwrap();
+        for g in gradient {
+            assert_relative_eq!(g, 0.0, epsilon = f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_hessian() {
+        let problem = ForwardDiff::new(Rosenbrock {});
+        let (x, y) = (-1.2f64, 1.0f64);
+        let hessian = problem.hessian(&vec![x, y]).unwrap();
+        let target = [
+            [2.0 - 400.0 * y + 1200.0 * x * x, -400.0 * x],
+            [-400.0 * x, 200.0],
+        ];
+        for i in 0..2 {
+            for j in 0..2 {
+                assert_relative_eq!(hessian[i][j], target[i][j], epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_jacobian() {
+        let problem = ForwardDiff::new(Residuals {});
+        let (x, y) = (0.5f64, 2.0f64);
+        let jacobian = problem.jacobian(&vec![x, y]).unwrap();
+        let target = [[y.exp(), x * y.exp()], [x.cos(), 2.0 * y], [0.0, 1.0 / y]];
+        assert_eq!(jacobian.len(), 3);
+        for i in 0..3 {
+            assert_eq!(jacobian[i].len(), 2);
+            for j in 0..2 {
+                assert_relative_eq!(jacobian[i][j], target[i][j], epsilon = 1e-12);
+            }
+        }
+        let output = problem.apply(&vec![x, y]).unwrap();
+        assert_relative_eq!(output[0], x * y.exp(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_lbfgs() {
+        let problem = ForwardDiff::new(Rosenbrock {});
+        let linesearch = MoreThuenteLineSearch::new();
+        let solver = LBFGS::new(linesearch, 7);
+        let res = Executor::new(problem, solver)
+            .configure(|state| state.param(vec![-1.2f64, 1.0]).max_iters(100))
+            .run()
+            .unwrap();
+        let best = res.state().get_best_param().unwrap();
+        assert_relative_eq!(best[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(best[1], 1.0, epsilon = 1e-6);
+    }
+}
@@ -31,6 +31,20 @@ pub struct Executor<O, S, I> {
     ctrlc: bool,
     /// Indicates whether to time execution or not
     timer: bool,
+    /// Determines when the solver's `init` method is called
+    init: Init,
+}
+
+/// Determines when the solver's `init` method is called in [`Executor::run`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Init {
+    /// Only if the iteration number is 0 (new runs and runs resumed from a checkpoint)
+    FirstIter,
+    /// Always, because the solver has not been initialized yet (see [`Executor::from_result`])
+    Always,
+    /// Only if the iteration number is 0, but observers are always notified (see
+    /// [`Executor::warm_start`])
+    WarmStart,
 }
 
 impl<O, S, I> Executor<O, S, I>
@@ -69,6 +83,108 @@ where
             checkpoint: None,
             ctrlc: true,
             timer: true,
+            init: Init::FirstIter,
+        }
+    }
+
+    /// Constructs an `Executor` which continues from the final state of a previous run.
+    ///
+    /// The problem (including its function evaluation counts) and the state are taken from
+    /// `result`, while `solver` may be a newly configured instance of a solver operating on the
+    /// same type of state. All information stored in the state, such as the current and best
+    /// parameter vectors, the best cost function value or the inverse Hessian, is kept. Only the
+    /// termination status is reset.
+    ///
+    /// Since `solver` has not been initialized yet, its `init` method is always called, which
+    /// allows it to initialize itself from the existing state. The iteration number is kept as
+    /// well, therefore the maximum number of iterations usually needs to be increased via
+    /// [`configure`](`Executor::configure`). If the iteration counters should start from zero
+    /// instead, call [`reset_iter`](`crate::core::State::reset_iter`) on the state.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, Executor, State};
+    /// # use argmin::core::test_utils::{TestSolver, TestProblem};
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// # let solver = TestSolver::new();
+    /// # let problem = TestProblem::new();
+    /// # let init_param = vec![1.0f64, 0.0];
+    /// #
+    /// let result = Executor::new(problem, solver)
+    ///     .configure(|state| state.param(init_param).max_iters(10))
+    ///     .run()?;
+    ///
+    /// // Continue with a (potentially reconfigured) solver for another 10 iterations
+    /// let new_solver = TestSolver::new();
+    /// let result = Executor::from_result(result, new_solver)
+    ///     .configure(|state| state.max_iters(20))
+    ///     .run()?;
+    /// # assert_eq!(result.state().get_iter(), 20);
+    ///
+    /// // Alternatively, start counting iterations from zero again
+    /// let result = Executor::from_result(result, TestSolver::new())
+    ///     .configure(|state| state.reset_iter().max_iters(10))
+    ///     .run()?;
+    /// # assert_eq!(result.state().get_iter(), 10);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_result<S2>(result: OptimizationResult<O, S2, I>, solver: S) -> Self {
+        let OptimizationResult { problem, state, .. } = result;
+        Executor {
+            solver,
+            problem,
+            state: Some(state.reset_termination_status()),
+            observers: Observers::new(),
+            checkpoint: None,
+            ctrlc: true,
+            timer: true,
+            init: Init::Always,
+        }
+    }
+
+    /// Constructs an `Executor` which continues from the final state of a previous run, using the
+    /// solver stored in `result`.
+    ///
+    /// This keeps internal information of the solver (such as the history of an L-BFGS solver).
+    /// See [`from_result`](`Executor::from_result`) for details on how the state is treated.
+    /// Unless the iteration number is reset via [`reset_iter`](`crate::core::State::reset_iter`),
+    /// the solver's `init` method is not called again (as is the case when resuming from a
+    /// checkpoint). Observers are notified via `observe_init` in either case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, Executor, State};
+    /// # use argmin::core::test_utils::{TestSolver, TestProblem};
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// # let solver = TestSolver::new();
+    /// # let problem = TestProblem::new();
+    /// # let init_param = vec![1.0f64, 0.0];
+    /// #
+    /// let result = Executor::new(problem, solver)
+    ///     .configure(|state| state.param(init_param).max_iters(10))
+    ///     .run()?;
+    ///
+    /// let result = Executor::warm_start(result)
+    ///     .configure(|state| state.max_iters(20))
+    ///     .run()?;
+    /// # assert_eq!(result.state().get_iter(), 20);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn warm_start(result: OptimizationResult<O, S, I>) -> Self {
+        let OptimizationResult {
+            problem,
+            solver,
+            state,
+        } = result;
+        Executor {
+            init: Init::WarmStart,
+            ..Executor::from_result(OptimizationResult::new(problem, (), state), solver)
         }
     }
 
@@ -133,6 +249,7 @@ where
             if let Some((solver, state)) = checkpoint.load()? {
                 self.state = Some(state);
                 self.solver = solver;
+                self.init = Init::FirstIter;
             }
         }
         let total_time = if self.timer {
@@ -166,30 +283,54 @@ where
             }
         }
 
-        // Only call `init` of `solver` if the current iteration number is 0. This avoids that
-        // `init` is called when starting from a checkpoint (because `init` could change the state
-        // of the `solver`, which would overwrite the state restored from the checkpoint).
-        let mut state = if state.get_iter() == 0 {
-            let (mut state, kv) = self.solver.init(&mut self.problem, state)?;
-            state.update();
-
-            if !self.observers.is_empty() {
-                let mut logs = kv!("max_iters" => state.get_max_iters(););
+        let result = self.optimize(state, &interrupt, total_time);
 
-                if let Some(kv) = kv {
-                    logs = logs.merge(kv);
+        if !self.observers.is_empty() {
+            match result.as_ref() {
+                Ok(state) => self.observers.observe_final(Ok(state))?,
+                // The error which stopped the solver takes precedence over errors of observers.
+                Err(err) => {
+                    let _ = self.observers.observe_final(Err(err));
                 }
-
-                // Observe after init
-                self.observers.observe_init(S::NAME, &logs)?;
             }
+        }
+
+        let state = result?;
+        Ok(OptimizationResult::new(self.problem, self.solver, state))
+    }
 
+    /// Initializes the solver (if needed) and iterates until termination or interruption.
+    fn optimize(
+        &mut self,
+        state: I,
+        interrupt: &AtomicBool,
+        total_time: Option<instant::Instant>,
+    ) -> Result<I, Error> {
+        // Only call `init` of `solver` if the current iteration number is 0 or if the solver has
+        // not been initialized yet. This avoids that `init` is called when starting from a
+        // checkpoint or warm-starting with the previous solver (because `init` could change the
+        // state of the `solver`, which would overwrite the state restored from the checkpoint).
+        let run_init = self.init == Init::Always || state.get_iter() == 0;
+        let (mut state, kv) = if run_init {
+            let (mut state, kv) = self.solver.init(&mut self.problem, state)?;
+            state.update();
             state.func_counts(&self.problem);
-            state
+            (state, kv)
         } else {
-            state
+            (state, None)
         };
 
+        if (run_init || self.init == Init::WarmStart) && !self.observers.is_empty() {
+            let mut logs = kv!("max_iters" => state.get_max_iters(););
+
+            if let Some(kv) = kv {
+                logs = logs.merge(kv);
+            }
+
+            // Observe after init
+            self.observers.observe_init(S::NAME, &logs)?;
+        }
+
         while !interrupt.load(Ordering::SeqCst) {
             // check first if it has already terminated
             // This should probably be solved better.
@@ -268,7 +409,7 @@ where
             state = state.terminate_with(TerminationReason::KeyboardInterrupt);
         }
 
-        Ok(OptimizationResult::new(self.problem, self.solver, state))
+        Ok(state)
     }
 
     /// Adds an observer to the executor. Observers are required to implement the
@@ -403,6 +544,7 @@ mod tests {
     use crate::core::test_utils::{TestProblem, TestSolver};
     use crate::core::IterState;
     use approx::assert_relative_eq;
+    use std::sync::Mutex;
 
     #[test]
     fn test_update() {
@@ -567,6 +709,186 @@ mod tests {
             .is_sign_negative());
     }
 
+    #[test]
+    fn test_warm_start() {
+        use crate::core::{ArgminFloat, CostFunction};
+
+        // Counts how often `init` and `next_iter` have been called
+        #[derive(Clone, Default)]
+        struct CountingSolver {
+            inits: u64,
+            iters: u64,
+        }
+
+        impl<O, P, F> Solver<O, IterState<P, (), (), (), F>> for CountingSolver
+        where
+            O: CostFunction<Param = P, Output = F>,
+            P: Clone,
+            F: ArgminFloat,
+        {
+            const NAME: &'static str = "CountingSolver";
+
+            fn init(
+                &mut self,
+                problem: &mut Problem<O>,
+                state: IterState<P, (), (), (), F>,
+            ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+                self.inits += 1;
+                let cost = problem.cost(state.get_param().unwrap())?;
+                Ok((state.cost(cost), None))
+            }
+
+            fn next_iter(
+                &mut self,
+                _problem: &mut Problem<O>,
+                state: IterState<P, (), (), (), F>,
+            ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+                self.iters += 1;
+                Ok((state, None))
+            }
+        }
+
+        let result = Executor::new(TestProblem::new(), CountingSolver::default())
+            .configure(|state| state.param(vec![1.0f64, 1.0]).max_iters(10))
+            .run()
+            .unwrap();
+        assert_eq!(result.state.get_iter(), 10);
+        assert_eq!(
+            result.state.get_termination_reason(),
+            Some(&TerminationReason::MaxItersReached)
+        );
+        assert_eq!(result.problem.counts["cost_count"], 1);
+
+        // Keeping the iteration number: `init` is not called again.
+        let result = Executor::warm_start(result)
+            .configure(|state| state.max_iters(15))
+            .run()
+            .unwrap();
+        assert_eq!(result.state.get_iter(), 15);
+        assert_eq!(result.solver.inits, 1);
+        assert_eq!(result.solver.iters, 15);
+        assert_eq!(result.state.get_best_param().unwrap(), &vec![1.0f64, 1.0]);
+        assert_eq!(result.state.get_func_counts()["cost_count"], 1);
+
+        // Resetting the iteration number with a new solver: `init` is called.
+        let result = Executor::from_result(result, CountingSolver::default())
+            .configure(|state| state.reset_iter().max_iters(5))
+            .run()
+            .unwrap();
+        assert_eq!(result.state.get_iter(), 5);
+        assert_eq!(result.solver.inits, 1);
+        assert_eq!(result.solver.iters, 5);
+        assert_eq!(result.state.get_best_param().unwrap(), &vec![1.0f64, 1.0]);
+        assert_eq!(result.state.get_func_counts()["cost_count"], 2);
+        assert_eq!(
+            result.state.get_termination_reason(),
+            Some(&TerminationReason::MaxItersReached)
+        );
+
+        // A new solver is initialized even if the iteration number is kept.
+        let result = Executor::from_result(result, CountingSolver::default())
+            .configure(|state| state.max_iters(8))
+            .run()
+            .unwrap();
+        assert_eq!(result.state.get_iter(), 8);
+        assert_eq!(result.solver.inits, 1);
+        assert_eq!(result.solver.iters, 3);
+        assert_eq!(result.state.get_func_counts()["cost_count"], 3);
+    }
+
+    #[test]
+    fn test_warm_start_observe_init() {
+        use std::sync::atomic::AtomicU64;
+
+        // Counts how often `observe_init` has been called
+        struct InitCounter(Arc<AtomicU64>);
+
+        impl<I> Observe<I> for InitCounter {
+            fn observe_init(&mut self, _name: &str, kv: &KV) -> Result<(), Error> {
+                assert!(kv.get("max_iters").is_some());
+                self.0.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        }
+
+        let count = Arc::new(AtomicU64::new(0));
+
+        let result = Executor::new(TestProblem::new(), TestSolver::new())
+            .configure(|state| state.param(vec![1.0f64, 1.0]).max_iters(10))
+            .add_observer(InitCounter(count.clone()), ObserverMode::Always)
+            .run()
+            .unwrap();
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+
+        // `init` of the solver is not called, but observers are still notified.
+        let result = Executor::warm_start(result)
+            .configure(|state| state.max_iters(15))
+            .add_observer(InitCounter(count.clone()), ObserverMode::Always)
+            .run()
+            .unwrap();
+        assert_eq!(result.state.get_iter(), 15);
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_observe_final() {
+        use crate::core::CostFunction;
+
+        // Fails in the third iteration
+        struct FailingSolver {}
+
+        impl<O> Solver<O, IterState<Vec<f64>, (), (), (), f64>> for FailingSolver
+        where
+            O: CostFunction<Param = Vec<f64>, Output = f64>,
+        {
+            const NAME: &'static str = "FailingSolver";
+
+            fn next_iter(
+                &mut self,
+                _problem: &mut Problem<O>,
+                state: IterState<Vec<f64>, (), (), (), f64>,
+            ) -> Result<(IterState<Vec<f64>, (), (), (), f64>, Option<KV>), Error> {
+                if state.get_iter() == 2 {
+                    return Err(argmin_error!(PotentialBug, "failing"));
+                }
+                Ok((state, None))
+            }
+        }
+
+        // Records what `observe_final` has been called with
+        struct FinalRecorder(Arc<Mutex<Vec<Result<u64, String>>>>);
+
+        impl<I: State> Observe<I> for FinalRecorder {
+            fn observe_final(&mut self, state: Result<&I, &Error>) -> Result<(), Error> {
+                let record = state.map(|s| s.get_iter()).map_err(|e| e.to_string());
+                self.0.lock().unwrap().push(record);
+                // Must not hide the error of the solver
+                Err(argmin_error!(PotentialBug, "observer"))
+            }
+        }
+
+        let records = Arc::new(Mutex::new(vec![]));
+
+        let res = Executor::new(TestProblem::new(), TestSolver::new())
+            .configure(|state| state.param(vec![1.0f64, 1.0]).max_iters(5))
+            .add_observer(FinalRecorder(records.clone()), ObserverMode::Never)
+            .add_observer(FinalRecorder(records.clone()), ObserverMode::Every(10))
+            .run();
+        let observer_err: Error = argmin_error!(PotentialBug, "observer");
+        assert_eq!(res.err().unwrap().to_string(), observer_err.to_string());
+        assert_eq!(*records.lock().unwrap(), vec![Ok(5)]);
+
+        records.lock().unwrap().clear();
+        let res = Executor::new(TestProblem::new(), FailingSolver {})
+            .configure(|state| state.param(vec![1.0f64, 1.0]).max_iters(5))
+            .add_observer(FinalRecorder(records.clone()), ObserverMode::Always)
+            .run();
+        let solver_err: Error = argmin_error!(PotentialBug, "failing");
+        let solver_err = solver_err.to_string();
+        assert_eq!(res.err().unwrap().to_string(), solver_err);
+        assert_eq!(*records.lock().unwrap(), vec![Err(solver_err)]);
+    }
+
     /// The solver's `init` should not be called when started from a checkpoint.
     /// See https://github.com/argmin-rs/argmin/issues/199.
     #[test]
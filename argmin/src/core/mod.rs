@@ -13,6 +13,8 @@
 /// Macros
 #[macro_use]
 pub mod macros;
+/// Forward-mode automatic differentiation
+mod autodiff;
 pub mod checkpointing;
 /// Error handling
 mod errors;
@@ -22,6 +24,8 @@ mod executor;
 mod float;
 /// Key value data structure
 mod kv;
+/// Vector norms used in convergence criteria
+mod norm;
 pub mod observers;
 /// Trait alias for `Send` and `Sync`
 mod parallelization;
@@ -44,12 +48,18 @@ pub use crate::solver::conjugategradient::beta::NLCGBetaUpdate;
 pub use crate::solver::linesearch::LineSearch;
 pub use crate::solver::trustregion::TrustRegionRadius;
 pub use anyhow::Error;
+pub use autodiff::{ForwardDiff, GenericCostFunction, GenericOperator};
 pub use errors::ArgminError;
 pub use executor::Executor;
 pub use float::ArgminFloat;
 pub use kv::{KvValue, KV};
+pub use norm::{L1Norm, L2Norm, LInfNorm, Norm, WeightedL2Norm};
 pub use parallelization::{SendAlias, SyncAlias};
-pub use problem::{CostFunction, Gradient, Hessian, Jacobian, LinearProgram, Operator, Problem};
+pub use problem::{
+    Bounds, CostFunction, EqualityConstraint, EqualityConstraintJacobian, Gradient, Hessian,
+    InequalityConstraint, InequalityConstraintJacobian, Jacobian, LinearConstraints, LinearProgram,
+    Operator, Problem, Projection, Proximal, QuadraticProgram,
+};
 pub use result::OptimizationResult;
 pub use serialization::{DeserializeOwnedAlias, SerializeAlias};
 pub use solver::Solver;
@@ -0,0 +1,132 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin_math::{ArgminL1Norm, ArgminL2Norm, ArgminLInfNorm, ArgminWeightedL2Norm};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Vector norm used in convergence criteria
+///
+/// Solvers which stop once the norm of a vector (typically the gradient) falls below a tolerance
+/// are generic over the norm used for this comparison. This way, only the norm which is actually
+/// used needs to be implemented for the vector type.
+///
+/// argmin provides [`L1Norm`], [`L2Norm`], [`LInfNorm`] and [`WeightedL2Norm`]. The
+/// l-infinity-norm is often preferred for large problems because, unlike the l2-norm, it does
+/// not grow with the dimension of the problem.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{L1Norm, L2Norm, LInfNorm, Norm, WeightedL2Norm};
+///
+/// let x = vec![3.0f64, -4.0];
+/// assert_eq!(L1Norm.norm(&x), 7.0);
+/// assert_eq!(L2Norm.norm(&x), 5.0);
+/// assert_eq!(LInfNorm.norm(&x), 4.0);
+/// assert_eq!(WeightedL2Norm::new(vec![4.0, 0.0]).norm(&x), 6.0);
+/// ```
+pub trait Norm<T, F> {
+    /// Compute the norm of `x`.
+    fn norm(&self, x: &T) -> F;
+}
+
+/// l1-norm: sum of the absolute values of all elements
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct L1Norm;
+
+impl<T, F> Norm<T, F> for L1Norm
+where
+    T: ArgminL1Norm<F>,
+{
+    fn norm(&self, x: &T) -> F {
+        x.l1_norm()
+    }
+}
+
+/// l2-norm: Euclidean norm
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct L2Norm;
+
+impl<T, F> Norm<T, F> for L2Norm
+where
+    T: ArgminL2Norm<F>,
+{
+    fn norm(&self, x: &T) -> F {
+        x.l2_norm()
+    }
+}
+
+/// l-infinity-norm: largest absolute value of all elements
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct LInfNorm;
+
+impl<T, F> Norm<T, F> for LInfNorm
+where
+    T: ArgminLInfNorm<F>,
+{
+    fn norm(&self, x: &T) -> F {
+        x.linf_norm()
+    }
+}
+
+/// l2-norm weighted elementwise by `weights`: `sqrt(sum_i w_i * |x_i|^2)`
+///
+/// This corresponds to a diagonal scaling of the parameter space, which is useful if the
+/// elements of the vector differ in scale.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct WeightedL2Norm<W> {
+    /// Weights of the elements
+    weights: W,
+}
+
+impl<W> WeightedL2Norm<W> {
+    /// Construct a new instance of [`WeightedL2Norm`] with the given (non-negative) `weights`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use argmin::core::WeightedL2Norm;
+    ///
+    /// let norm = WeightedL2Norm::new(vec![1.0f64, 100.0]);
+    /// ```
+    pub fn new(weights: W) -> Self {
+        WeightedL2Norm { weights }
+    }
+}
+
+impl<T, W, F> Norm<T, F> for WeightedL2Norm<W>
+where
+    T: ArgminWeightedL2Norm<W, F>,
+{
+    fn norm(&self, x: &T) -> F {
+        x.weighted_l2_norm(&self.weights)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_norm() {
+        let x = vec![1.0f64, -2.0, 2.0];
+        assert_relative_eq!(L1Norm.norm(&x), 5.0f64, epsilon = f64::EPSILON);
+        assert_relative_eq!(L2Norm.norm(&x), 3.0f64, epsilon = f64::EPSILON);
+        assert_relative_eq!(LInfNorm.norm(&x), 2.0f64, epsilon = f64::EPSILON);
+        assert_relative_eq!(
+            WeightedL2Norm::new(vec![4.0, 1.0, 0.0]).norm(&x),
+            8.0f64.sqrt(),
+            epsilon = f64::EPSILON
+        );
+    }
+}
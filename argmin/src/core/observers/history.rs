@@ -0,0 +1,418 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Record the convergence history of an optimization run in memory.
+//!
+//! See documentation of [`HistoryRecorder`] and [`History`] for details.
+
+use crate::core::observers::Observe;
+use crate::core::{ArgminFloat, Error, IterState, State, KV};
+use argmin_math::ArgminL2Norm;
+use std::sync::{Arc, Mutex};
+
+/// Information recorded for a single iteration.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry<P, F> {
+    /// Iteration number
+    pub iter: u64,
+    /// Cost function value
+    pub cost: F,
+    /// Best cost function value found so far
+    pub best_cost: F,
+    /// Parameter vector (only if enabled via [`HistoryRecorder::record_param`])
+    pub param: Option<P>,
+    /// L2 norm of the gradient (only if enabled via
+    /// [`HistoryRecorder::record_gradient_norm`])
+    pub gradient_norm: Option<F>,
+    /// Time elapsed since the start of the optimization
+    pub time: Option<instant::Duration>,
+    /// Key-value pairs provided by the solver
+    pub kv: KV,
+}
+
+/// Convergence history of an optimization run as recorded by [`HistoryRecorder`].
+///
+/// Entries are stored in the order in which they were recorded.
+#[derive(Clone, Debug, PartialEq)]
+pub struct History<P, F> {
+    /// Recorded entries
+    pub entries: Vec<HistoryEntry<P, F>>,
+}
+
+impl<P, F> History<P, F> {
+    /// Construct a new, empty `History`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::observers::History;
+    /// let history: History<Vec<f64>, f64> = History::new();
+    /// # assert!(history.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        History { entries: vec![] }
+    }
+
+    /// Returns the number of recorded entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::observers::History;
+    /// # let history: History<Vec<f64>, f64> = History::new();
+    /// let len = history.len();
+    /// # assert_eq!(len, 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if no entries have been recorded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::observers::History;
+    /// # let history: History<Vec<f64>, f64> = History::new();
+    /// let is_empty = history.is_empty();
+    /// # assert!(is_empty);
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the last recorded entry, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::observers::History;
+    /// # let history: History<Vec<f64>, f64> = History::new();
+    /// let last = history.last();
+    /// # assert!(last.is_none());
+    /// ```
+    pub fn last(&self) -> Option<&HistoryEntry<P, F>> {
+        self.entries.last()
+    }
+
+    /// Returns the iteration numbers of all entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::observers::History;
+    /// # let history: History<Vec<f64>, f64> = History::new();
+    /// let iters: Vec<u64> = history.iters();
+    /// ```
+    pub fn iters(&self) -> Vec<u64> {
+        self.entries.iter().map(|e| e.iter).collect()
+    }
+}
+
+impl<P, F: Copy> History<P, F> {
+    /// Returns the cost function values of all entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::observers::History;
+    /// # let history: History<Vec<f64>, f64> = History::new();
+    /// let costs: Vec<f64> = history.costs();
+    /// ```
+    pub fn costs(&self) -> Vec<F> {
+        self.entries.iter().map(|e| e.cost).collect()
+    }
+
+    /// Returns the best cost function values of all entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::observers::History;
+    /// # let history: History<Vec<f64>, f64> = History::new();
+    /// let best_costs: Vec<f64> = history.best_costs();
+    /// ```
+    pub fn best_costs(&self) -> Vec<F> {
+        self.entries.iter().map(|e| e.best_cost).collect()
+    }
+
+    /// Returns the gradient norms of all entries (`None` where not available).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::observers::History;
+    /// # let history: History<Vec<f64>, f64> = History::new();
+    /// let gradient_norms: Vec<Option<f64>> = history.gradient_norms();
+    /// ```
+    pub fn gradient_norms(&self) -> Vec<Option<F>> {
+        self.entries.iter().map(|e| e.gradient_norm).collect()
+    }
+}
+
+impl<P, F> Default for History<P, F> {
+    fn default() -> Self {
+        History::new()
+    }
+}
+
+/// Function computing the gradient norm from a state
+type GradientNorm<I> = fn(&I) -> Option<<I as State>::Float>;
+
+/// Record the convergence history of an optimization run in memory.
+///
+/// For every observed iteration, the iteration number, the current and best cost function values,
+/// the elapsed time and the key-value pairs provided by the solver are recorded. Optionally, the
+/// parameter vector ([`record_param`](`HistoryRecorder::record_param`)) and the L2 norm of the
+/// gradient ([`record_gradient_norm`](`HistoryRecorder::record_gradient_norm`), only for solvers
+/// using [`IterState`]) are recorded as well.
+///
+/// The [`History`] is shared between the recorder and the handle obtained via
+/// [`history`](`HistoryRecorder::history`) and can therefore be accessed after the `Executor` has
+/// finished.
+///
+/// For long runs, the number of stored entries can be limited via
+/// [`max_entries`](`HistoryRecorder::max_entries`). Whenever this limit is exceeded, every
+/// second entry is discarded and from then on only every second observed iteration is recorded.
+/// This keeps the entries evenly spaced over the entire run. Which iterations are observed in the
+/// first place is defined by the [`ObserverMode`](`crate::core::observers::ObserverMode`).
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{Error, Executor, IterState, State};
+/// # use argmin::core::observers::{HistoryRecorder, ObserverMode};
+/// # use argmin::core::test_utils::{TestSolver, TestProblem};
+/// #
+/// # fn main() -> Result<(), Error> {
+/// # let solver = TestSolver::new();
+/// # let problem = TestProblem::new();
+/// # let init_param = vec![1.0f64, 0.0];
+/// #
+/// let recorder = HistoryRecorder::new().record_param(true).max_entries(1000);
+///
+/// // Shared handle to the history
+/// let history = recorder.history();
+///
+/// let res = Executor::new(problem, solver)
+///     .configure(|state| state.param(init_param).max_iters(10))
+///     .add_observer(recorder, ObserverMode::Always)
+///     .run()?;
+///
+/// let history = history.lock().unwrap();
+/// # assert_eq!(history.len(), 10);
+/// let best_costs = history.best_costs();
+/// # Ok(())
+/// # }
+/// ```
+pub struct HistoryRecorder<I: State> {
+    /// Recorded history, shared with the handles returned by `history`
+    history: Arc<Mutex<History<I::Param, I::Float>>>,
+    /// Whether the parameter vector is recorded
+    record_param: bool,
+    /// Computes the gradient norm from the state
+    gradient_norm: Option<GradientNorm<I>>,
+    /// Maximum number of entries
+    max_entries: Option<usize>,
+    /// Only every `stride`th observed iteration is recorded
+    stride: u64,
+    /// Number of observed iterations
+    observed: u64,
+}
+
+impl<I: State> HistoryRecorder<I> {
+    /// Construct a new `HistoryRecorder`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::IterState;
+    /// # use argmin::core::observers::HistoryRecorder;
+    /// let recorder: HistoryRecorder<IterState<Vec<f64>, (), (), (), f64>> = HistoryRecorder::new();
+    /// ```
+    pub fn new() -> Self {
+        HistoryRecorder {
+            history: Arc::new(Mutex::new(History::new())),
+            record_param: false,
+            gradient_norm: None,
+            max_entries: None,
+            stride: 1,
+            observed: 0,
+        }
+    }
+
+    /// Returns a shared handle to the recorded history.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::IterState;
+    /// # use argmin::core::observers::HistoryRecorder;
+    /// # let recorder: HistoryRecorder<IterState<Vec<f64>, (), (), (), f64>> = HistoryRecorder::new();
+    /// let history = recorder.history();
+    /// # assert!(history.lock().unwrap().is_empty());
+    /// ```
+    pub fn history(&self) -> Arc<Mutex<History<I::Param, I::Float>>> {
+        Arc::clone(&self.history)
+    }
+
+    /// Enable or disable recording of the parameter vector (default: disabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::IterState;
+    /// # use argmin::core::observers::HistoryRecorder;
+    /// let recorder: HistoryRecorder<IterState<Vec<f64>, (), (), (), f64>> =
+    ///     HistoryRecorder::new().record_param(true);
+    /// ```
+    #[must_use]
+    pub fn record_param(mut self, record_param: bool) -> Self {
+        self.record_param = record_param;
+        self
+    }
+
+    /// Limit the number of stored entries. When the limit is exceeded, every second entry is
+    /// discarded and the interval between recorded iterations is doubled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::IterState;
+    /// # use argmin::core::observers::HistoryRecorder;
+    /// let recorder: HistoryRecorder<IterState<Vec<f64>, (), (), (), f64>> =
+    ///     HistoryRecorder::new().max_entries(1000);
+    /// ```
+    #[must_use]
+    pub fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries.max(1));
+        self
+    }
+}
+
+impl<P, G, J, H, F> HistoryRecorder<IterState<P, G, J, H, F>>
+where
+    IterState<P, G, J, H, F>: State<Param = P, Float = F>,
+    G: ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    /// Record the L2 norm of the gradient stored in the state (default: disabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::IterState;
+    /// # use argmin::core::observers::HistoryRecorder;
+    /// let recorder: HistoryRecorder<IterState<Vec<f64>, Vec<f64>, (), (), f64>> =
+    ///     HistoryRecorder::new().record_gradient_norm();
+    /// ```
+    #[must_use]
+    pub fn record_gradient_norm(mut self) -> Self {
+        self.gradient_norm = Some(|state| state.get_gradient().map(|grad| grad.l2_norm()));
+        self
+    }
+}
+
+impl<I: State> Default for HistoryRecorder<I> {
+    fn default() -> Self {
+        HistoryRecorder::new()
+    }
+}
+
+impl<I> Observe<I> for HistoryRecorder<I>
+where
+    I: State,
+    I::Param: Clone,
+{
+    fn observe_iter(&mut self, state: &I, kv: &KV) -> Result<(), Error> {
+        let observed = self.observed;
+        self.observed += 1;
+        if !observed.is_multiple_of(self.stride) {
+            return Ok(());
+        }
+
+        let mut history = self.history.lock().unwrap();
+        history.entries.push(HistoryEntry {
+            iter: state.get_iter(),
+            cost: state.get_cost(),
+            best_cost: state.get_best_cost(),
+            param: if self.record_param {
+                state.get_param().cloned()
+            } else {
+                None
+            },
+            gradient_norm: self.gradient_norm.and_then(|norm| norm(state)),
+            time: state.get_time(),
+            kv: kv.clone(),
+        });
+
+        if let Some(max_entries) = self.max_entries {
+            if history.entries.len() > max_entries {
+                let entries = std::mem::take(&mut history.entries);
+                history.entries = entries.into_iter().step_by(2).collect();
+                self.stride *= 2;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::observers::ObserverMode;
+    use crate::core::test_utils::{TestProblem, TestSolver};
+    use crate::core::Executor;
+    use approx::assert_relative_eq;
+
+    type TState = IterState<Vec<f64>, Vec<f64>, (), (), f64>;
+
+    send_sync_test!(history_recorder, HistoryRecorder<TState>);
+    send_sync_test!(history, History<Vec<f64>, f64>);
+
+    #[test]
+    fn test_record() {
+        let mut recorder: HistoryRecorder<TState> = HistoryRecorder::new()
+            .record_param(true)
+            .record_gradient_norm();
+        let history = recorder.history();
+
+        let mut state: TState = IterState::new()
+            .param(vec![1.0, 2.0])
+            .gradient(vec![3.0, 4.0])
+            .cost(2.0);
+        state.update();
+        recorder.observe_iter(&state, &kv!("a" => 1u64;)).unwrap();
+
+        let history = history.lock().unwrap();
+        assert_eq!(history.len(), 1);
+        let entry = history.last().unwrap();
+        assert_eq!(entry.iter, 0);
+        assert_relative_eq!(entry.cost, 2.0f64, epsilon = f64::EPSILON);
+        assert_relative_eq!(entry.best_cost, 2.0f64, epsilon = f64::EPSILON);
+        assert_eq!(entry.param, Some(vec![1.0, 2.0]));
+        assert_relative_eq!(entry.gradient_norm.unwrap(), 5.0f64, epsilon = f64::EPSILON);
+        assert_eq!(entry.kv, kv!("a" => 1u64;));
+    }
+
+    #[test]
+    fn test_max_entries() {
+        let recorder = HistoryRecorder::new().max_entries(4);
+        let history = recorder.history();
+
+        Executor::new(TestProblem::new(), TestSolver::new())
+            .configure(|state| state.param(vec![1.0, 2.0]).max_iters(20))
+            .add_observer(recorder, ObserverMode::Always)
+            .run()
+            .unwrap();
+
+        let history = history.lock().unwrap();
+        assert_eq!(history.iters(), vec![0, 8, 16]);
+        assert!(history.entries.iter().all(|e| e.param.is_none()));
+        assert!(history.entries.iter().all(|e| e.gradient_norm.is_none()));
+    }
+}
@@ -20,6 +20,26 @@
 //! optimization to screen or to disk. This requires the `slog-logger` feature. Writing to disk
 //! in addition requires the `serde1` feature.
 //!
+//! The observers [`WriteToCsv`](`crate::core::observers::WriteToCsv`) and
+//! [`WriteToJsonLines`](`crate::core::observers::WriteToJsonLines`) write the iteration number,
+//! cost function values, elapsed time, function evaluation counts and all solver-specific
+//! key-value pairs of each iteration in tabular form to a file, which is convenient for further
+//! analysis. The latter requires the `serde1` feature.
+//!
+//! The observer [`TracingLogger`](`crate::core::observers::TracingLogger`) emits spans and events
+//! via the [`tracing`](https://crates.io/crates/tracing) crate. This requires the `tracing`
+//! feature.
+//!
+//! The observer [`HistoryRecorder`](`crate::core::observers::HistoryRecorder`) records the
+//! convergence history (cost function values, elapsed time, optionally the parameter vector and
+//! gradient norm as well as all key-value pairs) in memory. The recorded
+//! [`History`](`crate::core::observers::History`) can be accessed after the optimization has
+//! finished.
+//!
+//! The observer [`ProgressDisplay`](`crate::core::observers::ProgressDisplay`) shows the progress
+//! of the optimization live in the terminal. When not attached to a terminal, plain progress lines
+//! are printed periodically instead. This requires the `indicatif` feature.
+//!
 //! For each observer it can be defined how often it will observe the progress of the solver. This
 //! is indicated via the enum `ObserverMode` which can be either `Always`, `Never`, `NewBest`
 //! (whenever a new best solution is found) or `Every(i)` which means every `i`th iteration.
@@ -126,17 +146,29 @@
 
 #[cfg(feature = "serde1")]
 pub mod file;
+pub mod history;
+#[cfg(feature = "indicatif")]
+pub mod progress;
 #[cfg(feature = "slog-logger")]
 pub mod slog_logger;
+pub mod tabular;
+#[cfg(feature = "tracing")]
+pub mod tracing_logger;
 
 #[cfg(feature = "serde1")]
 pub use file::*;
+pub use history::*;
+#[cfg(feature = "indicatif")]
+pub use progress::*;
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "slog-logger")]
 pub use slog_logger::*;
+pub use tabular::*;
+#[cfg(feature = "tracing")]
+pub use tracing_logger::*;
 
-use crate::core::{Error, State, KV};
+use crate::core::{Error, KvValue, State, KV};
 use std::default::Default;
 use std::sync::{Arc, Mutex};
 
@@ -167,6 +199,12 @@ use std::sync::{Arc, Mutex};
 ///         // Is executed after each iteration of a solver
 ///         Ok(())
 ///     }
+///
+///     fn observe_final(&mut self, state: Result<&I, &Error>) -> Result<(), Error> {
+///         // Do something with the final `state` or with the error which stopped the solver
+///         // Is executed once after the solver has terminated or failed
+///         Ok(())
+///     }
 /// }
 /// ```
 pub trait Observe<I> {
@@ -186,6 +224,45 @@ pub trait Observe<I> {
     fn observe_iter(&mut self, _state: &I, _kv: &KV) -> Result<(), Error> {
         Ok(())
     }
+
+    /// Called once after the solver has stopped.
+    ///
+    /// `state` is the final state of the solver if it terminated (including a manual
+    /// interruption), or the error which aborted the run otherwise. In the latter case the state
+    /// is not available, because it was consumed by the failing solver. This can be used to clean
+    /// up resources such as progress bars or to flush buffered output.
+    ///
+    /// If the run failed, errors returned by this method are ignored and the original error is
+    /// returned by [`Executor::run`](`crate::core::Executor::run`).
+    fn observe_final(&mut self, _state: Result<&I, &Error>) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Returns the function evaluation counts of `state` (sorted by name), followed by the entries of
+/// `kv` (sorted by key).
+///
+/// Observers which report these entries should use this order, such that it is the same across
+/// observers.
+pub(crate) fn sorted_entries<I: State>(state: &I, kv: &KV) -> Vec<(String, KvValue)> {
+    let mut counts: Vec<(&String, &u64)> = state.get_func_counts().iter().collect();
+    counts.sort_by(|a, b| a.0.cmp(b.0));
+    counts
+        .into_iter()
+        .map(|(k, &v)| (k.clone(), KvValue::Uint(v)))
+        .chain(sorted_kv(kv))
+        .collect()
+}
+
+/// Returns the entries of `kv` sorted by key.
+pub(crate) fn sorted_kv(kv: &KV) -> Vec<(String, KvValue)> {
+    let mut entries: Vec<(String, KvValue)> = kv
+        .kv
+        .iter()
+        .map(|(&k, v)| (k.to_string(), v.clone()))
+        .collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    entries
 }
 
 type ObserversVec<I> = Vec<(Arc<Mutex<dyn Observe<I>>>, ObserverMode)>;
@@ -290,6 +367,17 @@ impl<I: State> Observe<I> for Observers<I> {
         }
         Ok(())
     }
+
+    /// After the solver has stopped, this loops over all stored observers (except for those in
+    /// mode [`Never`](`ObserverMode::Never`)) and calls them.
+    fn observe_final(&mut self, state: Result<&I, &Error>) -> Result<(), Error> {
+        for l in self.observers.iter() {
+            if l.1 != ObserverMode::Never {
+                l.0.lock().unwrap().observe_final(state)?
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Indicates when to call an observer.
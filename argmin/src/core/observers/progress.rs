@@ -0,0 +1,343 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Live progress display in the terminal
+//!
+//! See [`ProgressDisplay`] for details regarding usage.
+
+use crate::core::observers::Observe;
+use crate::core::{Error, State, KV};
+use indicatif::{FormattedDuration, ProgressBar, ProgressDrawTarget, ProgressStyle};
+use instant::{Duration, Instant};
+use num_traits::ToPrimitive;
+
+/// Template used if the maximum number of iterations is known
+const BAR_TEMPLATE: &str =
+    "{prefix} [{elapsed_precise}] {wide_bar} {pos}/{len} (ETA {eta_precise}) {msg}";
+
+/// Template used if the maximum number of iterations is unknown
+const SPINNER_TEMPLATE: &str = "{prefix} {spinner} [{elapsed_precise}] iter {pos} {msg}";
+
+/// Shows the progress of an optimization run in the terminal.
+///
+/// The display contains the number of performed iterations compared to the maximum number of
+/// iterations, the best cost function value found so far, the rate at which the best cost
+/// function value improves (per second), the number of function evaluations per second and the
+/// estimated time until the maximum number of iterations is reached.
+///
+/// If `stderr` is a terminal, a progress bar based on
+/// [`indicatif`](https://crates.io/crates/indicatif) is redrawn in place. Otherwise (for instance
+/// when the output is redirected to a file) a plain line is printed to `stderr` periodically. The
+/// interval between two plain lines as well as the time window over which the improvement rate is
+/// computed can be set via [`with_interval`](`ProgressDisplay::with_interval`).
+///
+/// Requires the `indicatif` feature.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::observers::ProgressDisplay;
+/// use std::time::Duration;
+///
+/// let progress = ProgressDisplay::new();
+/// let progress = ProgressDisplay::new().with_interval(Duration::from_secs(5));
+/// ```
+#[derive(Debug)]
+pub struct ProgressDisplay {
+    /// Name of the solver
+    name: String,
+    /// Interval between plain lines and time window of the improvement rate
+    interval: Duration,
+    /// Whether the progress is shown as a progress bar or as plain lines
+    tty: bool,
+    /// Progress bar, created at the first observed iteration
+    bar: Option<ProgressBar>,
+    /// Start of the optimization run
+    start: Option<Instant>,
+    /// Time at which the last plain line was printed
+    last_line: Option<Instant>,
+    /// Elapsed seconds and best cost at the beginning of the current rate window
+    window: Option<(f64, f64)>,
+    /// Improvement of the best cost per second in the last completed rate window
+    rate: Option<f64>,
+}
+
+impl ProgressDisplay {
+    /// Create a new `ProgressDisplay`.
+    ///
+    /// Whether a progress bar or plain lines are shown is decided based on whether `stderr` is a
+    /// terminal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use argmin::core::observers::ProgressDisplay;
+    ///
+    /// let progress = ProgressDisplay::new();
+    /// ```
+    pub fn new() -> Self {
+        ProgressDisplay {
+            name: String::new(),
+            interval: Duration::from_secs(1),
+            tty: !ProgressDrawTarget::stderr().is_hidden(),
+            bar: None,
+            start: None,
+            last_line: None,
+            window: None,
+            rate: None,
+        }
+    }
+
+    /// Set the interval between two plain lines, which is also the time window over which the
+    /// improvement rate is computed (default: 1 second).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use argmin::core::observers::ProgressDisplay;
+    /// use std::time::Duration;
+    ///
+    /// let progress = ProgressDisplay::new().with_interval(Duration::from_secs(5));
+    /// ```
+    #[must_use]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Create the progress bar.
+    fn create_bar(&self, max_iters: u64) -> Result<ProgressBar, Error> {
+        let bar = if max_iters == u64::MAX {
+            ProgressBar::new_spinner().with_style(ProgressStyle::with_template(SPINNER_TEMPLATE)?)
+        } else {
+            ProgressBar::new(max_iters).with_style(ProgressStyle::with_template(BAR_TEMPLATE)?)
+        };
+        Ok(bar.with_prefix(self.name.clone()))
+    }
+
+    /// Summary of best cost, improvement rate and evaluations per second.
+    fn message(&self, best_cost: f64, evals_per_sec: Option<f64>) -> String {
+        let rate = self
+            .rate
+            .map(|rate| format!("{rate:.3e}/s"))
+            .unwrap_or_else(|| "-".to_string());
+        let evals = evals_per_sec
+            .map(|evals| format!("{evals:.1}/s"))
+            .unwrap_or_else(|| "-".to_string());
+        format!("best cost: {best_cost:.6e}, improvement: {rate}, evals: {evals}")
+    }
+}
+
+impl Default for ProgressDisplay {
+    fn default() -> Self {
+        ProgressDisplay::new()
+    }
+}
+
+/// Plain progress line used if `stderr` is not a terminal.
+fn plain_line(name: &str, iters: u64, max_iters: u64, elapsed: f64, message: &str) -> String {
+    let elapsed_fmt = FormattedDuration(Duration::from_secs_f64(elapsed));
+    if max_iters == u64::MAX {
+        format!("{name} [{elapsed_fmt}] iter {iters}, {message}")
+    } else {
+        let eta = if iters > 0 {
+            let remaining = max_iters.saturating_sub(iters) as f64 * elapsed / iters as f64;
+            FormattedDuration(Duration::from_secs_f64(remaining)).to_string()
+        } else {
+            "-".to_string()
+        };
+        format!("{name} [{elapsed_fmt}] iter {iters}/{max_iters} (ETA {eta}), {message}")
+    }
+}
+
+impl<I: State> Observe<I> for ProgressDisplay {
+    /// Stores the name of the solver and starts the timer.
+    fn observe_init(&mut self, name: &str, _kv: &KV) -> Result<(), Error> {
+        self.name = name.to_string();
+        self.start = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Updates the progress bar or prints a plain line if the interval has passed.
+    fn observe_iter(&mut self, state: &I, _kv: &KV) -> Result<(), Error> {
+        let now = Instant::now();
+        let elapsed = now.duration_since(*self.start.get_or_insert(now));
+        let secs = elapsed.as_secs_f64();
+        // The state is observed before the iteration counter is incremented.
+        let iters = state.get_iter() + 1;
+        let max_iters = state.get_max_iters();
+        let best_cost = state.get_best_cost().to_f64().unwrap_or(f64::NAN);
+
+        match self.window {
+            None => self.window = Some((secs, best_cost)),
+            Some((window_start, window_cost))
+                if secs > window_start && secs - window_start >= self.interval.as_secs_f64() =>
+            {
+                self.rate = Some((window_cost - best_cost) / (secs - window_start));
+                self.window = Some((secs, best_cost));
+            }
+            Some(_) => {}
+        }
+
+        let evals: u64 = state.get_func_counts().values().sum();
+        let evals_per_sec = if secs > 0.0 {
+            Some(evals as f64 / secs)
+        } else {
+            None
+        };
+        let message = self.message(best_cost, evals_per_sec);
+
+        if self.tty {
+            if self.bar.is_none() {
+                self.bar = Some(self.create_bar(max_iters)?);
+            }
+            let bar = self.bar.as_ref().unwrap();
+            bar.set_position(iters);
+            bar.set_message(message);
+        } else if self
+            .last_line
+            .map(|last| now.duration_since(last) >= self.interval)
+            .unwrap_or(true)
+        {
+            eprintln!(
+                "{}",
+                plain_line(&self.name, iters, max_iters, secs, &message)
+            );
+            self.last_line = Some(now);
+        }
+        Ok(())
+    }
+
+    /// Finishes the progress bar, or prints a final plain line, including the termination reason.
+    ///
+    /// If the solver failed, the progress bar is abandoned with the error message.
+    fn observe_final(&mut self, state: Result<&I, &Error>) -> Result<(), Error> {
+        let state = match state {
+            Ok(state) => state,
+            Err(err) => {
+                if let Some(bar) = self.bar.as_ref() {
+                    bar.abandon_with_message(format!("error: {err}"));
+                } else if !self.tty {
+                    eprintln!("{} failed: {err}", self.name);
+                }
+                return Ok(());
+            }
+        };
+        let best_cost = state.get_best_cost().to_f64().unwrap_or(f64::NAN);
+        let mut message = self.message(best_cost, None);
+        if let Some(reason) = state.get_termination_reason() {
+            message = format!("{message} ({reason})");
+        }
+        if let Some(bar) = self.bar.as_ref() {
+            bar.set_position(state.get_iter());
+            bar.finish_with_message(message);
+        } else if !self.tty {
+            let secs = self
+                .start
+                .map(|start| start.elapsed().as_secs_f64())
+                .unwrap_or(0.0);
+            eprintln!(
+                "{}",
+                plain_line(
+                    &self.name,
+                    state.get_iter(),
+                    state.get_max_iters(),
+                    secs,
+                    &message
+                )
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{IterState, TerminationReason};
+
+    send_sync_test!(progress_display, ProgressDisplay);
+
+    #[test]
+    fn test_plain_line() {
+        assert_eq!(
+            plain_line("Solver", 10, 100, 5.0, "msg"),
+            "Solver [00:00:05] iter 10/100 (ETA 00:00:45), msg"
+        );
+        assert_eq!(
+            plain_line("Solver", 0, 100, 0.0, "msg"),
+            "Solver [00:00:00] iter 0/100 (ETA -), msg"
+        );
+        assert_eq!(
+            plain_line("Solver", 10, u64::MAX, 5.0, "msg"),
+            "Solver [00:00:05] iter 10, msg"
+        );
+    }
+
+    #[test]
+    fn test_rate() {
+        let mut progress = ProgressDisplay::new().with_interval(Duration::from_secs(0));
+        progress.tty = false;
+        <ProgressDisplay as Observe<IterState<Vec<f64>, (), (), (), f64>>>::observe_init(
+            &mut progress,
+            "Solver",
+            &kv!(),
+        )
+        .unwrap();
+
+        let mut state: IterState<Vec<f64>, (), (), (), f64> = IterState::new().cost(10.0);
+        state.update();
+        progress.observe_iter(&state, &kv!()).unwrap();
+        assert!(progress.rate.is_none());
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        let mut state = state.cost(5.0);
+        state.update();
+        progress.observe_iter(&state, &kv!()).unwrap();
+        assert!(progress.rate.unwrap() > 0.0);
+        assert!(progress
+            .message(5.0, None)
+            .starts_with("best cost: 5.000000e0"));
+    }
+
+    #[test]
+    fn test_finish() {
+        let mut progress = ProgressDisplay::new();
+        progress.tty = true;
+        let state: IterState<Vec<f64>, (), (), (), f64> = IterState::new()
+            .cost(1.0)
+            .max_iters(10)
+            .terminate_with(TerminationReason::MaxItersReached);
+        progress.observe_iter(&state, &kv!()).unwrap();
+        assert!(!progress.bar.as_ref().unwrap().is_finished());
+
+        progress.observe_final(Ok(&state)).unwrap();
+        let bar = progress.bar.as_ref().unwrap();
+        assert!(bar.is_finished());
+        assert!(bar
+            .message()
+            .ends_with("(Maximum number of iterations reached)"));
+    }
+
+    #[test]
+    fn test_abandon() {
+        let mut progress = ProgressDisplay::new();
+        progress.tty = true;
+        let state: IterState<Vec<f64>, (), (), (), f64> = IterState::new().max_iters(10);
+        progress.observe_iter(&state, &kv!()).unwrap();
+
+        let err: Error = argmin_error!(PotentialBug, "failing");
+        <ProgressDisplay as Observe<IterState<Vec<f64>, (), (), (), f64>>>::observe_final(
+            &mut progress,
+            Err(&err),
+        )
+        .unwrap();
+        let bar = progress.bar.as_ref().unwrap();
+        assert!(bar.is_finished());
+        assert!(bar.message().starts_with("error: Potential bug"));
+    }
+}
@@ -0,0 +1,364 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Write per-iteration data in tabular form to a file during optimization.
+//!
+//! See documentation of [`WriteToCsv`] and [`WriteToJsonLines`] for details.
+
+use crate::core::observers::{sorted_entries, Observe};
+use crate::core::{Error, KvValue, State, KV};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Keeps track of the columns of a table in a stable order.
+///
+/// Each row consists of the iteration number, the current and best cost function values, the
+/// total time elapsed (if available), the function evaluation counts (sorted by name) and all
+/// entries of the `KV` provided by the solver (sorted by key). Columns which are seen for the
+/// first time are appended to the end; the position of existing columns never changes.
+#[derive(Clone, Debug, Default)]
+struct Columns {
+    /// Names of all known columns
+    names: Vec<String>,
+}
+
+impl Columns {
+    /// Collects the entries of the current iteration in the order described above.
+    fn entries<I: State>(state: &I, kv: &KV) -> Vec<(String, KvValue)> {
+        let mut entries: Vec<(String, KvValue)> = vec![
+            ("iter".to_string(), KvValue::Uint(state.get_iter())),
+            ("cost".to_string(), state.get_cost().into()),
+            ("best_cost".to_string(), state.get_best_cost().into()),
+        ];
+        if let Some(time) = state.get_time() {
+            entries.push(("total_time".to_string(), time.as_secs_f64().into()));
+        }
+
+        entries.extend(sorted_entries(state, kv));
+        entries
+    }
+
+    /// Appends the names of all entries which are not known yet.
+    fn extend(&mut self, entries: &[(String, KvValue)]) {
+        for (name, _) in entries.iter() {
+            if !self.names.contains(name) {
+                self.names.push(name.clone());
+            }
+        }
+    }
+
+    /// Orders `entries` according to the known columns, where missing entries are `None`.
+    ///
+    /// Returns the row and all entries which do not belong to a known column.
+    fn split(
+        &self,
+        entries: Vec<(String, KvValue)>,
+    ) -> (Vec<Option<KvValue>>, Vec<(String, KvValue)>) {
+        let (known, unknown): (Vec<_>, Vec<_>) = entries
+            .into_iter()
+            .partition(|(name, _)| self.names.contains(name));
+        let mut known: HashMap<String, KvValue> = known.into_iter().collect();
+        let row = self.names.iter().map(|name| known.remove(name)).collect();
+        (row, unknown)
+    }
+}
+
+/// Opens `path` for appending, creating parent directories if necessary.
+fn open_append(path: &Path, truncate: bool) -> Result<BufWriter<File>, Error> {
+    if let Some(dir) = path.parent() {
+        if !dir.as_os_str().is_empty() && !dir.exists() {
+            std::fs::create_dir_all(dir)?
+        }
+    }
+    if truncate {
+        File::create(path)?;
+    }
+    Ok(BufWriter::new(
+        OpenOptions::new().create(true).append(true).open(path)?,
+    ))
+}
+
+/// Write per-iteration data to a CSV file during optimization.
+///
+/// Each row contains the iteration number (`iter`), the current and best cost function values
+/// (`cost`, `best_cost`), the time elapsed since the start of the optimization in seconds
+/// (`total_time`), the function evaluation counts (such as `cost_count`) and all key-value pairs
+/// provided by the solver (including `time`, the duration of the iteration in seconds).
+///
+/// The columns are determined by the first iteration: function evaluation counts and
+/// solver-specific entries are sorted by name. Entries which are missing in a later iteration are
+/// left empty. Entries which appear for the first time in a later iteration do not fit into the
+/// header and are written to the last column, `extra`, as `name=value` pairs separated by `;`.
+///
+/// An existing file will be overwritten.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::observers::WriteToCsv;
+///
+/// let observer = WriteToCsv::new("progress.csv");
+/// ```
+#[derive(Debug)]
+pub struct WriteToCsv {
+    /// Path of the CSV file
+    path: PathBuf,
+    /// Columns of the table, determined by the first row
+    columns: Columns,
+    /// Writer, opened when the first row is written
+    writer: Option<BufWriter<File>>,
+}
+
+impl WriteToCsv {
+    /// Create a new instance of `WriteToCsv`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::observers::WriteToCsv;
+    /// let observer = WriteToCsv::new("progress.csv");
+    /// ```
+    pub fn new<N: AsRef<Path>>(path: N) -> Self {
+        WriteToCsv {
+            path: path.as_ref().to_path_buf(),
+            columns: Columns::default(),
+            writer: None,
+        }
+    }
+}
+
+/// Quotes a field if it contains a separator, a quote or a line break.
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// `WriteToCsv` only implements `observe_iter` and not `observe_init`, because the key-value
+/// pairs provided after initialization do not correspond to an iteration.
+impl<I> Observe<I> for WriteToCsv
+where
+    I: State,
+{
+    fn observe_iter(&mut self, state: &I, kv: &KV) -> Result<(), Error> {
+        let entries = Columns::entries(state, kv);
+        if self.writer.is_none() {
+            self.columns.extend(&entries);
+            let header = self
+                .columns
+                .names
+                .iter()
+                .map(|name| csv_escape(name))
+                .chain(std::iter::once("extra".to_string()))
+                .collect::<Vec<_>>()
+                .join(",");
+            let mut writer = open_append(&self.path, true)?;
+            writeln!(writer, "{header}")?;
+            self.writer = Some(writer);
+        }
+        let (row, extra) = self.columns.split(entries);
+        let extra = extra
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join(";");
+        let line = row
+            .iter()
+            .map(|value| {
+                value
+                    .as_ref()
+                    .map(|v| csv_escape(&v.to_string()))
+                    .unwrap_or_default()
+            })
+            .chain(std::iter::once(csv_escape(&extra)))
+            .collect::<Vec<_>>()
+            .join(",");
+        let writer = self.writer.as_mut().unwrap();
+        writeln!(writer, "{line}")?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Write per-iteration data to a file in the [JSON Lines](https://jsonlines.org) format during
+/// optimization.
+///
+/// Every iteration is written as a single JSON object per line. The entries are the same as the
+/// columns of [`WriteToCsv`] and they appear in the same stable order. Every line contains all
+/// entries seen so far; entries which are missing in an iteration are `null`. Non-finite floating
+/// point values are written as `null` as well.
+///
+/// An existing file will be overwritten.
+///
+/// This feature requires the `serde1` feature to be set.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::observers::WriteToJsonLines;
+///
+/// let observer = WriteToJsonLines::new("progress.jsonl");
+/// ```
+#[cfg(feature = "serde1")]
+#[derive(Debug)]
+pub struct WriteToJsonLines {
+    /// Path of the JSON lines file
+    path: PathBuf,
+    /// Entries of each line
+    columns: Columns,
+    /// Writer, opened when the first line is written
+    writer: Option<BufWriter<File>>,
+}
+
+#[cfg(feature = "serde1")]
+impl WriteToJsonLines {
+    /// Create a new instance of `WriteToJsonLines`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::observers::WriteToJsonLines;
+    /// let observer = WriteToJsonLines::new("progress.jsonl");
+    /// ```
+    pub fn new<N: AsRef<Path>>(path: N) -> Self {
+        WriteToJsonLines {
+            path: path.as_ref().to_path_buf(),
+            columns: Columns::default(),
+            writer: None,
+        }
+    }
+}
+
+/// `WriteToJsonLines` only implements `observe_iter` and not `observe_init`, because the
+/// key-value pairs provided after initialization do not correspond to an iteration.
+#[cfg(feature = "serde1")]
+impl<I> Observe<I> for WriteToJsonLines
+where
+    I: State,
+{
+    fn observe_iter(&mut self, state: &I, kv: &KV) -> Result<(), Error> {
+        use serde_json::Value;
+
+        let entries = Columns::entries(state, kv);
+        self.columns.extend(&entries);
+        let (row, _) = self.columns.split(entries);
+        let mut line = String::from("{");
+        for (idx, (name, value)) in self.columns.names.iter().zip(row).enumerate() {
+            if idx > 0 {
+                line.push(',');
+            }
+            let value = match value {
+                Some(KvValue::Float(x)) => Value::from(x),
+                Some(KvValue::Int(x)) => Value::from(x),
+                Some(KvValue::Uint(x)) => Value::from(x),
+                Some(KvValue::Bool(x)) => Value::from(x),
+                Some(KvValue::Str(x)) => Value::from(x),
+                None => Value::Null,
+            };
+            line.push_str(&serde_json::to_string(name)?);
+            line.push(':');
+            line.push_str(&serde_json::to_string(&value)?);
+        }
+        line.push('}');
+
+        if self.writer.is_none() {
+            self.writer = Some(open_append(&self.path, true)?);
+        }
+        let writer = self.writer.as_mut().unwrap();
+        writeln!(writer, "{line}")?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::IterState;
+
+    send_sync_test!(write_to_csv, WriteToCsv);
+    #[cfg(feature = "serde1")]
+    send_sync_test!(write_to_json_lines, WriteToJsonLines);
+
+    type TState = IterState<Vec<f64>, (), (), (), f64>;
+
+    /// Returns a path in the temporary directory which is unique to this process and test.
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("argmin_test_{}_{name}", std::process::id()))
+    }
+
+    fn test_state(iter: u64, cost: f64) -> TState {
+        let mut state: TState = IterState::new().cost(cost);
+        state.best_cost = cost;
+        state.iter = iter;
+        state.time = None;
+        state.counts.insert("cost_count".to_string(), iter + 1);
+        state
+    }
+
+    #[test]
+    fn test_csv() {
+        let path = temp_path("write_to_csv.csv");
+        let mut observer = WriteToCsv::new(&path);
+
+        observer
+            .observe_iter(&test_state(0, 3.0), &kv!("b" => 1u64; "a" => "x, \"y\"";))
+            .unwrap();
+        observer
+            .observe_iter(
+                &test_state(1, 2.0),
+                &kv!("c" => true; "a" => "z"; "d" => 0.5;),
+            )
+            .unwrap();
+        observer
+            .observe_iter(&test_state(2, 1.0), &kv!("b" => 3u64;))
+            .unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            content,
+            concat!(
+                "iter,cost,best_cost,cost_count,a,b,extra\n",
+                "0,3,3,1,\"x, \"\"y\"\"\",1,\n",
+                "1,2,2,2,z,,c=true;d=0.5\n",
+                "2,1,1,3,,3,\n",
+            )
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "serde1")]
+    fn test_json_lines() {
+        let path = temp_path("write_to_json_lines.jsonl");
+        let mut observer = WriteToJsonLines::new(&path);
+
+        observer
+            .observe_iter(
+                &test_state(0, f64::INFINITY),
+                &kv!("b" => 1u64; "a" => 0.5;),
+            )
+            .unwrap();
+        observer
+            .observe_iter(&test_state(1, 2.0), &kv!("c" => "z";))
+            .unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            content,
+            concat!(
+                "{\"iter\":0,\"cost\":null,\"best_cost\":null,\"cost_count\":1,\"a\":0.5,\"b\":1}\n",
+                "{\"iter\":1,\"cost\":2.0,\"best_cost\":2.0,\"cost_count\":2,\"a\":null,\"b\":null,\"c\":\"z\"}\n",
+            )
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+}
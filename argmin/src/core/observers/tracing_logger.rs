@@ -0,0 +1,349 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Logger based on the `tracing` crate
+//!
+//! Emits a span for each optimization run and an event for each iteration, which are handled by
+//! whichever `tracing` subscriber is installed.
+//! See [`TracingLogger`] for details regarding usage.
+
+use crate::core::observers::{sorted_entries, sorted_kv, Observe};
+use crate::core::{Error, KvValue, State, KV};
+use num_traits::ToPrimitive;
+use tracing::field::Value;
+use tracing::{Level, Span};
+
+/// A logger using the [`tracing`](https://crates.io/crates/tracing) crate as backend.
+///
+/// After initialization of the solver, a span named `argmin` with the field `solver` (the name of
+/// the solver) is created. All events of the run are emitted as children of this span.
+///
+/// After initialization, an event named `init` is emitted. After each iteration, an event named
+/// `iter` is emitted with the iteration number (`iter`), the current and best cost function values
+/// (`cost`, `best_cost`) and the time elapsed since the start of the optimization in seconds
+/// (`total_time`, only if the timer is enabled). Events use `argmin` as target.
+///
+/// The entries which differ between solvers and problems are emitted as separate events named
+/// `kv`, one per entry, with the fields `key` and `value` as well as `iter` (omitted after
+/// initialization). After each iteration, these are the function evaluation counts (such as
+/// `cost_count`, sorted by name) followed by the key-value pairs provided by the solver (sorted by
+/// key), after initialization only the latter. Floats, integers and booleans are recorded as such,
+/// therefore subscribers can filter and aggregate on them.
+///
+/// Requires the `tracing` feature.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::observers::TracingLogger;
+/// use tracing::Level;
+///
+/// let logger = TracingLogger::new();
+/// let debug_logger = TracingLogger::new().with_level(Level::DEBUG);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TracingLogger {
+    /// Level of spans and events
+    level: Level,
+    /// Span of the current optimization run
+    span: Span,
+}
+
+impl TracingLogger {
+    /// Create a new `TracingLogger` which emits spans and events on the `INFO` level.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use argmin::core::observers::TracingLogger;
+    ///
+    /// let logger = TracingLogger::new();
+    /// ```
+    pub fn new() -> Self {
+        TracingLogger {
+            level: Level::INFO,
+            span: Span::none(),
+        }
+    }
+
+    /// Set the level of spans and events (default: `INFO`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use argmin::core::observers::TracingLogger;
+    /// use tracing::Level;
+    ///
+    /// let logger = TracingLogger::new().with_level(Level::DEBUG);
+    /// ```
+    #[must_use]
+    pub fn with_level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+}
+
+impl Default for TracingLogger {
+    fn default() -> Self {
+        TracingLogger::new()
+    }
+}
+
+/// Emits an event via [`tracing::event!`] at the runtime `level`, which the macro requires to be
+/// a constant.
+macro_rules! emit {
+    ($level:expr, $name:literal, $parent:expr $(, $($fields:tt)*)?) => {
+        match $level {
+            Level::ERROR => tracing::event!(
+                name: $name, target: "argmin", parent: $parent, Level::ERROR, { $($($fields)*)? }
+            ),
+            Level::WARN => tracing::event!(
+                name: $name, target: "argmin", parent: $parent, Level::WARN, { $($($fields)*)? }
+            ),
+            Level::INFO => tracing::event!(
+                name: $name, target: "argmin", parent: $parent, Level::INFO, { $($($fields)*)? }
+            ),
+            Level::DEBUG => tracing::event!(
+                name: $name, target: "argmin", parent: $parent, Level::DEBUG, { $($($fields)*)? }
+            ),
+            _ => tracing::event!(
+                name: $name, target: "argmin", parent: $parent, Level::TRACE, { $($($fields)*)? }
+            ),
+        }
+    };
+}
+
+/// Returns the value of a KV entry as recorded by `tracing`.
+fn kv_value(value: &KvValue) -> &dyn Value {
+    match value {
+        KvValue::Float(v) => v,
+        KvValue::Int(v) => v,
+        KvValue::Uint(v) => v,
+        KvValue::Bool(v) => v,
+        KvValue::Str(v) => v,
+    }
+}
+
+impl TracingLogger {
+    /// Emits a `kv` event for each of `entries`.
+    fn kv_events(&self, iter: Option<u64>, entries: &[(String, KvValue)]) {
+        for (key, value) in entries {
+            emit!(
+                self.level,
+                "kv",
+                &self.span,
+                iter,
+                key = key.as_str(),
+                value = kv_value(value)
+            );
+        }
+    }
+}
+
+impl<I> Observe<I> for TracingLogger
+where
+    I: State,
+{
+    /// Creates a span for the optimization run and emits an `init` event.
+    fn observe_init(&mut self, name: &str, kv: &KV) -> Result<(), Error> {
+        self.span = match self.level {
+            Level::ERROR => tracing::error_span!(target: "argmin", "argmin", solver = name),
+            Level::WARN => tracing::warn_span!(target: "argmin", "argmin", solver = name),
+            Level::INFO => tracing::info_span!(target: "argmin", "argmin", solver = name),
+            Level::DEBUG => tracing::debug_span!(target: "argmin", "argmin", solver = name),
+            _ => tracing::trace_span!(target: "argmin", "argmin", solver = name),
+        };
+        emit!(self.level, "init", &self.span);
+        self.kv_events(None, &sorted_kv(kv));
+        Ok(())
+    }
+
+    /// Emits an `iter` event after every iteration.
+    fn observe_iter(&mut self, state: &I, kv: &KV) -> Result<(), Error> {
+        let iter = state.get_iter();
+        emit!(
+            self.level,
+            "iter",
+            &self.span,
+            iter,
+            cost = state.get_cost().to_f64(),
+            best_cost = state.get_best_cost().to_f64(),
+            total_time = state.get_time().map(|time| time.as_secs_f64())
+        );
+        self.kv_events(Some(iter), &sorted_entries(state, kv));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_utils::{TestProblem, TestSolver};
+    use crate::core::{observers::ObserverMode, Executor, IterState};
+    use std::collections::HashMap;
+    use std::fmt::Debug;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use tracing::field::Field;
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    send_sync_test!(tracing_logger, TracingLogger);
+
+    /// Recorded fields as `(name, value)`
+    type Fields = Vec<(String, String)>;
+
+    /// Recorded event as `(name, parent span name, fields)`
+    type RecordedEvent = (String, Option<String>, Fields);
+
+    /// Records all spans as `(name, fields)` and all events as `(name, parent span name, fields)`.
+    #[derive(Clone, Default)]
+    struct TestSubscriber {
+        spans: Arc<Mutex<HashMap<u64, (String, Fields)>>>,
+        events: Arc<Mutex<Vec<RecordedEvent>>>,
+        next_id: Arc<AtomicU64>,
+    }
+
+    struct Visitor<'a>(&'a mut Fields);
+
+    impl<'a> tracing::field::Visit for Visitor<'a> {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0
+                .push((field.name().to_string(), format!("{value:?}")));
+        }
+    }
+
+    impl Subscriber for TestSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let id = self.next_id.fetch_add(1, Ordering::SeqCst) + 1;
+            let mut fields = vec![];
+            span.record(&mut Visitor(&mut fields));
+            self.spans
+                .lock()
+                .unwrap()
+                .insert(id, (span.metadata().name().to_string(), fields));
+            Id::from_u64(id)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = vec![];
+            event.record(&mut Visitor(&mut fields));
+            let parent = event
+                .parent()
+                .map(|id| self.spans.lock().unwrap()[&id.into_u64()].0.clone());
+            self.events
+                .lock()
+                .unwrap()
+                .push((event.metadata().name().to_string(), parent, fields));
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[test]
+    fn test_tracing_logger() {
+        let subscriber = TestSubscriber::default();
+
+        tracing::subscriber::with_default(subscriber.clone(), || {
+            Executor::new(TestProblem::new(), TestSolver::new())
+                .configure(|state: IterState<Vec<f64>, (), (), (), f64>| {
+                    state.param(vec![1.0, 2.0]).max_iters(2)
+                })
+                .add_observer(TracingLogger::new(), ObserverMode::Always)
+                .timer(false)
+                .run()
+                .unwrap();
+        });
+
+        let spans = subscriber.spans.lock().unwrap();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(
+            spans[&1],
+            (
+                "argmin".to_string(),
+                vec![("solver".to_string(), "\"TestSolver\"".to_string())]
+            )
+        );
+
+        let events = subscriber.events.lock().unwrap();
+        assert_eq!(events.len(), 4);
+        assert_eq!(events[0].0, "init");
+        assert_eq!(events[0].1, Some("argmin".to_string()));
+        assert!(events[0].2.is_empty());
+        assert_eq!(
+            events[1],
+            (
+                "kv".to_string(),
+                Some("argmin".to_string()),
+                vec![
+                    ("key".to_string(), "\"max_iters\"".to_string()),
+                    ("value".to_string(), "2".to_string()),
+                ]
+            )
+        );
+        for (i, event) in events[2..].iter().enumerate() {
+            assert_eq!(event.0, "iter");
+            assert_eq!(event.1, Some("argmin".to_string()));
+            assert_eq!(
+                event.2,
+                vec![
+                    ("iter".to_string(), format!("{i}")),
+                    ("cost".to_string(), "inf".to_string()),
+                    ("best_cost".to_string(), "inf".to_string()),
+                    ("total_time".to_string(), "0.0".to_string()),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_kv_events() {
+        let subscriber = TestSubscriber::default();
+
+        tracing::subscriber::with_default(subscriber.clone(), || {
+            let mut state: IterState<Vec<f64>, (), (), (), f64> = IterState::new();
+            state.iter = 3;
+            state.counts.insert("cost_count".to_string(), 4);
+            let mut logger = TracingLogger::new().with_level(Level::DEBUG);
+            logger
+                .observe_iter(&state, &kv!("b" => true; "a" => 1.5;))
+                .unwrap();
+        });
+
+        let events = subscriber.events.lock().unwrap();
+        let kv_events: Vec<&Fields> = events[1..]
+            .iter()
+            .map(|(name, parent, fields)| {
+                assert_eq!(name, "kv");
+                assert_eq!(*parent, None);
+                fields
+            })
+            .collect();
+        let expected = [("cost_count", "4"), ("a", "1.5"), ("b", "true")];
+        assert_eq!(kv_events.len(), expected.len());
+        for (fields, (key, value)) in kv_events.into_iter().zip(expected) {
+            assert_eq!(
+                *fields,
+                vec![
+                    ("iter".to_string(), "3".to_string()),
+                    ("key".to_string(), format!("{key:?}")),
+                    ("value".to_string(), value.to_string()),
+                ]
+            );
+        }
+    }
+}
@@ -478,6 +478,151 @@ pub trait Jacobian {
     bulk!(jacobian, Self::Param, Self::Jacobian);
 }
 
+/// Defines the computation of vector-valued inequality constraints `c(x) >= 0`.
+///
+/// Each component of the output corresponds to one constraint. A parameter vector is feasible if
+/// all components are non-negative. The default implementation returns an empty output, therefore
+/// problems without inequality constraints only need to define the associated types.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{InequalityConstraint, Error};
+///
+/// struct Problem {}
+///
+/// impl InequalityConstraint for Problem {
+///     type Param = Vec<f64>;
+///     type Output = Vec<f64>;
+///
+///     // Unit disk: 1 - x^2 - y^2 >= 0
+///     fn inequality_constraint(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+///         Ok(vec![1.0 - p[0].powi(2) - p[1].powi(2)])
+///     }
+/// }
+/// ```
+pub trait InequalityConstraint {
+    /// Type of the parameter vector
+    type Param;
+    /// Type of the constraint values
+    type Output: Default;
+
+    /// Compute the values of the inequality constraints. Defaults to no constraints.
+    fn inequality_constraint(&self, _param: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(Self::Output::default())
+    }
+
+    bulk!(inequality_constraint, Self::Param, Self::Output);
+}
+
+/// Defines the computation of the Jacobian of the inequality constraints (see
+/// [`InequalityConstraint`]).
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{InequalityConstraintJacobian, Error};
+///
+/// struct Problem {}
+///
+/// impl InequalityConstraintJacobian for Problem {
+///     type Param = Vec<f64>;
+///     type Jacobian = Vec<Vec<f64>>;
+///
+///     // Gradients of the constraint 1 - x^2 - y^2 >= 0
+///     fn inequality_constraint_jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+///         Ok(vec![vec![-2.0 * p[0], -2.0 * p[1]]])
+///     }
+/// }
+/// ```
+pub trait InequalityConstraintJacobian {
+    /// Type of the parameter vector
+    type Param;
+    /// Type of the Jacobian
+    type Jacobian: Default;
+
+    /// Compute the Jacobian of the inequality constraints. Defaults to no constraints.
+    fn inequality_constraint_jacobian(
+        &self,
+        _param: &Self::Param,
+    ) -> Result<Self::Jacobian, Error> {
+        Ok(Self::Jacobian::default())
+    }
+
+    bulk!(inequality_constraint_jacobian, Self::Param, Self::Jacobian);
+}
+
+/// Defines the computation of vector-valued equality constraints `h(x) = 0`.
+///
+/// Each component of the output corresponds to one constraint. The default implementation returns
+/// an empty output, therefore problems without equality constraints only need to define the
+/// associated types.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{EqualityConstraint, Error};
+///
+/// struct Problem {}
+///
+/// impl EqualityConstraint for Problem {
+///     type Param = Vec<f64>;
+///     type Output = Vec<f64>;
+///
+///     // Unit circle: x^2 + y^2 - 1 = 0
+///     fn equality_constraint(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+///         Ok(vec![p[0].powi(2) + p[1].powi(2) - 1.0])
+///     }
+/// }
+/// ```
+pub trait EqualityConstraint {
+    /// Type of the parameter vector
+    type Param;
+    /// Type of the constraint values
+    type Output: Default;
+
+    /// Compute the values of the equality constraints. Defaults to no constraints.
+    fn equality_constraint(&self, _param: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(Self::Output::default())
+    }
+
+    bulk!(equality_constraint, Self::Param, Self::Output);
+}
+
+/// Defines the computation of the Jacobian of the equality constraints (see
+/// [`EqualityConstraint`]).
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{EqualityConstraintJacobian, Error};
+///
+/// struct Problem {}
+///
+/// impl EqualityConstraintJacobian for Problem {
+///     type Param = Vec<f64>;
+///     type Jacobian = Vec<Vec<f64>>;
+///
+///     // Gradients of the constraint x^2 + y^2 - 1 = 0
+///     fn equality_constraint_jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+///         Ok(vec![vec![2.0 * p[0], 2.0 * p[1]]])
+///     }
+/// }
+/// ```
+pub trait EqualityConstraintJacobian {
+    /// Type of the parameter vector
+    type Param;
+    /// Type of the Jacobian
+    type Jacobian: Default;
+
+    /// Compute the Jacobian of the equality constraints. Defaults to no constraints.
+    fn equality_constraint_jacobian(&self, _param: &Self::Param) -> Result<Self::Jacobian, Error> {
+        Ok(Self::Jacobian::default())
+    }
+
+    bulk!(equality_constraint_jacobian, Self::Param, Self::Jacobian);
+}
+
 /// Defines a linear Program
 ///
 /// # Example
@@ -537,6 +682,160 @@ pub trait LinearProgram {
     }
 }
 
+/// Rows `a_i` of a constraint matrix together with the corresponding entries `b_i` of the
+/// right-hand side, as used by [`QuadraticProgram`]
+pub type LinearConstraints<P, F> = (Vec<P>, Vec<F>);
+
+/// Optional lower and upper bounds on the parameters, as used by [`QuadraticProgram`]
+pub type Bounds<P> = Option<(P, P)>;
+
+/// Defines a quadratic program
+///
+/// A quadratic program minimizes `1/2 x^T Q x + c^T x` subject to equality constraints
+/// `A_eq x = b_eq`, inequality constraints `A_in x <= b_in` and bounds `lower <= x <= upper`.
+/// Constraint matrices are given as the list of their rows. Constraints and bounds are optional
+/// and default to none.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{Bounds, Error, LinearConstraints, QuadraticProgram};
+///
+/// struct Problem {}
+///
+/// impl QuadraticProgram for Problem {
+///     type Param = Vec<f64>;
+///     type Hessian = Vec<Vec<f64>>;
+///     type Float = f64;
+///
+///     fn objective_matrix(&self) -> Result<Self::Hessian, Error> {
+///         Ok(vec![vec![2.0, 0.0], vec![0.0, 2.0]])
+///     }
+///
+///     fn objective_vector(&self) -> Result<Self::Param, Error> {
+///         Ok(vec![-2.0, -5.0])
+///     }
+///
+///     fn inequality_constraints(&self) -> Result<LinearConstraints<Self::Param, Self::Float>, Error> {
+///         Ok((vec![vec![1.0, 2.0]], vec![6.0]))
+///     }
+///
+///     fn bounds(&self) -> Result<Bounds<Self::Param>, Error> {
+///         Ok(Some((vec![0.0, 0.0], vec![f64::INFINITY, f64::INFINITY])))
+///     }
+/// }
+/// ```
+pub trait QuadraticProgram {
+    /// Type of the parameter vector
+    type Param;
+    /// Type of the matrix `Q`
+    type Hessian;
+    /// Precision of floats
+    type Float: ArgminFloat;
+
+    /// Symmetric matrix `Q` of the objective
+    fn objective_matrix(&self) -> Result<Self::Hessian, Error> {
+        Err(argmin_error!(
+            NotImplemented,
+            "Method `objective_matrix` of QuadraticProgram trait not implemented!"
+        ))
+    }
+
+    /// Vector `c` of the objective
+    fn objective_vector(&self) -> Result<Self::Param, Error> {
+        Err(argmin_error!(
+            NotImplemented,
+            "Method `objective_vector` of QuadraticProgram trait not implemented!"
+        ))
+    }
+
+    /// Rows of `A_eq` and entries of `b_eq` of the equality constraints `A_eq x = b_eq`
+    fn equality_constraints(&self) -> Result<LinearConstraints<Self::Param, Self::Float>, Error> {
+        Ok((vec![], vec![]))
+    }
+
+    /// Rows of `A_in` and entries of `b_in` of the inequality constraints `A_in x <= b_in`
+    fn inequality_constraints(&self) -> Result<LinearConstraints<Self::Param, Self::Float>, Error> {
+        Ok((vec![], vec![]))
+    }
+
+    /// Lower and upper bounds on the parameters. Infinite entries denote unbounded parameters.
+    fn bounds(&self) -> Result<Bounds<Self::Param>, Error> {
+        Ok(None)
+    }
+}
+
+/// Defines the proximal operator of a (possibly non-smooth) convex function `g`.
+///
+/// Proximal gradient methods minimize composite objectives `f(x) + g(x)`, where the smooth part
+/// `f` is defined via [`CostFunction`] and [`Gradient`] and `g` via this trait. The proximal
+/// operator with step length `t` is defined as `prox(v, t) = argmin_x g(x) + 1/(2t) ||x - v||^2`.
+/// Common proximal operators are provided in [`crate::solver::proximalgradient`].
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{Proximal, Error};
+///
+/// struct Problem {}
+///
+/// impl Proximal for Problem {
+///     type Param = Vec<f64>;
+///     type Float = f64;
+///
+///     // g(x) = ||x||_1
+///     fn prox(&self, p: &Self::Param, step: Self::Float) -> Result<Self::Param, Error> {
+///         Ok(p.iter().map(|x| x.signum() * (x.abs() - step).max(0.0)).collect())
+///     }
+///
+///     fn regularization(&self, p: &Self::Param) -> Result<Self::Float, Error> {
+///         Ok(p.iter().map(|x| x.abs()).sum())
+///     }
+/// }
+/// ```
+pub trait Proximal {
+    /// Type of the parameter vector
+    type Param;
+    /// Precision of floats
+    type Float: ArgminFloat;
+
+    /// Compute the proximal operator of `step * g` at `param`
+    fn prox(&self, param: &Self::Param, step: Self::Float) -> Result<Self::Param, Error>;
+
+    /// Compute the value of `g` at `param`
+    fn regularization(&self, param: &Self::Param) -> Result<Self::Float, Error>;
+}
+
+/// Defines the projection onto a closed convex set.
+///
+/// Projected gradient methods minimize a smooth function defined via [`CostFunction`] and
+/// [`Gradient`] over the set. Common projections are provided in
+/// [`crate::solver::proximalgradient`].
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{Projection, Error};
+///
+/// struct Problem {}
+///
+/// impl Projection for Problem {
+///     type Param = Vec<f64>;
+///
+///     // Nonnegative orthant
+///     fn project(&self, p: &Self::Param) -> Result<Self::Param, Error> {
+///         Ok(p.iter().map(|x| x.max(0.0)).collect())
+///     }
+/// }
+/// ```
+pub trait Projection {
+    /// Type of the parameter vector
+    type Param;
+
+    /// Compute the projection of `param` onto the set
+    fn project(&self, param: &Self::Param) -> Result<Self::Param, Error>;
+}
+
 /// Wraps a call to `apply` defined in the `Operator` trait and as such allows to call `apply` on
 /// an instance of `Problem`. Internally, the number of evaluations of `apply` is counted.
 impl<O: Operator> Problem<O> {
@@ -938,119 +1237,822 @@ impl<O: Jacobian> Problem<O> {
     }
 }
 
-/// Wraps a calls to `c`, `b` and `A` defined in the `LinearProgram` trait and as such allows to
-/// call those methods on an instance of `Problem`.
-impl<O: LinearProgram> Problem<O> {
-    /// Calls `c` defined in the `LinearProgram` trait.
+impl<O: InequalityConstraint> Problem<O> {
+    /// Calls `inequality_constraint` defined in the `InequalityConstraint` trait and keeps track
+    /// of the number of evaluations.
     ///
     /// # Example
     ///
     /// ```
-    /// # use argmin::core::{Problem, LinearProgram, Error};
+    /// # use argmin::core::{Problem, InequalityConstraint, Error};
     /// #
     /// # #[derive(Eq, PartialEq, Debug, Clone)]
     /// # struct UserDefinedProblem {};
     /// #
-    /// # impl LinearProgram for UserDefinedProblem {
+    /// # impl InequalityConstraint for UserDefinedProblem {
     /// #     type Param = Vec<f64>;
-    /// #     type Float = f64;
-    /// #
-    /// #     fn c(&self) -> Result<Vec<Self::Float>, Error> {
-    /// #         Ok(vec![4.0f64, 3.0f64])
-    /// #     }
-    /// #
-    /// #     fn b(&self) -> Result<Vec<Self::Float>, Error> {
-    /// #         Ok(vec![3.0f64, 2.0f64])
-    /// #     }
+    /// #     type Output = Vec<f64>;
     /// #
-    /// #     fn A(&self) -> Result<Vec<Vec<Self::Float>>, Error> {
-    /// #         Ok(vec![vec![1.0f64, 2.0f64], vec![3.0f64, 2.0f64]])
+    /// #     fn inequality_constraint(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+    /// #         Ok(vec![1.0f64 - param[0], param[1]])
     /// #     }
     /// # }
-    /// // `UserDefinedProblem` implements `LinearProgram`.
+    /// // `UserDefinedProblem` implements `InequalityConstraint`.
     /// let mut problem1 = Problem::new(UserDefinedProblem {});
     ///
-    /// let c = problem1.c();
-    /// let b = problem1.b();
+    /// let param = vec![2.0f64, 1.0f64];
     ///
-    /// # assert_eq!(c.unwrap(), vec![4.0f64, 3.0f64]);
+    /// let res = problem1.inequality_constraint(&param);
+    ///
+    /// assert_eq!(problem1.counts["inequality_constraint_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![-1.0f64, 1.0f64]);
     /// ```
-    pub fn c(&self) -> Result<Vec<O::Float>, Error> {
-        self.problem.as_ref().unwrap().c()
+    pub fn inequality_constraint(&mut self, param: &O::Param) -> Result<O::Output, Error> {
+        self.problem("inequality_constraint_count", |problem| {
+            problem.inequality_constraint(param)
+        })
     }
 
-    /// Calls `b` defined in the `LinearProgram` trait.
+    /// Calls `bulk_inequality_constraint` defined in the `InequalityConstraint` trait and keeps
+    /// track of the number of evaluations.
     ///
     /// # Example
     ///
     /// ```
-    /// # use argmin::core::{Problem, LinearProgram, Error};
+    /// # use argmin::core::{Problem, InequalityConstraint, Error};
     /// #
     /// # #[derive(Eq, PartialEq, Debug, Clone)]
     /// # struct UserDefinedProblem {};
     /// #
-    /// # impl LinearProgram for UserDefinedProblem {
+    /// # impl InequalityConstraint for UserDefinedProblem {
     /// #     type Param = Vec<f64>;
-    /// #     type Float = f64;
-    /// #
-    /// #     fn c(&self) -> Result<Vec<Self::Float>, Error> {
-    /// #         Ok(vec![4.0f64, 3.0f64])
-    /// #     }
-    /// #
-    /// #     fn b(&self) -> Result<Vec<Self::Float>, Error> {
-    /// #         Ok(vec![3.0f64, 2.0f64])
-    /// #     }
+    /// #     type Output = Vec<f64>;
     /// #
-    /// #     fn A(&self) -> Result<Vec<Vec<Self::Float>>, Error> {
-    /// #         Ok(vec![vec![1.0f64, 2.0f64], vec![3.0f64, 2.0f64]])
+    /// #     fn inequality_constraint(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+    /// #         Ok(vec![1.0f64 - param[0], param[1]])
     /// #     }
     /// # }
-    /// // `UserDefinedProblem` implements `LinearProgram`.
+    /// // `UserDefinedProblem` implements `InequalityConstraint`.
     /// let mut problem1 = Problem::new(UserDefinedProblem {});
     ///
-    /// let b = problem1.b();
+    /// let params = vec![vec![2.0f64, 1.0f64], vec![0.0f64, 5.0f64]];
     ///
-    /// # assert_eq!(b.unwrap(), vec![3.0f64, 2.0f64]);
+    /// let res = problem1.bulk_inequality_constraint(&params);
+    ///
+    /// assert_eq!(problem1.counts["inequality_constraint_count"], 2);
+    /// # let res = res.unwrap();
+    /// # assert_eq!(res[0], vec![-1.0f64, 1.0f64]);
+    /// # assert_eq!(res[1], vec![1.0f64, 5.0f64]);
     /// ```
-    pub fn b(&self) -> Result<Vec<O::Float>, Error> {
-        self.problem.as_ref().unwrap().b()
+    pub fn bulk_inequality_constraint<P>(&mut self, params: &[P]) -> Result<Vec<O::Output>, Error>
+    where
+        P: std::borrow::Borrow<O::Param> + SyncAlias,
+        O::Output: SendAlias,
+        O: SyncAlias,
+    {
+        self.bulk_problem("inequality_constraint_count", params.len(), |problem| {
+            problem.bulk_inequality_constraint(params)
+        })
     }
+}
 
-    /// Calls `A` defined in the `LinearProgram` trait.
+impl<O: InequalityConstraintJacobian> Problem<O> {
+    /// Calls `inequality_constraint_jacobian` defined in the `InequalityConstraintJacobian` trait
+    /// and keeps track of the number of evaluations.
     ///
     /// # Example
     ///
     /// ```
-    /// # use argmin::core::{Problem, LinearProgram, Error};
+    /// # use argmin::core::{Problem, InequalityConstraintJacobian, Error};
     /// #
     /// # #[derive(Eq, PartialEq, Debug, Clone)]
     /// # struct UserDefinedProblem {};
     /// #
-    /// # impl LinearProgram for UserDefinedProblem {
+    /// # impl InequalityConstraintJacobian for UserDefinedProblem {
     /// #     type Param = Vec<f64>;
-    /// #     type Float = f64;
-    /// #
-    /// #     fn c(&self) -> Result<Vec<Self::Float>, Error> {
-    /// #         Ok(vec![4.0f64, 3.0f64])
-    /// #     }
-    /// #
-    /// #     fn b(&self) -> Result<Vec<Self::Float>, Error> {
-    /// #         Ok(vec![3.0f64, 2.0f64])
-    /// #     }
+    /// #     type Jacobian = Vec<Vec<f64>>;
     /// #
-    /// #     fn A(&self) -> Result<Vec<Vec<Self::Float>>, Error> {
-    /// #         Ok(vec![vec![1.0f64, 2.0f64], vec![3.0f64, 2.0f64]])
+    /// #     fn inequality_constraint_jacobian(
+    /// #         &self,
+    /// #         param: &Self::Param,
+    /// #     ) -> Result<Self::Jacobian, Error> {
+    /// #         Ok(vec![vec![-1.0f64, 0.0f64], vec![0.0f64, param[1]]])
     /// #     }
     /// # }
-    /// // `UserDefinedProblem` implements `LinearProgram`.
+    /// // `UserDefinedProblem` implements `InequalityConstraintJacobian`.
     /// let mut problem1 = Problem::new(UserDefinedProblem {});
     ///
-    /// let a = problem1.A();
+    /// let param = vec![2.0f64, 1.0f64];
     ///
-    /// # assert_eq!(a.unwrap(), vec![vec![1.0f64, 2.0f64], vec![3.0f64, 2.0f64]]);
+    /// let res = problem1.inequality_constraint_jacobian(&param);
+    ///
+    /// assert_eq!(problem1.counts["inequality_constraint_jacobian_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![vec![-1.0f64, 0.0f64], vec![0.0f64, 1.0f64]]);
     /// ```
-    #[allow(non_snake_case)]
-    pub fn A(&self) -> Result<Vec<Vec<O::Float>>, Error> {
-        self.problem.as_ref().unwrap().A()
+    pub fn inequality_constraint_jacobian(
+        &mut self,
+        param: &O::Param,
+    ) -> Result<O::Jacobian, Error> {
+        self.problem("inequality_constraint_jacobian_count", |problem| {
+            problem.inequality_constraint_jacobian(param)
+        })
+    }
+
+    /// Calls `bulk_inequality_constraint_jacobian` defined in the `InequalityConstraintJacobian`
+    /// trait and keeps track of the number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, InequalityConstraintJacobian, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl InequalityConstraintJacobian for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Jacobian = Vec<Vec<f64>>;
+    /// #
+    /// #     fn inequality_constraint_jacobian(
+    /// #         &self,
+    /// #         param: &Self::Param,
+    /// #     ) -> Result<Self::Jacobian, Error> {
+    /// #         Ok(vec![vec![-1.0f64, 0.0f64], vec![0.0f64, param[1]]])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `InequalityConstraintJacobian`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let params = vec![vec![2.0f64, 1.0f64], vec![0.0f64, 5.0f64]];
+    ///
+    /// let res = problem1.bulk_inequality_constraint_jacobian(&params);
+    ///
+    /// assert_eq!(problem1.counts["inequality_constraint_jacobian_count"], 2);
+    /// # let res = res.unwrap();
+    /// # assert_eq!(res[0], vec![vec![-1.0f64, 0.0f64], vec![0.0f64, 1.0f64]]);
+    /// # assert_eq!(res[1], vec![vec![-1.0f64, 0.0f64], vec![0.0f64, 5.0f64]]);
+    /// ```
+    pub fn bulk_inequality_constraint_jacobian<P>(
+        &mut self,
+        params: &[P],
+    ) -> Result<Vec<O::Jacobian>, Error>
+    where
+        P: std::borrow::Borrow<O::Param> + SyncAlias,
+        O::Jacobian: SendAlias,
+        O: SyncAlias,
+    {
+        self.bulk_problem(
+            "inequality_constraint_jacobian_count",
+            params.len(),
+            |problem| problem.bulk_inequality_constraint_jacobian(params),
+        )
+    }
+}
+
+impl<O: EqualityConstraint> Problem<O> {
+    /// Calls `equality_constraint` defined in the `EqualityConstraint` trait and keeps track of the
+    /// number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, EqualityConstraint, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl EqualityConstraint for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Output = Vec<f64>;
+    /// #
+    /// #     fn equality_constraint(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+    /// #         Ok(vec![param[0] + param[1] - 1.0f64])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `EqualityConstraint`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, 1.0f64];
+    ///
+    /// let res = problem1.equality_constraint(&param);
+    ///
+    /// assert_eq!(problem1.counts["equality_constraint_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![2.0f64]);
+    /// ```
+    pub fn equality_constraint(&mut self, param: &O::Param) -> Result<O::Output, Error> {
+        self.problem("equality_constraint_count", |problem| {
+            problem.equality_constraint(param)
+        })
+    }
+
+    /// Calls `bulk_equality_constraint` defined in the `EqualityConstraint` trait and keeps track
+    /// of the number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, EqualityConstraint, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl EqualityConstraint for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Output = Vec<f64>;
+    /// #
+    /// #     fn equality_constraint(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+    /// #         Ok(vec![param[0] + param[1] - 1.0f64])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `EqualityConstraint`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let params = vec![vec![2.0f64, 1.0f64], vec![0.0f64, 5.0f64]];
+    ///
+    /// let res = problem1.bulk_equality_constraint(&params);
+    ///
+    /// assert_eq!(problem1.counts["equality_constraint_count"], 2);
+    /// # let res = res.unwrap();
+    /// # assert_eq!(res[0], vec![2.0f64]);
+    /// # assert_eq!(res[1], vec![4.0f64]);
+    /// ```
+    pub fn bulk_equality_constraint<P>(&mut self, params: &[P]) -> Result<Vec<O::Output>, Error>
+    where
+        P: std::borrow::Borrow<O::Param> + SyncAlias,
+        O::Output: SendAlias,
+        O: SyncAlias,
+    {
+        self.bulk_problem("equality_constraint_count", params.len(), |problem| {
+            problem.bulk_equality_constraint(params)
+        })
+    }
+}
+
+impl<O: EqualityConstraintJacobian> Problem<O> {
+    /// Calls `equality_constraint_jacobian` defined in the `EqualityConstraintJacobian` trait and
+    /// keeps track of the number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, EqualityConstraintJacobian, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl EqualityConstraintJacobian for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Jacobian = Vec<Vec<f64>>;
+    /// #
+    /// #     fn equality_constraint_jacobian(
+    /// #         &self,
+    /// #         param: &Self::Param,
+    /// #     ) -> Result<Self::Jacobian, Error> {
+    /// #         Ok(vec![vec![1.0f64, param[1]]])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `EqualityConstraintJacobian`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, 1.0f64];
+    ///
+    /// let res = problem1.equality_constraint_jacobian(&param);
+    ///
+    /// assert_eq!(problem1.counts["equality_constraint_jacobian_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![vec![1.0f64, 1.0f64]]);
+    /// ```
+    pub fn equality_constraint_jacobian(&mut self, param: &O::Param) -> Result<O::Jacobian, Error> {
+        self.problem("equality_constraint_jacobian_count", |problem| {
+            problem.equality_constraint_jacobian(param)
+        })
+    }
+
+    /// Calls `bulk_equality_constraint_jacobian` defined in the `EqualityConstraintJacobian` trait
+    /// and keeps track of the number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, EqualityConstraintJacobian, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl EqualityConstraintJacobian for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Jacobian = Vec<Vec<f64>>;
+    /// #
+    /// #     fn equality_constraint_jacobian(
+    /// #         &self,
+    /// #         param: &Self::Param,
+    /// #     ) -> Result<Self::Jacobian, Error> {
+    /// #         Ok(vec![vec![1.0f64, param[1]]])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `EqualityConstraintJacobian`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let params = vec![vec![2.0f64, 1.0f64], vec![0.0f64, 5.0f64]];
+    ///
+    /// let res = problem1.bulk_equality_constraint_jacobian(&params);
+    ///
+    /// assert_eq!(problem1.counts["equality_constraint_jacobian_count"], 2);
+    /// # let res = res.unwrap();
+    /// # assert_eq!(res[0], vec![vec![1.0f64, 1.0f64]]);
+    /// # assert_eq!(res[1], vec![vec![1.0f64, 5.0f64]]);
+    /// ```
+    pub fn bulk_equality_constraint_jacobian<P>(
+        &mut self,
+        params: &[P],
+    ) -> Result<Vec<O::Jacobian>, Error>
+    where
+        P: std::borrow::Borrow<O::Param> + SyncAlias,
+        O::Jacobian: SendAlias,
+        O: SyncAlias,
+    {
+        self.bulk_problem(
+            "equality_constraint_jacobian_count",
+            params.len(),
+            |problem| problem.bulk_equality_constraint_jacobian(params),
+        )
+    }
+}
+
+/// Wraps a calls to `c`, `b` and `A` defined in the `LinearProgram` trait and as such allows to
+/// call those methods on an instance of `Problem`.
+impl<O: LinearProgram> Problem<O> {
+    /// Calls `c` defined in the `LinearProgram` trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, LinearProgram, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl LinearProgram for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn c(&self) -> Result<Vec<Self::Float>, Error> {
+    /// #         Ok(vec![4.0f64, 3.0f64])
+    /// #     }
+    /// #
+    /// #     fn b(&self) -> Result<Vec<Self::Float>, Error> {
+    /// #         Ok(vec![3.0f64, 2.0f64])
+    /// #     }
+    /// #
+    /// #     fn A(&self) -> Result<Vec<Vec<Self::Float>>, Error> {
+    /// #         Ok(vec![vec![1.0f64, 2.0f64], vec![3.0f64, 2.0f64]])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `LinearProgram`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let c = problem1.c();
+    /// let b = problem1.b();
+    ///
+    /// # assert_eq!(c.unwrap(), vec![4.0f64, 3.0f64]);
+    /// ```
+    pub fn c(&self) -> Result<Vec<O::Float>, Error> {
+        self.problem.as_ref().unwrap().c()
+    }
+
+    /// Calls `b` defined in the `LinearProgram` trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, LinearProgram, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl LinearProgram for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn c(&self) -> Result<Vec<Self::Float>, Error> {
+    /// #         Ok(vec![4.0f64, 3.0f64])
+    /// #     }
+    /// #
+    /// #     fn b(&self) -> Result<Vec<Self::Float>, Error> {
+    /// #         Ok(vec![3.0f64, 2.0f64])
+    /// #     }
+    /// #
+    /// #     fn A(&self) -> Result<Vec<Vec<Self::Float>>, Error> {
+    /// #         Ok(vec![vec![1.0f64, 2.0f64], vec![3.0f64, 2.0f64]])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `LinearProgram`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let b = problem1.b();
+    ///
+    /// # assert_eq!(b.unwrap(), vec![3.0f64, 2.0f64]);
+    /// ```
+    pub fn b(&self) -> Result<Vec<O::Float>, Error> {
+        self.problem.as_ref().unwrap().b()
+    }
+
+    /// Calls `A` defined in the `LinearProgram` trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, LinearProgram, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl LinearProgram for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn c(&self) -> Result<Vec<Self::Float>, Error> {
+    /// #         Ok(vec![4.0f64, 3.0f64])
+    /// #     }
+    /// #
+    /// #     fn b(&self) -> Result<Vec<Self::Float>, Error> {
+    /// #         Ok(vec![3.0f64, 2.0f64])
+    /// #     }
+    /// #
+    /// #     fn A(&self) -> Result<Vec<Vec<Self::Float>>, Error> {
+    /// #         Ok(vec![vec![1.0f64, 2.0f64], vec![3.0f64, 2.0f64]])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `LinearProgram`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let a = problem1.A();
+    ///
+    /// # assert_eq!(a.unwrap(), vec![vec![1.0f64, 2.0f64], vec![3.0f64, 2.0f64]]);
+    /// ```
+    #[allow(non_snake_case)]
+    pub fn A(&self) -> Result<Vec<Vec<O::Float>>, Error> {
+        self.problem.as_ref().unwrap().A()
+    }
+}
+
+/// Wraps calls to the methods defined in the `QuadraticProgram` trait and as such allows to call
+/// those methods on an instance of `Problem`.
+impl<O: QuadraticProgram> Problem<O> {
+    /// Calls `objective_matrix` defined in the `QuadraticProgram` trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Bounds, Error, LinearConstraints, Problem, QuadraticProgram};
+    /// #
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl QuadraticProgram for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Hessian = Vec<Vec<f64>>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn objective_matrix(&self) -> Result<Self::Hessian, Error> {
+    /// #         Ok(vec![vec![2.0f64, 0.0f64], vec![0.0f64, 2.0f64]])
+    /// #     }
+    /// #
+    /// #     fn objective_vector(&self) -> Result<Self::Param, Error> {
+    /// #         Ok(vec![-2.0f64, -5.0f64])
+    /// #     }
+    /// #
+    /// #     fn equality_constraints(
+    /// #         &self,
+    /// #     ) -> Result<LinearConstraints<Self::Param, Self::Float>, Error> {
+    /// #         Ok((vec![vec![1.0f64, 1.0f64]], vec![1.0f64]))
+    /// #     }
+    /// #
+    /// #     fn inequality_constraints(
+    /// #         &self,
+    /// #     ) -> Result<LinearConstraints<Self::Param, Self::Float>, Error> {
+    /// #         Ok((vec![vec![1.0f64, 2.0f64]], vec![6.0f64]))
+    /// #     }
+    /// #
+    /// #     fn bounds(&self) -> Result<Bounds<Self::Param>, Error> {
+    /// #         Ok(Some((vec![0.0f64, 0.0f64], vec![1.0f64, 1.0f64])))
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `QuadraticProgram`.
+    /// let problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let q = problem1.objective_matrix();
+    ///
+    /// # assert_eq!(q.unwrap(), vec![vec![2.0f64, 0.0f64], vec![0.0f64, 2.0f64]]);
+    /// ```
+    pub fn objective_matrix(&self) -> Result<O::Hessian, Error> {
+        self.problem.as_ref().unwrap().objective_matrix()
+    }
+
+    /// Calls `objective_vector` defined in the `QuadraticProgram` trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Bounds, Error, LinearConstraints, Problem, QuadraticProgram};
+    /// #
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl QuadraticProgram for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Hessian = Vec<Vec<f64>>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn objective_matrix(&self) -> Result<Self::Hessian, Error> {
+    /// #         Ok(vec![vec![2.0f64, 0.0f64], vec![0.0f64, 2.0f64]])
+    /// #     }
+    /// #
+    /// #     fn objective_vector(&self) -> Result<Self::Param, Error> {
+    /// #         Ok(vec![-2.0f64, -5.0f64])
+    /// #     }
+    /// #
+    /// #     fn equality_constraints(
+    /// #         &self,
+    /// #     ) -> Result<LinearConstraints<Self::Param, Self::Float>, Error> {
+    /// #         Ok((vec![vec![1.0f64, 1.0f64]], vec![1.0f64]))
+    /// #     }
+    /// #
+    /// #     fn inequality_constraints(
+    /// #         &self,
+    /// #     ) -> Result<LinearConstraints<Self::Param, Self::Float>, Error> {
+    /// #         Ok((vec![vec![1.0f64, 2.0f64]], vec![6.0f64]))
+    /// #     }
+    /// #
+    /// #     fn bounds(&self) -> Result<Bounds<Self::Param>, Error> {
+    /// #         Ok(Some((vec![0.0f64, 0.0f64], vec![1.0f64, 1.0f64])))
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `QuadraticProgram`.
+    /// let problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let c = problem1.objective_vector();
+    ///
+    /// # assert_eq!(c.unwrap(), vec![-2.0f64, -5.0f64]);
+    /// ```
+    pub fn objective_vector(&self) -> Result<O::Param, Error> {
+        self.problem.as_ref().unwrap().objective_vector()
+    }
+
+    /// Calls `equality_constraints` defined in the `QuadraticProgram` trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Bounds, Error, LinearConstraints, Problem, QuadraticProgram};
+    /// #
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl QuadraticProgram for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Hessian = Vec<Vec<f64>>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn objective_matrix(&self) -> Result<Self::Hessian, Error> {
+    /// #         Ok(vec![vec![2.0f64, 0.0f64], vec![0.0f64, 2.0f64]])
+    /// #     }
+    /// #
+    /// #     fn objective_vector(&self) -> Result<Self::Param, Error> {
+    /// #         Ok(vec![-2.0f64, -5.0f64])
+    /// #     }
+    /// #
+    /// #     fn equality_constraints(
+    /// #         &self,
+    /// #     ) -> Result<LinearConstraints<Self::Param, Self::Float>, Error> {
+    /// #         Ok((vec![vec![1.0f64, 1.0f64]], vec![1.0f64]))
+    /// #     }
+    /// #
+    /// #     fn inequality_constraints(
+    /// #         &self,
+    /// #     ) -> Result<LinearConstraints<Self::Param, Self::Float>, Error> {
+    /// #         Ok((vec![vec![1.0f64, 2.0f64]], vec![6.0f64]))
+    /// #     }
+    /// #
+    /// #     fn bounds(&self) -> Result<Bounds<Self::Param>, Error> {
+    /// #         Ok(Some((vec![0.0f64, 0.0f64], vec![1.0f64, 1.0f64])))
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `QuadraticProgram`.
+    /// let problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let (a, b) = problem1.equality_constraints().unwrap();
+    ///
+    /// # assert_eq!(a, vec![vec![1.0f64, 1.0f64]]);
+    /// # assert_eq!(b, vec![1.0f64]);
+    /// ```
+    pub fn equality_constraints(&self) -> Result<LinearConstraints<O::Param, O::Float>, Error> {
+        self.problem.as_ref().unwrap().equality_constraints()
+    }
+
+    /// Calls `inequality_constraints` defined in the `QuadraticProgram` trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Bounds, Error, LinearConstraints, Problem, QuadraticProgram};
+    /// #
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl QuadraticProgram for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Hessian = Vec<Vec<f64>>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn objective_matrix(&self) -> Result<Self::Hessian, Error> {
+    /// #         Ok(vec![vec![2.0f64, 0.0f64], vec![0.0f64, 2.0f64]])
+    /// #     }
+    /// #
+    /// #     fn objective_vector(&self) -> Result<Self::Param, Error> {
+    /// #         Ok(vec![-2.0f64, -5.0f64])
+    /// #     }
+    /// #
+    /// #     fn equality_constraints(
+    /// #         &self,
+    /// #     ) -> Result<LinearConstraints<Self::Param, Self::Float>, Error> {
+    /// #         Ok((vec![vec![1.0f64, 1.0f64]], vec![1.0f64]))
+    /// #     }
+    /// #
+    /// #     fn inequality_constraints(
+    /// #         &self,
+    /// #     ) -> Result<LinearConstraints<Self::Param, Self::Float>, Error> {
+    /// #         Ok((vec![vec![1.0f64, 2.0f64]], vec![6.0f64]))
+    /// #     }
+    /// #
+    /// #     fn bounds(&self) -> Result<Bounds<Self::Param>, Error> {
+    /// #         Ok(Some((vec![0.0f64, 0.0f64], vec![1.0f64, 1.0f64])))
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `QuadraticProgram`.
+    /// let problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let (a, b) = problem1.inequality_constraints().unwrap();
+    ///
+    /// # assert_eq!(a, vec![vec![1.0f64, 2.0f64]]);
+    /// # assert_eq!(b, vec![6.0f64]);
+    /// ```
+    pub fn inequality_constraints(&self) -> Result<LinearConstraints<O::Param, O::Float>, Error> {
+        self.problem.as_ref().unwrap().inequality_constraints()
+    }
+
+    /// Calls `bounds` defined in the `QuadraticProgram` trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Bounds, Error, LinearConstraints, Problem, QuadraticProgram};
+    /// #
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl QuadraticProgram for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Hessian = Vec<Vec<f64>>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn objective_matrix(&self) -> Result<Self::Hessian, Error> {
+    /// #         Ok(vec![vec![2.0f64, 0.0f64], vec![0.0f64, 2.0f64]])
+    /// #     }
+    /// #
+    /// #     fn objective_vector(&self) -> Result<Self::Param, Error> {
+    /// #         Ok(vec![-2.0f64, -5.0f64])
+    /// #     }
+    /// #
+    /// #     fn equality_constraints(
+    /// #         &self,
+    /// #     ) -> Result<LinearConstraints<Self::Param, Self::Float>, Error> {
+    /// #         Ok((vec![vec![1.0f64, 1.0f64]], vec![1.0f64]))
+    /// #     }
+    /// #
+    /// #     fn inequality_constraints(
+    /// #         &self,
+    /// #     ) -> Result<LinearConstraints<Self::Param, Self::Float>, Error> {
+    /// #         Ok((vec![vec![1.0f64, 2.0f64]], vec![6.0f64]))
+    /// #     }
+    /// #
+    /// #     fn bounds(&self) -> Result<Bounds<Self::Param>, Error> {
+    /// #         Ok(Some((vec![0.0f64, 0.0f64], vec![1.0f64, 1.0f64])))
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `QuadraticProgram`.
+    /// let problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let bounds = problem1.bounds();
+    ///
+    /// # assert_eq!(bounds.unwrap(), Some((vec![0.0f64, 0.0f64], vec![1.0f64, 1.0f64])));
+    /// ```
+    pub fn bounds(&self) -> Result<Bounds<O::Param>, Error> {
+        self.problem.as_ref().unwrap().bounds()
+    }
+}
+
+impl<O: Proximal> Problem<O> {
+    /// Calls `prox` defined in the `Proximal` trait and keeps track of the number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, Proximal, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl Proximal for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn prox(&self, param: &Self::Param, step: Self::Float) -> Result<Self::Param, Error> {
+    /// #         Ok(param.iter().map(|x| x.signum() * (x.abs() - step).max(0.0)).collect())
+    /// #     }
+    /// #
+    /// #     fn regularization(&self, param: &Self::Param) -> Result<Self::Float, Error> {
+    /// #         Ok(param.iter().map(|x| x.abs()).sum())
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `Proximal`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, -0.5f64];
+    ///
+    /// let res = problem1.prox(&param, 1.0);
+    ///
+    /// assert_eq!(problem1.counts["prox_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![1.0f64, 0.0f64]);
+    /// ```
+    pub fn prox(&mut self, param: &O::Param, step: O::Float) -> Result<O::Param, Error> {
+        self.problem("prox_count", |problem| problem.prox(param, step))
+    }
+
+    /// Calls `regularization` defined in the `Proximal` trait and keeps track of the number of
+    /// evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, Proximal, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl Proximal for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn prox(&self, param: &Self::Param, step: Self::Float) -> Result<Self::Param, Error> {
+    /// #         Ok(param.iter().map(|x| x.signum() * (x.abs() - step).max(0.0)).collect())
+    /// #     }
+    /// #
+    /// #     fn regularization(&self, param: &Self::Param) -> Result<Self::Float, Error> {
+    /// #         Ok(param.iter().map(|x| x.abs()).sum())
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `Proximal`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, -0.5f64];
+    ///
+    /// let res = problem1.regularization(&param);
+    ///
+    /// assert_eq!(problem1.counts["regularization_count"], 1);
+    /// # assert_eq!(res.unwrap(), 2.5f64);
+    /// ```
+    pub fn regularization(&mut self, param: &O::Param) -> Result<O::Float, Error> {
+        self.problem("regularization_count", |problem| {
+            problem.regularization(param)
+        })
+    }
+}
+
+impl<O: Projection> Problem<O> {
+    /// Calls `project` defined in the `Projection` trait and keeps track of the number of
+    /// evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, Projection, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl Projection for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #
+    /// #     fn project(&self, param: &Self::Param) -> Result<Self::Param, Error> {
+    /// #         Ok(param.iter().map(|x| x.max(0.0)).collect())
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `Projection`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, -0.5f64];
+    ///
+    /// let res = problem1.project(&param);
+    ///
+    /// assert_eq!(problem1.counts["projection_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![2.0f64, 0.0f64]);
+    /// ```
+    pub fn project(&mut self, param: &O::Param) -> Result<O::Param, Error> {
+        self.problem("projection_count", |problem| problem.project(param))
     }
 }
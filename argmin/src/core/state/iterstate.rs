@@ -23,6 +23,7 @@ use std::collections::HashMap;
 /// * Jacobian of current and previous iteration
 /// * Hessian of current and previous iteration
 /// * inverse Hessian of current and previous iteration
+/// * Lagrange multipliers of constraints
 /// * cost function value of current and previous iteration
 /// * current and previous best cost function value
 /// * target cost function value
@@ -70,6 +71,8 @@ pub struct IterState<P, G, J, H, F> {
     pub jacobian: Option<J>,
     /// Previous Jacobian
     pub prev_jacobian: Option<J>,
+    /// Lagrange multipliers of the constraints
+    pub lagrange_multipliers: Option<Vec<F>>,
     /// Current iteration
     pub iter: u64,
     /// Iteration number of last best cost
@@ -220,6 +223,27 @@ where
         self
     }
 
+    /// Set the Lagrange multipliers of the constraints.
+    ///
+    /// Solvers for constrained problems store the multipliers of the equality constraints
+    /// followed by those of the inequality constraints.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{IterState, State};
+    /// # let state: IterState<Vec<f64>, (), (), (), f64> = IterState::new();
+    /// # assert!(state.lagrange_multipliers.is_none());
+    /// let state = state.lagrange_multipliers(vec![1.0f64, 0.5f64]);
+    /// # assert_eq!(state.lagrange_multipliers.as_ref().unwrap()[0].to_ne_bytes(), 1.0f64.to_ne_bytes());
+    /// # assert_eq!(state.lagrange_multipliers.as_ref().unwrap()[1].to_ne_bytes(), 0.5f64.to_ne_bytes());
+    /// ```
+    #[must_use]
+    pub fn lagrange_multipliers(mut self, multipliers: Vec<F>) -> Self {
+        self.lagrange_multipliers = Some(multipliers);
+        self
+    }
+
     /// Set the current cost function value. This shifts the stored cost function value to the
     /// previous cost function value.
     ///
@@ -860,6 +884,41 @@ where
     pub fn take_prev_jacobian(&mut self) -> Option<J> {
         self.prev_jacobian.take()
     }
+
+    /// Returns a reference to the Lagrange multipliers of the constraints
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{IterState, State, ArgminFloat};
+    /// # let mut state: IterState<Vec<f64>, (), (), (), f64> = IterState::new();
+    /// # assert!(state.get_lagrange_multipliers().is_none());
+    /// # state.lagrange_multipliers = Some(vec![1.0, 2.0]);
+    /// let multipliers = state.get_lagrange_multipliers();  // Option<&Vec<F>>
+    /// # assert_eq!(multipliers.as_ref().unwrap()[0].to_ne_bytes(), 1.0f64.to_ne_bytes());
+    /// # assert_eq!(multipliers.as_ref().unwrap()[1].to_ne_bytes(), 2.0f64.to_ne_bytes());
+    /// ```
+    pub fn get_lagrange_multipliers(&self) -> Option<&Vec<F>> {
+        self.lagrange_multipliers.as_ref()
+    }
+
+    /// Moves the Lagrange multipliers out and replaces them internally with `None`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{IterState, State, ArgminFloat};
+    /// # let mut state: IterState<Vec<f64>, (), (), (), f64> = IterState::new();
+    /// # assert!(state.take_lagrange_multipliers().is_none());
+    /// # state.lagrange_multipliers = Some(vec![1.0, 2.0]);
+    /// let multipliers = state.take_lagrange_multipliers();  // Option<Vec<F>>
+    /// # assert!(state.take_lagrange_multipliers().is_none());
+    /// # assert_eq!(multipliers.as_ref().unwrap()[0].to_ne_bytes(), 1.0f64.to_ne_bytes());
+    /// # assert_eq!(multipliers.as_ref().unwrap()[1].to_ne_bytes(), 2.0f64.to_ne_bytes());
+    /// ```
+    pub fn take_lagrange_multipliers(&mut self) -> Option<Vec<F>> {
+        self.lagrange_multipliers.take()
+    }
 }
 
 impl<P, G, J, H, F> State for IterState<P, G, J, H, F>
@@ -898,6 +957,7 @@ where
     /// # assert!(state.prev_inv_hessian.is_none());
     /// # assert!(state.jacobian.is_none());
     /// # assert!(state.prev_jacobian.is_none());
+    /// # assert!(state.lagrange_multipliers.is_none());
     /// # assert_eq!(state.iter, 0);
     /// # assert_eq!(state.last_best_iter, 0);
     /// # assert_eq!(state.max_iters, std::u64::MAX);
@@ -924,6 +984,7 @@ where
             prev_inv_hessian: None,
             jacobian: None,
             prev_jacobian: None,
+            lagrange_multipliers: None,
             iter: 0,
             last_best_iter: 0,
             max_iters: std::u64::MAX,
@@ -1053,6 +1114,42 @@ where
         self
     }
 
+    /// Resets the termination status to [`NotTerminated`](`TerminationStatus::NotTerminated`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{IterState, State, ArgminFloat, TerminationReason, TerminationStatus};
+    /// # let state: IterState<Vec<f64>, (), (), (), f64> = IterState::new();
+    /// # let state = state.terminate_with(TerminationReason::MaxItersReached);
+    /// let state = state.reset_termination_status();
+    /// # assert_eq!(state.termination_status, TerminationStatus::NotTerminated);
+    /// ```
+    fn reset_termination_status(mut self) -> Self {
+        self.termination_status = TerminationStatus::NotTerminated;
+        self
+    }
+
+    /// Resets the current iteration number and the iteration number of the last best parameter
+    /// vector to zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{IterState, State, ArgminFloat};
+    /// # let mut state: IterState<Vec<f64>, (), (), (), f64> = IterState::new();
+    /// # state.iter = 12;
+    /// # state.last_best_iter = 10;
+    /// let state = state.reset_iter();
+    /// # assert_eq!(state.iter, 0);
+    /// # assert_eq!(state.last_best_iter, 0);
+    /// ```
+    fn reset_iter(mut self) -> Self {
+        self.iter = 0;
+        self.last_best_iter = 0;
+        self
+    }
+
     /// Sets the time required so far.
     ///
     /// # Example
@@ -1339,6 +1436,7 @@ mod tests {
         assert!(state.get_prev_inv_hessian().is_none());
         assert!(state.get_jacobian().is_none());
         assert!(state.get_prev_jacobian().is_none());
+        assert!(state.get_lagrange_multipliers().is_none());
         assert_eq!(state.get_iter(), 0);
 
         assert!(state.is_best());
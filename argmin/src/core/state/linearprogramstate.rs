@@ -330,6 +330,42 @@ where
         self
     }
 
+    /// Resets the termination status to [`NotTerminated`](`TerminationStatus::NotTerminated`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{LinearProgramState, State, ArgminFloat, TerminationReason, TerminationStatus};
+    /// # let state: LinearProgramState<Vec<f64>, f64> = LinearProgramState::new();
+    /// # let state = state.terminate_with(TerminationReason::MaxItersReached);
+    /// let state = state.reset_termination_status();
+    /// # assert_eq!(state.termination_status, TerminationStatus::NotTerminated);
+    /// ```
+    fn reset_termination_status(mut self) -> Self {
+        self.termination_status = TerminationStatus::NotTerminated;
+        self
+    }
+
+    /// Resets the current iteration number and the iteration number of the last best parameter
+    /// vector to zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{LinearProgramState, State, ArgminFloat};
+    /// # let mut state: LinearProgramState<Vec<f64>, f64> = LinearProgramState::new();
+    /// # state.iter = 12;
+    /// # state.last_best_iter = 10;
+    /// let state = state.reset_iter();
+    /// # assert_eq!(state.iter, 0);
+    /// # assert_eq!(state.last_best_iter, 0);
+    /// ```
+    fn reset_iter(mut self) -> Self {
+        self.iter = 0;
+        self.last_best_iter = 0;
+        self
+    }
+
     /// Sets the time required so far.
     ///
     /// # Example
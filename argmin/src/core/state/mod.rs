@@ -107,6 +107,17 @@ pub trait State {
     #[must_use]
     fn terminate_with(self, termination_reason: TerminationReason) -> Self;
 
+    /// Resets the termination status to [`NotTerminated`](`TerminationStatus::NotTerminated`).
+    ///
+    /// This is required when a terminated state is used to warm-start another run.
+    #[must_use]
+    fn reset_termination_status(self) -> Self;
+
+    /// Resets the current iteration number and the iteration number where the last best parameter
+    /// vector was found to zero. The best parameter vector and best cost function value are kept.
+    #[must_use]
+    fn reset_iter(self) -> Self;
+
     /// Returns termination status.
     fn get_termination_status(&self) -> &TerminationStatus;
 
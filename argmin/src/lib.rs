@@ -79,8 +79,27 @@
 //!
 //! - [Simulated Annealing](`crate::solver::simulatedannealing::SimulatedAnnealing`)
 //!
+//! - [Parallel Tempering](`crate::solver::paralleltempering::ParallelTempering`)
+//!
 //! - [Particle Swarm Optimization](`crate::solver::particleswarm::ParticleSwarm`)
 //!
+//! - [Powell's method](`crate::solver::powell::Powell`)
+//!
+//! - [BOBYQA-style derivative-free trust region method](`crate::solver::bobyqa::BOBYQA`)
+//!
+//! - [COBYLA](`crate::solver::cobyla::COBYLA`)
+//!
+//! - [Augmented Lagrangian method](`crate::solver::augmentedlagrangian::AugmentedLagrangian`)
+//!
+//! - [Sequential quadratic programming](`crate::solver::sqp::SQP`)
+//!
+//! - [Active set method for quadratic programs](`crate::solver::activeset::ActiveSet`)
+//!
+//! - [Proximal and projected gradient methods](`crate::solver::proximalgradient`)
+//!   - [ISTA](`crate::solver::proximalgradient::ISTA`)
+//!   - [FISTA](`crate::solver::proximalgradient::FISTA`)
+//!   - [Spectral projected gradient method](`crate::solver::proximalgradient::ProjectedGradient`)
+//!
 //! ## External solvers compatible with argmin
 //!
 //! External solvers which implement the `Solver` trait are compatible with argmins `Executor`,
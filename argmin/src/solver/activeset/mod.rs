@@ -0,0 +1,823 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Active set method for quadratic programs
+//!
+//! Solves convex quadratic programs with linear equality and inequality constraints and bounds.
+//!
+//! For details see [`ActiveSet`].
+//!
+//! ## References
+//!
+//! Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+//! Springer. ISBN 0-387-30303-0.
+
+use crate::core::{
+    ArgminFloat, Error, IterState, Problem, QuadraticProgram, SerializeAlias, Solver, State,
+    TerminationReason, KV,
+};
+use crate::solver::dense::{solve_qp, to_coords, Cholesky, QpError};
+use argmin_math::{
+    ArgminBasis, ArgminDot, ArgminMinMax, ArgminScaledAdd, ArgminSolve, ArgminZeroLike,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Active set method for quadratic programs
+///
+/// Minimizes `1/2 x^T Q x + c^T x` subject to equality constraints `A_eq x = b_eq`, inequality
+/// constraints `A_in x <= b_in` and bounds `lower <= x <= upper` by the primal active set
+/// method. `Q` must be symmetric and positive definite.
+///
+/// The method maintains a feasible point and a working set of inequality constraints (and
+/// bounds) which are treated as equalities. In each iteration, the quadratic objective is
+/// minimized subject to the equality constraints and the constraints in the working set. The
+/// resulting step is shortened if it would violate a constraint outside of the working set, which
+/// is then added to the working set. Once the step vanishes, a constraint with a negative
+/// multiplier is removed from the working set, or the algorithm terminates if there is none.
+/// The equality constrained subproblems are solved via products with `Q^-1`, which are computed
+/// once per constraint by solving linear systems with
+/// [`ArgminSolve`](`argmin_math::ArgminSolve`), and a Cholesky factorization
+/// ([`ArgminCholesky`](`argmin_math::ArgminCholesky`)) of the positive definite matrix
+/// `A Q^-1 A^T` of the normals `A` of the constraints in the working set.
+///
+/// The working set can be warm started via
+/// [`with_working_set`](`ActiveSet::with_working_set`), for instance with the final working set
+/// ([`working_set`](`ActiveSet::working_set`)) of a previous run on a similar problem. If the
+/// minimizer subject to the warm start working set is feasible, the iterations start from there.
+/// Otherwise, the iterations start from the projection of the initial parameter vector (or of
+/// this minimizer if no initial parameter vector is given) onto the feasible set, which is
+/// computed by the dual active set method of Goldfarb and Idnani. This also detects infeasible
+/// constraints, in which case the algorithm terminates with
+/// [`TerminationReason::SolverExit`].
+///
+/// At the solution, `Q x + c = A_eq^T lambda - A_in^T mu + nu_lower - nu_upper` holds with
+/// `mu`, `nu_lower`, `nu_upper >= 0`. The multipliers `lambda`, `mu`, `nu_lower` and `nu_upper`
+/// are stored in this order in the state (see [`IterState::get_lagrange_multipliers`]). If
+/// bounds are given, `nu_lower` and `nu_upper` contain one entry per parameter.
+///
+/// The initial parameter vector is optional.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`QuadraticProgram`]. The matrix type needs
+/// to implement [`ArgminSolve`](`argmin_math::ArgminSolve`) and
+/// [`ArgminDot`](`argmin_math::ArgminDot`) with the parameter vector type.
+///
+/// ## References
+///
+/// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0.
+///
+/// Goldfarb, D., Idnani, A. (1983): A numerically stable dual method for solving strictly
+/// convex quadratic programs. Mathematical Programming 27, 1-33.
+/// <https://doi.org/10.1007/BF02591962>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ActiveSet<P, F> {
+    /// Tolerance for feasibility, vanishing steps and multipliers
+    tol: F,
+    /// Working set as indices of the inequality constraints followed by the lower and upper
+    /// bounds
+    working_set: Vec<usize>,
+    /// Constraints `a^T x >= b`: equality constraints followed by inequality constraints, lower
+    /// bounds and upper bounds. `b` is `None` for infinite bounds, which are never active.
+    constraints: Vec<(P, Option<F>)>,
+    /// Number of equality constraints
+    num_eq: usize,
+    /// Indices of the equality constraints and of the constraints in the working set
+    active: Vec<usize>,
+    /// Products of `Q^-1` with the normals of the constraints
+    q_inv_constraints: Vec<P>,
+    /// Product of `Q^-1` with `c`
+    q_inv_c: Option<P>,
+}
+
+impl<P, F> ActiveSet<P, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`ActiveSet`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::activeset::ActiveSet;
+    /// let solver: ActiveSet<Vec<f64>, f64> = ActiveSet::new();
+    /// ```
+    pub fn new() -> Self {
+        ActiveSet {
+            tol: F::epsilon().sqrt(),
+            working_set: vec![],
+            constraints: vec![],
+            num_eq: 0,
+            active: vec![],
+            q_inv_constraints: vec![],
+            q_inv_c: None,
+        }
+    }
+
+    /// Set the tolerance for feasibility, vanishing steps and multipliers
+    ///
+    /// Must be larger than zero and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::activeset::ActiveSet;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let solver: ActiveSet<Vec<f64>, f64> = ActiveSet::new().with_tolerance(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= F::zero() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ActiveSet`: tolerance must be > 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+
+    /// Set the initial working set
+    ///
+    /// The indices refer to the inequality constraints, followed by the lower bounds and the
+    /// upper bounds of all parameters (if bounds are given). Indices of infinite bounds are
+    /// ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::activeset::ActiveSet;
+    /// let solver: ActiveSet<Vec<f64>, f64> = ActiveSet::new().with_working_set(vec![0, 2]);
+    /// ```
+    #[must_use]
+    pub fn with_working_set(mut self, working_set: Vec<usize>) -> Self {
+        self.working_set = working_set;
+        self
+    }
+
+    /// Returns the working set
+    ///
+    /// After a run, this is the final working set, which can be used to warm start subsequent
+    /// runs via [`with_working_set`](`ActiveSet::with_working_set`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::activeset::ActiveSet;
+    /// let solver: ActiveSet<Vec<f64>, f64> = ActiveSet::new().with_working_set(vec![1]);
+    /// let working_set = solver.working_set();
+    /// # assert_eq!(working_set, &[1]);
+    /// ```
+    pub fn working_set(&self) -> &[usize] {
+        &self.working_set
+    }
+
+    /// Sets the active constraints and updates the working set accordingly
+    fn set_active(&mut self, active: Vec<usize>) {
+        self.working_set = active
+            .iter()
+            .filter(|&&i| i >= self.num_eq)
+            .map(|i| i - self.num_eq)
+            .collect();
+        self.active = active;
+    }
+}
+
+impl<P, F> Default for ActiveSet<P, F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        ActiveSet::new()
+    }
+}
+
+impl<P, F> ActiveSet<P, F>
+where
+    P: Clone + ArgminDot<P, F> + ArgminScaledAdd<P, F, P> + ArgminZeroLike,
+    F: ArgminFloat,
+{
+    /// Minimizer of the objective subject to the constraints `active` holding with equality,
+    /// together with the corresponding multipliers. Returns `None` if the normals of the
+    /// constraints are linearly dependent or if `active` contains an infinite bound.
+    fn face_minimizer(&self, active: &[usize]) -> Option<(P, Vec<F>)> {
+        let q_inv_c = self.q_inv_c.as_ref()?;
+        let gram: Vec<Vec<F>> = active
+            .iter()
+            .map(|&i| {
+                active
+                    .iter()
+                    .map(|&j| self.constraints[i].0.dot(&self.q_inv_constraints[j]))
+                    .collect()
+            })
+            .collect();
+        let rhs = active
+            .iter()
+            .map(|&i| {
+                let (a, b) = &self.constraints[i];
+                b.map(|b| b + a.dot(q_inv_c))
+            })
+            .collect::<Option<Vec<F>>>()?;
+        let multipliers = if active.is_empty() {
+            vec![]
+        } else {
+            Cholesky::new(&gram)?.solve(&rhs)
+        };
+        let minimizer = active.iter().zip(&multipliers).fold(
+            q_inv_c.zero_like().scaled_add(&(-F::one()), q_inv_c),
+            |acc, (&i, lambda)| acc.scaled_add(lambda, &self.q_inv_constraints[i]),
+        );
+        Some((minimizer, multipliers))
+    }
+
+    /// Whether `param` satisfies all constraints
+    fn is_feasible(&self, param: &P) -> bool {
+        self.constraints
+            .iter()
+            .enumerate()
+            .all(|(i, (a, b))| match b {
+                Some(b) => {
+                    let residual = a.dot(param) - *b;
+                    let tol = self.tol * (F::one() + b.abs());
+                    if i < self.num_eq {
+                        residual.abs() <= tol
+                    } else {
+                        residual >= -tol
+                    }
+                }
+                None => true,
+            })
+    }
+}
+
+/// Entry of `v` belonging to the basis vector `e`, where `ones` is the vector of all ones
+///
+/// Since `v` may contain infinite entries, the other entries are not removed by multiplying them
+/// with zero (which would result in NaN), but by clamping them to zero with masks which are zero
+/// everywhere except for the entry of `e`, where they are plus or minus infinity.
+fn coordinate<P, F>(v: &P, e: &P, ones: &P) -> F
+where
+    P: ArgminDot<P, F> + ArgminMinMax + ArgminScaledAdd<P, F, P> + ArgminZeroLike,
+    F: ArgminFloat,
+{
+    let zero = e.zero_like();
+    // `-1` for the entry of `e` and `1` for all others
+    let sign = ones.scaled_add(&float!(-2.0), e);
+    let upper = P::max(&zero.scaled_add(&F::neg_infinity(), &sign), &zero);
+    let lower = P::min(&zero.scaled_add(&F::infinity(), &sign), &zero);
+    P::max(&P::min(v, &upper), &lower).dot(e)
+}
+
+impl<O, P, H, F> Solver<O, IterState<P, P, (), (), F>> for ActiveSet<P, F>
+where
+    O: QuadraticProgram<Param = P, Hessian = H, Float = F>,
+    P: Clone
+        + SerializeAlias
+        + ArgminBasis
+        + ArgminDot<P, F>
+        + ArgminMinMax
+        + ArgminScaledAdd<P, F, P>
+        + ArgminZeroLike,
+    H: ArgminSolve<P, P> + ArgminDot<P, P>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Active set method";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, P, (), (), F>,
+    ) -> Result<(IterState<P, P, (), (), F>, Option<KV>), Error> {
+        let q = problem.objective_matrix()?;
+        let c = problem.objective_vector()?;
+        let (a_eq, b_eq) = problem.equality_constraints()?;
+        let (a_in, b_in) = problem.inequality_constraints()?;
+        if a_eq.len() != b_eq.len() || a_in.len() != b_in.len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ActiveSet`: number of constraint rows and right-hand sides differ."
+            ));
+        }
+        let basis = c.basis_like();
+
+        self.num_eq = a_eq.len();
+        self.constraints = a_eq.into_iter().zip(b_eq.into_iter().map(Some)).collect();
+        self.constraints.extend(
+            a_in.iter()
+                .zip(b_in)
+                .map(|(a, b)| (a.zero_like().scaled_add(&(-F::one()), a), Some(-b))),
+        );
+        if let Some((lower, upper)) = problem.bounds()? {
+            let ones = basis
+                .iter()
+                .fold(c.zero_like(), |acc, e| acc.scaled_add(&F::one(), e));
+            let bound = |v: &P, e: &P| Some(coordinate(v, e, &ones)).filter(|x| x.is_finite());
+            self.constraints
+                .extend(basis.iter().map(|e| (e.clone(), bound(&lower, e))));
+            self.constraints.extend(basis.iter().map(|e| {
+                (
+                    e.zero_like().scaled_add(&(-F::one()), e),
+                    bound(&upper, e).map(|u| -u),
+                )
+            }));
+        }
+        let num_constraints = self.constraints.len();
+        if let Some(&idx) = self
+            .working_set
+            .iter()
+            .find(|&&i| self.num_eq + i >= num_constraints)
+        {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`ActiveSet`: index {} of working set exceeds number of constraints.",
+                    idx
+                )
+            ));
+        }
+        self.q_inv_c = Some(q.solve(&c)?);
+        self.q_inv_constraints = self
+            .constraints
+            .iter()
+            .map(|(a, _)| q.solve(a))
+            .collect::<Result<_, _>>()?;
+
+        // Warm start from the minimizer subject to the working set
+        let mut active: Vec<usize> = (0..self.num_eq).collect();
+        active.extend(
+            self.working_set
+                .iter()
+                .map(|i| self.num_eq + i)
+                .filter(|&i| self.constraints[i].1.is_some()),
+        );
+        let warm_start = self.face_minimizer(&active);
+        let param = match warm_start {
+            Some((minimizer, _)) if self.is_feasible(&minimizer) => {
+                self.set_active(active);
+                minimizer
+            }
+            _ => {
+                // Projection onto the feasible set
+                let center = state
+                    .take_param()
+                    .or_else(|| warm_start.map(|(minimizer, _)| minimizer))
+                    .unwrap_or_else(|| c.zero_like());
+                // Infinite bounds are left out
+                let finite: Vec<usize> = (0..num_constraints)
+                    .filter(|&i| self.constraints[i].1.is_some())
+                    .collect();
+                let constraints: Vec<(Vec<F>, F)> = self
+                    .constraints
+                    .iter()
+                    .filter_map(|(a, b)| b.map(|b| (to_coords(&basis, a), b)))
+                    .collect();
+                let neg_center: Vec<F> = to_coords(&basis, &center).iter().map(|&x| -x).collect();
+                match solve_qp(
+                    &Cholesky::identity(basis.len()),
+                    &neg_center,
+                    &constraints,
+                    self.num_eq,
+                ) {
+                    Ok(solution) => {
+                        self.set_active(solution.active.iter().map(|&i| finite[i]).collect());
+                        basis
+                            .iter()
+                            .zip(&solution.x)
+                            .fold(c.zero_like(), |acc, (e, x)| acc.scaled_add(x, e))
+                    }
+                    Err(QpError::Infeasible) => {
+                        return Ok((
+                            state
+                                .param(center)
+                                .terminate_with(TerminationReason::SolverExit(
+                                    "Infeasible constraints".to_string(),
+                                )),
+                            Some(kv!("feasible" => false;)),
+                        ))
+                    }
+                    Err(QpError::IterationLimit) => {
+                        return Err(argmin_error!(
+                            ConditionViolated,
+                            "`ActiveSet`: projection onto the feasible set did not converge."
+                        ))
+                    }
+                    Err(QpError::Singular) => {
+                        return Err(argmin_error!(
+                            ConditionViolated,
+                            "`ActiveSet`: constraints are numerically linearly dependent."
+                        ))
+                    }
+                }
+            }
+        };
+
+        let gradient = q.dot(&param).scaled_add(&F::one(), &c);
+        let cost = float!(0.5) * (gradient.dot(&param) + c.dot(&param));
+        Ok((
+            state.param(param).cost(cost).gradient(gradient),
+            Some(kv!(
+                "feasible" => true;
+                "working_set_size" => self.working_set.len() as u64;
+            )),
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        _problem: &mut Problem<O>,
+        mut state: IterState<P, P, (), (), F>,
+    ) -> Result<(IterState<P, P, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`ActiveSet`: No parameter vector in state."
+        ))?;
+        let gradient = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`ActiveSet`: No gradient in state."
+        ))?;
+        let cost = state.get_cost();
+
+        let mut active = self.active.clone();
+        let (minimizer, lambda) =
+            self.face_minimizer(&active)
+                .ok_or_else(argmin_error_closure!(
+                    PotentialBug,
+                    "`ActiveSet`: Constraints in working set are linearly dependent."
+                ))?;
+        let mut multipliers = vec![F::zero(); self.constraints.len()];
+        for (&i, &l) in active.iter().zip(&lambda) {
+            multipliers[i] = l;
+        }
+
+        let step = minimizer.scaled_add(&(-F::one()), &param);
+        let step_norm = step.dot(&step).sqrt();
+        let param_norm = param.dot(&param).sqrt();
+        let mut converged = false;
+        let step_length = if step_norm <= self.tol * (F::one() + param_norm) {
+            // Minimizer on the current face: remove the constraint with the most negative
+            // multiplier from the working set, if any.
+            let max_multiplier = lambda.iter().fold(F::zero(), |acc, l| acc.max(l.abs()));
+            let most_negative = active
+                .iter()
+                .enumerate()
+                .filter(|(_, &i)| i >= self.num_eq)
+                .map(|(k, &i)| (k, multipliers[i]))
+                .fold(None, |best: Option<(usize, F)>, (k, l)| match best {
+                    Some((_, lb)) if lb <= l => best,
+                    _ => Some((k, l)),
+                });
+            match most_negative {
+                Some((k, l)) if l < -self.tol * (F::one() + max_multiplier) => {
+                    active.remove(k);
+                }
+                _ => converged = true,
+            }
+            F::one()
+        } else {
+            // Shorten the step if it violates a constraint outside of the working set
+            let blocking = (self.num_eq..self.constraints.len())
+                .filter(|i| !active.contains(i))
+                .filter_map(|i| {
+                    let (a, b) = &self.constraints[i];
+                    let b = (*b)?;
+                    let slope = a.dot(&step);
+                    if slope < F::zero() {
+                        Some((i, ((b - a.dot(&param)) / slope).max(F::zero())))
+                    } else {
+                        None
+                    }
+                })
+                .fold(None, |best: Option<(usize, F)>, (i, t)| match best {
+                    Some((_, tb)) if tb <= t => best,
+                    _ => Some((i, t)),
+                });
+            match blocking {
+                Some((i, t)) if t < F::one() => {
+                    active.push(i);
+                    t
+                }
+                _ => F::one(),
+            }
+        };
+
+        // Objective and gradient along the step, using `Q step = sum_i lambda_i a_i - gradient`
+        let q_step = self.active.iter().zip(&lambda).fold(
+            gradient.zero_like().scaled_add(&(-F::one()), &gradient),
+            |acc, (&i, l)| acc.scaled_add(l, &self.constraints[i].0),
+        );
+        let slope = gradient.dot(&step);
+        let curvature = q_step.dot(&step);
+        let new_cost =
+            cost + step_length * slope + float!(0.5) * step_length * step_length * curvature;
+        let new_gradient = gradient.scaled_add(&step_length, &q_step);
+        let new_param = param.scaled_add(&step_length, &step);
+        self.set_active(active);
+
+        let state = state
+            .param(new_param)
+            .cost(new_cost)
+            .gradient(new_gradient)
+            .lagrange_multipliers(multipliers);
+        let state = if converged {
+            state.terminate_with(TerminationReason::SolverConverged)
+        } else {
+            state
+        };
+        Ok((
+            state,
+            Some(kv!(
+                "step_length" => step_length;
+                "working_set_size" => self.working_set.len() as u64;
+            )),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Bounds, Executor, LinearConstraints};
+    use approx::assert_relative_eq;
+
+    /// Example 16.4 of Nocedal and Wright: minimize `(x1 - 1)^2 + (x2 - 2.5)^2` subject to three
+    /// inequality constraints and `x >= 0`. The solution is `(1.4, 1.7)`, where the first
+    /// inequality constraint is active with multiplier `0.8`.
+    struct Example {}
+
+    impl QuadraticProgram for Example {
+        type Param = Vec<f64>;
+        type Hessian = Vec<Vec<f64>>;
+        type Float = f64;
+
+        fn objective_matrix(&self) -> Result<Self::Hessian, Error> {
+            Ok(vec![vec![2.0, 0.0], vec![0.0, 2.0]])
+        }
+
+        fn objective_vector(&self) -> Result<Self::Param, Error> {
+            Ok(vec![-2.0, -5.0])
+        }
+
+        fn inequality_constraints(
+            &self,
+        ) -> Result<LinearConstraints<Self::Param, Self::Float>, Error> {
+            Ok((
+                vec![vec![-1.0, 2.0], vec![1.0, 2.0], vec![1.0, -2.0]],
+                vec![2.0, 6.0, 2.0],
+            ))
+        }
+
+        fn bounds(&self) -> Result<Bounds<Self::Param>, Error> {
+            Ok(Some((vec![0.0, 0.0], vec![f64::INFINITY, f64::INFINITY])))
+        }
+    }
+
+    /// Minimize `x1^2 + x2^2` subject to `x1 + x2 = 1` and `x1 <= b`
+    struct Equality {
+        b: f64,
+    }
+
+    impl QuadraticProgram for Equality {
+        type Param = Vec<f64>;
+        type Hessian = Vec<Vec<f64>>;
+        type Float = f64;
+
+        fn objective_matrix(&self) -> Result<Self::Hessian, Error> {
+            Ok(vec![vec![2.0, 0.0], vec![0.0, 2.0]])
+        }
+
+        fn objective_vector(&self) -> Result<Self::Param, Error> {
+            Ok(vec![0.0, 0.0])
+        }
+
+        fn equality_constraints(
+            &self,
+        ) -> Result<LinearConstraints<Self::Param, Self::Float>, Error> {
+            Ok((vec![vec![1.0, 1.0]], vec![1.0]))
+        }
+
+        fn inequality_constraints(
+            &self,
+        ) -> Result<LinearConstraints<Self::Param, Self::Float>, Error> {
+            Ok((vec![vec![1.0, 0.0]], vec![self.b]))
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let solver: ActiveSet<Vec<f64>, f64> = ActiveSet::new();
+        assert_eq!(solver.tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(solver.working_set().is_empty());
+        assert!(solver.constraints.is_empty());
+        assert!(solver.q_inv_c.is_none());
+    }
+
+    #[test]
+    fn test_with_tolerance() {
+        let solver: ActiveSet<Vec<f64>, f64> = ActiveSet::new().with_tolerance(1e-10).unwrap();
+        assert_eq!(solver.tol.to_ne_bytes(), 1e-10f64.to_ne_bytes());
+
+        for tol in [0.0, -1.0] {
+            let res: Result<ActiveSet<Vec<f64>, f64>, _> = ActiveSet::new().with_tolerance(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`ActiveSet`: tolerance must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_working_set_out_of_range() {
+        let mut solver: ActiveSet<Vec<f64>, f64> = ActiveSet::new().with_working_set(vec![7]);
+        let res = solver.init(&mut Problem::new(Example {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`ActiveSet`: index 7 of working set exceeds number of ",
+                "constraints.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        for param in [None, Some(vec![2.0, 0.0]), Some(vec![0.0, 0.0])] {
+            let res = Executor::new(Example {}, ActiveSet::new())
+                .configure(|state| {
+                    let state = state.max_iters(20);
+                    match param {
+                        Some(param) => state.param(param),
+                        None => state,
+                    }
+                })
+                .run()
+                .unwrap();
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::SolverConverged)
+            );
+            let x = res.state.get_param().unwrap();
+            assert_relative_eq!(x[0], 1.4, epsilon = 1e-10);
+            assert_relative_eq!(x[1], 1.7, epsilon = 1e-10);
+            assert_relative_eq!(res.state.get_cost(), 0.8 - 7.25, epsilon = 1e-10);
+            assert_eq!(res.solver.working_set(), &[0]);
+            let multipliers = res.state.get_lagrange_multipliers().unwrap();
+            assert_eq!(multipliers.len(), 7);
+            assert_relative_eq!(multipliers[0], 0.8, epsilon = 1e-10);
+            for m in &multipliers[1..] {
+                assert_relative_eq!(*m, 0.0, epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_warm_start() {
+        let res = Executor::new(Example {}, ActiveSet::new().with_working_set(vec![0]))
+            .configure(|state| state.max_iters(20))
+            .run()
+            .unwrap();
+        assert_eq!(res.state.get_iter(), 1);
+        let x = res.state.get_param().unwrap();
+        assert_relative_eq!(x[0], 1.4, epsilon = 1e-10);
+        assert_relative_eq!(x[1], 1.7, epsilon = 1e-10);
+
+        // infeasible warm start falls back to the projection
+        let res = Executor::new(Example {}, ActiveSet::new().with_working_set(vec![1, 3]))
+            .configure(|state| state.max_iters(20))
+            .run()
+            .unwrap();
+        let x = res.state.get_param().unwrap();
+        assert_relative_eq!(x[0], 1.4, epsilon = 1e-10);
+        assert_relative_eq!(x[1], 1.7, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_equality_constraints() {
+        let res = Executor::new(Equality { b: 1.0 }, ActiveSet::new())
+            .configure(|state| state.max_iters(20))
+            .run()
+            .unwrap();
+        let x = res.state.get_param().unwrap();
+        assert_relative_eq!(x[0], 0.5, epsilon = 1e-10);
+        assert_relative_eq!(x[1], 0.5, epsilon = 1e-10);
+        let multipliers = res.state.get_lagrange_multipliers().unwrap();
+        assert_relative_eq!(multipliers[0], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(multipliers[1], 0.0, epsilon = 1e-10);
+
+        // active inequality constraint: x = (0.2, 0.8), 2 x = lambda (1, 1) - mu (1, 0)
+        let res = Executor::new(Equality { b: 0.2 }, ActiveSet::new())
+            .configure(|state| state.max_iters(20))
+            .run()
+            .unwrap();
+        let x = res.state.get_param().unwrap();
+        assert_relative_eq!(x[0], 0.2, epsilon = 1e-10);
+        assert_relative_eq!(x[1], 0.8, epsilon = 1e-10);
+        let multipliers = res.state.get_lagrange_multipliers().unwrap();
+        assert_relative_eq!(multipliers[0], 1.6, epsilon = 1e-10);
+        assert_relative_eq!(multipliers[1], 1.2, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_coordinate() {
+        let v = vec![f64::NEG_INFINITY, 3.0, f64::MAX];
+        let basis = v.basis_like();
+        let ones = vec![1.0; 3];
+        let coords: Vec<f64> = basis.iter().map(|e| coordinate(&v, e, &ones)).collect();
+        assert_eq!(coords, v);
+    }
+
+    #[test]
+    fn test_infinite_bounds() {
+        /// Minimize `(x1 - 1)^2 + (x2 + 2)^2` subject to `x1 <= 0.5` and `-1 <= x2 <= f64::MAX`
+        struct HalfBounded {}
+
+        impl QuadraticProgram for HalfBounded {
+            type Param = Vec<f64>;
+            type Hessian = Vec<Vec<f64>>;
+            type Float = f64;
+
+            fn objective_matrix(&self) -> Result<Self::Hessian, Error> {
+                Ok(vec![vec![2.0, 0.0], vec![0.0, 2.0]])
+            }
+
+            fn objective_vector(&self) -> Result<Self::Param, Error> {
+                Ok(vec![-2.0, 4.0])
+            }
+
+            fn bounds(&self) -> Result<Bounds<Self::Param>, Error> {
+                Ok(Some((vec![f64::NEG_INFINITY, -1.0], vec![0.5, f64::MAX])))
+            }
+        }
+
+        let res = Executor::new(HalfBounded {}, ActiveSet::new())
+            .configure(|state| state.max_iters(20))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let bounds: Vec<Option<f64>> = res.solver.constraints.iter().map(|(_, b)| *b).collect();
+        assert_eq!(bounds, vec![None, Some(-1.0), Some(-0.5), Some(-f64::MAX)]);
+        let x = res.state.get_param().unwrap();
+        assert_relative_eq!(x[0], 0.5, epsilon = 1e-10);
+        assert_relative_eq!(x[1], -1.0, epsilon = 1e-10);
+        let multipliers = res.state.get_lagrange_multipliers().unwrap();
+        assert_eq!(multipliers.len(), 4);
+        assert_relative_eq!(multipliers[0], 0.0, epsilon = 1e-10);
+        assert_relative_eq!(multipliers[1], 2.0, epsilon = 1e-10);
+        assert_relative_eq!(multipliers[2], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(multipliers[3], 0.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_infeasible() {
+        struct Infeasible {}
+
+        impl QuadraticProgram for Infeasible {
+            type Param = Vec<f64>;
+            type Hessian = Vec<Vec<f64>>;
+            type Float = f64;
+
+            fn objective_matrix(&self) -> Result<Self::Hessian, Error> {
+                Ok(vec![vec![1.0]])
+            }
+
+            fn objective_vector(&self) -> Result<Self::Param, Error> {
+                Ok(vec![0.0])
+            }
+
+            fn inequality_constraints(
+                &self,
+            ) -> Result<LinearConstraints<Self::Param, Self::Float>, Error> {
+                Ok((vec![vec![1.0]], vec![-1.0]))
+            }
+
+            fn bounds(&self) -> Result<Bounds<Self::Param>, Error> {
+                Ok(Some((vec![0.0], vec![1.0])))
+            }
+        }
+
+        let res = Executor::new(Infeasible {}, ActiveSet::new())
+            .configure(|state| state.max_iters(20))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverExit(
+                "Infeasible constraints".to_string()
+            ))
+        );
+        assert_eq!(res.state.get_iter(), 0);
+    }
+}
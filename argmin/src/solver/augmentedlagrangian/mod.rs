@@ -0,0 +1,1049 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Augmented Lagrangian method
+//!
+//! Solves equality and inequality constrained problems by a sequence of unconstrained
+//! subproblems, each of which is solved by an existing unconstrained solver such as
+//! [`LBFGS`](`crate::solver::quasinewton::LBFGS`), [`BFGS`](`crate::solver::quasinewton::BFGS`)
+//! or [`NewtonCG`](`crate::solver::newton::NewtonCG`).
+//!
+//! For details see [`AugmentedLagrangian`].
+//!
+//! ## References
+//!
+//! \[0\] Andreani, R., Birgin, E.G., Martínez, J.M., Schuverdt, M.L. (2008): On Augmented
+//! Lagrangian Methods with General Lower-Level Constraints. SIAM Journal on Optimization 18(4),
+//! 1286-1309. <https://doi.org/10.1137/060654797>
+//!
+//! \[1\] Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+//! Springer. ISBN 0-387-30303-0.
+
+use crate::core::{
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, EqualityConstraint,
+    EqualityConstraintJacobian, Error, Executor, Gradient, Hessian, InequalityConstraint,
+    InequalityConstraintJacobian, IterState, OptimizationResult, Problem, SerializeAlias, Solver,
+    State, TerminationReason, KV,
+};
+use argmin_math::{ArgminL2Norm, ArgminOuter, ArgminScaledAdd};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Augmented Lagrangian method
+///
+/// Minimizes `f(x)` subject to equality constraints `h(x) = 0` and inequality constraints
+/// `g(x) >= 0` in the style of ALGENCAN \[0\]. In each outer iteration, the augmented Lagrangian
+///
+/// `L(x) = f(x) + rho/2 * sum_i (h_i(x) - lambda_i/rho)^2
+///        + rho/2 * sum_j max(0, mu_j/rho - g_j(x))^2`
+///
+/// (up to terms which do not depend on `x`) is minimized by an inner solver, starting from the
+/// current parameter vector. The inner solver is created anew in every outer iteration by the
+/// factory passed to [`AugmentedLagrangian::new`], and operates on an
+/// [`AugmentedLagrangianProblem`]. Afterwards, the multipliers are updated via
+/// `lambda_i <- lambda_i - rho h_i(x)` and `mu_j <- max(0, mu_j - rho g_j(x))` and safeguarded by
+/// projecting them onto a bounded box. The penalty parameter `rho` is increased whenever the
+/// infeasibility and complementarity measure `max(max_i |h_i(x)|, max_j |min(g_j(x), mu_j/rho)|)`
+/// did not decrease sufficiently.
+///
+/// In each outer iteration, the KKT residuals at the new parameter vector are reported:
+/// `stationarity` (norm of the gradient of the Lagrangian), `feasibility` (greatest constraint
+/// violation) and `complementarity` (`max_j |min(g_j(x), mu_j)|`). The algorithm terminates once
+/// stationarity is below the optimality tolerance and feasibility and complementarity are below
+/// the feasibility tolerance (see [`with_tolerances`](`AugmentedLagrangian::with_tolerances`)).
+/// The multipliers of the equality constraints followed by those of the inequality constraints
+/// are stored in the state (see [`IterState::get_lagrange_multipliers`]).
+///
+/// Each inner solver run starts from the current parameter vector. A Hessian or inverse Hessian
+/// provided to the [`Executor`] as initial guess is passed on to every inner solver run, which
+/// is required for solvers such as [`BFGS`](`crate::solver::quasinewton::BFGS`).
+///
+/// The number of iterations of the inner solver is limited per outer iteration (see
+/// [`with_inner_max_iters`](`AugmentedLagrangian::with_inner_max_iters`)). The accuracy of the
+/// subproblem solutions is determined by the termination criteria of the inner solver.
+///
+/// Requires an initial parameter vector.
+///
+/// The factory is not serialized. Deserializing an [`AugmentedLagrangian`] therefore requires
+/// the factory to implement [`Default`], which closures and function items do not; checkpointing
+/// is not supported in this case.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`], [`Gradient`],
+/// [`EqualityConstraint`], [`InequalityConstraint`], [`EqualityConstraintJacobian`] and
+/// [`InequalityConstraintJacobian`]. Constraint values are returned as `Vec<F>` and Jacobians as
+/// `Vec<P>`, holding the gradient of each constraint. For problems without equality or inequality
+/// constraints, the corresponding traits can be implemented without any methods, which yields
+/// empty vectors. If the inner solver requires second derivatives, the
+/// problem additionally needs to implement [`Hessian`] (see [`AugmentedLagrangianProblem`]).
+///
+/// ## References
+///
+/// \[0\] Andreani, R., Birgin, E.G., Martínez, J.M., Schuverdt, M.L. (2008): On Augmented
+/// Lagrangian Methods with General Lower-Level Constraints. SIAM Journal on Optimization 18(4),
+/// 1286-1309. <https://doi.org/10.1137/060654797>
+///
+/// \[1\] Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct AugmentedLagrangian<B, F> {
+    /// Creates the inner solver
+    #[cfg_attr(feature = "serde1", serde(skip))]
+    factory: B,
+    /// Penalty parameter
+    penalty: F,
+    /// Factor by which the penalty parameter is increased
+    penalty_increase: F,
+    /// Required relative decrease of the infeasibility measure
+    sufficient_decrease: F,
+    /// Bound on the magnitude of the multipliers
+    max_multiplier: F,
+    /// Tolerance on stationarity
+    optimality_tol: F,
+    /// Tolerance on feasibility and complementarity
+    feasibility_tol: F,
+    /// Maximum number of iterations of the inner solver per outer iteration
+    inner_max_iters: u64,
+    /// Multipliers of the equality constraints
+    equality_multipliers: Vec<F>,
+    /// Multipliers of the inequality constraints
+    inequality_multipliers: Vec<F>,
+    /// Infeasibility measure of the previous outer iteration
+    infeasibility: F,
+}
+
+impl<B, F> AugmentedLagrangian<B, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`AugmentedLagrangian`]
+    ///
+    /// `factory` creates the inner solver for each outer iteration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::augmentedlagrangian::AugmentedLagrangian;
+    /// # use argmin::solver::linesearch::MoreThuenteLineSearch;
+    /// # use argmin::solver::quasinewton::LBFGS;
+    /// type Inner = LBFGS<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, Vec<f64>, Vec<f64>, f64>;
+    /// let solver: AugmentedLagrangian<_, f64> =
+    ///     AugmentedLagrangian::new(|| Inner::new(MoreThuenteLineSearch::new(), 7));
+    /// ```
+    pub fn new(factory: B) -> Self {
+        AugmentedLagrangian {
+            factory,
+            penalty: float!(10.0),
+            penalty_increase: float!(10.0),
+            sufficient_decrease: float!(0.5),
+            max_multiplier: float!(1e20),
+            optimality_tol: float!(1e-6),
+            feasibility_tol: float!(1e-8),
+            inner_max_iters: 1000,
+            equality_multipliers: vec![],
+            inequality_multipliers: vec![],
+            infeasibility: F::infinity(),
+        }
+    }
+
+    /// Set the initial penalty parameter
+    ///
+    /// Must be positive. Defaults to `10`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::augmentedlagrangian::AugmentedLagrangian;
+    /// # use argmin::solver::linesearch::MoreThuenteLineSearch;
+    /// # use argmin::solver::quasinewton::LBFGS;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # type Inner =
+    /// #     LBFGS<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, Vec<f64>, Vec<f64>, f64>;
+    /// # let factory = || Inner::new(MoreThuenteLineSearch::new(), 7);
+    /// let solver: AugmentedLagrangian<_, f64> =
+    ///     AugmentedLagrangian::new(factory).with_penalty(100.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_penalty(mut self, penalty: F) -> Result<Self, Error> {
+        if penalty <= F::zero() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`AugmentedLagrangian`: penalty parameter must be > 0."
+            ));
+        }
+        self.penalty = penalty;
+        Ok(self)
+    }
+
+    /// Set the factor by which the penalty parameter is increased and the relative decrease of
+    /// the infeasibility measure below which it is increased
+    ///
+    /// The factor must be larger than 1 and the relative decrease in `(0, 1)`. Default to `10`
+    /// and `0.5`, respectively.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::augmentedlagrangian::AugmentedLagrangian;
+    /// # use argmin::solver::linesearch::MoreThuenteLineSearch;
+    /// # use argmin::solver::quasinewton::LBFGS;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # type Inner =
+    /// #     LBFGS<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, Vec<f64>, Vec<f64>, f64>;
+    /// # let factory = || Inner::new(MoreThuenteLineSearch::new(), 7);
+    /// let solver: AugmentedLagrangian<_, f64> =
+    ///     AugmentedLagrangian::new(factory).with_penalty_update(5.0, 0.25)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_penalty_update(
+        mut self,
+        increase: F,
+        sufficient_decrease: F,
+    ) -> Result<Self, Error> {
+        if increase <= F::one() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`AugmentedLagrangian`: penalty increase factor must be > 1."
+            ));
+        }
+        if sufficient_decrease <= F::zero() || sufficient_decrease >= F::one() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`AugmentedLagrangian`: sufficient decrease must be in (0, 1)."
+            ));
+        }
+        self.penalty_increase = increase;
+        self.sufficient_decrease = sufficient_decrease;
+        Ok(self)
+    }
+
+    /// Set the bound on the magnitude of the multipliers
+    ///
+    /// Updated multipliers are projected onto `[-max, max]` (equality constraints) and `[0, max]`
+    /// (inequality constraints). Must be positive. Defaults to `1e20`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::augmentedlagrangian::AugmentedLagrangian;
+    /// # use argmin::solver::linesearch::MoreThuenteLineSearch;
+    /// # use argmin::solver::quasinewton::LBFGS;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # type Inner =
+    /// #     LBFGS<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, Vec<f64>, Vec<f64>, f64>;
+    /// # let factory = || Inner::new(MoreThuenteLineSearch::new(), 7);
+    /// let solver: AugmentedLagrangian<_, f64> =
+    ///     AugmentedLagrangian::new(factory).with_max_multiplier(1e6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_max_multiplier(mut self, max: F) -> Result<Self, Error> {
+        if max <= F::zero() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`AugmentedLagrangian`: maximum multiplier must be > 0."
+            ));
+        }
+        self.max_multiplier = max;
+        Ok(self)
+    }
+
+    /// Set the tolerances on stationarity and on feasibility and complementarity
+    ///
+    /// Both must be non-negative. Default to `1e-6` and `1e-8`, respectively.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::augmentedlagrangian::AugmentedLagrangian;
+    /// # use argmin::solver::linesearch::MoreThuenteLineSearch;
+    /// # use argmin::solver::quasinewton::LBFGS;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # type Inner =
+    /// #     LBFGS<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, Vec<f64>, Vec<f64>, f64>;
+    /// # let factory = || Inner::new(MoreThuenteLineSearch::new(), 7);
+    /// let solver: AugmentedLagrangian<_, f64> =
+    ///     AugmentedLagrangian::new(factory).with_tolerances(1e-5, 1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerances(mut self, optimality: F, feasibility: F) -> Result<Self, Error> {
+        if optimality < F::zero() || feasibility < F::zero() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`AugmentedLagrangian`: tolerances must be >= 0."
+            ));
+        }
+        self.optimality_tol = optimality;
+        self.feasibility_tol = feasibility;
+        Ok(self)
+    }
+
+    /// Set the maximum number of iterations of the inner solver per outer iteration
+    ///
+    /// Defaults to `1000`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::augmentedlagrangian::AugmentedLagrangian;
+    /// # use argmin::solver::linesearch::MoreThuenteLineSearch;
+    /// # use argmin::solver::quasinewton::LBFGS;
+    /// # type Inner =
+    /// #     LBFGS<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, Vec<f64>, Vec<f64>, f64>;
+    /// # let factory = || Inner::new(MoreThuenteLineSearch::new(), 7);
+    /// let solver: AugmentedLagrangian<_, f64> =
+    ///     AugmentedLagrangian::new(factory).with_inner_max_iters(200);
+    /// ```
+    #[must_use]
+    pub fn with_inner_max_iters(mut self, max_iters: u64) -> Self {
+        self.inner_max_iters = max_iters;
+        self
+    }
+
+    /// Updates the multipliers from the constraint values and returns the infeasibility measure
+    fn update_multipliers(&mut self, equality: &[F], inequality: &[F]) -> F {
+        let penalty = self.penalty;
+        let max = self.max_multiplier;
+        let mut infeasibility = F::zero();
+        for (lambda, &h) in self.equality_multipliers.iter_mut().zip(equality) {
+            *lambda = (*lambda - penalty * h).max(-max).min(max);
+            infeasibility = infeasibility.max(h.abs());
+        }
+        for (mu, &g) in self.inequality_multipliers.iter_mut().zip(inequality) {
+            infeasibility = infeasibility.max(g.min(*mu / penalty).abs());
+            *mu = (*mu - penalty * g).max(F::zero()).min(max);
+        }
+        infeasibility
+    }
+}
+
+/// Counts of the evaluations of the optimization problem caused by one evaluation of the
+/// augmented Lagrangian, its gradient and its Hessian, respectively
+const EVALUATIONS: [(&str, [u64; 3]); 7] = [
+    ("cost_count", [1, 0, 0]),
+    ("gradient_count", [0, 1, 0]),
+    ("hessian_count", [0, 0, 1]),
+    ("equality_constraint_count", [1, 1, 0]),
+    ("inequality_constraint_count", [1, 1, 1]),
+    ("equality_constraint_jacobian_count", [0, 1, 1]),
+    ("inequality_constraint_jacobian_count", [0, 1, 1]),
+];
+
+impl<O, B, S, P, G, H, F> Solver<O, IterState<P, G, (), H, F>> for AugmentedLagrangian<B, F>
+where
+    O: CostFunction<Param = P, Output = F>
+        + Gradient<Param = P, Gradient = P>
+        + EqualityConstraint<Param = P, Output = Vec<F>>
+        + InequalityConstraint<Param = P, Output = Vec<F>>
+        + EqualityConstraintJacobian<Param = P, Jacobian = Vec<P>>
+        + InequalityConstraintJacobian<Param = P, Jacobian = Vec<P>>,
+    B: Fn() -> S,
+    S: Solver<AugmentedLagrangianProblem<O, F>, IterState<P, G, (), H, F>>,
+    P: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminScaledAdd<P, F, P> + ArgminL2Norm<F>,
+    G: Clone + SerializeAlias + DeserializeOwnedAlias,
+    H: Clone + SerializeAlias + DeserializeOwnedAlias,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Augmented Lagrangian";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, (), H, F>,
+    ) -> Result<(IterState<P, G, (), H, F>, Option<KV>), Error> {
+        let param = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`AugmentedLagrangian` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let cost = problem.cost(param)?;
+        self.equality_multipliers = vec![F::zero(); problem.equality_constraint(param)?.len()];
+        self.inequality_multipliers = vec![F::zero(); problem.inequality_constraint(param)?.len()];
+        self.infeasibility = F::infinity();
+        Ok((state.cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), H, F>,
+    ) -> Result<(IterState<P, G, (), H, F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`AugmentedLagrangian` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        // Minimize the augmented Lagrangian
+        let subproblem = AugmentedLagrangianProblem {
+            problem: problem.take_problem().ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`AugmentedLagrangian`: Failed to take `problem` for inner solver"
+            ))?,
+            equality_multipliers: self.equality_multipliers.clone(),
+            inequality_multipliers: self.inequality_multipliers.clone(),
+            penalty: self.penalty,
+        };
+        let max_iters = self.inner_max_iters;
+        let hessian = state.get_hessian().cloned();
+        let inv_hessian = state.get_inv_hessian().cloned();
+        let OptimizationResult {
+            problem: mut inner_problem,
+            state: mut inner_state,
+            ..
+        } = Executor::new(subproblem, (self.factory)())
+            .configure(|config| {
+                let config = config.param(param).max_iters(max_iters);
+                let config = match hessian {
+                    Some(hessian) => config.hessian(hessian),
+                    None => config,
+                };
+                match inv_hessian {
+                    Some(inv_hessian) => config.inv_hessian(inv_hessian),
+                    None => config,
+                }
+            })
+            .ctrlc(false)
+            .run()?;
+
+        problem.problem = Some(
+            inner_problem
+                .take_problem()
+                .ok_or_else(argmin_error_closure!(
+                    PotentialBug,
+                    "`AugmentedLagrangian`: Failed to take `problem` from inner solver"
+                ))?
+                .problem,
+        );
+        let calls = [
+            inner_problem.counts.get("cost_count"),
+            inner_problem.counts.get("gradient_count"),
+            inner_problem.counts.get("hessian_count"),
+        ]
+        .map(|count| count.copied().unwrap_or(0));
+        for (name, evaluations) in EVALUATIONS {
+            let count: u64 = calls.iter().zip(evaluations).map(|(c, e)| c * e).sum();
+            if count > 0 {
+                *problem.counts.entry(name).or_insert(0) += count;
+            }
+        }
+
+        let inner_iters = inner_state.get_iter();
+        let param = inner_state
+            .take_best_param()
+            .or_else(|| inner_state.take_param())
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`AugmentedLagrangian`: Failed to take `param` from inner solver state"
+            ))?;
+
+        // Update multipliers and penalty parameter
+        let cost = problem.cost(&param)?;
+        let equality = problem.equality_constraint(&param)?;
+        let inequality = problem.inequality_constraint(&param)?;
+        let infeasibility = self.update_multipliers(&equality, &inequality);
+        if infeasibility > self.sufficient_decrease * self.infeasibility {
+            self.penalty = self.penalty * self.penalty_increase;
+        }
+        self.infeasibility = infeasibility;
+
+        // KKT residuals
+        let gradient = problem.gradient(&param)?;
+        let equality_jacobian = problem.equality_constraint_jacobian(&param)?;
+        let inequality_jacobian = problem.inequality_constraint_jacobian(&param)?;
+        let lagrangian_gradient = equality_jacobian
+            .iter()
+            .zip(&self.equality_multipliers)
+            .chain(inequality_jacobian.iter().zip(&self.inequality_multipliers))
+            .fold(gradient, |acc, (grad, &multiplier)| {
+                acc.scaled_add(&-multiplier, grad)
+            });
+        let stationarity = lagrangian_gradient.l2_norm();
+        let feasibility = equality
+            .iter()
+            .map(|h| h.abs())
+            .chain(inequality.iter().map(|&g| -g))
+            .fold(F::zero(), |acc, v| acc.max(v));
+        let complementarity = inequality
+            .iter()
+            .zip(&self.inequality_multipliers)
+            .fold(F::zero(), |acc, (&g, &mu)| acc.max(g.min(mu).abs()));
+
+        let multipliers = self
+            .equality_multipliers
+            .iter()
+            .chain(&self.inequality_multipliers)
+            .cloned()
+            .collect();
+        let state = state
+            .param(param)
+            .cost(cost)
+            .lagrange_multipliers(multipliers);
+        let state = if stationarity <= self.optimality_tol
+            && feasibility <= self.feasibility_tol
+            && complementarity <= self.feasibility_tol
+        {
+            state.terminate_with(TerminationReason::SolverConverged)
+        } else {
+            state
+        };
+        Ok((
+            state,
+            Some(kv!(
+                "penalty" => self.penalty;
+                "stationarity" => stationarity;
+                "feasibility" => feasibility;
+                "complementarity" => complementarity;
+                "inner_iters" => inner_iters;
+            )),
+        ))
+    }
+}
+
+/// Unconstrained subproblem of the [`AugmentedLagrangian`] method
+///
+/// Wraps the constrained optimization problem and implements [`CostFunction`] and [`Gradient`]
+/// for the augmented Lagrangian
+///
+/// `L(x) = f(x) - sum_i lambda_i h_i(x) + rho/2 * sum_i h_i(x)^2
+///        + 1/(2 rho) * sum_j (max(0, mu_j - rho g_j(x))^2 - mu_j^2)`
+///
+/// for fixed multipliers and penalty parameter. If the problem implements [`Hessian`], it
+/// also implements [`Hessian`] via the approximation
+///
+/// `H(x) = hess f(x) + rho * sum_i grad h_i(x) grad h_i(x)^T
+///        + rho * sum_{j: mu_j - rho g_j(x) > 0} grad g_j(x) grad g_j(x)^T`,
+///
+/// which neglects the second derivatives of the constraints.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct AugmentedLagrangianProblem<O, F> {
+    /// Constrained optimization problem
+    problem: O,
+    /// Multipliers of the equality constraints
+    equality_multipliers: Vec<F>,
+    /// Multipliers of the inequality constraints
+    inequality_multipliers: Vec<F>,
+    /// Penalty parameter
+    penalty: F,
+}
+
+impl<O, F: ArgminFloat> AugmentedLagrangianProblem<O, F> {
+    /// Weights of the gradients of the equality and inequality constraints in the gradient of
+    /// the augmented Lagrangian
+    fn weights(&self, equality: &[F], inequality: &[F]) -> (Vec<F>, Vec<F>) {
+        (
+            equality
+                .iter()
+                .zip(&self.equality_multipliers)
+                .map(|(&h, &lambda)| lambda - self.penalty * h)
+                .collect(),
+            inequality
+                .iter()
+                .zip(&self.inequality_multipliers)
+                .map(|(&g, &mu)| (mu - self.penalty * g).max(F::zero()))
+                .collect(),
+        )
+    }
+}
+
+impl<O, P, F> CostFunction for AugmentedLagrangianProblem<O, F>
+where
+    O: CostFunction<Param = P, Output = F>
+        + EqualityConstraint<Param = P, Output = Vec<F>>
+        + InequalityConstraint<Param = P, Output = Vec<F>>,
+    F: ArgminFloat,
+{
+    type Param = P;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        let rho = self.penalty;
+        let half = float!(0.5);
+        let equality = self
+            .problem
+            .equality_constraint(param)?
+            .iter()
+            .zip(&self.equality_multipliers)
+            .fold(F::zero(), |acc, (&h, &lambda)| {
+                acc - lambda * h + half * rho * h * h
+            });
+        let inequality = self
+            .problem
+            .inequality_constraint(param)?
+            .iter()
+            .zip(&self.inequality_multipliers)
+            .fold(F::zero(), |acc, (&g, &mu)| {
+                let shifted = (mu - rho * g).max(F::zero());
+                acc + half * (shifted * shifted - mu * mu) / rho
+            });
+        Ok(self.problem.cost(param)? + equality + inequality)
+    }
+}
+
+impl<O, P, F> Gradient for AugmentedLagrangianProblem<O, F>
+where
+    O: Gradient<Param = P, Gradient = P>
+        + EqualityConstraint<Param = P, Output = Vec<F>>
+        + InequalityConstraint<Param = P, Output = Vec<F>>
+        + EqualityConstraintJacobian<Param = P, Jacobian = Vec<P>>
+        + InequalityConstraintJacobian<Param = P, Jacobian = Vec<P>>,
+    P: ArgminScaledAdd<P, F, P>,
+    F: ArgminFloat,
+{
+    type Param = P;
+    type Gradient = P;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        let (equality_weights, inequality_weights) = self.weights(
+            &self.problem.equality_constraint(param)?,
+            &self.problem.inequality_constraint(param)?,
+        );
+        let equality_jacobian = self.problem.equality_constraint_jacobian(param)?;
+        let inequality_jacobian = self.problem.inequality_constraint_jacobian(param)?;
+        Ok(equality_jacobian
+            .iter()
+            .zip(&equality_weights)
+            .chain(inequality_jacobian.iter().zip(&inequality_weights))
+            .fold(self.problem.gradient(param)?, |acc, (grad, &weight)| {
+                acc.scaled_add(&-weight, grad)
+            }))
+    }
+}
+
+impl<O, P, H, F> Hessian for AugmentedLagrangianProblem<O, F>
+where
+    O: Hessian<Param = P, Hessian = H>
+        + InequalityConstraint<Param = P, Output = Vec<F>>
+        + EqualityConstraintJacobian<Param = P, Jacobian = Vec<P>>
+        + InequalityConstraintJacobian<Param = P, Jacobian = Vec<P>>,
+    P: ArgminOuter<P, H>,
+    H: ArgminScaledAdd<H, F, H>,
+    F: ArgminFloat,
+{
+    type Param = P;
+    type Hessian = H;
+
+    fn hessian(&self, param: &Self::Param) -> Result<Self::Hessian, Error> {
+        let rho = self.penalty;
+        let inequality = self.problem.inequality_constraint(param)?;
+        let active = inequality
+            .iter()
+            .zip(&self.inequality_multipliers)
+            .map(|(&g, &mu)| mu - rho * g > F::zero());
+        let equality_jacobian = self.problem.equality_constraint_jacobian(param)?;
+        let inequality_jacobian = self.problem.inequality_constraint_jacobian(param)?;
+        Ok(equality_jacobian
+            .iter()
+            .chain(
+                inequality_jacobian
+                    .iter()
+                    .zip(active)
+                    .filter_map(|(grad, active)| active.then_some(grad)),
+            )
+            .fold(self.problem.hessian(param)?, |acc, grad| {
+                acc.scaled_add(&rho, &grad.outer(grad))
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use crate::solver::newton::NewtonCG;
+    use crate::solver::quasinewton::{BFGS, LBFGS};
+    use approx::assert_relative_eq;
+
+    /// Minimize `(x - 2)^2 + (y - 1)^2` subject to `x = 2y` and `x + y <= 2`. The solution is
+    /// `(4/3, 2/3)` with multipliers `lambda = -2/9` and `mu = 10/9`.
+    #[derive(Clone)]
+    struct Problem1 {}
+
+    impl CostFunction for Problem1 {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0] - 2.0).powi(2) + (p[1] - 1.0).powi(2))
+        }
+    }
+
+    impl Gradient for Problem1 {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![2.0 * (p[0] - 2.0), 2.0 * (p[1] - 1.0)])
+        }
+    }
+
+    impl Hessian for Problem1 {
+        type Param = Vec<f64>;
+        type Hessian = Vec<Vec<f64>>;
+
+        fn hessian(&self, _p: &Self::Param) -> Result<Self::Hessian, Error> {
+            Ok(vec![vec![2.0, 0.0], vec![0.0, 2.0]])
+        }
+    }
+
+    impl EqualityConstraint for Problem1 {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn equality_constraint(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![p[0] - 2.0 * p[1]])
+        }
+    }
+
+    impl EqualityConstraintJacobian for Problem1 {
+        type Param = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn equality_constraint_jacobian(&self, _p: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(vec![vec![1.0, -2.0]])
+        }
+    }
+
+    impl InequalityConstraint for Problem1 {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn inequality_constraint(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![2.0 - p[0] - p[1]])
+        }
+    }
+
+    impl InequalityConstraintJacobian for Problem1 {
+        type Param = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn inequality_constraint_jacobian(
+            &self,
+            _p: &Self::Param,
+        ) -> Result<Self::Jacobian, Error> {
+            Ok(vec![vec![-1.0, -1.0]])
+        }
+    }
+
+    /// Rosenbrock function subject to `x^2 + y^2 <= 1` only. The constraint is active at the
+    /// solution.
+    struct Problem2 {}
+
+    impl CostFunction for Problem2 {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((1.0 - p[0]).powi(2) + 100.0 * (p[1] - p[0].powi(2)).powi(2))
+        }
+    }
+
+    impl Gradient for Problem2 {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![
+                -2.0 * (1.0 - p[0]) - 400.0 * p[0] * (p[1] - p[0].powi(2)),
+                200.0 * (p[1] - p[0].powi(2)),
+            ])
+        }
+    }
+
+    impl EqualityConstraint for Problem2 {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+    }
+
+    impl EqualityConstraintJacobian for Problem2 {
+        type Param = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+    }
+
+    impl InequalityConstraint for Problem2 {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn inequality_constraint(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![1.0 - p[0].powi(2) - p[1].powi(2)])
+        }
+    }
+
+    impl InequalityConstraintJacobian for Problem2 {
+        type Param = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn inequality_constraint_jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(vec![vec![-2.0 * p[0], -2.0 * p[1]]])
+        }
+    }
+
+    type Inner = LBFGS<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, Vec<f64>, Vec<f64>, f64>;
+
+    fn lbfgs() -> Inner {
+        LBFGS::new(MoreThuenteLineSearch::new(), 7)
+            .with_tolerance_grad(1e-10)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let solver: AugmentedLagrangian<_, f64> = AugmentedLagrangian::new(lbfgs);
+        assert_eq!(solver.penalty.to_ne_bytes(), 10.0f64.to_ne_bytes());
+        assert_eq!(solver.penalty_increase.to_ne_bytes(), 10.0f64.to_ne_bytes());
+        assert_eq!(
+            solver.sufficient_decrease.to_ne_bytes(),
+            0.5f64.to_ne_bytes()
+        );
+        assert_eq!(solver.max_multiplier.to_ne_bytes(), 1e20f64.to_ne_bytes());
+        assert_eq!(solver.optimality_tol.to_ne_bytes(), 1e-6f64.to_ne_bytes());
+        assert_eq!(solver.feasibility_tol.to_ne_bytes(), 1e-8f64.to_ne_bytes());
+        assert_eq!(solver.inner_max_iters, 1000);
+    }
+
+    #[test]
+    fn test_builders() {
+        let solver: AugmentedLagrangian<_, f64> = AugmentedLagrangian::new(lbfgs)
+            .with_penalty(2.0)
+            .unwrap()
+            .with_penalty_update(4.0, 0.1)
+            .unwrap()
+            .with_max_multiplier(100.0)
+            .unwrap()
+            .with_tolerances(1e-4, 1e-5)
+            .unwrap()
+            .with_inner_max_iters(10);
+        assert_eq!(solver.penalty.to_ne_bytes(), 2.0f64.to_ne_bytes());
+        assert_eq!(solver.penalty_increase.to_ne_bytes(), 4.0f64.to_ne_bytes());
+        assert_eq!(
+            solver.sufficient_decrease.to_ne_bytes(),
+            0.1f64.to_ne_bytes()
+        );
+        assert_eq!(solver.max_multiplier.to_ne_bytes(), 100.0f64.to_ne_bytes());
+        assert_eq!(solver.optimality_tol.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_eq!(solver.feasibility_tol.to_ne_bytes(), 1e-5f64.to_ne_bytes());
+        assert_eq!(solver.inner_max_iters, 10);
+    }
+
+    #[test]
+    fn test_builder_errors() {
+        let res: Result<AugmentedLagrangian<_, f64>, _> =
+            AugmentedLagrangian::new(lbfgs).with_penalty(0.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`AugmentedLagrangian`: penalty parameter must be > 0.\""
+        );
+        let res: Result<AugmentedLagrangian<_, f64>, _> =
+            AugmentedLagrangian::new(lbfgs).with_penalty_update(1.0, 0.5);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`AugmentedLagrangian`: penalty increase factor must be > 1.\""
+        );
+        for decrease in [0.0, 1.0] {
+            let res: Result<AugmentedLagrangian<_, f64>, _> =
+                AugmentedLagrangian::new(lbfgs).with_penalty_update(10.0, decrease);
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`AugmentedLagrangian`: ",
+                    "sufficient decrease must be in (0, 1).\""
+                )
+            );
+        }
+        let res: Result<AugmentedLagrangian<_, f64>, _> =
+            AugmentedLagrangian::new(lbfgs).with_max_multiplier(0.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`AugmentedLagrangian`: maximum multiplier must be > 0.\""
+        );
+        let res: Result<AugmentedLagrangian<_, f64>, _> =
+            AugmentedLagrangian::new(lbfgs).with_tolerances(-1.0, 0.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`AugmentedLagrangian`: tolerances must be >= 0.\""
+        );
+    }
+
+    #[test]
+    fn test_init_param_not_initialized() {
+        let mut solver: AugmentedLagrangian<_, f64> = AugmentedLagrangian::new(lbfgs);
+        let res = solver.init(&mut Problem::new(Problem1 {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`AugmentedLagrangian` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_update_multipliers() {
+        let mut solver: AugmentedLagrangian<_, f64> = AugmentedLagrangian::new(lbfgs)
+            .with_max_multiplier(5.0)
+            .unwrap();
+        solver.equality_multipliers = vec![1.0, 0.0];
+        solver.inequality_multipliers = vec![1.0, 0.0, 2.0];
+        let infeasibility = solver.update_multipliers(&[0.05, 1.0], &[0.2, -0.1, 0.0]);
+        assert_relative_eq!(solver.equality_multipliers[0], 0.5, epsilon = 1e-12);
+        // clamped to the bound
+        assert_relative_eq!(solver.equality_multipliers[1], -5.0, epsilon = 1e-12);
+        assert_relative_eq!(solver.inequality_multipliers[0], 0.0, epsilon = 1e-12);
+        assert_relative_eq!(solver.inequality_multipliers[1], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(solver.inequality_multipliers[2], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(infeasibility, 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_subproblem() {
+        let subproblem = AugmentedLagrangianProblem {
+            problem: Problem1 {},
+            equality_multipliers: vec![0.5],
+            inequality_multipliers: vec![1.0],
+            penalty: 2.0,
+        };
+        // h = 1 - 0 = 1, g = 2 - 1 - 0 = 1 (inactive: mu - rho g < 0)
+        let x = vec![1.0, 0.0];
+        let cost = subproblem.cost(&x).unwrap();
+        assert_relative_eq!(cost, 2.0 - 0.5 + 1.0 - 0.25, epsilon = 1e-12);
+        let gradient = subproblem.gradient(&x).unwrap();
+        // grad f = (-2, -2), h weight = 0.5 - 2 = -1.5
+        assert_relative_eq!(gradient[0], -2.0 + 1.5, epsilon = 1e-12);
+        assert_relative_eq!(gradient[1], -2.0 - 3.0, epsilon = 1e-12);
+        let hessian = subproblem.hessian(&x).unwrap();
+        assert_relative_eq!(hessian[0][0], 2.0 + 2.0, epsilon = 1e-12);
+        assert_relative_eq!(hessian[0][1], -4.0, epsilon = 1e-12);
+        assert_relative_eq!(hessian[1][1], 2.0 + 8.0, epsilon = 1e-12);
+
+        // g = 2 - 1 - 1 = 0 (active)
+        let x = vec![1.0, 1.0];
+        let gradient = subproblem.gradient(&x).unwrap();
+        // grad f = (-2, 0), h = -1, h weight = 0.5 + 2 = 2.5, g weight = 1
+        assert_relative_eq!(gradient[0], -2.0 - 2.5 + 1.0, epsilon = 1e-12);
+        assert_relative_eq!(gradient[1], 5.0 + 1.0, epsilon = 1e-12);
+        let hessian = subproblem.hessian(&x).unwrap();
+        assert_relative_eq!(hessian[0][0], 2.0 + 2.0 + 2.0, epsilon = 1e-12);
+        assert_relative_eq!(hessian[0][1], -4.0 + 2.0, epsilon = 1e-12);
+        assert_relative_eq!(hessian[1][1], 2.0 + 8.0 + 2.0, epsilon = 1e-12);
+
+        // the gradient is consistent with the cost function
+        let eps = 1e-6;
+        for x in [vec![1.0, 0.0], vec![0.3, 1.1], vec![2.0, 1.0]] {
+            let gradient = subproblem.gradient(&x).unwrap();
+            for i in 0..2 {
+                let mut xp = x.clone();
+                xp[i] += eps;
+                let mut xm = x.clone();
+                xm[i] -= eps;
+                let fd =
+                    (subproblem.cost(&xp).unwrap() - subproblem.cost(&xm).unwrap()) / (2.0 * eps);
+                assert_relative_eq!(gradient[i], fd, epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_solver_lbfgs() {
+        let solver = AugmentedLagrangian::new(lbfgs);
+        let res = Executor::new(Problem1 {}, solver)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(50))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_param().unwrap();
+        assert_relative_eq!(param[0], 4.0 / 3.0, epsilon = 1e-6);
+        assert_relative_eq!(param[1], 2.0 / 3.0, epsilon = 1e-6);
+        let multipliers = res.state.get_lagrange_multipliers().unwrap();
+        assert_relative_eq!(multipliers[0], -2.0 / 9.0, epsilon = 1e-5);
+        assert_relative_eq!(multipliers[1], 10.0 / 9.0, epsilon = 1e-5);
+        // Evaluations in the inner solver are attributed to the problem.
+        let counts = &res.problem.counts;
+        assert!(counts["cost_count"] > res.state.get_iter());
+        assert!(counts["equality_constraint_count"] >= counts["cost_count"]);
+        assert!(counts["inequality_constraint_jacobian_count"] >= counts["gradient_count"] - 1);
+        assert!(!counts.contains_key("hessian_count"));
+    }
+
+    #[test]
+    fn test_solver_bfgs() {
+        let solver = AugmentedLagrangian::new(|| {
+            BFGS::new(MoreThuenteLineSearch::new())
+                .with_tolerance_grad(1e-10)
+                .unwrap()
+        });
+        let res = Executor::new(Problem1 {}, solver)
+            .configure(|state| {
+                state
+                    .param(vec![0.0, 0.0])
+                    .inv_hessian(vec![vec![1.0, 0.0], vec![0.0, 1.0]])
+                    .max_iters(50)
+            })
+            .run()
+            .unwrap();
+        let param = res.state.get_param().unwrap();
+        assert_relative_eq!(param[0], 4.0 / 3.0, epsilon = 1e-6);
+        assert_relative_eq!(param[1], 2.0 / 3.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_solver_newton_cg() {
+        let solver = AugmentedLagrangian::new(|| {
+            NewtonCG::new(MoreThuenteLineSearch::new())
+                .with_tolerance(1e-12)
+                .unwrap()
+        });
+        let res = Executor::new(Problem1 {}, solver)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(50))
+            .run()
+            .unwrap();
+        let param = res.state.get_param().unwrap();
+        assert_relative_eq!(param[0], 4.0 / 3.0, epsilon = 1e-6);
+        assert_relative_eq!(param[1], 2.0 / 3.0, epsilon = 1e-6);
+        assert!(res.problem.counts["hessian_count"] > 0);
+    }
+
+    #[test]
+    fn test_solver_inequality_only() {
+        let solver = AugmentedLagrangian::new(lbfgs);
+        let res = Executor::new(Problem2 {}, solver)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(50))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_param().unwrap();
+        assert_relative_eq!(param[0].powi(2) + param[1].powi(2), 1.0, epsilon = 1e-7);
+        assert_relative_eq!(param[0], 0.786415, epsilon = 1e-5);
+        assert_relative_eq!(param[1], 0.617698, epsilon = 1e-5);
+    }
+}
@@ -0,0 +1,1217 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # BOBYQA-style derivative-free trust region method
+//!
+//! Bound constrained minimization with quadratic models built by interpolation of cost function
+//! values, in the spirit of Powell's NEWUOA \[0\] and BOBYQA \[1\].
+//!
+//! For details see [`BOBYQA`].
+//!
+//! ## References
+//!
+//! \[0\] Powell, M.J.D. (2006): The NEWUOA software for unconstrained optimization without
+//! derivatives. In: Large-Scale Nonlinear Optimization, Springer, 255-297.
+//! <https://doi.org/10.1007/0-387-30065-1_16>
+//!
+//! \[1\] Powell, M.J.D. (2009): The BOBYQA algorithm for bound constrained optimization without
+//! derivatives. Technical Report DAMTP 2009/NA06, University of Cambridge.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, IterState, Problem, SerializeAlias, Solver, State,
+    TerminationReason, KV,
+};
+use crate::solver::dense::{distance, dot, mat_vec, norm, param_from_coords, to_coords};
+use argmin_math::{ArgminBasis, ArgminDot, ArgminInv, ArgminScaledAdd, ArgminZeroLike};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # BOBYQA-style derivative-free trust region method
+///
+/// Minimizes a cost function subject to optional bounds on the parameters without using
+/// derivatives. In each iteration, a quadratic model of the cost function is built from `m`
+/// interpolation points and minimized within a trust region and the bounds. Each trial point
+/// replaces one of the interpolation points, such that typically only one cost function
+/// evaluation is required per iteration. This makes the method well suited for smooth but
+/// expensive cost functions.
+///
+/// The method follows the structure of Powell's NEWUOA \[0\] and BOBYQA \[1\], but favours
+/// simplicity over efficiency in the linear algebra:
+///
+/// * The model is updated such that the Frobenius norm of the change of its Hessian is minimal.
+///   As in NEWUOA, the inverse of the corresponding interpolation system is updated whenever an
+///   interpolation point is replaced, which costs `O((m + n)^2)` operations. It is only computed
+///   from scratch, which costs `O((m + n)^3)` operations, after the trust region radius has
+///   changed considerably or the best point has moved far away from the point about which the
+///   system is formed. In contrast to NEWUOA, the inverse is stored explicitly instead of in
+///   factored form.
+/// * The trust region subproblem is solved with a truncated conjugate gradient method which
+///   fixes variables once they reach their bounds.
+/// * Interpolation points which are far away from the best point are moved such that the
+///   magnitude of their Lagrange function is maximized over a small set of candidate points.
+///
+/// The trust region radius is bounded from below by a radius `rho`, which is decreased from an
+/// initial to a final value (see
+/// [`with_trust_region_radius`](`BOBYQA::with_trust_region_radius`)) whenever no further progress
+/// can be made at the current resolution. The algorithm terminates once the final radius is
+/// reached and no progress can be made. The initial radius should be about one tenth of the
+/// greatest expected change of a parameter and the final radius determines the accuracy of the
+/// result.
+///
+/// By default `2n + 1` interpolation points are used, where `n` is the number of parameters (see
+/// [`with_num_interpolation_points`](`BOBYQA::with_num_interpolation_points`)). The inverse of
+/// the interpolation system is a dense matrix of dimension `m + n + 1`, therefore the method is
+/// intended for problems with a moderate number of parameters.
+///
+/// Requires an initial parameter vector. If it lies too close to a bound, it is moved onto the
+/// bound or away from it, as in BOBYQA.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`].
+///
+/// ## References
+///
+/// \[0\] Powell, M.J.D. (2006): The NEWUOA software for unconstrained optimization without
+/// derivatives. In: Large-Scale Nonlinear Optimization, Springer, 255-297.
+/// <https://doi.org/10.1007/0-387-30065-1_16>
+///
+/// \[1\] Powell, M.J.D. (2009): The BOBYQA algorithm for bound constrained optimization without
+/// derivatives. Technical Report DAMTP 2009/NA06, University of Cambridge.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct BOBYQA<P, F> {
+    /// Bounds on the parameters
+    bounds: Option<(P, P)>,
+    /// Initial lower bound on the trust region radius
+    rho_begin: F,
+    /// Final lower bound on the trust region radius
+    rho_end: F,
+    /// Number of interpolation points
+    num_points: Option<usize>,
+    /// Coordinate axes of the parameter space
+    basis: Vec<P>,
+    /// Lower bounds in coordinates
+    lower: Vec<F>,
+    /// Upper bounds in coordinates
+    upper: Vec<F>,
+    /// Interpolation points in coordinates
+    points: Vec<Vec<F>>,
+    /// Cost function values at the interpolation points
+    values: Vec<F>,
+    /// Index of the best interpolation point
+    kopt: usize,
+    /// Point about which the model is expanded
+    base: Vec<F>,
+    /// Constant term of the model
+    model_const: F,
+    /// Gradient of the model at `base`
+    model_grad: Vec<F>,
+    /// Hessian of the model
+    model_hess: Vec<Vec<F>>,
+    /// Point about which the interpolation system is formed
+    reference: Vec<F>,
+    /// Scaling factor of the points in the interpolation system
+    scale: F,
+    /// Inverse of the interpolation system
+    system_inv: Vec<Vec<F>>,
+    /// Current lower bound on the trust region radius
+    rho: F,
+    /// Trust region radius
+    delta: F,
+    /// Whether the geometry of the interpolation set needs to be improved
+    improve_geometry: bool,
+}
+
+impl<P, F> BOBYQA<P, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`BOBYQA`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bobyqa::BOBYQA;
+    /// let bobyqa: BOBYQA<Vec<f64>, f64> = BOBYQA::new();
+    /// ```
+    pub fn new() -> Self {
+        BOBYQA {
+            bounds: None,
+            rho_begin: float!(0.5),
+            rho_end: float!(1e-6),
+            num_points: None,
+            basis: vec![],
+            lower: vec![],
+            upper: vec![],
+            points: vec![],
+            values: vec![],
+            kopt: 0,
+            base: vec![],
+            model_const: F::zero(),
+            model_grad: vec![],
+            model_hess: vec![],
+            reference: vec![],
+            scale: F::one(),
+            system_inv: vec![],
+            rho: float!(0.5),
+            delta: float!(0.5),
+            improve_geometry: false,
+        }
+    }
+
+    /// Set lower and upper bounds on the parameters
+    ///
+    /// In every dimension, the difference between upper and lower bound must be at least twice
+    /// the initial trust region radius. The cost function is only evaluated within the bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bobyqa::BOBYQA;
+    /// let bobyqa: BOBYQA<Vec<f64>, f64> =
+    ///     BOBYQA::new().with_bounds(vec![-1.0, -1.0], vec![1.0, 1.0]);
+    /// ```
+    #[must_use]
+    pub fn with_bounds(mut self, lower: P, upper: P) -> Self {
+        self.bounds = Some((lower, upper));
+        self
+    }
+
+    /// Set the initial and final lower bound on the trust region radius
+    ///
+    /// Both must be positive and the final radius must not exceed the initial one. Default to
+    /// `0.5` and `1e-6`, respectively.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bobyqa::BOBYQA;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let bobyqa: BOBYQA<Vec<f64>, f64> = BOBYQA::new().with_trust_region_radius(0.1, 1e-8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_trust_region_radius(mut self, rho_begin: F, rho_end: F) -> Result<Self, Error> {
+        if rho_end <= F::zero() || rho_begin < rho_end {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BOBYQA`: trust region radii must satisfy 0 < rho_end <= rho_begin."
+            ));
+        }
+        self.rho_begin = rho_begin;
+        self.rho_end = rho_end;
+        Ok(self)
+    }
+
+    /// Set the number of interpolation points
+    ///
+    /// Must be in `[n + 2, (n + 1)(n + 2) / 2]`, where `n` is the number of parameters, which is
+    /// checked when the solver is initialized. Defaults to `2n + 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bobyqa::BOBYQA;
+    /// let bobyqa: BOBYQA<Vec<f64>, f64> = BOBYQA::new().with_num_interpolation_points(6);
+    /// ```
+    #[must_use]
+    pub fn with_num_interpolation_points(mut self, num_points: usize) -> Self {
+        self.num_points = Some(num_points);
+        self
+    }
+}
+
+impl<P, F> Default for BOBYQA<P, F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        BOBYQA::new()
+    }
+}
+
+impl<P, F> BOBYQA<P, F>
+where
+    P: Clone + ArgminDot<P, F> + ArgminScaledAdd<P, F, P> + ArgminZeroLike,
+    F: ArgminFloat,
+{
+    /// Evaluates the cost function at the point with coordinates `coords`
+    fn cost<O>(&self, problem: &mut Problem<O>, coords: &[F]) -> Result<F, Error>
+    where
+        O: CostFunction<Param = P, Output = F>,
+    {
+        problem.cost(&param_from_coords(&self.basis, coords))
+    }
+}
+
+impl<P, F> BOBYQA<P, F>
+where
+    F: ArgminFloat,
+{
+    /// Moves the initial point onto a bound or away from it, such that all initial interpolation
+    /// points are feasible, and returns the initial interpolation points.
+    fn initial_points(&self, x0: &[F], num_points: usize) -> Vec<Vec<F>> {
+        let n = x0.len();
+        let rho = self.rho_begin;
+        let half = float!(0.5);
+        let mut x0 = x0.to_vec();
+        let mut steps = Vec::with_capacity(n);
+        for (x0i, (&l, &u)) in x0.iter_mut().zip(self.lower.iter().zip(&self.upper)) {
+            let xi = x0i.max(l).min(u);
+            *x0i = if xi - l < half * rho {
+                l
+            } else if xi - l < rho {
+                l + rho
+            } else if u - xi < half * rho {
+                u
+            } else if u - xi < rho {
+                u - rho
+            } else {
+                xi
+            };
+            #[allow(clippy::float_cmp)]
+            steps.push(if *x0i == l {
+                (rho, float!(2.0) * rho)
+            } else if *x0i == u {
+                (-rho, float!(-2.0) * rho)
+            } else {
+                (rho, -rho)
+            });
+        }
+
+        let mut points = vec![x0.clone()];
+        for i in 0..n {
+            let mut point = x0.clone();
+            point[i] = point[i] + steps[i].0;
+            points.push(point);
+        }
+        for i in 0..n {
+            let mut point = x0.clone();
+            point[i] = point[i] + steps[i].1;
+            points.push(point);
+        }
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let mut point = x0.clone();
+                point[i] = point[i] + steps[i].0;
+                point[j] = point[j] + steps[j].0;
+                points.push(point);
+            }
+        }
+        points.truncate(num_points);
+        points
+    }
+
+    /// Value of the model at the point with coordinates `x`
+    fn model_value(&self, x: &[F]) -> F {
+        let s: Vec<F> = x.iter().zip(&self.base).map(|(&a, &b)| a - b).collect();
+        self.model_const
+            + dot(&self.model_grad, &s)
+            + float!(0.5) * dot(&s, &mat_vec(&self.model_hess, &s))
+    }
+
+    /// Expands the model about the point with coordinates `new_base`
+    fn shift_model(&mut self, new_base: &[F]) {
+        let s: Vec<F> = new_base
+            .iter()
+            .zip(&self.base)
+            .map(|(&a, &b)| a - b)
+            .collect();
+        let hs = mat_vec(&self.model_hess, &s);
+        self.model_const = self.model_value(new_base);
+        for (g, h) in self.model_grad.iter_mut().zip(hs) {
+            *g = *g + h;
+        }
+        self.base = new_base.to_vec();
+    }
+
+    /// Coordinates of `x` relative to the reference point of the interpolation system, divided by
+    /// its scaling factor
+    fn scaled(&self, x: &[F]) -> Vec<F> {
+        x.iter()
+            .zip(&self.reference)
+            .map(|(&a, &b)| (a - b) / self.scale)
+            .collect()
+    }
+
+    /// Column of the interpolation system which corresponds to the point with coordinates `x`:
+    /// `0.5 (s_i^T s)^2` for all scaled interpolation points `s_i`, followed by `1` and the scaled
+    /// point `s` itself
+    fn system_column(&self, x: &[F]) -> Vec<F> {
+        let s = self.scaled(x);
+        let mut w: Vec<F> = self
+            .points
+            .iter()
+            .map(|p| float!(0.5) * dot(&self.scaled(p), &s).powi(2))
+            .collect();
+        w.push(F::one());
+        w.extend(s);
+        w
+    }
+
+    /// Forms the interpolation system about the best point, scaled by the trust region radius, and
+    /// computes its inverse from scratch.
+    fn reset_system(&mut self) -> Result<(), Error> {
+        let n = self.base.len();
+        let m = self.points.len();
+        self.reference = self.points[self.kopt].clone();
+        self.scale = self.delta;
+
+        let mut w = vec![vec![F::zero(); m + n + 1]; m + n + 1];
+        for (i, point) in self.points.iter().enumerate() {
+            let column = self.system_column(point);
+            for (j, &value) in column.iter().enumerate() {
+                w[i][j] = value;
+                w[j][i] = value;
+            }
+        }
+        w[m][m] = F::zero();
+        for row in w[(m + 1)..].iter_mut() {
+            row[m..].iter_mut().for_each(|x| *x = F::zero());
+        }
+        self.system_inv = w.inv().map_err(|_| -> Error {
+            argmin_error!(
+                ConditionViolated,
+                "`BOBYQA`: interpolation points are degenerate."
+            )
+        })?;
+        Ok(())
+    }
+
+    /// Updates the inverse `H` of the interpolation system when interpolation point `t` is moved
+    /// to the point with coordinates `x`, by the formula of Powell \[0\]:
+    ///
+    /// `H+ = H + 1/sigma (alpha (e_t - H w) (e_t - H w)^T - beta H e_t e_t^T H
+    ///        + tau (H e_t (e_t - H w)^T + (e_t - H w) e_t^T H))`
+    ///
+    /// where `w` is the column of the system which corresponds to `x`, `alpha = e_t^T H e_t`,
+    /// `beta = 0.5 |s|^4 - w^T H w`, `tau = e_t^T H w` and `sigma = alpha beta + tau^2`. Must be
+    /// called before the point is replaced. Returns `false` if `sigma` is too small for the update
+    /// to be numerically reliable, in which case `H` is left unchanged.
+    fn update_system(&mut self, t: usize, x: &[F]) -> bool {
+        let w = self.system_column(x);
+        let s = self.scaled(x);
+        let hw: Vec<F> = self.system_inv.iter().map(|row| dot(row, &w)).collect();
+        let ht: Vec<F> = self.system_inv[t].clone();
+        let alpha = ht[t];
+        let beta = float!(0.5) * dot(&s, &s).powi(2) - dot(&w, &hw);
+        let tau = hw[t];
+        let sigma = alpha * beta + tau * tau;
+        if !sigma.is_finite() || sigma.abs() <= F::epsilon() * (alpha * beta).abs().max(tau * tau) {
+            return false;
+        }
+        // `e_t - H w`
+        let v: Vec<F> = hw
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| if i == t { F::one() - x } else { -x })
+            .collect();
+        for (i, row) in self.system_inv.iter_mut().enumerate() {
+            for (j, h) in row.iter_mut().enumerate() {
+                *h = *h
+                    + (alpha * v[i] * v[j] - beta * ht[i] * ht[j]
+                        + tau * (ht[i] * v[j] + v[i] * ht[j]))
+                        / sigma;
+            }
+        }
+        true
+    }
+
+    /// Updates the model such that it interpolates the cost function values at all interpolation
+    /// points while the Frobenius norm of the change of its Hessian is minimal. The model must be
+    /// expanded about the best interpolation point.
+    ///
+    /// The change of the model is `c + b^T s + 0.5 sum_i lambda_i (s_i^T s)^2` in terms of the
+    /// scaled coordinates `s` of the interpolation system, where the coefficients are obtained by
+    /// multiplying the inverse of the system with the residuals of the model.
+    fn update_model(&mut self) {
+        let n = self.base.len();
+        let m = self.points.len();
+
+        let residuals: Vec<F> = self
+            .points
+            .iter()
+            .zip(&self.values)
+            .map(|(p, &f)| f - self.model_value(p))
+            .collect();
+        let coefficients: Vec<F> = self
+            .system_inv
+            .iter()
+            .map(|row| dot(&row[..m], &residuals))
+            .collect();
+        let (lambda, rest) = coefficients.split_at(m);
+        let (c, b) = (rest[0], &rest[1..]);
+
+        let points: Vec<Vec<F>> = self.points.iter().map(|p| self.scaled(p)).collect();
+        let s_base = self.scaled(&self.base);
+        let products: Vec<F> = points.iter().map(|si| dot(si, &s_base)).collect();
+
+        self.model_const = self.model_const
+            + c
+            + dot(b, &s_base)
+            + lambda
+                .iter()
+                .zip(&products)
+                .fold(F::zero(), |acc, (&l, &p)| acc + float!(0.5) * l * p * p);
+        for k in 0..n {
+            let g = points
+                .iter()
+                .zip(lambda.iter().zip(&products))
+                .fold(b[k], |acc, (si, (&l, &p))| acc + l * p * si[k]);
+            self.model_grad[k] = self.model_grad[k] + g / self.scale;
+        }
+        let scale2 = self.scale * self.scale;
+        for (l, si) in lambda.iter().zip(&points) {
+            for r in 0..n {
+                for c in 0..n {
+                    self.model_hess[r][c] = self.model_hess[r][c] + *l * si[r] * si[c] / scale2;
+                }
+            }
+        }
+    }
+
+    /// Values of the Lagrange functions of all interpolation points at the point with coordinates
+    /// `x`
+    fn lagrange_values(&self, x: &[F]) -> Vec<F> {
+        let m = self.points.len();
+        let w = self.system_column(x);
+        self.system_inv[..m]
+            .iter()
+            .map(|row| dot(row, &w))
+            .collect()
+    }
+
+    /// Index of and distance to the interpolation point farthest away from the best point
+    fn farthest_point(&self) -> (usize, F) {
+        let xopt = &self.points[self.kopt];
+        self.points
+            .iter()
+            .map(|p| distance(p, xopt))
+            .enumerate()
+            .fold((self.kopt, F::zero()), |(ia, a), (ib, b)| {
+                if b > a {
+                    (ib, b)
+                } else {
+                    (ia, a)
+                }
+            })
+    }
+
+    /// Decreases the lower bound on the trust region radius. Returns `false` if the final value
+    /// has already been reached.
+    fn reduce_rho(&mut self) -> bool {
+        if self.rho <= self.rho_end {
+            return false;
+        }
+        let ratio = self.rho / self.rho_end;
+        let rho = if ratio <= float!(16.0) {
+            self.rho_end
+        } else if ratio <= float!(250.0) {
+            ratio.sqrt() * self.rho_end
+        } else {
+            float!(0.1) * self.rho
+        };
+        self.delta = (float!(0.5) * self.rho).max(rho);
+        self.rho = rho;
+        true
+    }
+
+    /// Replaces interpolation point `idx` by the value of the function at `x` and updates the
+    /// index of the best point as well as the inverse of the interpolation system
+    fn replace_point(&mut self, idx: usize, x: Vec<F>, value: F) -> Result<(), Error> {
+        let updated = self.update_system(idx, &x);
+        self.points[idx] = x;
+        self.values[idx] = value;
+        if value < self.values[self.kopt] {
+            self.kopt = idx;
+        }
+        if !updated {
+            self.reset_system()?;
+        }
+        Ok(())
+    }
+}
+
+impl<O, P, F> Solver<O, IterState<P, (), (), (), F>> for BOBYQA<P, F>
+where
+    O: CostFunction<Param = P, Output = F>,
+    P: Clone + SerializeAlias + ArgminBasis + ArgminDot<P, F> + ArgminScaledAdd<P, F, P>,
+    P: ArgminZeroLike,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "BOBYQA";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`BOBYQA` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        self.basis = param.basis_like();
+        let n = self.basis.len();
+        let num_points = self.num_points.unwrap_or(2 * n + 1);
+        if num_points < n + 2 || num_points > (n + 1) * (n + 2) / 2 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`BOBYQA`: number of interpolation points must be in [{}, {}], got {}.",
+                    n + 2,
+                    (n + 1) * (n + 2) / 2,
+                    num_points
+                )
+            ));
+        }
+
+        (self.lower, self.upper) = match &self.bounds {
+            Some((lower, upper)) => (to_coords(&self.basis, lower), to_coords(&self.basis, upper)),
+            None => (vec![F::neg_infinity(); n], vec![F::infinity(); n]),
+        };
+        if self
+            .lower
+            .iter()
+            .zip(&self.upper)
+            .any(|(&l, &u)| u - l < float!(2.0) * self.rho_begin)
+        {
+            return Err(argmin_error!(
+                InvalidParameter,
+                concat!(
+                    "`BOBYQA`: difference between upper and lower bounds must be at least twice ",
+                    "the initial trust region radius."
+                )
+            ));
+        }
+
+        self.points = self.initial_points(&to_coords(&self.basis, &param), num_points);
+        self.values = self
+            .points
+            .iter()
+            .map(|p| self.cost(problem, p))
+            .collect::<Result<_, _>>()?;
+        self.kopt = 0;
+        for (i, &value) in self.values.iter().enumerate() {
+            if value < self.values[self.kopt] {
+                self.kopt = i;
+            }
+        }
+        self.base = self.points[0].clone();
+        self.model_const = F::zero();
+        self.model_grad = vec![F::zero(); n];
+        self.model_hess = vec![vec![F::zero(); n]; n];
+        self.rho = self.rho_begin;
+        self.delta = self.rho_begin;
+        self.improve_geometry = false;
+        self.reset_system()?;
+
+        let best = param_from_coords(&self.basis, &self.points[self.kopt]);
+        Ok((state.param(best).cost(self.values[self.kopt]), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let xopt = self.points[self.kopt].clone();
+        let fopt = self.values[self.kopt];
+        // The interpolation system is formed anew if its scaling does not match the trust region
+        // radius anymore or if the best point moved far away from its reference point.
+        if self.delta < float!(0.1) * self.scale
+            || distance(&xopt, &self.reference) > float!(10.0) * self.scale
+        {
+            self.reset_system()?;
+        }
+        self.shift_model(&xopt);
+        self.update_model();
+
+        let (far_idx, far_dist) = self.farthest_point();
+        let mut step_taken = false;
+        let mut converged = false;
+
+        if self.improve_geometry && far_dist > float!(2.0) * self.delta {
+            self.geometry_step(problem, far_idx)?;
+            self.improve_geometry = false;
+        } else {
+            self.improve_geometry = false;
+            let d = trust_region_step(
+                &self.model_grad,
+                &self.model_hess,
+                &xopt,
+                &self.lower,
+                &self.upper,
+                self.delta,
+            );
+            let d_norm = norm(&d);
+            if d_norm < float!(0.5) * self.rho {
+                // The step is too short to be worth a function evaluation: improve the geometry
+                // of the interpolation set or increase the resolution.
+                if far_dist > float!(2.0) * self.delta {
+                    self.geometry_step(problem, far_idx)?;
+                } else {
+                    converged = !self.reduce_rho();
+                }
+            } else {
+                step_taken = true;
+                let x: Vec<F> = xopt
+                    .iter()
+                    .zip(&d)
+                    .zip(self.lower.iter().zip(&self.upper))
+                    .map(|((&a, &b), (&l, &u))| (a + b).max(l).min(u))
+                    .collect();
+                let value = self.cost(problem, &x)?;
+                let predicted = -(dot(&self.model_grad, &d)
+                    + float!(0.5) * dot(&d, &mat_vec(&self.model_hess, &d)));
+                let ratio = if predicted > F::zero() {
+                    (fopt - value) / predicted
+                } else {
+                    float!(-1.0)
+                };
+
+                self.delta = if ratio <= float!(0.1) {
+                    (float!(0.5) * self.delta).min(d_norm)
+                } else if ratio <= float!(0.7) {
+                    (float!(0.5) * self.delta).max(d_norm)
+                } else {
+                    (float!(0.5) * self.delta).max(float!(2.0) * d_norm)
+                };
+                if self.delta <= float!(1.5) * self.rho {
+                    self.delta = self.rho;
+                }
+
+                // Replace the point with the largest Lagrange function value at the new point,
+                // weighted by its distance to the best point.
+                let lagrange = self.lagrange_values(&x);
+                let mut replace_idx = None;
+                let mut max_score = F::zero();
+                for (i, l) in lagrange.iter().enumerate() {
+                    if i == self.kopt && value >= fopt {
+                        continue;
+                    }
+                    let dist = distance(&self.points[i], &xopt) / self.delta;
+                    let score = l.abs() * (dist * dist).max(F::one());
+                    if replace_idx.is_none() || score > max_score {
+                        replace_idx = Some(i);
+                        max_score = score;
+                    }
+                }
+                if let Some(idx) = replace_idx {
+                    self.replace_point(idx, x, value)?;
+                }
+
+                if ratio < float!(0.1) {
+                    let (_, far_dist) = self.farthest_point();
+                    if far_dist > float!(2.0) * self.delta {
+                        self.improve_geometry = true;
+                    } else if self.delta <= self.rho && ratio <= F::zero() {
+                        converged = !self.reduce_rho();
+                    }
+                }
+            }
+        }
+
+        let best = param_from_coords(&self.basis, &self.points[self.kopt]);
+        let state = state.param(best).cost(self.values[self.kopt]);
+        let state = if converged {
+            state.terminate_with(TerminationReason::SolverConverged)
+        } else {
+            state
+        };
+        Ok((
+            state,
+            Some(kv!(
+                "rho" => self.rho;
+                "delta" => self.delta;
+                "trust_region_step" => step_taken;
+            )),
+        ))
+    }
+}
+
+impl<P, F> BOBYQA<P, F>
+where
+    P: Clone + ArgminDot<P, F> + ArgminScaledAdd<P, F, P> + ArgminZeroLike,
+    F: ArgminFloat,
+{
+    /// Moves interpolation point `idx` to the point within the trust region around the best point
+    /// which maximizes the magnitude of its Lagrange function among a set of candidates: steps
+    /// along the coordinate axes and along the line through the best point and the old point.
+    fn geometry_step<O>(&mut self, problem: &mut Problem<O>, idx: usize) -> Result<(), Error>
+    where
+        O: CostFunction<Param = P, Output = F>,
+    {
+        let n = self.base.len();
+        let xopt = &self.points[self.kopt];
+        let step = self.delta.max(self.rho);
+        let dist = distance(&self.points[idx], xopt);
+
+        let mut directions: Vec<Vec<F>> = (0..n)
+            .map(|i| {
+                let mut e = vec![F::zero(); n];
+                e[i] = F::one();
+                e
+            })
+            .collect();
+        directions.push(
+            self.points[idx]
+                .iter()
+                .zip(xopt)
+                .map(|(&a, &b)| (a - b) / dist)
+                .collect(),
+        );
+
+        let mut best: Option<(Vec<F>, F)> = None;
+        for direction in directions {
+            for sign in [F::one(), -F::one()] {
+                let x: Vec<F> = xopt
+                    .iter()
+                    .zip(&direction)
+                    .zip(self.lower.iter().zip(&self.upper))
+                    .map(|((&a, &b), (&l, &u))| (a + sign * step * b).max(l).min(u))
+                    .collect();
+                let value = self.lagrange_values(&x)[idx].abs();
+                if best.as_ref().is_none_or(|(_, v)| value > *v) {
+                    best = Some((x, value));
+                }
+            }
+        }
+
+        let (x, _) = best.unwrap();
+        let value = self.cost(problem, &x)?;
+        self.replace_point(idx, x, value)
+    }
+}
+
+/// Approximately minimizes `g^T d + 0.5 d^T H d` subject to `||d|| <= delta` and
+/// `lower <= x + d <= upper` with a truncated conjugate gradient method. Variables which reach
+/// a bound are fixed and the method is restarted on the remaining variables.
+fn trust_region_step<F: ArgminFloat>(
+    g: &[F],
+    h: &[Vec<F>],
+    x: &[F],
+    lower: &[F],
+    upper: &[F],
+    delta: F,
+) -> Vec<F> {
+    let n = g.len();
+    let mut d = vec![F::zero(); n];
+    let mut free: Vec<bool> = (0..n)
+        .map(|i| {
+            !((x[i] <= lower[i] && g[i] >= F::zero()) || (x[i] >= upper[i] && g[i] <= F::zero()))
+        })
+        .collect();
+    let tol = float!(1e-10) * norm(g);
+
+    for _ in 0..=n {
+        let hd = mat_vec(h, &d);
+        let mut r: Vec<F> = (0..n)
+            .map(|i| if free[i] { -(g[i] + hd[i]) } else { F::zero() })
+            .collect();
+        let mut rr = dot(&r, &r);
+        if rr.sqrt() <= tol {
+            return d;
+        }
+        let mut p = r.clone();
+        let mut bound_hit = false;
+
+        for _ in 0..n {
+            let hp = mat_vec(h, &p);
+            let php = dot(&p, &hp);
+
+            // Step length to the trust region boundary
+            let (dd, dp, pp) = (dot(&d, &d), dot(&d, &p), dot(&p, &p));
+            let alpha_tr = (-dp + (dp * dp + pp * (delta * delta - dd)).max(F::zero()).sqrt()) / pp;
+            // Step length to the nearest bound
+            let mut alpha_bound = F::infinity();
+            let mut bound_idx = 0;
+            for i in (0..n).filter(|&i| free[i]) {
+                let alpha = if p[i] > F::zero() {
+                    (upper[i] - x[i] - d[i]) / p[i]
+                } else if p[i] < F::zero() {
+                    (lower[i] - x[i] - d[i]) / p[i]
+                } else {
+                    continue;
+                };
+                if alpha < alpha_bound {
+                    alpha_bound = alpha.max(F::zero());
+                    bound_idx = i;
+                }
+            }
+            let alpha_cg = if php > F::zero() {
+                rr / php
+            } else {
+                F::infinity()
+            };
+
+            let alpha = alpha_cg.min(alpha_tr).min(alpha_bound);
+            for i in 0..n {
+                d[i] = d[i] + alpha * p[i];
+            }
+            if alpha_bound <= alpha_tr && alpha_bound < alpha_cg {
+                d[bound_idx] = if p[bound_idx] > F::zero() {
+                    upper[bound_idx] - x[bound_idx]
+                } else {
+                    lower[bound_idx] - x[bound_idx]
+                };
+                free[bound_idx] = false;
+                bound_hit = true;
+                break;
+            }
+            if alpha_tr <= alpha_cg {
+                return d;
+            }
+
+            for i in 0..n {
+                r[i] = r[i] - alpha * hp[i];
+            }
+            let rr_new = dot(&r, &r);
+            if rr_new.sqrt() <= tol {
+                return d;
+            }
+            let beta = rr_new / rr;
+            for i in 0..n {
+                p[i] = r[i] + beta * p[i];
+            }
+            rr = rr_new;
+        }
+
+        if !bound_hit {
+            return d;
+        }
+    }
+    d
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{test_utils::TestProblem, ArgminError, Executor};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(bobyqa, BOBYQA<Vec<f64>, f64>);
+
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((1.0 - p[0]).powi(2) + 100.0 * (p[1] - p[0].powi(2)).powi(2))
+        }
+    }
+
+    struct Quadratic {}
+
+    impl CostFunction for Quadratic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0] - 1.0).powi(2)
+                + 2.0 * (p[1] + 2.0).powi(2)
+                + (p[0] - 1.0) * (p[1] + 2.0)
+                + 3.0 * (p[2] - 0.5).powi(2))
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let bobyqa: BOBYQA<Vec<f64>, f64> = BOBYQA::new();
+        assert!(bobyqa.bounds.is_none());
+        assert_eq!(bobyqa.rho_begin.to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert_eq!(bobyqa.rho_end.to_ne_bytes(), 1e-6f64.to_ne_bytes());
+        assert!(bobyqa.num_points.is_none());
+    }
+
+    #[test]
+    fn test_with_trust_region_radius() {
+        let bobyqa: BOBYQA<Vec<f64>, f64> =
+            BOBYQA::new().with_trust_region_radius(0.1, 1e-8).unwrap();
+        assert_eq!(bobyqa.rho_begin.to_ne_bytes(), 0.1f64.to_ne_bytes());
+        assert_eq!(bobyqa.rho_end.to_ne_bytes(), 1e-8f64.to_ne_bytes());
+
+        for (rho_begin, rho_end) in [(0.1, 0.0), (0.1, -1.0), (0.1, 0.2)] {
+            let res: Result<BOBYQA<Vec<f64>, f64>, _> =
+                BOBYQA::new().with_trust_region_radius(rho_begin, rho_end);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`BOBYQA`: trust region radii must satisfy 0 < rho_end <= rho_begin.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_init_param_not_initialized() {
+        let mut bobyqa: BOBYQA<Vec<f64>, f64> = BOBYQA::new();
+        let res = bobyqa.init(&mut Problem::new(TestProblem::new()), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`BOBYQA` requires an initial parameter vector. Please ",
+                "provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_init_invalid_num_points() {
+        for num_points in [3, 7] {
+            let mut bobyqa: BOBYQA<Vec<f64>, f64> =
+                BOBYQA::new().with_num_interpolation_points(num_points);
+            let res = bobyqa.init(
+                &mut Problem::new(TestProblem::new()),
+                IterState::new().param(vec![0.0, 0.0]),
+            );
+            assert_error!(
+                res,
+                ArgminError,
+                format!(
+                    concat!(
+                        "Invalid parameter: \"`BOBYQA`: number of interpolation points must be ",
+                        "in [4, 6], got {}.\""
+                    ),
+                    num_points
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_init_bounds_too_close() {
+        let mut bobyqa: BOBYQA<Vec<f64>, f64> =
+            BOBYQA::new().with_bounds(vec![0.0, 0.0], vec![1.0, 0.5]);
+        let res = bobyqa.init(
+            &mut Problem::new(TestProblem::new()),
+            IterState::new().param(vec![0.0, 0.0]),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`BOBYQA`: difference between upper and lower bounds must ",
+                "be at least twice the initial trust region radius.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_init() {
+        let mut bobyqa: BOBYQA<Vec<f64>, f64> = BOBYQA::new()
+            .with_bounds(vec![0.0, -5.0, -5.0], vec![5.0, 5.0, 0.0])
+            .with_num_interpolation_points(10);
+        let mut problem = Problem::new(Quadratic {});
+        let (state, kv) = bobyqa
+            .init(&mut problem, IterState::new().param(vec![0.2, 1.0, -0.3]))
+            .unwrap();
+        assert!(kv.is_none());
+        assert_eq!(problem.counts["cost_count"], 10);
+
+        // The first coordinate is moved onto the lower bound, the third one away from the upper
+        // bound.
+        let points = &bobyqa.points;
+        assert_eq!(points.len(), 10);
+        assert_eq!(points[0], vec![0.0, 1.0, -0.5]);
+        assert_eq!(points[1], vec![0.5, 1.0, -0.5]);
+        assert_eq!(points[2], vec![0.0, 1.5, -0.5]);
+        assert_eq!(points[3], vec![0.0, 1.0, 0.0]);
+        assert_eq!(points[4], vec![1.0, 1.0, -0.5]);
+        assert_eq!(points[5], vec![0.0, 0.5, -0.5]);
+        assert_eq!(points[6], vec![0.0, 1.0, -1.0]);
+        assert_eq!(points[7], vec![0.5, 1.5, -0.5]);
+        assert_eq!(points[8], vec![0.5, 1.0, 0.0]);
+        assert_eq!(points[9], vec![0.0, 1.5, 0.0]);
+
+        let best = bobyqa
+            .values
+            .iter()
+            .fold(f64::INFINITY, |acc, &v| acc.min(v));
+        assert_eq!(bobyqa.values[bobyqa.kopt].to_ne_bytes(), best.to_ne_bytes());
+        assert_eq!(state.get_cost().to_ne_bytes(), best.to_ne_bytes());
+        assert_eq!(*state.get_param().unwrap(), points[bobyqa.kopt]);
+    }
+
+    #[test]
+    fn test_update_model_interpolates_quadratic() {
+        // With (n+1)(n+2)/2 points, a quadratic is reproduced exactly.
+        let mut bobyqa: BOBYQA<Vec<f64>, f64> = BOBYQA::new().with_num_interpolation_points(10);
+        let mut problem = Problem::new(Quadratic {});
+        bobyqa
+            .init(&mut problem, IterState::new().param(vec![0.0, 0.0, 0.0]))
+            .unwrap();
+        let xopt = bobyqa.points[bobyqa.kopt].clone();
+        bobyqa.shift_model(&xopt);
+        bobyqa.update_model();
+
+        for x in [
+            vec![0.3, -1.0, 2.0],
+            vec![1.0, -2.0, 0.5],
+            vec![-4.0, 3.0, 1.0],
+        ] {
+            let expected = Quadratic {}.cost(&x).unwrap();
+            assert_relative_eq!(bobyqa.model_value(&x), expected, epsilon = 1e-10);
+        }
+        let hess = [[2.0, 1.0, 0.0], [1.0, 4.0, 0.0], [0.0, 0.0, 6.0]];
+        for (row, expected) in bobyqa.model_hess.iter().zip(&hess) {
+            for (&value, &expected) in row.iter().zip(expected) {
+                assert_relative_eq!(value, expected, epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lagrange_values() {
+        let mut bobyqa: BOBYQA<Vec<f64>, f64> = BOBYQA::new();
+        let mut problem = Problem::new(Quadratic {});
+        bobyqa
+            .init(&mut problem, IterState::new().param(vec![0.0, 0.0, 0.0]))
+            .unwrap();
+        let xopt = bobyqa.points[bobyqa.kopt].clone();
+        bobyqa.shift_model(&xopt);
+        bobyqa.update_model();
+
+        // Lagrange functions are one at their own point and zero at all others.
+        for (j, point) in bobyqa.points.iter().enumerate() {
+            let values = bobyqa.lagrange_values(point);
+            for (i, value) in values.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(*value, expected, epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_system() {
+        let mut bobyqa: BOBYQA<Vec<f64>, f64> = BOBYQA::new();
+        let mut problem = Problem::new(Quadratic {});
+        bobyqa
+            .init(&mut problem, IterState::new().param(vec![0.0, 0.0, 0.0]))
+            .unwrap();
+        let t = (bobyqa.kopt + 1) % bobyqa.points.len();
+        let x = vec![0.2, -0.3, 0.4];
+        assert!(bobyqa.update_system(t, &x));
+        bobyqa.points[t] = x;
+
+        // The updated inverse agrees with the inverse computed from scratch.
+        let mut expected = bobyqa.clone();
+        expected.reset_system().unwrap();
+        assert_eq!(bobyqa.reference, expected.reference);
+        for (row, expected) in bobyqa.system_inv.iter().zip(&expected.system_inv) {
+            for (&value, &expected) in row.iter().zip(expected) {
+                assert_relative_eq!(value, expected, epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_trust_region_step() {
+        let h = vec![vec![2.0, 0.0], vec![0.0, 4.0]];
+        let g = vec![-2.0, 4.0];
+        let x = vec![0.0, 0.0];
+        let inf = f64::INFINITY;
+
+        // unconstrained minimum lies within the trust region
+        let d = trust_region_step(&g, &h, &x, &[-inf, -inf], &[inf, inf], 10.0);
+        assert_relative_eq!(d[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(d[1], -1.0, epsilon = 1e-12);
+
+        // the trust region is active
+        let d = trust_region_step(&g, &h, &x, &[-inf, -inf], &[inf, inf], 0.5);
+        assert_relative_eq!(norm(&d), 0.5, epsilon = 1e-12);
+
+        // a bound is active
+        let d = trust_region_step(&g, &h, &x, &[-inf, -0.5], &[inf, inf], 10.0);
+        assert_relative_eq!(d[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(d[1], -0.5, epsilon = 1e-12);
+
+        // variables at a bound with the gradient pointing outwards are fixed
+        let d = trust_region_step(&g, &h, &x, &[-inf, 0.0], &[inf, inf], 10.0);
+        assert_relative_eq!(d[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(d[1], 0.0, epsilon = 1e-12);
+
+        // negative curvature leads to the trust region boundary
+        let h = vec![vec![-1.0, 0.0], vec![0.0, 1.0]];
+        let d = trust_region_step(&[1.0, 0.0], &h, &x, &[-inf, -inf], &[inf, inf], 2.0);
+        assert_relative_eq!(d[0], -2.0, epsilon = 1e-12);
+        assert_relative_eq!(d[1], 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_reduce_rho() {
+        let mut bobyqa: BOBYQA<Vec<f64>, f64> =
+            BOBYQA::new().with_trust_region_radius(1.0, 1e-6).unwrap();
+        bobyqa.rho = 1.0;
+        assert!(bobyqa.reduce_rho());
+        assert_relative_eq!(bobyqa.rho, 0.1, epsilon = f64::EPSILON);
+        assert_relative_eq!(bobyqa.delta, 0.5, epsilon = f64::EPSILON);
+        bobyqa.rho = 1e-4;
+        assert!(bobyqa.reduce_rho());
+        assert_relative_eq!(bobyqa.rho, 1e-5, epsilon = 1e-15);
+        bobyqa.rho = 1e-5;
+        assert!(bobyqa.reduce_rho());
+        assert_relative_eq!(bobyqa.rho, 1e-6, epsilon = f64::EPSILON);
+        assert!(!bobyqa.reduce_rho());
+    }
+
+    #[test]
+    fn test_solver_quadratic() {
+        let bobyqa = BOBYQA::new().with_trust_region_radius(1.0, 1e-8).unwrap();
+        let res = Executor::new(Quadratic {}, bobyqa)
+            .configure(|state| state.param(vec![-3.0, 4.0, 2.0]).max_iters(500))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(param[1], -2.0, epsilon = 1e-6);
+        assert_relative_eq!(param[2], 0.5, epsilon = 1e-6);
+        // a quadratic model captures a quadratic cost function quickly
+        assert!(res.problem.counts["cost_count"] < 100);
+    }
+
+    #[test]
+    fn test_solver_rosenbrock() {
+        let bobyqa = BOBYQA::new().with_trust_region_radius(0.5, 1e-8).unwrap();
+        let res = Executor::new(Rosenbrock {}, bobyqa)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(1000))
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-5);
+        assert_relative_eq!(param[1], 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_solver_bounds() {
+        let bobyqa = BOBYQA::new()
+            .with_bounds(vec![-2.0, -2.0, 1.0], vec![0.5, 2.0, 2.0])
+            .with_trust_region_radius(0.2, 1e-8)
+            .unwrap();
+        let res = Executor::new(Quadratic {}, bobyqa)
+            .configure(|state| state.param(vec![0.0, 0.0, 1.5]).max_iters(500))
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        // the minimum over the box lies on its boundary: x0 = 0.5, x2 = 1 and
+        // x1 = -2 - (x0 - 1) / 4
+        assert_relative_eq!(param[0], 0.5, epsilon = 1e-6);
+        assert_relative_eq!(param[1], -1.875, epsilon = 1e-6);
+        assert_relative_eq!(param[2], 1.0, epsilon = 1e-6);
+    }
+}
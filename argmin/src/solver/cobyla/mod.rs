@@ -0,0 +1,983 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # COBYLA
+//!
+//! Constrained Optimization BY Linear Approximations \[0\]: derivative-free minimization subject
+//! to inequality constraints.
+//!
+//! For details see [`COBYLA`].
+//!
+//! ## Reference
+//!
+//! \[0\] Powell, M.J.D. (1994): A Direct Search Optimization Method That Models the Objective and
+//! Constraint Functions by Linear Interpolation. In: Advances in Optimization and Numerical
+//! Analysis, Springer, 51-67. <https://doi.org/10.1007/978-94-015-8330-5_4>
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, InequalityConstraint, IterState, Problem, SerializeAlias,
+    Solver, State, TerminationReason, KV,
+};
+use crate::solver::dense::{distance, dot, norm, param_from_coords, to_coords};
+use argmin_math::{ArgminBasis, ArgminDot, ArgminScaledAdd, ArgminSolve, ArgminZeroLike};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # COBYLA
+///
+/// Constrained Optimization BY Linear Approximations \[0\] minimizes a cost function `f(x)`
+/// subject to inequality constraints `c(x) >= 0` without using derivatives of either.
+///
+/// The method maintains a simplex of `n + 1` points, where `n` is the number of parameters. The
+/// cost function and every constraint are approximated by the linear functions which interpolate
+/// their values at the vertices of the simplex. In each iteration, a trial step is computed from
+/// these approximations within a trust region of radius `rho` around the best vertex: first, the
+/// greatest violation of the linearized constraints is minimized, then the linearized cost
+/// function is minimized while this violation is not increased. Trial points are compared via the
+/// merit function `f(x) + mu * max(0, -min_i c_i(x))`, where the penalty parameter `mu` is
+/// increased whenever required for the trial step to reduce the merit function. Each trial point
+/// replaces a vertex of the simplex. Whenever the simplex becomes too distorted, a vertex is
+/// moved to improve its geometry.
+///
+/// The radius `rho` is decreased from an initial to a final value (see
+/// [`with_trust_region_radius`](`COBYLA::with_trust_region_radius`)) whenever no further progress
+/// can be made at the current resolution. The algorithm terminates once the final radius is
+/// reached and no progress can be made. The initial radius should be about one tenth of the
+/// greatest expected change of a parameter and the final radius determines the accuracy of the
+/// result.
+///
+/// In each iteration, the current parameter vector is the best vertex with respect to the merit
+/// function and the cost stored in the state is its merit function value. Since the penalty
+/// parameter only increases, the merit of the best parameter vector of the state is updated
+/// accordingly, such that the best parameter vector is the one with the lowest merit function
+/// value for the current penalty parameter. The cost function value, the greatest constraint
+/// violation at the current vertex and whether it is feasible are reported as `objective`,
+/// `constraint_violation` and `feasible`.
+///
+/// Requires an initial parameter vector.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and
+/// [`InequalityConstraint`], with the constraint values returned as `Vec<F>`.
+///
+/// ## Reference
+///
+/// \[0\] Powell, M.J.D. (1994): A Direct Search Optimization Method That Models the Objective and
+/// Constraint Functions by Linear Interpolation. In: Advances in Optimization and Numerical
+/// Analysis, Springer, 51-67. <https://doi.org/10.1007/978-94-015-8330-5_4>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct COBYLA<P, F> {
+    /// Initial radius of the trust region
+    rho_begin: F,
+    /// Final radius of the trust region
+    rho_end: F,
+    /// Coordinate axes of the parameter space
+    basis: Vec<P>,
+    /// Vertices of the simplex in coordinates
+    points: Vec<Vec<F>>,
+    /// Cost function values at the vertices
+    values: Vec<F>,
+    /// Constraint values at the vertices
+    constraints: Vec<Vec<F>>,
+    /// Index of the best vertex
+    kopt: usize,
+    /// Current radius of the trust region
+    rho: F,
+    /// Penalty parameter of the merit function
+    penalty: F,
+    /// Cost function value and greatest constraint violation of the best parameter vector of the
+    /// state
+    best: (F, F),
+    /// Whether the geometry of the simplex needs to be improved
+    improve_geometry: bool,
+}
+
+impl<P, F> COBYLA<P, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`COBYLA`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::cobyla::COBYLA;
+    /// let cobyla: COBYLA<Vec<f64>, f64> = COBYLA::new();
+    /// ```
+    pub fn new() -> Self {
+        COBYLA {
+            rho_begin: float!(0.5),
+            rho_end: float!(1e-6),
+            basis: vec![],
+            points: vec![],
+            values: vec![],
+            constraints: vec![],
+            kopt: 0,
+            rho: float!(0.5),
+            penalty: F::zero(),
+            best: (F::infinity(), F::zero()),
+            improve_geometry: false,
+        }
+    }
+
+    /// Set the initial and final radius of the trust region
+    ///
+    /// Both must be positive and the final radius must not exceed the initial one. Default to
+    /// `0.5` and `1e-6`, respectively.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::cobyla::COBYLA;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let cobyla: COBYLA<Vec<f64>, f64> = COBYLA::new().with_trust_region_radius(0.1, 1e-8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_trust_region_radius(mut self, rho_begin: F, rho_end: F) -> Result<Self, Error> {
+        if rho_end <= F::zero() || rho_begin < rho_end {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`COBYLA`: trust region radii must satisfy 0 < rho_end <= rho_begin."
+            ));
+        }
+        self.rho_begin = rho_begin;
+        self.rho_end = rho_end;
+        Ok(self)
+    }
+
+    /// Greatest constraint violation at vertex `idx`
+    fn violation(&self, idx: usize) -> F {
+        violation(&self.constraints[idx])
+    }
+
+    /// Value of the merit function at vertex `idx`
+    fn merit(&self, idx: usize) -> F {
+        self.values[idx] + self.penalty * self.violation(idx)
+    }
+
+    /// Selects the vertex with the lowest merit function value as the best vertex. Ties are
+    /// broken in favour of the smaller constraint violation.
+    fn select_best(&mut self) {
+        for i in 0..self.points.len() {
+            let (merit, best_merit) = (self.merit(i), self.merit(self.kopt));
+            if merit < best_merit
+                || (merit <= best_merit && self.violation(i) < self.violation(self.kopt))
+            {
+                self.kopt = i;
+            }
+        }
+    }
+
+    /// Halves the trust region radius. Returns `false` if the final radius has already been
+    /// reached.
+    fn reduce_rho(&mut self) -> bool {
+        if self.rho <= self.rho_end {
+            return false;
+        }
+        self.rho = float!(0.5) * self.rho;
+        if self.rho <= float!(1.5) * self.rho_end {
+            self.rho = self.rho_end;
+        }
+        true
+    }
+}
+
+impl<P, F> Default for COBYLA<P, F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        COBYLA::new()
+    }
+}
+
+/// Linear approximations of the cost function and the constraints around the best vertex
+struct LinearModel<F> {
+    /// Gradient of the cost function
+    gradient: Vec<F>,
+    /// Gradients of the constraints
+    constraint_gradients: Vec<Vec<F>>,
+    /// Indices of the vertices other than the best vertex
+    others: Vec<usize>,
+    /// Gradients of the linear functions which are one at vertex `others[k]` and zero at all
+    /// other vertices
+    lagrange_gradients: Vec<Vec<F>>,
+}
+
+impl<P, F> COBYLA<P, F>
+where
+    P: Clone + ArgminDot<P, F> + ArgminScaledAdd<P, F, P> + ArgminZeroLike,
+    F: ArgminFloat,
+{
+    /// Evaluates cost function and constraints at the point with coordinates `coords`
+    fn evaluate<O>(&self, problem: &mut Problem<O>, coords: &[F]) -> Result<(F, Vec<F>), Error>
+    where
+        O: CostFunction<Param = P, Output = F> + InequalityConstraint<Param = P, Output = Vec<F>>,
+    {
+        let param = param_from_coords(&self.basis, coords);
+        let value = problem.cost(&param)?;
+        let constraints = problem.inequality_constraint(&param)?;
+        if !self.constraints.is_empty() && constraints.len() != self.constraints[0].len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`COBYLA`: number of constraints must not change between evaluations."
+            ));
+        }
+        Ok((value, constraints))
+    }
+
+    /// Replaces vertex `idx` by the point with coordinates `x`
+    fn replace_vertex(&mut self, idx: usize, x: Vec<F>, value: F, constraints: Vec<F>) {
+        self.points[idx] = x;
+        self.values[idx] = value;
+        self.constraints[idx] = constraints;
+    }
+
+    /// Computes the linear interpolation of cost function and constraints on the simplex
+    fn linear_model(&self) -> Result<LinearModel<F>, Error> {
+        let xopt = &self.points[self.kopt];
+        let others: Vec<usize> = (0..self.points.len()).filter(|&i| i != self.kopt).collect();
+        let edges: Vec<Vec<F>> = others
+            .iter()
+            .map(|&i| {
+                self.points[i]
+                    .iter()
+                    .zip(xopt)
+                    .map(|(&a, &b)| a - b)
+                    .collect()
+            })
+            .collect();
+
+        // The gradient of the linear interpolation of the values `v` solves `E g = v - v_opt`,
+        // where the rows of `E` are the edges of the simplex at the best vertex.
+        let interpolate = |differences: Vec<F>| -> Result<Vec<F>, Error> {
+            edges
+                .solve(&differences)
+                .map_err(|_| argmin_error!(ConditionViolated, "`COBYLA`: simplex is degenerate."))
+        };
+        let fopt = self.values[self.kopt];
+        let gradient = interpolate(others.iter().map(|&i| self.values[i] - fopt).collect())?;
+        let copt = &self.constraints[self.kopt];
+        let constraint_gradients = (0..copt.len())
+            .map(|j| {
+                interpolate(
+                    others
+                        .iter()
+                        .map(|&i| self.constraints[i][j] - copt[j])
+                        .collect(),
+                )
+            })
+            .collect::<Result<_, _>>()?;
+        let lagrange_gradients = (0..others.len())
+            .map(|k| {
+                let mut unit = vec![F::zero(); others.len()];
+                unit[k] = F::one();
+                interpolate(unit)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(LinearModel {
+            gradient,
+            constraint_gradients,
+            others,
+            lagrange_gradients,
+        })
+    }
+
+    /// Whether the simplex is acceptable: no vertex may be farther than `2.1 rho` away from the
+    /// best vertex and no vertex may be closer than `0.25 rho` to the face spanned by the other
+    /// vertices. Returns the index of the vertex to be moved otherwise.
+    fn unacceptable_vertex(&self, model: &LinearModel<F>) -> Option<usize> {
+        let xopt = &self.points[self.kopt];
+        let (far_k, far_dist) = model
+            .others
+            .iter()
+            .map(|&i| distance(&self.points[i], xopt))
+            .enumerate()
+            .fold(
+                (0, F::zero()),
+                |(ka, a), (kb, b)| if b > a { (kb, b) } else { (ka, a) },
+            );
+        if far_dist > float!(2.1) * self.rho {
+            return Some(far_k);
+        }
+        // Distance of vertex `k` from the opposite face is the inverse of the norm of the
+        // gradient of its Lagrange function.
+        let (flat_k, flat_dist) = (0..model.others.len())
+            .map(|k| F::one() / norm(&model.lagrange_gradients[k]))
+            .enumerate()
+            .fold(
+                (0, F::infinity()),
+                |(ka, a), (kb, b)| if b < a { (kb, b) } else { (ka, a) },
+            );
+        if flat_dist < float!(0.25) * self.rho {
+            return Some(flat_k);
+        }
+        None
+    }
+
+    /// Moves vertex `others[k]` to the best vertex plus a step of length `0.5 rho` orthogonal to
+    /// the face spanned by the remaining vertices. The sign of the step is chosen such that the
+    /// linearized merit function is smaller.
+    fn geometry_step<O>(
+        &mut self,
+        problem: &mut Problem<O>,
+        model: &LinearModel<F>,
+        k: usize,
+    ) -> Result<(), Error>
+    where
+        O: CostFunction<Param = P, Output = F> + InequalityConstraint<Param = P, Output = Vec<F>>,
+    {
+        let direction = &model.lagrange_gradients[k];
+        let scale = float!(0.5) * self.rho / norm(direction);
+        let step: Vec<F> = direction.iter().map(|&d| d * scale).collect();
+
+        let copt = &self.constraints[self.kopt];
+        let linear_merit = |sign: F| {
+            let constraints: Vec<F> = copt
+                .iter()
+                .zip(&model.constraint_gradients)
+                .map(|(&c, a)| c + sign * dot(a, &step))
+                .collect();
+            sign * dot(&model.gradient, &step) + self.penalty * violation(&constraints)
+        };
+        let sign = if linear_merit(-F::one()) < linear_merit(F::one()) {
+            -F::one()
+        } else {
+            F::one()
+        };
+
+        let x: Vec<F> = self.points[self.kopt]
+            .iter()
+            .zip(&step)
+            .map(|(&a, &b)| a + sign * b)
+            .collect();
+        let (value, constraints) = self.evaluate(problem, &x)?;
+        self.replace_vertex(model.others[k], x, value, constraints);
+        Ok(())
+    }
+}
+
+impl<O, P, F> Solver<O, IterState<P, (), (), (), F>> for COBYLA<P, F>
+where
+    O: CostFunction<Param = P, Output = F> + InequalityConstraint<Param = P, Output = Vec<F>>,
+    P: Clone + SerializeAlias + ArgminBasis + ArgminDot<P, F> + ArgminScaledAdd<P, F, P>,
+    P: ArgminZeroLike,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "COBYLA";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`COBYLA` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        self.basis = param.basis_like();
+        let x0 = to_coords(&self.basis, &param);
+
+        self.points = vec![x0.clone()];
+        for i in 0..x0.len() {
+            let mut point = x0.clone();
+            point[i] = point[i] + self.rho_begin;
+            self.points.push(point);
+        }
+        self.values = vec![];
+        self.constraints = vec![];
+        for i in 0..self.points.len() {
+            let (value, constraints) = self.evaluate(problem, &self.points[i])?;
+            self.values.push(value);
+            self.constraints.push(constraints);
+        }
+        self.rho = self.rho_begin;
+        self.penalty = F::zero();
+        self.kopt = 0;
+        self.improve_geometry = false;
+        self.select_best();
+
+        let (value, violation) = (self.values[self.kopt], self.violation(self.kopt));
+        self.best = (value, violation);
+        Ok((
+            state
+                .param(param_from_coords(&self.basis, &self.points[self.kopt]))
+                .cost(self.merit(self.kopt)),
+            Some(kv!(
+                "objective" => value;
+                "constraint_violation" => violation;
+                "feasible" => violation <= F::zero();
+            )),
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let model = self.linear_model()?;
+        let unacceptable = self.unacceptable_vertex(&model);
+        let mut converged = false;
+
+        if let (true, Some(k)) = (self.improve_geometry, unacceptable) {
+            self.geometry_step(problem, &model, k)?;
+            self.improve_geometry = false;
+        } else {
+            self.improve_geometry = false;
+            let copt = self.constraints[self.kopt].clone();
+            let d = trust_region_step(
+                &model.gradient,
+                &model.constraint_gradients,
+                &copt,
+                self.rho,
+            );
+
+            if norm(&d) < float!(0.5) * self.rho {
+                // The step is too short to be worth a function evaluation: improve the geometry
+                // of the simplex or increase the resolution.
+                match unacceptable {
+                    Some(k) => self.geometry_step(problem, &model, k)?,
+                    None => converged = !self.reduce_rho(),
+                }
+            } else {
+                let violation_old = violation(&copt);
+                let violation_new = copt
+                    .iter()
+                    .zip(&model.constraint_gradients)
+                    .fold(F::zero(), |acc, (&c, a)| acc.max(-(c + dot(a, &d))));
+                let linear_change = dot(&model.gradient, &d);
+
+                // Increase the penalty parameter if required for the step to reduce the
+                // linearized merit function.
+                let mut best_changed = false;
+                if violation_new < violation_old {
+                    let min_penalty = linear_change / (violation_old - violation_new);
+                    if self.penalty < float!(1.5) * min_penalty {
+                        self.penalty = float!(2.0) * min_penalty;
+                        let kopt = self.kopt;
+                        self.select_best();
+                        best_changed = kopt != self.kopt;
+                    }
+                }
+
+                // If the best vertex changed, the linear model needs to be recomputed first.
+                if !best_changed {
+                    let merit_old = self.merit(self.kopt);
+                    let x: Vec<F> = self.points[self.kopt]
+                        .iter()
+                        .zip(&d)
+                        .map(|(&a, &b)| a + b)
+                        .collect();
+                    let (value, constraints) = self.evaluate(problem, &x)?;
+                    let merit_new = value + self.penalty * violation(&constraints);
+                    let predicted = self.penalty * (violation_old - violation_new) - linear_change;
+                    let ratio = if predicted > F::zero() {
+                        (merit_old - merit_new) / predicted
+                    } else {
+                        float!(-1.0)
+                    };
+
+                    // Replace the vertex whose replacement keeps the simplex volume largest,
+                    // weighted by its distance to the trial point. The best vertex may only be
+                    // replaced if the trial point is better.
+                    let theta: Vec<F> = (0..model.others.len())
+                        .map(|k| dot(&model.lagrange_gradients[k], &d))
+                        .collect();
+                    let mut candidates: Vec<(usize, F)> = model
+                        .others
+                        .iter()
+                        .zip(&theta)
+                        .map(|(&i, &t)| (i, t))
+                        .collect();
+                    if merit_new < merit_old {
+                        let theta_opt = theta.iter().fold(F::one(), |acc, &t| acc - t);
+                        candidates.push((self.kopt, theta_opt));
+                    }
+                    let mut replace = None;
+                    let mut max_score = F::zero();
+                    for (i, t) in candidates {
+                        let dist = distance(&self.points[i], &x) / self.rho;
+                        let score = t.abs() * dist.max(F::one());
+                        if score > max_score {
+                            replace = Some(i);
+                            max_score = score;
+                        }
+                    }
+                    if let Some(idx) = replace {
+                        self.replace_vertex(idx, x, value, constraints);
+                    }
+
+                    if ratio < float!(0.1) {
+                        if unacceptable.is_some() {
+                            self.improve_geometry = true;
+                        } else {
+                            converged = !self.reduce_rho();
+                        }
+                    }
+                }
+            }
+        }
+
+        self.select_best();
+        let (value, violation) = (self.values[self.kopt], self.violation(self.kopt));
+        let merit = self.merit(self.kopt);
+        // The merit of the best parameter vector of the state is updated to the current penalty
+        // parameter, such that it is compared with the current vertex consistently.
+        state.best_cost = self.best.0 + self.penalty * self.best.1;
+        if merit < state.best_cost {
+            self.best = (value, violation);
+        }
+        let state = state
+            .param(param_from_coords(&self.basis, &self.points[self.kopt]))
+            .cost(merit);
+        let state = if converged {
+            state.terminate_with(TerminationReason::SolverConverged)
+        } else {
+            state
+        };
+        Ok((
+            state,
+            Some(kv!(
+                "objective" => value;
+                "rho" => self.rho;
+                "penalty" => self.penalty;
+                "constraint_violation" => violation;
+                "feasible" => violation <= F::zero();
+            )),
+        ))
+    }
+}
+
+/// Greatest violation of the constraints `c >= 0`
+fn violation<F: ArgminFloat>(constraints: &[F]) -> F {
+    constraints.iter().fold(F::zero(), |acc, &c| acc.max(-c))
+}
+
+/// Computes the trial step `d` with `||d|| <= rho` from the linear approximations
+/// `f + g^T d` of the cost function and `c_i + a_i^T d` of the constraints.
+///
+/// First, the greatest violation `t` of the linearized constraints is minimized. Then the
+/// linearized cost function is minimized subject to `c_i + a_i^T d >= -t`. Both stages end early
+/// if the boundary of the trust region is reached.
+fn trust_region_step<F: ArgminFloat>(g: &[F], a: &[Vec<F>], c: &[F], rho: F) -> Vec<F> {
+    let n = g.len();
+    let t0 = violation(c);
+
+    let (d, t) = if t0 > F::zero() {
+        // Variables (d, t): minimize t subject to a_i^T d + t >= -c_i and t >= 0
+        let mut objective = vec![F::zero(); n + 1];
+        objective[n] = F::one();
+        let mut rows: Vec<Vec<F>> = a
+            .iter()
+            .map(|ai| {
+                let mut row = ai.clone();
+                row.push(F::one());
+                row
+            })
+            .collect();
+        rows.push(objective.clone());
+        let mut rhs: Vec<F> = c.iter().map(|&ci| -ci).collect();
+        rhs.push(F::zero());
+        let mut start = vec![F::zero(); n + 1];
+        start[n] = t0;
+        let mut y = linear_program_in_ball(&objective, &rows, &rhs, start, n, rho);
+        let t = y.pop().unwrap().max(F::zero());
+        (y, t)
+    } else {
+        (vec![F::zero(); n], F::zero())
+    };
+
+    let rhs: Vec<F> = c.iter().map(|&ci| -ci - t).collect();
+    linear_program_in_ball(g, a, &rhs, d, n, rho)
+}
+
+/// Minimizes `objective^T y` subject to `rows_i^T y >= rhs_i` and `||y[..n]|| <= rho` with an
+/// active set method, starting from the feasible point `y`. The method moves along the projected
+/// steepest descent direction until a constraint becomes active and releases constraints with
+/// negative Lagrange multipliers. It ends once the boundary of the ball is reached.
+fn linear_program_in_ball<F: ArgminFloat>(
+    objective: &[F],
+    rows: &[Vec<F>],
+    rhs: &[F],
+    mut y: Vec<F>,
+    n: usize,
+    rho: F,
+) -> Vec<F> {
+    let dim = y.len();
+    let eps = F::epsilon().sqrt();
+    let mut active: Vec<usize> = vec![];
+
+    for _ in 0..2 * (rows.len() + dim) + 1 {
+        // Orthonormal basis `q` of the span of the active constraint gradients with
+        // `rows[active[k]] = sum_l l_mat[k][l] * q[l]`. Linearly dependent constraints are
+        // released.
+        let mut q: Vec<Vec<F>> = vec![];
+        let mut l_mat: Vec<Vec<F>> = vec![];
+        active.retain(|&i| {
+            let mut v = rows[i].clone();
+            let coefficients: Vec<F> = q.iter().map(|qk| dot(qk, &rows[i])).collect();
+            for (qk, &ck) in q.iter().zip(&coefficients) {
+                for (vj, &qj) in v.iter_mut().zip(qk) {
+                    *vj = *vj - ck * qj;
+                }
+            }
+            let v_norm = norm(&v);
+            if v_norm <= eps * norm(&rows[i]) {
+                return false;
+            }
+            let mut coefficients = coefficients;
+            coefficients.push(v_norm);
+            l_mat.push(coefficients);
+            q.push(v.into_iter().map(|x| x / v_norm).collect());
+            true
+        });
+
+        // Projected steepest descent direction
+        let mut s: Vec<F> = objective.iter().map(|&x| -x).collect();
+        for qk in &q {
+            let c = dot(qk, &s);
+            for (sj, &qj) in s.iter_mut().zip(qk) {
+                *sj = *sj - c * qj;
+            }
+        }
+
+        if norm(&s) <= eps * norm(objective) {
+            // Lagrange multipliers from `sum_k lambda_k rows[active[k]] = objective`
+            let b: Vec<F> = q.iter().map(|qk| dot(qk, objective)).collect();
+            let mut lambda = vec![F::zero(); q.len()];
+            for k in (0..q.len()).rev() {
+                let sum =
+                    ((k + 1)..q.len()).fold(F::zero(), |acc, j| acc + l_mat[j][k] * lambda[j]);
+                lambda[k] = (b[k] - sum) / l_mat[k][k];
+            }
+            let (k_min, lambda_min) =
+                lambda
+                    .iter()
+                    .enumerate()
+                    .fold(
+                        (0, F::zero()),
+                        |(ka, a), (kb, &b)| if b < a { (kb, b) } else { (ka, a) },
+                    );
+            if lambda_min < F::zero() {
+                active.remove(k_min);
+                continue;
+            }
+            break;
+        }
+
+        // Step length to the boundary of the ball
+        let (yy, ys, ss) = (
+            dot(&y[..n], &y[..n]),
+            dot(&y[..n], &s[..n]),
+            dot(&s[..n], &s[..n]),
+        );
+        let alpha_ball = if ss > F::zero() {
+            (-ys + (ys * ys + ss * (rho * rho - yy)).max(F::zero()).sqrt()) / ss
+        } else {
+            F::infinity()
+        };
+        // Step length to the nearest inactive constraint
+        let s_norm = norm(&s);
+        let mut alpha_con = F::infinity();
+        let mut blocking = None;
+        for (i, (row, &b)) in rows.iter().zip(rhs).enumerate() {
+            if active.contains(&i) {
+                continue;
+            }
+            let rs = dot(row, &s);
+            if rs < -eps * norm(row) * s_norm {
+                let alpha = ((dot(row, &y) - b) / -rs).max(F::zero());
+                if alpha < alpha_con {
+                    alpha_con = alpha;
+                    blocking = Some(i);
+                }
+            }
+        }
+
+        let alpha = alpha_ball.min(alpha_con);
+        if alpha.is_infinite() {
+            break;
+        }
+        for (yj, &sj) in y.iter_mut().zip(&s) {
+            *yj = *yj + alpha * sj;
+        }
+        match blocking {
+            Some(i) if alpha_con < alpha_ball => active.push(i),
+            _ => break,
+        }
+    }
+    y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(cobyla, COBYLA<Vec<f64>, f64>);
+
+    /// Minimize `x + y` on the unit disk. The solution is `-(1, 1) / sqrt(2)`.
+    struct Disk {}
+
+    impl CostFunction for Disk {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p[0] + p[1])
+        }
+    }
+
+    impl InequalityConstraint for Disk {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn inequality_constraint(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![1.0 - p[0].powi(2) - p[1].powi(2)])
+        }
+    }
+
+    /// Rosenbrock function subject to `x + y <= 1.5` and `x >= 0`. The solution lies on the first
+    /// constraint.
+    struct ConstrainedRosenbrock {}
+
+    impl CostFunction for ConstrainedRosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((1.0 - p[0]).powi(2) + 100.0 * (p[1] - p[0].powi(2)).powi(2))
+        }
+    }
+
+    impl InequalityConstraint for ConstrainedRosenbrock {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn inequality_constraint(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![1.5 - p[0] - p[1], p[0]])
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let cobyla: COBYLA<Vec<f64>, f64> = COBYLA::new();
+        assert_eq!(cobyla.rho_begin.to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert_eq!(cobyla.rho_end.to_ne_bytes(), 1e-6f64.to_ne_bytes());
+        assert_eq!(cobyla.penalty.to_ne_bytes(), 0.0f64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_with_trust_region_radius() {
+        let cobyla: COBYLA<Vec<f64>, f64> =
+            COBYLA::new().with_trust_region_radius(0.1, 1e-8).unwrap();
+        assert_eq!(cobyla.rho_begin.to_ne_bytes(), 0.1f64.to_ne_bytes());
+        assert_eq!(cobyla.rho_end.to_ne_bytes(), 1e-8f64.to_ne_bytes());
+
+        for (rho_begin, rho_end) in [(0.1, 0.0), (0.1, -1.0), (0.1, 0.2)] {
+            let res: Result<COBYLA<Vec<f64>, f64>, _> =
+                COBYLA::new().with_trust_region_radius(rho_begin, rho_end);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`COBYLA`: trust region radii must satisfy 0 < rho_end <= rho_begin.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_init_param_not_initialized() {
+        let mut cobyla: COBYLA<Vec<f64>, f64> = COBYLA::new();
+        let res = cobyla.init(&mut Problem::new(Disk {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`COBYLA` requires an initial parameter vector. Please ",
+                "provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_init() {
+        let mut cobyla: COBYLA<Vec<f64>, f64> = COBYLA::new();
+        let mut problem = Problem::new(Disk {});
+        let (state, kv) = cobyla
+            .init(&mut problem, IterState::new().param(vec![1.0, 1.0]))
+            .unwrap();
+        assert_eq!(
+            cobyla.points,
+            vec![vec![1.0, 1.0], vec![1.5, 1.0], vec![1.0, 1.5]]
+        );
+        assert_eq!(problem.counts["cost_count"], 3);
+        assert_eq!(problem.counts["inequality_constraint_count"], 3);
+        // Without penalty, the vertex with the lowest cost is the best one.
+        assert_eq!(cobyla.kopt, 0);
+        assert_eq!(*state.get_param().unwrap(), vec![1.0, 1.0]);
+        assert_eq!(state.get_cost().to_ne_bytes(), 2.0f64.to_ne_bytes());
+        let kv = kv.unwrap();
+        assert_eq!(
+            kv.get("constraint_violation").unwrap().get_float(),
+            Some(1.0)
+        );
+        assert_eq!(kv.get("feasible").unwrap().get_bool(), Some(false));
+    }
+
+    #[test]
+    fn test_linear_model() {
+        let mut cobyla: COBYLA<Vec<f64>, f64> = COBYLA::new();
+        cobyla.points = vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![0.0, 2.0]];
+        cobyla.values = vec![1.0, 3.0, -1.0];
+        cobyla.constraints = vec![vec![0.0], vec![1.0], vec![1.0]];
+        let model = cobyla.linear_model().unwrap();
+        assert_relative_eq!(model.gradient[0], 2.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(model.gradient[1], -1.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(
+            model.constraint_gradients[0][0],
+            1.0,
+            epsilon = f64::EPSILON
+        );
+        assert_relative_eq!(
+            model.constraint_gradients[0][1],
+            0.5,
+            epsilon = f64::EPSILON
+        );
+
+        cobyla.points[2] = vec![2.0, 0.0];
+        let res = cobyla.linear_model();
+        assert_error!(
+            res,
+            ArgminError,
+            "Condition violated: \"`COBYLA`: simplex is degenerate.\""
+        );
+    }
+
+    #[test]
+    fn test_unacceptable_vertex() {
+        let mut cobyla: COBYLA<Vec<f64>, f64> = COBYLA::new();
+        cobyla.rho = 1.0;
+        cobyla.values = vec![0.0; 3];
+        cobyla.constraints = vec![vec![]; 3];
+
+        cobyla.points = vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![0.0, 1.0]];
+        let model = cobyla.linear_model().unwrap();
+        assert!(cobyla.unacceptable_vertex(&model).is_none());
+
+        // too far away
+        cobyla.points = vec![vec![0.0, 0.0], vec![3.0, 0.0], vec![0.0, 1.0]];
+        let model = cobyla.linear_model().unwrap();
+        assert_eq!(cobyla.unacceptable_vertex(&model), Some(0));
+
+        // too flat
+        cobyla.points = vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![0.1, 0.1]];
+        let model = cobyla.linear_model().unwrap();
+        assert_eq!(cobyla.unacceptable_vertex(&model), Some(1));
+    }
+
+    #[test]
+    fn test_reduce_rho() {
+        let mut cobyla: COBYLA<Vec<f64>, f64> =
+            COBYLA::new().with_trust_region_radius(1.0, 0.2).unwrap();
+        cobyla.rho = 1.0;
+        assert!(cobyla.reduce_rho());
+        assert_relative_eq!(cobyla.rho, 0.5, epsilon = f64::EPSILON);
+        assert!(cobyla.reduce_rho());
+        assert_relative_eq!(cobyla.rho, 0.2, epsilon = f64::EPSILON);
+        assert!(!cobyla.reduce_rho());
+    }
+
+    #[test]
+    fn test_trust_region_step_unconstrained() {
+        let d = trust_region_step(&[3.0, 4.0], &[], &[], 2.0);
+        assert_relative_eq!(d[0], -1.2, epsilon = 1e-12);
+        assert_relative_eq!(d[1], -1.6, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_trust_region_step_constrained() {
+        // minimize -x subject to 0.5 - x >= 0 within radius 2
+        let d = trust_region_step(&[-1.0, 0.0], &[vec![-1.0, 0.0]], &[0.5], 2.0);
+        assert_relative_eq!(d[0], 0.5, epsilon = 1e-12);
+        // the cost is constant along the constraint
+        assert_relative_eq!(d[1], 0.0, epsilon = 1e-12);
+
+        // minimize -x - y subject to 0.5 - x >= 0 within radius 1
+        let d = trust_region_step(&[-1.0, -1.0], &[vec![-1.0, 0.0]], &[0.5], 1.0);
+        assert_relative_eq!(d[0], 0.5, epsilon = 1e-12);
+        assert_relative_eq!(d[1], 0.75f64.sqrt(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_trust_region_step_infeasible() {
+        // The linearized constraint x - 1 >= 0 is violated at d = 0 and the cost pushes in the
+        // opposite direction. Feasibility takes precedence.
+        let d = trust_region_step(&[1.0, 0.0], &[vec![1.0, 0.0]], &[-1.0], 2.0);
+        assert_relative_eq!(d[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(d[1], 0.0, epsilon = 1e-12);
+
+        // Feasibility can not be reached within the trust region.
+        let d = trust_region_step(&[1.0, 0.0], &[vec![1.0, 0.0]], &[-3.0], 2.0);
+        assert_relative_eq!(d[0], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(d[1], 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_solver_disk() {
+        let cobyla = COBYLA::new().with_trust_region_radius(0.5, 1e-8).unwrap();
+        let res = Executor::new(Disk {}, cobyla)
+            .configure(|state| state.param(vec![1.0, 1.0]).max_iters(1000))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_param().unwrap();
+        let expected = -1.0 / 2.0f64.sqrt();
+        assert_relative_eq!(param[0], expected, epsilon = 1e-6);
+        assert_relative_eq!(param[1], expected, epsilon = 1e-6);
+        // Points outside of the disk may have a lower cost, but must not be the best.
+        let best_param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(best_param[0], expected, epsilon = 1e-6);
+        assert_relative_eq!(best_param[1], expected, epsilon = 1e-6);
+        assert_eq!(
+            res.problem.counts["cost_count"],
+            res.problem.counts["inequality_constraint_count"]
+        );
+    }
+
+    #[test]
+    fn test_solver_rosenbrock() {
+        let cobyla = COBYLA::new().with_trust_region_radius(0.5, 1e-8).unwrap();
+        let res = Executor::new(ConstrainedRosenbrock {}, cobyla)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(10000))
+            .run()
+            .unwrap();
+        let param = res.state.get_param().unwrap();
+        // On x + y = 1.5, the minimum of the Rosenbrock function satisfies
+        // 2 (x - 1) - 200 (1.5 - x - x^2) (1 + 2x) = 0.
+        let x = param[0];
+        assert!(1.5 - param[0] - param[1] >= -1e-7);
+        assert_relative_eq!(param[0] + param[1], 1.5, epsilon = 1e-6);
+        assert_relative_eq!(
+            2.0 * (x - 1.0) - 200.0 * (1.5 - x - x * x) * (1.0 + 2.0 * x),
+            0.0,
+            epsilon = 1e-3
+        );
+    }
+}
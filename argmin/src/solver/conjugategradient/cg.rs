@@ -8,7 +8,10 @@
 use crate::core::{
     ArgminFloat, Error, IterState, Operator, Problem, SerializeAlias, Solver, State, KV,
 };
-use argmin_math::{ArgminConj, ArgminDot, ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminSub};
+use argmin_math::{
+    ArgminConj, ArgminDot, ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminScaledAddAssign,
+    ArgminSub,
+};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -97,6 +100,7 @@ where
         + ArgminDot<P, F>
         + ArgminSub<P, P>
         + ArgminScaledAdd<P, F, P>
+        + ArgminScaledAddAssign<P, F>
         + ArgminConj
         + ArgminMul<F, P>,
     F: ArgminFloat + ArgminL2Norm<F>,
@@ -133,7 +137,7 @@ where
             PotentialBug,
             "`ConjugateGradient`: Field `p` not set"
         ))?;
-        let r = self.r.as_ref().ok_or_else(argmin_error_closure!(
+        let mut r = self.r.take().ok_or_else(argmin_error_closure!(
             PotentialBug,
             "`ConjugateGradient`: Field `r` not set"
         ))?;
@@ -145,11 +149,12 @@ where
             "`ConjugateGradient`: Parameter vector in `state` not set"
         ))?;
         let new_param = state_param.scaled_add(&alpha, &p);
-        let r = r.scaled_add(&alpha, &apk);
+        r.scaled_add_assign(&alpha, &apk);
         let rtr_n = r.dot(&r.conj());
         let beta = rtr_n.div(self.rtr);
         self.rtr = rtr_n;
-        let p_n = r.mul(&(float!(-1.0))).scaled_add(&beta, &p);
+        let mut p_n = r.mul(&(float!(-1.0)));
+        p_n.scaled_add_assign(&beta, &p);
         let norm = r.dot(&r.conj()).l2_norm();
 
         self.p = Some(p_n);
@@ -342,4 +347,54 @@ mod tests {
         assert_relative_eq!(norm, state.get_cost());
         assert_relative_eq!(new_param, state.get_param().unwrap()[0]);
     }
+
+    #[cfg(feature = "_sprsl")]
+    #[test]
+    fn test_sparse() {
+        use crate::core::{Executor, Operator, State};
+        use argmin_math::ArgminDot;
+        use sprs::{CsMat, TriMat};
+
+        /// Symmetric positive definite sparse matrix
+        fn sparse_matrix() -> CsMat<f64> {
+            let mut a = TriMat::new((3, 3));
+            for (i, j, v) in [
+                (0, 0, 4.0),
+                (0, 1, 1.0),
+                (1, 0, 1.0),
+                (1, 1, 3.0),
+                (1, 2, 1.0),
+                (2, 1, 1.0),
+                (2, 2, 2.0),
+            ] {
+                a.add_triplet(i, j, v);
+            }
+            a.to_csr()
+        }
+
+        /// Linear operator given by a sparse matrix
+        struct SparseOperator {
+            a: CsMat<f64>,
+        }
+
+        impl Operator for SparseOperator {
+            type Param = Vec<f64>;
+            type Output = Vec<f64>;
+
+            fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(self.a.dot(x))
+            }
+        }
+
+        let problem = SparseOperator { a: sparse_matrix() };
+        let solver: ConjugateGradient<_, f64> = ConjugateGradient::new(vec![1.0, 2.0, 3.0]);
+        let res = Executor::new(problem, solver)
+            .configure(|state| state.param(vec![0.0, 0.0, 0.0]).max_iters(3))
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 2.0 / 9.0, epsilon = 1e-12);
+        assert_relative_eq!(param[1], 1.0 / 9.0, epsilon = 1e-12);
+        assert_relative_eq!(param[2], 13.0 / 9.0, epsilon = 1e-12);
+    }
 }
@@ -9,7 +9,9 @@ use crate::core::{
     ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState,
     LineSearch, NLCGBetaUpdate, OptimizationResult, Problem, SerializeAlias, Solver, State, KV,
 };
-use argmin_math::{ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul};
+use argmin_math::{
+    ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminMulAssign, ArgminSubAssign,
+};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -122,7 +124,13 @@ impl<O, P, G, L, B, F> Solver<O, IterState<P, G, (), (), F>>
     for NonlinearConjugateGradient<P, L, B, F>
 where
     O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
-    P: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminAdd<P, P> + ArgminMul<F, P>,
+    P: Clone
+        + SerializeAlias
+        + DeserializeOwnedAlias
+        + ArgminAdd<P, P>
+        + ArgminMul<F, P>
+        + ArgminMulAssign<F>
+        + ArgminSubAssign<G>,
     G: Clone
         + SerializeAlias
         + DeserializeOwnedAlias
@@ -158,7 +166,7 @@ where
         problem: &mut Problem<O>,
         mut state: IterState<P, G, (), (), F>,
     ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
-        let p = self.p.as_ref().ok_or_else(argmin_error_closure!(
+        let mut p = self.p.take().ok_or_else(argmin_error_closure!(
             PotentialBug,
             "`NonlinearConjugateGradient`: Field `p` not set"
         ))?;
@@ -213,11 +221,13 @@ where
         if restart_iter || restart_orthogonality {
             self.beta = float!(0.0);
         } else {
-            self.beta = self.beta_method.update(&grad, &new_grad, p);
+            self.beta = self.beta_method.update(&grad, &new_grad, &p);
         }
 
         // Update of p
-        self.p = Some(new_grad.mul(&(float!(-1.0))).add(&p.mul(&self.beta)));
+        p.mul_assign(&self.beta);
+        p.sub_assign(&new_grad);
+        self.p = Some(p);
 
         // Housekeeping
         let cost = problem.cost(&xk1)?;
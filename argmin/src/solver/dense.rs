@@ -0,0 +1,361 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Dense linear algebra on coordinate vectors
+//!
+//! Some solvers work on the coordinates of the parameter vectors internally, for instance to
+//! build interpolation models or to solve quadratic subproblems. These helpers operate on such
+//! coordinates.
+
+use crate::core::ArgminFloat;
+use argmin_math::{ArgminCholesky, ArgminDot, ArgminScaledAdd, ArgminZeroLike};
+
+/// Cholesky factorization `L L^T` of a symmetric positive definite matrix, which is used to solve
+/// linear systems with this matrix
+pub(crate) struct Cholesky<F> {
+    /// Lower triangular factor `L`
+    l: Vec<Vec<F>>,
+}
+
+impl<F: ArgminFloat> Cholesky<F> {
+    /// Factorizes `a` via [`ArgminCholesky`]. Returns `None` if `a` is not (numerically) positive
+    /// definite.
+    pub(crate) fn new(a: &Vec<Vec<F>>) -> Option<Self> {
+        let l = a.cholesky().ok()?;
+        // The factorization only fails for non-positive pivots, while pivots which are tiny
+        // compared to the diagonal of `a` indicate that `a` is numerically singular as well.
+        let scale = a
+            .iter()
+            .enumerate()
+            .fold(F::zero(), |acc, (i, row)| acc.max(row[i]));
+        if l.iter()
+            .enumerate()
+            .any(|(i, row)| row[i] * row[i] <= F::epsilon() * scale)
+        {
+            return None;
+        }
+        Some(Cholesky { l })
+    }
+
+    /// Factorization of the identity matrix of dimension `n`
+    pub(crate) fn identity(n: usize) -> Self {
+        Cholesky { l: identity(n) }
+    }
+
+    /// Solves `a x = b` for `x`, where `a` is the factorized matrix, by forward substitution with
+    /// `L` followed by back substitution with `L^T`
+    pub(crate) fn solve(&self, b: &[F]) -> Vec<F> {
+        let n = self.l.len();
+        let mut x = b.to_vec();
+        for i in 0..n {
+            let sum = (0..i).fold(x[i], |acc, j| acc - self.l[i][j] * x[j]);
+            x[i] = sum / self.l[i][i];
+        }
+        for i in (0..n).rev() {
+            let sum = (i + 1..n).fold(x[i], |acc, j| acc - self.l[j][i] * x[j]);
+            x[i] = sum / self.l[i][i];
+        }
+        x
+    }
+}
+
+/// Coordinates of `param` with respect to the orthonormal basis `basis`
+pub(crate) fn to_coords<P, F>(basis: &[P], param: &P) -> Vec<F>
+where
+    P: ArgminDot<P, F>,
+{
+    basis.iter().map(|e| param.dot(e)).collect()
+}
+
+/// Parameter vector with coordinates `coords` with respect to the non-empty basis `basis`
+pub(crate) fn param_from_coords<P, F>(basis: &[P], coords: &[F]) -> P
+where
+    P: ArgminScaledAdd<P, F, P> + ArgminZeroLike,
+{
+    basis
+        .iter()
+        .zip(coords)
+        .fold(basis[0].zero_like(), |acc, (e, c)| acc.scaled_add(c, e))
+}
+
+/// Dot product of two vectors
+pub(crate) fn dot<F: ArgminFloat>(a: &[F], b: &[F]) -> F {
+    a.iter().zip(b).fold(F::zero(), |acc, (&x, &y)| acc + x * y)
+}
+
+/// Euclidean norm of a vector
+pub(crate) fn norm<F: ArgminFloat>(a: &[F]) -> F {
+    dot(a, a).sqrt()
+}
+
+/// Euclidean distance between two points
+pub(crate) fn distance<F: ArgminFloat>(a: &[F], b: &[F]) -> F {
+    a.iter()
+        .zip(b)
+        .fold(F::zero(), |acc, (&x, &y)| acc + (x - y) * (x - y))
+        .sqrt()
+}
+
+/// Product of a matrix, stored as rows, and a vector
+pub(crate) fn mat_vec<F: ArgminFloat>(a: &[Vec<F>], x: &[F]) -> Vec<F> {
+    a.iter().map(|row| dot(row, x)).collect()
+}
+
+/// Identity matrix of dimension `n`
+pub(crate) fn identity<F: ArgminFloat>(n: usize) -> Vec<Vec<F>> {
+    (0..n)
+        .map(|i| {
+            let mut row = vec![F::zero(); n];
+            row[i] = F::one();
+            row
+        })
+        .collect()
+}
+
+/// Solution of a quadratic program
+pub(crate) struct QpSolution<F> {
+    /// Minimizer
+    pub(crate) x: Vec<F>,
+    /// Multipliers `u` of all constraints, such that `G x + c = sum_i u_i a_i`
+    pub(crate) multipliers: Vec<F>,
+    /// Indices of the constraints in the final active set
+    pub(crate) active: Vec<usize>,
+}
+
+/// Reason why [`solve_qp`] failed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum QpError {
+    /// The constraints are inconsistent
+    Infeasible,
+    /// The maximum number of iterations was reached
+    IterationLimit,
+    /// The normals of the active constraints are numerically linearly dependent
+    Singular,
+}
+
+/// Minimizes `1/2 x^T G x + c^T x` subject to `a_i^T x = b_i` for the first `num_eq` constraints
+/// `(a_i, b_i)` and `a_i^T x >= b_i` for the remaining ones by the dual active set method of
+/// Goldfarb and Idnani. `g` is the Cholesky factorization of the symmetric positive definite
+/// matrix `G`.
+pub(crate) fn solve_qp<F: ArgminFloat>(
+    g: &Cholesky<F>,
+    c: &[F],
+    constraints: &[(Vec<F>, F)],
+    num_eq: usize,
+) -> Result<QpSolution<F>, QpError> {
+    let m = constraints.len();
+    let residual = |i: usize, x: &[F]| dot(&constraints[i].0, x) - constraints[i].1;
+    let tolerance = |i: usize, x: &[F]| {
+        float!(1e3)
+            * F::epsilon()
+            * (F::one() + constraints[i].1.abs() + norm(&constraints[i].0) * norm(x))
+    };
+
+    // Products of `G^-1` with `c` and with the normals of the constraints
+    let g_inv_c = g.solve(c);
+    let g_inv_constraints: Vec<Vec<F>> = constraints.iter().map(|(a, _)| g.solve(a)).collect();
+
+    // Unconstrained minimizer
+    let mut x: Vec<F> = g_inv_c.iter().map(|&v| -v).collect();
+    // Active constraints as (index, sign of the normal, multiplier)
+    let mut active: Vec<(usize, F, F)> = vec![];
+    let max_iters = 10 * (m + x.len()) + 100;
+    let mut iters = 0;
+    let mut next_eq = 0;
+
+    loop {
+        // Select the next constraint: all equality constraints first, then the most violated
+        // inequality constraint. The normals of equality constraints are oriented such that
+        // they are violated in the sense of an inequality constraint.
+        let (p, sign) = if next_eq < num_eq {
+            next_eq += 1;
+            let p = next_eq - 1;
+            let sign = if residual(p, &x) > F::zero() {
+                -F::one()
+            } else {
+                F::one()
+            };
+            (p, sign)
+        } else {
+            let violated = (num_eq..m)
+                .filter(|i| active.iter().all(|(j, _, _)| j != i))
+                .map(|i| (i, residual(i, &x)))
+                .filter(|&(i, r)| r < -tolerance(i, &x))
+                .fold(None, |best: Option<(usize, F)>, (i, r)| match best {
+                    Some((_, rb)) if rb <= r => best,
+                    _ => Some((i, r)),
+                });
+            match violated {
+                Some((p, _)) => (p, F::one()),
+                None => break,
+            }
+        };
+        let normal: Vec<F> = constraints[p].0.iter().map(|&a| sign * a).collect();
+        let g_inv_normal: Vec<F> = g_inv_constraints[p].iter().map(|&a| sign * a).collect();
+        let mut multiplier = F::zero();
+
+        loop {
+            iters += 1;
+            if iters > max_iters {
+                return Err(QpError::IterationLimit);
+            }
+            let s = sign * residual(p, &x);
+
+            // Step direction in the primal space `z` and in the dual space `-r`
+            let normals: Vec<Vec<F>> = active
+                .iter()
+                .map(|&(i, sg, _)| constraints[i].0.iter().map(|&a| sg * a).collect())
+                .collect();
+            let g_inv_normals: Vec<Vec<F>> = active
+                .iter()
+                .map(|&(i, sg, _)| g_inv_constraints[i].iter().map(|&a| sg * a).collect())
+                .collect();
+            let gram: Vec<Vec<F>> = normals
+                .iter()
+                .map(|a| g_inv_normals.iter().map(|b| dot(a, b)).collect())
+                .collect();
+            let r = if active.is_empty() {
+                vec![]
+            } else {
+                let rhs: Vec<F> = normals.iter().map(|a| dot(a, &g_inv_normal)).collect();
+                Cholesky::new(&gram).ok_or(QpError::Singular)?.solve(&rhs)
+            };
+            let z = g_inv_normals
+                .iter()
+                .zip(&r)
+                .fold(g_inv_normal.clone(), |mut acc, (b, &rk)| {
+                    for (a, &bi) in acc.iter_mut().zip(b) {
+                        *a = *a - rk * bi;
+                    }
+                    acc
+                });
+            let zn = dot(&z, &normal);
+            let dependent = zn <= float!(1e3) * F::epsilon() * dot(&normal, &g_inv_normal);
+
+            if dependent && p < num_eq && s.abs() <= tolerance(p, &x) {
+                // consistent with the active equality constraints
+                break;
+            }
+
+            // Full step, which makes constraint `p` active, and partial step, which makes a
+            // multiplier of an active inequality constraint zero
+            let full = if dependent { None } else { Some(-s / zn) };
+            let partial = active
+                .iter()
+                .zip(&r)
+                .enumerate()
+                .filter(|(_, (&(i, _, _), &rk))| i >= num_eq && rk > F::zero())
+                .map(|(k, (&(_, _, u), &rk))| (k, u / rk))
+                .fold(None, |best: Option<(usize, F)>, (k, t)| match best {
+                    Some((_, tb)) if tb <= t => best,
+                    _ => Some((k, t)),
+                });
+
+            let (t, drop) = match (full, partial) {
+                (None, None) => return Err(QpError::Infeasible),
+                (Some(t), None) => (t, None),
+                (None, Some((k, t))) => (t, Some(k)),
+                (Some(tf), Some((k, tp))) => {
+                    if tf <= tp {
+                        (tf, None)
+                    } else {
+                        (tp, Some(k))
+                    }
+                }
+            };
+            if full.is_some() {
+                for (xi, &zi) in x.iter_mut().zip(&z) {
+                    *xi = *xi + t * zi;
+                }
+            }
+            for ((_, _, u), &rk) in active.iter_mut().zip(&r) {
+                *u = *u - t * rk;
+            }
+            multiplier = multiplier + t;
+            match drop {
+                Some(k) => {
+                    active.remove(k);
+                }
+                None => {
+                    active.push((p, sign, multiplier));
+                    break;
+                }
+            }
+        }
+    }
+
+    let mut multipliers = vec![F::zero(); m];
+    for &(i, sign, u) in &active {
+        multipliers[i] = sign * u;
+    }
+    Ok(QpSolution {
+        x,
+        multipliers,
+        active: active.into_iter().map(|(i, _, _)| i).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_cholesky() {
+        let a = vec![
+            vec![4.0, 2.0, 0.0],
+            vec![2.0, 5.0, 1.0],
+            vec![0.0, 1.0, 3.0],
+        ];
+        let x = Cholesky::new(&a).unwrap().solve(&[2.0, -1.0, 5.0]);
+        for (xi, expected) in x.iter().zip([1.0, -1.0, 2.0]) {
+            assert_relative_eq!(*xi, expected, epsilon = 1e-12);
+        }
+
+        assert!(Cholesky::new(&vec![vec![1.0, 2.0], vec![2.0, 4.0]]).is_none());
+        assert!(Cholesky::new(&vec![vec![1.0, 2.0], vec![2.0, 1.0]]).is_none());
+    }
+
+    #[test]
+    fn test_solve_qp() {
+        // min 1/2 (x^2 + y^2) - x - y s.t. x + y = 1, x >= 0.8
+        let g = Cholesky::identity(2);
+        let c = vec![-1.0, -1.0];
+        let constraints = vec![(vec![1.0, 1.0], 1.0), (vec![1.0, 0.0], 0.8)];
+        let QpSolution {
+            x,
+            multipliers,
+            active,
+        } = solve_qp(&g, &c, &constraints, 1).unwrap();
+        assert_relative_eq!(x[0], 0.8, epsilon = 1e-12);
+        assert_relative_eq!(x[1], 0.2, epsilon = 1e-12);
+        // x - 1 = u0 + u1, y - 1 = u0
+        assert_relative_eq!(multipliers[0], -0.8, epsilon = 1e-12);
+        assert_relative_eq!(multipliers[1], 0.6, epsilon = 1e-12);
+        assert_eq!(active, vec![0, 1]);
+
+        // inactive inequality constraint
+        let constraints = vec![(vec![1.0, 1.0], 1.0), (vec![1.0, 0.0], 0.0)];
+        let QpSolution {
+            x,
+            multipliers,
+            active,
+        } = solve_qp(&g, &c, &constraints, 1).unwrap();
+        assert_relative_eq!(x[0], 0.5, epsilon = 1e-12);
+        assert_relative_eq!(x[1], 0.5, epsilon = 1e-12);
+        assert_relative_eq!(multipliers[0], -0.5, epsilon = 1e-12);
+        assert_relative_eq!(multipliers[1], 0.0, epsilon = 1e-12);
+        assert_eq!(active, vec![0]);
+
+        // inconsistent constraints
+        let constraints = vec![(vec![1.0, 0.0], 1.0), (vec![-1.0, 0.0], 0.0)];
+        assert_eq!(
+            solve_qp(&g, &c, &constraints, 0).err(),
+            Some(QpError::Infeasible)
+        );
+    }
+}
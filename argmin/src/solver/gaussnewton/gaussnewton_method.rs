@@ -9,7 +9,7 @@ use crate::core::{
     ArgminFloat, Error, IterState, Jacobian, Operator, Problem, Solver, State, TerminationReason,
     TerminationStatus, KV,
 };
-use argmin_math::{ArgminDot, ArgminInv, ArgminL2Norm, ArgminMul, ArgminSub, ArgminTranspose};
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminMul, ArgminSolve, ArgminSub, ArgminTranspose};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -114,12 +114,7 @@ where
     O: Operator<Param = P, Output = U> + Jacobian<Param = P, Jacobian = J>,
     P: Clone + ArgminSub<P, P> + ArgminMul<F, P>,
     U: ArgminL2Norm<F>,
-    J: Clone
-        + ArgminTranspose<J>
-        + ArgminInv<J>
-        + ArgminDot<J, J>
-        + ArgminDot<U, P>
-        + ArgminDot<P, P>,
+    J: Clone + ArgminTranspose<J> + ArgminDot<J, J> + ArgminDot<U, P> + ArgminSolve<P, P>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "Gauss-Newton method";
@@ -143,8 +138,7 @@ where
             .clone()
             .t()
             .dot(&jacobian)
-            .inv()?
-            .dot(&jacobian.t().dot(&residuals));
+            .solve(&jacobian.t().dot(&residuals))?;
 
         let new_param = param.sub(&p.mul(&self.gamma));
 
@@ -379,4 +373,55 @@ mod tests {
         assert_relative_eq!(param[0], -0.7, epsilon = f64::EPSILON.sqrt());
         assert_relative_eq!(param[1], 0.15, epsilon = f64::EPSILON.sqrt());
     }
+
+    #[test]
+    fn test_solver_array() {
+        use crate::core::{Executor, State};
+        use approx::assert_relative_eq;
+        use std::cell::RefCell;
+
+        struct Problem {
+            counter: RefCell<usize>,
+        }
+
+        impl Operator for Problem {
+            type Param = [f64; 2];
+            type Output = [f64; 2];
+
+            fn apply(&self, _p: &Self::Param) -> Result<Self::Output, Error> {
+                if *self.counter.borrow() == 0 {
+                    let mut c = self.counter.borrow_mut();
+                    *c += 1;
+                    Ok([0.5, 2.0])
+                } else {
+                    Ok([0.3, 1.0])
+                }
+            }
+        }
+
+        impl Jacobian for Problem {
+            type Param = [f64; 2];
+            type Jacobian = [[f64; 2]; 2];
+
+            fn jacobian(&self, _p: &Self::Param) -> Result<Self::Jacobian, Error> {
+                Ok([[1.0, 2.0], [3.0, 4.0]])
+            }
+        }
+
+        // Two iterations, starting from [0, 0], gamma = 1
+        let problem = Problem {
+            counter: RefCell::new(0),
+        };
+        let solver: GaussNewton<f64> = GaussNewton::new();
+
+        let param = *Executor::new(problem, solver)
+            .configure(|config| config.param([0.0, 0.0]).max_iters(2))
+            .run()
+            .unwrap()
+            .state
+            .get_best_param()
+            .unwrap();
+        assert_relative_eq!(param[0], -1.4, epsilon = f64::EPSILON.sqrt());
+        assert_relative_eq!(param[1], 0.3, epsilon = f64::EPSILON.sqrt());
+    }
 }
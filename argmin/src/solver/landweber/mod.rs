@@ -134,4 +134,59 @@ mod tests {
         assert_relative_eq!(new_param[0], 1.0, epsilon = f64::EPSILON);
         assert_relative_eq!(new_param[1], 2.0, epsilon = f64::EPSILON);
     }
+
+    #[cfg(feature = "_sprsl")]
+    #[test]
+    fn test_sparse() {
+        use crate::core::{Executor, Gradient};
+        use argmin_math::{ArgminDot, ArgminSub, ArgminTranspose};
+        use sprs::{CsMat, TriMat};
+
+        /// Symmetric positive definite sparse matrix
+        fn sparse_matrix() -> CsMat<f64> {
+            let mut a = TriMat::new((3, 3));
+            for (i, j, v) in [
+                (0, 0, 4.0),
+                (0, 1, 1.0),
+                (1, 0, 1.0),
+                (1, 1, 3.0),
+                (1, 2, 1.0),
+                (2, 1, 1.0),
+                (2, 2, 2.0),
+            ] {
+                a.add_triplet(i, j, v);
+            }
+            a.to_csr()
+        }
+
+        /// Least squares problem `1/2 ||A x - b||^2` with a sparse matrix `A`
+        struct SparseLeastSquares {
+            a: CsMat<f64>,
+            b: Vec<f64>,
+        }
+
+        impl Gradient for SparseLeastSquares {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+                let residual = self.a.dot(x).sub(&self.b);
+                let a_t: CsMat<f64> = self.a.clone().t();
+                Ok(a_t.dot(&residual))
+            }
+        }
+
+        let problem = SparseLeastSquares {
+            a: sparse_matrix(),
+            b: vec![1.0, 2.0, 3.0],
+        };
+        let res = Executor::new(problem, Landweber::new(0.05))
+            .configure(|state| state.param(vec![0.0, 0.0, 0.0]).max_iters(500))
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 2.0 / 9.0, epsilon = 1e-10);
+        assert_relative_eq!(param[1], 1.0 / 9.0, epsilon = 1e-10);
+        assert_relative_eq!(param[2], 13.0 / 9.0, epsilon = 1e-10);
+    }
 }
@@ -5,7 +5,11 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+pub mod activeset;
+pub mod augmentedlagrangian;
+pub mod bobyqa;
 pub mod brent;
+pub mod cobyla;
 pub mod conjugategradient;
 pub mod gaussnewton;
 pub mod goldensectionsearch;
@@ -14,7 +18,13 @@ pub mod landweber;
 pub mod linesearch;
 pub mod neldermead;
 pub mod newton;
+pub mod paralleltempering;
 pub mod particleswarm;
+pub mod powell;
+pub mod proximalgradient;
 pub mod quasinewton;
 pub mod simulatedannealing;
+pub mod sqp;
 pub mod trustregion;
+
+mod dense;
@@ -17,12 +17,18 @@
 //! <https://en.wikipedia.org/wiki/Nelder%E2%80%93Mead_method>
 //!
 //! <http://www.scholarpedia.org/article/Nelder-Mead_algorithm#Simplex_transformation_algorithm>
+//!
+//! F Gao, L Han. (2012). "Implementing the Nelder-Mead simplex algorithm with adaptive
+//! parameters". Computational Optimization and Applications 51, pp. 259-277
+//! DOI: 10.1007/s10589-010-9329-3
 
 use crate::core::{
     ArgminFloat, CostFunction, Error, IterState, Problem, SerializeAlias, Solver,
     TerminationReason, TerminationStatus, KV,
 };
-use argmin_math::{ArgminAdd, ArgminMul, ArgminSub};
+use argmin_math::{
+    ArgminAdd, ArgminBasis, ArgminDot, ArgminMinMax, ArgminMul, ArgminSub, ArgminZeroLike,
+};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -49,6 +55,26 @@ use std::fmt;
 /// 4) Shrink (Parameter `sigma`, defaults to `0.5`, configurable via
 ///    [`with_sigma`](`NelderMead::with_sigma`))
 ///
+/// Alternatively, the dimension-dependent parameters of Gao and Han can be used via
+/// [`with_adaptive_parameters`](`NelderMead::with_adaptive_parameters`), which considerably
+/// improve the performance for higher-dimensional problems.
+///
+/// The initial simplex is either provided by the user ([`NelderMead::new`]) or constructed from a
+/// single point and step sizes along the coordinate axes ([`NelderMead::from_point`]).
+///
+/// Box constraints can be set via [`with_bounds`](`NelderMead::with_bounds`). Points outside of
+/// the box are either projected onto the box or reflected at its boundary (see
+/// [`BoundHandling`]).
+///
+/// If enabled via [`with_restart_tolerance`](`NelderMead::with_restart_tolerance`), the simplex is
+/// rebuilt around the best vertex when it becomes degenerate, i.e. when its vertices are close to
+/// lying in a lower-dimensional subspace.
+///
+/// The algorithm terminates if the sample standard deviation of the cost function values at the
+/// vertices falls below [`with_sd_tolerance`](`NelderMead::with_sd_tolerance`) or if the diameter
+/// of the simplex falls below
+/// [`with_diameter_tolerance`](`NelderMead::with_diameter_tolerance`).
+///
 /// ## Requirements on the optimization problem
 ///
 /// The optimization problem is required to implement [`CostFunction`].
@@ -58,6 +84,10 @@ use std::fmt;
 /// <https://en.wikipedia.org/wiki/Nelder%E2%80%93Mead_method>
 ///
 /// <http://www.scholarpedia.org/article/Nelder-Mead_algorithm#Simplex_transformation_algorithm>
+///
+/// F Gao, L Han. (2012). "Implementing the Nelder-Mead simplex algorithm with adaptive
+/// parameters". Computational Optimization and Applications 51, pp. 259-277
+/// DOI: 10.1007/s10589-010-9329-3
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
 pub struct NelderMead<P, F> {
@@ -73,6 +103,28 @@ pub struct NelderMead<P, F> {
     params: Vec<(P, F)>,
     /// Sample standard deviation tolerance
     sd_tolerance: F,
+    /// Simplex diameter tolerance
+    diameter_tolerance: F,
+    /// Restart if the normalized volume of the simplex falls below this value
+    restart_tolerance: F,
+    /// Number of restarts performed
+    restarts: u64,
+    /// Lower and upper bounds
+    bounds: Option<(P, P)>,
+    /// How points outside of the bounds are treated
+    bound_handling: BoundHandling,
+}
+
+/// Treatment of points outside of the box constraints of [`NelderMead`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum BoundHandling {
+    /// Project the point onto the box (`x_i = min(max(x_i, l_i), u_i)`)
+    #[default]
+    Projection,
+    /// Reflect the point at the violated bound (`x_i = 2 l_i - x_i` or `x_i = 2 u_i - x_i`),
+    /// followed by a projection in case the reflected point violates the opposite bound
+    Reflection,
 }
 
 impl<P, F> NelderMead<P, F>
@@ -100,9 +152,148 @@ where
             sigma: float!(0.5),
             params: params.into_iter().map(|p| (p, F::nan())).collect(),
             sd_tolerance: F::epsilon(),
+            diameter_tolerance: float!(0.0),
+            restart_tolerance: float!(0.0),
+            restarts: 0,
+            bounds: None,
+            bound_handling: BoundHandling::Projection,
         }
     }
 
+    /// Construct a new instance of `NelderMead` from a single point
+    ///
+    /// The initial simplex consists of `x0` and the `n` points `x0 + steps_i * e_i`, where `e_i`
+    /// is the `i`-th unit vector. All entries of `steps` must be non-zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// let nm: NelderMead<Vec<f64>, f64> = NelderMead::from_point(vec![1.0, 2.0], &vec![0.1, 0.5]);
+    /// ```
+    pub fn from_point(x0: P, steps: &P) -> Self
+    where
+        P: ArgminBasis + ArgminMul<P, P>,
+    {
+        let mut params: Vec<P> = x0
+            .basis_like()
+            .iter()
+            .map(|e| x0.add(&e.mul(steps)))
+            .collect();
+        params.insert(0, x0);
+        NelderMead::new(params)
+    }
+
+    /// Use the dimension-dependent parameters of Gao and Han
+    ///
+    /// For a problem with `n` parameters, these are `alpha = 1`, `gamma = 1 + 2/n`,
+    /// `rho = 3/4 - 1/(2n)` and `sigma = 1 - 1/n`. For `n = 2` they coincide with the standard
+    /// parameters. For one-dimensional problems the standard parameters are kept, since `sigma`
+    /// would vanish.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # let vec_of_parameters = vec![vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0], vec![0.0, 0.0, 1.0], vec![1.0, 1.0, 1.0]];
+    /// let nm: NelderMead<Vec<f64>, f64> =
+    ///     NelderMead::new(vec_of_parameters).with_adaptive_parameters();
+    /// ```
+    #[must_use]
+    pub fn with_adaptive_parameters(mut self) -> Self {
+        let n = self.params.len().saturating_sub(1);
+        if n >= 2 {
+            let n = F::from_usize(n).unwrap();
+            self.alpha = float!(1.0);
+            self.gamma = float!(1.0) + float!(2.0) / n;
+            self.rho = float!(0.75) - float!(1.0) / (float!(2.0) * n);
+            self.sigma = float!(1.0) - float!(1.0) / n;
+        }
+        self
+    }
+
+    /// Set box constraints
+    ///
+    /// `lower` and `upper` must be of the same shape as the parameter vectors and satisfy
+    /// `lower <= upper` element-wise. Points outside of the box are treated according to
+    /// `handling`. Vertices of the initial simplex outside of the box are treated as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::neldermead::{NelderMead, BoundHandling};
+    /// # let vec_of_parameters = vec![vec![1.0], vec![2.0]];
+    /// let nm: NelderMead<Vec<f64>, f64> = NelderMead::new(vec_of_parameters)
+    ///     .with_bounds(vec![0.0], vec![10.0], BoundHandling::Reflection);
+    /// ```
+    #[must_use]
+    pub fn with_bounds(mut self, lower: P, upper: P, handling: BoundHandling) -> Self {
+        self.bounds = Some((lower, upper));
+        self.bound_handling = handling;
+        self
+    }
+
+    /// Set simplex diameter tolerance
+    ///
+    /// The algorithm terminates if the largest distance of a vertex to the best vertex falls
+    /// below `tol`. Must be non-negative and defaults to `0` (disabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let vec_of_parameters = vec![vec![1.0], vec![2.0]];
+    /// let nm: NelderMead<Vec<f64>, f64> =
+    ///     NelderMead::new(vec_of_parameters).with_diameter_tolerance(1e-8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_diameter_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Nelder-Mead`: diameter_tolerance must be >= 0."
+            ));
+        }
+        self.diameter_tolerance = tol;
+        Ok(self)
+    }
+
+    /// Set tolerance for the detection of degenerate simplices
+    ///
+    /// The degeneracy of the simplex is measured by its normalized volume, i.e. the volume of
+    /// the simplex divided by the volume of a simplex with orthogonal edges of the same lengths.
+    /// It is 1 for simplices with orthogonal edges and 0 for a simplex whose vertices lie in a
+    /// lower-dimensional subspace. If it falls below `tol`, the simplex is rebuilt around the best
+    /// vertex along the coordinate axes with edges of half the current diameter.
+    ///
+    /// Must be in `[0, 1)` and defaults to `0` (disabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let vec_of_parameters = vec![vec![1.0], vec![2.0]];
+    /// let nm: NelderMead<Vec<f64>, f64> =
+    ///     NelderMead::new(vec_of_parameters).with_restart_tolerance(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_restart_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol < float!(0.0) || tol >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Nelder-Mead`: restart_tolerance must be in [0, 1)."
+            ));
+        }
+        self.restart_tolerance = tol;
+        Ok(self)
+    }
+
     /// Set sample standard deviation tolerance
     ///
     /// Must be non-negative and defaults to `EPSILON`.
@@ -295,6 +486,115 @@ where
     }
 }
 
+impl<P, F> NelderMead<P, F>
+where
+    P: Clone
+        + ArgminAdd<P, P>
+        + ArgminSub<P, P>
+        + ArgminMul<F, P>
+        + ArgminDot<P, F>
+        + ArgminMinMax
+        + ArgminZeroLike
+        + ArgminBasis,
+    F: ArgminFloat,
+{
+    /// Treat points outside of the bounds according to `bound_handling`
+    fn constrain(&self, x: P) -> P {
+        match self.bounds.as_ref() {
+            None => x,
+            Some((lower, upper)) => {
+                let x = match self.bound_handling {
+                    BoundHandling::Projection => x,
+                    BoundHandling::Reflection => {
+                        let zero = x.zero_like();
+                        let below = P::max(&lower.sub(&x), &zero);
+                        let above = P::max(&x.sub(upper), &zero);
+                        x.add(&below.sub(&above).mul(&float!(2.0)))
+                    }
+                };
+                P::min(&P::max(&x, lower), upper)
+            }
+        }
+    }
+
+    /// Largest distance of a vertex to the best vertex
+    fn diameter(&self) -> F {
+        let x0 = &self.params[0].0;
+        self.params
+            .iter()
+            .skip(1)
+            .map(|(p, _)| {
+                let d = p.sub(x0);
+                d.dot(&d).sqrt()
+            })
+            .fold(float!(0.0), |acc, d| acc.max(d))
+    }
+
+    /// Volume of the simplex divided by the volume of a simplex with orthogonal edges of the same
+    /// lengths. The edges are orthogonalized with the (modified) Gram-Schmidt process.
+    fn normalized_volume(&self) -> F {
+        let x0 = &self.params[0].0;
+        let mut ortho: Vec<(P, F)> = Vec::with_capacity(self.params.len() - 1);
+        let mut volume = float!(1.0);
+        for (p, _) in self.params.iter().skip(1) {
+            let edge = p.sub(x0);
+            let edge_sq = edge.dot(&edge);
+            let r = ortho.iter().fold(edge, |r, (q, q_sq)| {
+                let coeff = r.dot(q) / *q_sq;
+                r.sub(&q.mul(&coeff))
+            });
+            let r_sq = r.dot(&r);
+            if edge_sq <= float!(0.0) || r_sq <= float!(0.0) {
+                return float!(0.0);
+            }
+            volume = volume * (r_sq / edge_sq).sqrt();
+            ortho.push((r, r_sq));
+        }
+        volume
+    }
+
+    /// Rebuild the simplex around the best vertex along the coordinate axes with edges of half the
+    /// current diameter. Along each axis, the direction which leads to the lower cost function
+    /// value is chosen. Returns `false` if the simplex has collapsed to a single point, in which
+    /// case no restart is performed.
+    fn restart<C>(&mut self, mut cost: C) -> Result<bool, Error>
+    where
+        C: FnMut(&P) -> Result<F, Error>,
+    {
+        let d = self.diameter() * float!(0.5);
+        if d <= float!(0.0) {
+            return Ok(false);
+        }
+        let (x0, c0) = self.params[0].clone();
+        let mut params = vec![(x0.clone(), c0)];
+        for e in x0.basis_like() {
+            let step = e.mul(&d);
+            let p = self.constrain(x0.add(&step));
+            let c = (cost)(&p)?;
+            let dist = p.sub(&x0);
+            let blocked = dist.dot(&dist).sqrt() < d * float!(0.5);
+            // Go into the opposite direction if the step is blocked by a bound or does not lead to
+            // a decrease of the cost function
+            let (p, c) = if blocked || c >= c0 {
+                let q = self.constrain(x0.sub(&step));
+                let c_q = (cost)(&q)?;
+                if blocked || c_q < c {
+                    (q, c_q)
+                } else {
+                    (p, c)
+                }
+            } else {
+                (p, c)
+            };
+            params.push((p, c));
+        }
+        self.params = params;
+        self.restarts += 1;
+        self.sort_param_vecs();
+        Ok(true)
+    }
+}
+
 #[derive(Debug)]
 enum Action {
     Reflection,
@@ -319,7 +619,15 @@ impl fmt::Display for Action {
 impl<O, P, F> Solver<O, IterState<P, (), (), (), F>> for NelderMead<P, F>
 where
     O: CostFunction<Param = P, Output = F>,
-    P: Clone + SerializeAlias + ArgminSub<P, P> + ArgminAdd<P, P> + ArgminMul<F, P>,
+    P: Clone
+        + SerializeAlias
+        + ArgminSub<P, P>
+        + ArgminAdd<P, P>
+        + ArgminMul<F, P>
+        + ArgminDot<P, F>
+        + ArgminMinMax
+        + ArgminZeroLike
+        + ArgminBasis,
     F: ArgminFloat + std::iter::Sum<F>,
 {
     const NAME: &'static str = "Nelder-Mead method";
@@ -329,9 +637,15 @@ where
         problem: &mut Problem<O>,
         state: IterState<P, (), (), (), F>,
     ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
-        self.params
-            .iter_mut()
-            .for_each(|(p, c)| *c = problem.cost(p).unwrap());
+        let params = std::mem::take(&mut self.params);
+        self.params = params
+            .into_iter()
+            .map(|(p, _)| {
+                let p = self.constrain(p);
+                let c = problem.cost(&p)?;
+                Ok((p, c))
+            })
+            .collect::<Result<_, Error>>()?;
 
         self.sort_param_vecs();
 
@@ -354,7 +668,7 @@ where
         let p_worst = &self.params[num_param_vecs - 1];
         let p_second_worst = &self.params[num_param_vecs - 2];
 
-        let xr = self.reflect(&x0, &p_worst.0);
+        let xr = self.constrain(self.reflect(&x0, &p_worst.0));
         let xr_cost = problem.cost(&xr)?;
 
         let action = if xr_cost < p_second_worst.1 && xr_cost >= p_best.1 {
@@ -363,7 +677,7 @@ where
             Action::Reflection
         } else if xr_cost < p_best.1 {
             // expansion
-            let xe = self.expand(&x0, &xr);
+            let xe = self.constrain(self.expand(&x0, &xr));
             let xe_cost = problem.cost(&xe)?;
             *self.params.last_mut().unwrap() = if xe_cost < xr_cost {
                 (xe, xe_cost)
@@ -407,9 +721,17 @@ where
 
         self.sort_param_vecs();
 
+        let restarted = self.restart_tolerance > float!(0.0)
+            && self.normalized_volume() < self.restart_tolerance
+            && self.restart(|x| problem.cost(x))?;
+
         Ok((
             state.param(self.params[0].0.clone()).cost(self.params[0].1),
-            Some(kv!("action" => format!("{action}");)),
+            Some(kv!(
+                "action" => format!("{action}");
+                "restarted" => restarted;
+                "restarts" => self.restarts;
+            )),
         ))
     }
 
@@ -426,6 +748,9 @@ where
         if s < self.sd_tolerance {
             return TerminationStatus::Terminated(TerminationReason::SolverConverged);
         }
+        if self.diameter() < self.diameter_tolerance {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
         TerminationStatus::NotTerminated
     }
 }
@@ -433,7 +758,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::{test_utils::TestProblem, ArgminError, IterState, State};
+    use crate::core::{test_utils::TestProblem, ArgminError, Executor, IterState, State};
     use crate::test_trait_impl;
     use approx::assert_relative_eq;
 
@@ -462,6 +787,11 @@ mod tests {
             sigma,
             params,
             sd_tolerance,
+            diameter_tolerance,
+            restart_tolerance,
+            restarts,
+            bounds,
+            bound_handling,
         } = nm;
 
         assert_eq!(alpha.to_ne_bytes(), 1.0f64.to_ne_bytes());
@@ -473,6 +803,11 @@ mod tests {
         assert_eq!(params[0].1.to_ne_bytes(), f64::NAN.to_ne_bytes());
         assert_eq!(params[1].1.to_ne_bytes(), f64::NAN.to_ne_bytes());
         assert_eq!(sd_tolerance.to_ne_bytes(), f64::EPSILON.to_ne_bytes());
+        assert_eq!(diameter_tolerance.to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_eq!(restart_tolerance.to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_eq!(restarts, 0);
+        assert!(bounds.is_none());
+        assert_eq!(bound_handling, BoundHandling::Projection);
     }
 
     #[test]
@@ -867,4 +1202,236 @@ mod tests {
         assert_relative_eq!(nm.params[2].0[1], 0.0f64, epsilon = f64::EPSILON);
         assert_relative_eq!(nm.params[2].1, 1.00f64, epsilon = f64::EPSILON);
     }
+
+    #[test]
+    fn test_solver_array() {
+        struct ArrayProblem {}
+
+        impl CostFunction for ArrayProblem {
+            type Param = [f64; 2];
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok((p[0] - 1.0).powi(2) + (p[1] + 2.0).powi(2))
+            }
+        }
+
+        let params = vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]];
+        let nm: NelderMead<_, f64> = NelderMead::new(params).with_sd_tolerance(1e-12).unwrap();
+        let res = Executor::new(ArrayProblem {}, nm)
+            .configure(|state| state.max_iters(200))
+            .run()
+            .unwrap();
+
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0f64, epsilon = 1e-4);
+        assert_relative_eq!(param[1], -2.0f64, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_from_point() {
+        let nm: NelderMead<Vec<f64>, f64> =
+            NelderMead::from_point(vec![1.0, 2.0, 3.0], &vec![0.5, -1.0, 2.0]);
+        let expected = [
+            vec![1.0, 2.0, 3.0],
+            vec![1.5, 2.0, 3.0],
+            vec![1.0, 1.0, 3.0],
+            vec![1.0, 2.0, 5.0],
+        ];
+        assert_eq!(nm.params.len(), 4);
+        for ((p, c), e) in nm.params.iter().zip(expected.iter()) {
+            assert_eq!(p, e);
+            assert!(c.is_nan());
+        }
+    }
+
+    #[test]
+    fn test_with_adaptive_parameters() {
+        let nm: NelderMead<Vec<f64>, f64> =
+            NelderMead::from_point(vec![0.0; 4], &vec![1.0; 4]).with_adaptive_parameters();
+        assert_relative_eq!(nm.alpha, 1.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(nm.gamma, 1.5, epsilon = f64::EPSILON);
+        assert_relative_eq!(nm.rho, 0.625, epsilon = f64::EPSILON);
+        assert_relative_eq!(nm.sigma, 0.75, epsilon = f64::EPSILON);
+
+        // Coincide with the standard parameters for n = 2
+        let nm: NelderMead<Vec<f64>, f64> =
+            NelderMead::from_point(vec![0.0; 2], &vec![1.0; 2]).with_adaptive_parameters();
+        assert_relative_eq!(nm.gamma, 2.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(nm.rho, 0.5, epsilon = f64::EPSILON);
+        assert_relative_eq!(nm.sigma, 0.5, epsilon = f64::EPSILON);
+
+        // Unchanged for n = 1
+        let nm: NelderMead<Vec<f64>, f64> =
+            NelderMead::new(vec![vec![1.0], vec![2.0]]).with_adaptive_parameters();
+        assert_relative_eq!(nm.gamma, 2.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(nm.sigma, 0.5, epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_with_diameter_tolerance() {
+        for tol in [0.0, 1e-6, 1.0] {
+            let nm: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![1.0], vec![2.0]]);
+            let nm = nm.with_diameter_tolerance(tol).unwrap();
+            assert_eq!(nm.diameter_tolerance.to_ne_bytes(), tol.to_ne_bytes());
+        }
+
+        for tol in [-f64::EPSILON, -1.0] {
+            let nm: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![1.0], vec![2.0]]);
+            let res = nm.with_diameter_tolerance(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`Nelder-Mead`: ",
+                    "diameter_tolerance must be >= 0.\""
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_restart_tolerance() {
+        for tol in [0.0, 1e-6, 0.5, 1.0 - f64::EPSILON] {
+            let nm: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![1.0], vec![2.0]]);
+            let nm = nm.with_restart_tolerance(tol).unwrap();
+            assert_eq!(nm.restart_tolerance.to_ne_bytes(), tol.to_ne_bytes());
+        }
+
+        for tol in [-f64::EPSILON, -1.0, 1.0, 2.0] {
+            let nm: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![1.0], vec![2.0]]);
+            let res = nm.with_restart_tolerance(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`Nelder-Mead`: ",
+                    "restart_tolerance must be in [0, 1).\""
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_constrain() {
+        let x = vec![-1.5, 0.5, 2.5, 5.0];
+        for (handling, expected) in [
+            (BoundHandling::Projection, vec![-1.0, 0.5, 1.0, 1.0]),
+            // the last entry violates the opposite bound after reflection and is projected
+            (BoundHandling::Reflection, vec![-0.5, 0.5, -0.5, -1.0]),
+        ] {
+            let nm: NelderMead<Vec<f64>, f64> =
+                NelderMead::new(vec![]).with_bounds(vec![-1.0; 4], vec![1.0; 4], handling);
+            let y = nm.constrain(x.clone());
+            for (a, b) in y.iter().zip(expected.iter()) {
+                assert_relative_eq!(a, b, epsilon = f64::EPSILON);
+            }
+        }
+
+        // No bounds
+        let nm: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![]);
+        assert_eq!(nm.constrain(x.clone()), x);
+    }
+
+    #[test]
+    fn test_diameter_and_normalized_volume() {
+        let mut nm: NelderMead<Vec<f64>, f64> =
+            NelderMead::from_point(vec![0.0, 0.0], &vec![3.0, 4.0]);
+        assert_relative_eq!(nm.diameter(), 4.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(nm.normalized_volume(), 1.0, epsilon = f64::EPSILON);
+
+        // edges at 45 degrees
+        nm.params[2].0 = vec![4.0, 4.0];
+        assert_relative_eq!(
+            nm.normalized_volume(),
+            0.5f64.sqrt(),
+            epsilon = f64::EPSILON
+        );
+
+        // collinear vertices
+        nm.params[2].0 = vec![6.0, 0.0];
+        assert_relative_eq!(nm.normalized_volume(), 0.0, epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_restart() {
+        let params = vec![vec![0.0, 0.0], vec![1.0, 1.0], vec![2.0, 2.0 + 1e-12]];
+        let mut nm: NelderMead<Vec<f64>, f64> = NelderMead::new(params)
+            .with_restart_tolerance(1e-6)
+            .unwrap();
+        let mut problem = Problem::new(MwProblem {});
+        let state: IterState<Vec<f64>, (), (), (), f64> = IterState::new();
+        let (_, _) = nm.init(&mut problem, state).unwrap();
+        assert!(nm.normalized_volume() < 1e-6);
+
+        let d = nm.diameter();
+        assert!(nm.restart(|x| problem.cost(x)).unwrap());
+        assert_eq!(nm.restarts, 1);
+        assert_relative_eq!(nm.normalized_volume(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(nm.diameter(), d / 2.0, epsilon = 1e-12);
+        assert_eq!(nm.params[0].0, vec![0.0, 0.0]);
+
+        // A collapsed simplex is not restarted
+        let mut nm: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![1.0, 1.0]; 3]);
+        assert!(!nm.restart(|_| Ok(0.0)).unwrap());
+        assert_eq!(nm.restarts, 0);
+    }
+
+    #[test]
+    fn test_solver_bounds() {
+        struct ShiftedProblem {}
+
+        impl CostFunction for ShiftedProblem {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok((p[0] - 3.0).powi(2) + (p[1] + 1.0).powi(2))
+            }
+        }
+
+        for handling in [BoundHandling::Projection, BoundHandling::Reflection] {
+            let nm: NelderMead<Vec<f64>, f64> =
+                NelderMead::from_point(vec![0.0, 0.0], &vec![0.5, 0.5])
+                    .with_bounds(vec![-2.0, -2.0], vec![2.0, 2.0], handling)
+                    .with_sd_tolerance(1e-14)
+                    .unwrap()
+                    // Projection tends to flatten the simplex against the bound
+                    .with_restart_tolerance(1e-3)
+                    .unwrap();
+            let res = Executor::new(ShiftedProblem {}, nm)
+                .configure(|state| state.max_iters(500))
+                .run()
+                .unwrap();
+
+            let param = res.state.get_best_param().unwrap();
+            assert_relative_eq!(param[0], 2.0f64, epsilon = 1e-4);
+            assert_relative_eq!(param[1], -1.0f64, epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_solver_adaptive_with_restart_and_diameter() {
+        let nm: NelderMead<Vec<f64>, f64> = NelderMead::from_point(vec![1.0; 6], &vec![0.5; 6])
+            .with_adaptive_parameters()
+            .with_sd_tolerance(0.0)
+            .unwrap()
+            .with_diameter_tolerance(1e-8)
+            .unwrap()
+            .with_restart_tolerance(1e-10)
+            .unwrap();
+        let res = Executor::new(MwProblem {}, nm)
+            .configure(|state| state.max_iters(10000))
+            .run()
+            .unwrap();
+
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert!(res.state.get_iter() < 10000);
+        for x in res.state.get_best_param().unwrap() {
+            assert!(x.abs() < 1e-6);
+        }
+    }
 }
@@ -12,7 +12,8 @@ use crate::core::{
 };
 use crate::solver::conjugategradient::ConjugateGradient;
 use argmin_math::{
-    ArgminConj, ArgminDot, ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminSub, ArgminZeroLike,
+    ArgminConj, ArgminDot, ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminScaledAddAssign,
+    ArgminSub, ArgminZeroLike,
 };
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
@@ -115,6 +116,7 @@ where
         + ArgminSub<P, P>
         + ArgminDot<P, F>
         + ArgminScaledAdd<P, F, P>
+        + ArgminScaledAddAssign<P, F>
         + ArgminMul<F, P>
         + ArgminConj
         + ArgminZeroLike,
@@ -342,4 +344,89 @@ mod tests {
     }
 
     // TODO: Test next_iter.
+
+    #[cfg(feature = "_sprsl")]
+    #[test]
+    fn test_sparse() {
+        use crate::core::{CostFunction, Executor, Gradient, Hessian, State};
+        use approx::assert_relative_eq;
+        use argmin_math::{ArgminDot, ArgminSub};
+        use sprs::{CsMat, TriMat};
+
+        /// Symmetric positive definite sparse matrix
+        fn sparse_matrix() -> CsMat<f64> {
+            let mut a = TriMat::new((3, 3));
+            for (i, j, v) in [
+                (0, 0, 4.0),
+                (0, 1, 1.0),
+                (1, 0, 1.0),
+                (1, 1, 3.0),
+                (1, 2, 1.0),
+                (2, 1, 1.0),
+                (2, 2, 2.0),
+            ] {
+                a.add_triplet(i, j, v);
+            }
+            a.to_csr()
+        }
+
+        /// Quadratic problem `1/2 x^T A x - b^T x` with a sparse Hessian `A`
+        struct SparseQuadratic {
+            a: CsMat<f64>,
+            b: Vec<f64>,
+        }
+
+        impl CostFunction for SparseQuadratic {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+                let ax: Vec<f64> = self.a.dot(x);
+                let xax: f64 = x.dot(&ax);
+                let bx: f64 = self.b.dot(x);
+                Ok(0.5 * xax - bx)
+            }
+        }
+
+        impl Gradient for SparseQuadratic {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+                let ax: Vec<f64> = self.a.dot(x);
+                Ok(ax.sub(&self.b))
+            }
+        }
+
+        impl Hessian for SparseQuadratic {
+            type Param = Vec<f64>;
+            type Hessian = CsMat<f64>;
+
+            fn hessian(&self, _x: &Self::Param) -> Result<Self::Hessian, Error> {
+                Ok(self.a.clone())
+            }
+        }
+
+        let problem = SparseQuadratic {
+            a: sparse_matrix(),
+            b: vec![1.0, 2.0, 3.0],
+        };
+        let linesearch: MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64> =
+            MoreThuenteLineSearch::new();
+        let res = Executor::new(problem, NewtonCG::new(linesearch))
+            .configure(|state| state.param(vec![0.0, 0.0, 0.0]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        // The solver stops once the change of the cost function is below `EPSILON`. Since the
+        // cost function is quadratic, the error of the parameter vector is of the order of
+        // `sqrt(EPSILON)`.
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 2.0 / 9.0, epsilon = 1e-6);
+        assert_relative_eq!(param[1], 1.0 / 9.0, epsilon = 1e-6);
+        assert_relative_eq!(param[2], 13.0 / 9.0, epsilon = 1e-6);
+    }
 }
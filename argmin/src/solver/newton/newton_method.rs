@@ -6,7 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{ArgminFloat, Error, Gradient, Hessian, IterState, Problem, Solver, KV};
-use argmin_math::{ArgminDot, ArgminInv, ArgminScaledSub};
+use argmin_math::{ArgminScaledSub, ArgminSolve};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 use std::default::Default;
@@ -89,7 +89,7 @@ impl<O, P, G, H, F> Solver<O, IterState<P, G, (), H, F>> for Newton<F>
 where
     O: Gradient<Param = P, Gradient = G> + Hessian<Param = P, Hessian = H>,
     P: Clone + ArgminScaledSub<P, F, P>,
-    H: ArgminInv<H> + ArgminDot<G, P>,
+    H: ArgminSolve<G, P>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "Newton method";
@@ -108,7 +108,7 @@ where
         ))?;
         let grad = problem.gradient(&param)?;
         let hessian = problem.hessian(&param)?;
-        let new_param = param.scaled_sub(&self.gamma, &hessian.inv()?.dot(&grad));
+        let new_param = param.scaled_sub(&self.gamma, &hessian.solve(&grad)?);
         Ok((state.param(new_param), None))
     }
 }
@@ -285,4 +285,40 @@ mod tests {
         assert_relative_eq!(param[0], -1.0, epsilon = f64::EPSILON);
         assert_relative_eq!(param[1], -2.0, epsilon = f64::EPSILON);
     }
+
+    #[test]
+    fn test_solver_vec() {
+        use crate::core::{Executor, State};
+        struct Problem {}
+
+        impl Gradient for Problem {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok(vec![2.0 * (p[0] - 1.0), 4.0 * (p[1] + 2.0)])
+            }
+        }
+
+        impl Hessian for Problem {
+            type Param = Vec<f64>;
+            type Hessian = Vec<Vec<f64>>;
+
+            fn hessian(&self, _p: &Self::Param) -> Result<Self::Hessian, Error> {
+                Ok(vec![vec![2.0, 0.0], vec![0.0, 4.0]])
+            }
+        }
+
+        // Quadratic problem is solved in a single iteration
+        let param = Executor::new(Problem {}, Newton::<f64>::new())
+            .configure(|config| config.param(vec![0.0, 0.0]).max_iters(1))
+            .run()
+            .unwrap()
+            .state
+            .get_best_param()
+            .unwrap()
+            .clone();
+        approx::assert_relative_eq!(param[0], 1.0, epsilon = f64::EPSILON);
+        approx::assert_relative_eq!(param[1], -2.0, epsilon = f64::EPSILON);
+    }
 }
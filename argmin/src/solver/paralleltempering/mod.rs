@@ -0,0 +1,714 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Parallel Tempering
+//!
+//! Parallel Tempering (also known as replica-exchange annealing) runs several annealing chains at
+//! a ladder of fixed temperatures and regularly exchanges the states of neighbouring chains. For
+//! details see [`ParallelTempering`].
+//!
+//! ## References
+//!
+//! RH Swendsen, JS Wang. (1986). "Replica Monte Carlo Simulation of Spin-Glasses".
+//! Physical Review Letters 57, pp. 2607-2609
+//! DOI: 10.1103/PhysRevLett.57.2607
+//!
+//! DJ Earl, MW Deem. (2005). "Parallel tempering: Theory, applications, and new perspectives".
+//! Physical Chemistry Chemical Physics 7, pp. 3910-3916
+//! DOI: 10.1039/B509983H
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, IterState, Problem, SerializeAlias, Solver, State, SyncAlias,
+    KV,
+};
+use crate::solver::simulatedannealing::Anneal;
+use rand::prelude::*;
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+/// # Parallel Tempering
+///
+/// Parallel Tempering (replica-exchange annealing) runs one chain (replica) per temperature of a
+/// ladder `t_0 < t_1 < ... < t_{n-1}`. In each iteration, every replica proposes a move via
+/// [`Anneal`] (using its temperature as extent), the candidates of all replicas are evaluated at
+/// once via `bulk_cost` (in parallel if the `rayon` feature is enabled) and each move is accepted
+/// according to the Metropolis criterion
+///
+/// `p_accept = min(1, exp(-(c_new - c_old) / t_i))`.
+///
+/// Afterwards, the states of neighbouring replicas `i` and `i + 1` are exchanged with probability
+///
+/// `p_swap = min(1, exp((1 / t_i - 1 / t_{i+1}) * (c_i - c_{i+1})))`,
+///
+/// where even and odd pairs of neighbours are considered in alternating iterations. Hot replicas
+/// explore the parameter space on a large scale, while cold replicas refine the solutions which
+/// are passed down the ladder. This allows the algorithm to escape local minima which trap a
+/// single annealing chain.
+///
+/// All replicas start at the initial parameter vector provided via
+/// [`configure`](`crate::core::Executor::configure`) of [`Executor`](`crate::core::Executor`).
+/// The state holds the replica with the currently lowest cost. Per-replica acceptance rates and
+/// per-pair swap rates are reported to the observers as `acc_rate_<i>` for replica `i` and
+/// `swap_rate_<i>` for the pair of replicas `i` and `i + 1`.
+///
+/// The random number generator is owned by the solver (by default `Xoshiro256PlusPlus`) and, with
+/// the `serde1` feature, is part of checkpoints.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Anneal`].
+///
+/// ## References
+///
+/// RH Swendsen, JS Wang. (1986). "Replica Monte Carlo Simulation of Spin-Glasses".
+/// Physical Review Letters 57, pp. 2607-2609
+/// DOI: 10.1103/PhysRevLett.57.2607
+///
+/// DJ Earl, MW Deem. (2005). "Parallel tempering: Theory, applications, and new perspectives".
+/// Physical Chemistry Chemical Physics 7, pp. 3910-3916
+/// DOI: 10.1039/B509983H
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ParallelTempering<P, F, R = Xoshiro256PlusPlus> {
+    /// Temperature ladder in increasing order
+    temperatures: Vec<F>,
+    /// Attempt swaps every `swap_interval` iterations
+    swap_interval: u64,
+    /// Replicas, ordered like the temperatures
+    replicas: Vec<Replica<P, F>>,
+    /// Number of proposed moves per replica
+    moves_proposed: u64,
+    /// Number of accepted moves per replica
+    moves_accepted: Vec<u64>,
+    /// Number of proposed swaps per pair of neighbours
+    swaps_proposed: Vec<u64>,
+    /// Number of accepted swaps per pair of neighbours
+    swaps_accepted: Vec<u64>,
+    /// Random number generator
+    rng: R,
+}
+
+/// A single replica of [`ParallelTempering`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Replica<P, F> {
+    /// Current parameter vector of the replica
+    pub param: P,
+    /// Cost function value of `param`
+    pub cost: F,
+}
+
+/// Computes a geometric temperature ladder of `num` temperatures from `t_min` to `t_max`.
+///
+/// Geometric spacing leads to roughly uniform swap rates if the heat capacity of the problem is
+/// approximately constant.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::paralleltempering::geometric_temperatures;
+/// # use argmin::core::Error;
+/// # fn main() -> Result<(), Error> {
+/// let temperatures = geometric_temperatures(1.0f64, 100.0, 3)?;
+/// # assert!((temperatures[1] - 10.0f64).abs() < 1e-12);
+/// # Ok(())
+/// # }
+/// ```
+pub fn geometric_temperatures<F: ArgminFloat>(
+    t_min: F,
+    t_max: F,
+    num: usize,
+) -> Result<Vec<F>, Error> {
+    if num < 2 {
+        return Err(argmin_error!(
+            InvalidParameter,
+            "`geometric_temperatures`: Number of temperatures must be >= 2."
+        ));
+    }
+    if t_min <= float!(0.0) || t_max <= t_min {
+        return Err(argmin_error!(
+            InvalidParameter,
+            "`geometric_temperatures`: Temperatures must satisfy 0 < t_min < t_max."
+        ));
+    }
+    let ratio = (t_max / t_min).powf(float!(1.0) / F::from_usize(num - 1).unwrap());
+    let mut temperatures: Vec<F> = (0..num).map(|i| t_min * ratio.powi(i as i32)).collect();
+    // avoid rounding errors at the upper end
+    temperatures[num - 1] = t_max;
+    Ok(temperatures)
+}
+
+impl<P, F> ParallelTempering<P, F, Xoshiro256PlusPlus>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`ParallelTempering`]
+    ///
+    /// Takes the temperature ladder as input, which must consist of at least two strictly
+    /// increasing, positive temperatures. See [`geometric_temperatures`] for a common choice.
+    ///
+    /// Uses the `Xoshiro256PlusPlus` RNG internally. For use of another RNG, consider using
+    /// [`ParallelTempering::new_with_rng`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::paralleltempering::ParallelTempering;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let pt: ParallelTempering<Vec<f64>, f64> = ParallelTempering::new(vec![1.0, 5.0, 25.0])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(temperatures: Vec<F>) -> Result<Self, Error> {
+        ParallelTempering::new_with_rng(temperatures, Xoshiro256PlusPlus::from_entropy())
+    }
+}
+
+impl<P, F, R> ParallelTempering<P, F, R>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`ParallelTempering`]
+    ///
+    /// Takes the temperature ladder as input, which must consist of at least two strictly
+    /// increasing, positive temperatures.
+    /// Requires a RNG which must implement `rand::Rng` (and `serde::Serialize` if the `serde1`
+    /// feature is enabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::paralleltempering::ParallelTempering;
+    /// # use argmin::core::Error;
+    /// # use rand::SeedableRng;
+    /// # fn main() -> Result<(), Error> {
+    /// let rng = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(42);
+    /// let pt: ParallelTempering<Vec<f64>, f64, _> =
+    ///     ParallelTempering::new_with_rng(vec![1.0, 5.0, 25.0], rng)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_rng(temperatures: Vec<F>, rng: R) -> Result<Self, Error> {
+        if temperatures.len() < 2 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ParallelTempering`: At least two temperatures are required."
+            ));
+        }
+        if temperatures[0] <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ParallelTempering`: Temperatures must be > 0."
+            ));
+        }
+        if temperatures.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ParallelTempering`: Temperatures must be strictly increasing."
+            ));
+        }
+        let n = temperatures.len();
+        Ok(ParallelTempering {
+            temperatures,
+            swap_interval: 1,
+            replicas: vec![],
+            moves_proposed: 0,
+            moves_accepted: vec![0; n],
+            swaps_proposed: vec![0; n - 1],
+            swaps_accepted: vec![0; n - 1],
+            rng,
+        })
+    }
+
+    /// Set the number of iterations between two rounds of swap attempts.
+    ///
+    /// Must be > 0. Defaults to 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::paralleltempering::ParallelTempering;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let pt: ParallelTempering<Vec<f64>, f64> =
+    ///     ParallelTempering::new(vec![1.0, 5.0, 25.0])?.with_swap_interval(10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_swap_interval(mut self, interval: u64) -> Result<Self, Error> {
+        if interval == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ParallelTempering`: Swap interval must be > 0."
+            ));
+        }
+        self.swap_interval = interval;
+        Ok(self)
+    }
+
+    /// Returns the temperature ladder
+    pub fn temperatures(&self) -> &[F] {
+        &self.temperatures
+    }
+
+    /// Returns the replicas, ordered like the temperatures
+    pub fn replicas(&self) -> &[Replica<P, F>] {
+        &self.replicas
+    }
+
+    /// Acceptance rate of the moves of each replica
+    pub fn acceptance_rates(&self) -> Vec<f64> {
+        let proposed = self.moves_proposed;
+        self.moves_accepted
+            .iter()
+            .map(|&a| rate(a, proposed))
+            .collect()
+    }
+
+    /// Swap rate of each pair of neighbouring replicas
+    pub fn swap_rates(&self) -> Vec<f64> {
+        self.swaps_accepted
+            .iter()
+            .zip(self.swaps_proposed.iter())
+            .map(|(&a, &p)| rate(a, p))
+            .collect()
+    }
+}
+
+impl<P, F, R> ParallelTempering<P, F, R>
+where
+    F: ArgminFloat,
+    R: Rng,
+{
+    /// Metropolis criterion: returns true with probability `min(1, exp(log_ratio))`.
+    fn metropolis(&mut self, log_ratio: F) -> bool {
+        if log_ratio >= float!(0.0) {
+            return true;
+        }
+        let prob: f64 = self.rng.gen();
+        float!(prob) < log_ratio.exp()
+    }
+
+    /// Attempt to swap neighbouring replicas, starting with the pair (`first`, `first + 1`) and
+    /// considering every other pair.
+    fn swap_replicas(&mut self, first: usize) {
+        for i in (first..self.temperatures.len() - 1).step_by(2) {
+            let beta_diff =
+                float!(1.0) / self.temperatures[i] - float!(1.0) / self.temperatures[i + 1];
+            let log_ratio = beta_diff * (self.replicas[i].cost - self.replicas[i + 1].cost);
+            self.swaps_proposed[i] += 1;
+            if self.metropolis(log_ratio) {
+                self.replicas.swap(i, i + 1);
+                self.swaps_accepted[i] += 1;
+            }
+        }
+    }
+}
+
+/// Ratio of accepted to proposed, zero if nothing has been proposed yet
+fn rate(accepted: u64, proposed: u64) -> f64 {
+    if proposed == 0 {
+        0.0
+    } else {
+        accepted as f64 / proposed as f64
+    }
+}
+
+/// Key `<prefix>_<index>` of a per-replica entry of the [`KV`]
+///
+/// Since keys of a [`KV`] are `&'static str`, each key is leaked once and cached afterwards.
+fn indexed_key(prefix: &'static str, index: usize) -> &'static str {
+    type Keys = Mutex<HashMap<(&'static str, usize), &'static str>>;
+    static KEYS: OnceLock<Keys> = OnceLock::new();
+    KEYS.get_or_init(Default::default)
+        .lock()
+        .unwrap()
+        .entry((prefix, index))
+        .or_insert_with(|| Box::leak(format!("{prefix}_{index}").into_boxed_str()))
+}
+
+impl<O, P, F, R> Solver<O, IterState<P, (), (), (), F>> for ParallelTempering<P, F, R>
+where
+    O: CostFunction<Param = P, Output = F> + Anneal<Param = P, Output = P, Float = F> + SyncAlias,
+    P: Clone + SyncAlias,
+    F: ArgminFloat,
+    R: Rng + SerializeAlias,
+{
+    const NAME: &'static str = "Parallel Tempering";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`ParallelTempering` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let cost = state.get_cost();
+        let cost = if cost.is_infinite() {
+            problem.cost(&param)?
+        } else {
+            cost
+        };
+
+        let n = self.temperatures.len();
+        self.replicas = vec![
+            Replica {
+                param: param.clone(),
+                cost,
+            };
+            n
+        ];
+        self.moves_proposed = 0;
+        self.moves_accepted = vec![0; n];
+        self.swaps_proposed = vec![0; n - 1];
+        self.swaps_accepted = vec![0; n - 1];
+
+        Ok((
+            state.param(param).cost(cost),
+            Some(kv!(
+                "num_replicas" => n as u64;
+                "t_min" => self.temperatures[0];
+                "t_max" => self.temperatures[n - 1];
+                "swap_interval" => self.swap_interval;
+            )),
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        if self.replicas.len() != self.temperatures.len() {
+            return Err(argmin_error!(
+                PotentialBug,
+                "`ParallelTempering`: Replicas not initialized."
+            ));
+        }
+
+        // Propose a move for every replica
+        let candidates = self
+            .replicas
+            .iter()
+            .zip(self.temperatures.iter())
+            .map(|(r, &t)| problem.anneal(&r.param, t))
+            .collect::<Result<Vec<P>, Error>>()?;
+
+        // Evaluate all candidates at once
+        let costs = problem.bulk_cost(&candidates)?;
+
+        // Metropolis acceptance of the moves
+        self.moves_proposed += 1;
+        for (i, (candidate, cost)) in candidates.into_iter().zip(costs).enumerate() {
+            let log_ratio = (self.replicas[i].cost - cost) / self.temperatures[i];
+            if self.metropolis(log_ratio) {
+                self.replicas[i] = Replica {
+                    param: candidate,
+                    cost,
+                };
+                self.moves_accepted[i] += 1;
+            }
+        }
+
+        // Exchange neighbouring replicas, alternating between even and odd pairs
+        let iter = state.get_iter();
+        let swapped = (iter + 1).is_multiple_of(self.swap_interval);
+        if swapped {
+            self.swap_replicas(((iter / self.swap_interval) % 2) as usize);
+        }
+
+        let best = self
+            .replicas
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                a.cost
+                    .partial_cmp(&b.cost)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(i, _)| i)
+            .unwrap();
+
+        let mut kv = kv!(
+            "best_replica" => best as u64;
+            "swapped" => swapped;
+        );
+        for (i, rate) in self.acceptance_rates().into_iter().enumerate() {
+            kv.insert(indexed_key("acc_rate", i), rate.into());
+        }
+        for (i, rate) in self.swap_rates().into_iter().enumerate() {
+            kv.insert(indexed_key("swap_rate", i), rate.into());
+        }
+
+        let Replica { param, cost } = self.replicas[best].clone();
+
+        Ok((state.param(param).cost(cost), Some(kv)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{test_utils::TestProblem, ArgminError, Executor, KvValue};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use rand::distributions::Uniform;
+    use std::sync::Mutex;
+
+    test_trait_impl!(pt, ParallelTempering<Vec<f64>, f64, StdRng>);
+
+    /// Rastrigin function with random-walk moves scaled by the temperature
+    struct Rastrigin {
+        rng: Mutex<StdRng>,
+    }
+
+    impl Rastrigin {
+        fn new() -> Self {
+            Rastrigin {
+                rng: Mutex::new(StdRng::seed_from_u64(0)),
+            }
+        }
+    }
+
+    impl CostFunction for Rastrigin {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(param.iter().fold(10.0 * param.len() as f64, |acc, x| {
+                acc + x.powi(2) - 10.0 * (2.0 * std::f64::consts::PI * x).cos()
+            }))
+        }
+    }
+
+    impl Anneal for Rastrigin {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+        type Float = f64;
+
+        fn anneal(&self, param: &Vec<f64>, temp: f64) -> Result<Vec<f64>, Error> {
+            let mut rng = self.rng.lock().unwrap();
+            let step = (0.1 * temp).sqrt().min(1.0);
+            let dist = Uniform::new_inclusive(-step, step);
+            Ok(param.iter().map(|x| x + rng.sample(dist)).collect())
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let pt: ParallelTempering<Vec<f64>, f64> = ParallelTempering::new(vec![1.0, 2.0]).unwrap();
+        assert_eq!(pt.temperatures(), &[1.0, 2.0]);
+        assert_eq!(pt.swap_interval, 1);
+        assert!(pt.replicas().is_empty());
+        assert_eq!(pt.moves_accepted, vec![0, 0]);
+        assert_eq!(pt.swaps_proposed, vec![0]);
+        assert_eq!(pt.swaps_accepted, vec![0]);
+
+        for (temps, msg) in [
+            (vec![], "At least two temperatures are required."),
+            (vec![1.0], "At least two temperatures are required."),
+            (vec![0.0, 1.0], "Temperatures must be > 0."),
+            (vec![-1.0, 1.0], "Temperatures must be > 0."),
+            (vec![1.0, 1.0], "Temperatures must be strictly increasing."),
+            (
+                vec![1.0, 3.0, 2.0],
+                "Temperatures must be strictly increasing.",
+            ),
+        ] {
+            let res: Result<ParallelTempering<Vec<f64>, f64>, _> = ParallelTempering::new(temps);
+            assert_error!(
+                res,
+                ArgminError,
+                format!("Invalid parameter: \"`ParallelTempering`: {msg}\"")
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_swap_interval() {
+        let pt: ParallelTempering<Vec<f64>, f64> = ParallelTempering::new(vec![1.0, 2.0])
+            .unwrap()
+            .with_swap_interval(5)
+            .unwrap();
+        assert_eq!(pt.swap_interval, 5);
+
+        let res = ParallelTempering::<Vec<f64>, f64>::new(vec![1.0, 2.0])
+            .unwrap()
+            .with_swap_interval(0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`ParallelTempering`: Swap interval must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_geometric_temperatures() {
+        let temps = geometric_temperatures(1.0f64, 1000.0, 4).unwrap();
+        for (t, expected) in temps.iter().zip([1.0, 10.0, 100.0, 1000.0]) {
+            assert_relative_eq!(*t, expected, max_relative = 1e-12);
+        }
+
+        for (t_min, t_max, num) in [(1.0f64, 10.0, 1), (0.0, 10.0, 3), (10.0, 1.0, 3)] {
+            assert!(geometric_temperatures(t_min, t_max, num).is_err());
+        }
+    }
+
+    #[test]
+    fn test_init() {
+        let mut pt: ParallelTempering<Vec<f64>, f64, Xoshiro256PlusPlus> =
+            ParallelTempering::new_with_rng(
+                vec![1.0, 2.0, 4.0],
+                Xoshiro256PlusPlus::seed_from_u64(1),
+            )
+            .unwrap();
+
+        let state: IterState<Vec<f64>, (), (), (), f64> = IterState::new();
+        let res = pt.init(&mut Problem::new(TestProblem::new()), state);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`ParallelTempering` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+
+        let state: IterState<Vec<f64>, (), (), (), f64> = IterState::new().param(vec![1.0, 2.0]);
+        let (mut state, kv) = pt.init(&mut Problem::new(Rastrigin::new()), state).unwrap();
+        assert_eq!(
+            kv.unwrap(),
+            kv!(
+                "num_replicas" => 3u64;
+                "t_min" => 1.0f64;
+                "t_max" => 4.0f64;
+                "swap_interval" => 1u64;
+            )
+        );
+        assert_eq!(pt.replicas().len(), 3);
+        for r in pt.replicas() {
+            assert_eq!(r.param, vec![1.0, 2.0]);
+            assert_relative_eq!(r.cost, 5.0, epsilon = 1e-12);
+        }
+        assert_eq!(state.take_param().unwrap(), vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_swap_replicas() {
+        let mut pt: ParallelTempering<Vec<f64>, f64, Xoshiro256PlusPlus> =
+            ParallelTempering::new_with_rng(
+                vec![1.0, 2.0, 4.0],
+                Xoshiro256PlusPlus::seed_from_u64(1),
+            )
+            .unwrap();
+        // The hotter replica has a lower cost: swap is always accepted.
+        pt.replicas = vec![
+            Replica {
+                param: vec![0.0],
+                cost: 3.0,
+            },
+            Replica {
+                param: vec![1.0],
+                cost: 2.0,
+            },
+            Replica {
+                param: vec![2.0],
+                cost: 1.0,
+            },
+        ];
+
+        pt.swap_replicas(0);
+        assert_eq!(pt.replicas[0].param, vec![1.0]);
+        assert_eq!(pt.replicas[1].param, vec![0.0]);
+        assert_eq!(pt.replicas[2].param, vec![2.0]);
+        assert_eq!(pt.swaps_proposed, vec![1, 0]);
+        assert_eq!(pt.swaps_accepted, vec![1, 0]);
+
+        pt.swap_replicas(1);
+        assert_eq!(pt.replicas[1].param, vec![2.0]);
+        assert_eq!(pt.replicas[2].param, vec![0.0]);
+        assert_eq!(pt.swaps_proposed, vec![1, 1]);
+        assert_eq!(pt.swaps_accepted, vec![1, 1]);
+        assert_relative_eq!(pt.swap_rates()[0], 1.0, epsilon = f64::EPSILON);
+
+        // A much worse hot replica is practically never swapped down.
+        pt.replicas[0].cost = 0.0;
+        pt.replicas[1].cost = 1000.0;
+        pt.swap_replicas(0);
+        assert_eq!(pt.swaps_proposed, vec![2, 1]);
+        assert_eq!(pt.swaps_accepted, vec![1, 1]);
+        assert_relative_eq!(pt.swap_rates()[0], 0.5, epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_next_iter_kv() {
+        let mut pt: ParallelTempering<Vec<f64>, f64, Xoshiro256PlusPlus> =
+            ParallelTempering::new_with_rng(
+                vec![0.1, 1.0, 10.0],
+                Xoshiro256PlusPlus::seed_from_u64(3),
+            )
+            .unwrap();
+        let mut problem = Problem::new(Rastrigin::new());
+        let state: IterState<Vec<f64>, (), (), (), f64> = IterState::new().param(vec![2.0, -2.0]);
+        let (state, _) = pt.init(&mut problem, state).unwrap();
+        let (state, kv) = pt.next_iter(&mut problem, state).unwrap();
+        let kv = kv.unwrap();
+
+        assert_eq!(problem.counts["anneal_count"], 3);
+        assert_eq!(problem.counts["cost_count"], 4);
+        assert_eq!(kv.get("swapped"), Some(&KvValue::Bool(true)));
+        for (i, rate) in pt.acceptance_rates().into_iter().enumerate() {
+            assert_eq!(
+                kv.get(indexed_key("acc_rate", i)),
+                Some(&KvValue::Float(rate))
+            );
+        }
+        assert!(kv.get("acc_rate_3").is_none());
+        assert_eq!(kv.get("swap_rate_0"), Some(&KvValue::Float(1.0)));
+        assert!(kv.get("swap_rate_1").is_some());
+        assert!(kv.get("swap_rate_2").is_none());
+
+        let best = pt
+            .replicas()
+            .iter()
+            .map(|r| r.cost)
+            .fold(f64::INFINITY, f64::min);
+        assert_relative_eq!(state.get_cost(), best, epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_rastrigin() {
+        let temperatures = geometric_temperatures(0.05, 20.0, 6).unwrap();
+        let solver =
+            ParallelTempering::new_with_rng(temperatures, Xoshiro256PlusPlus::seed_from_u64(42))
+                .unwrap()
+                .with_swap_interval(2)
+                .unwrap();
+
+        let res = Executor::new(Rastrigin::new(), solver)
+            .configure(|state| state.param(vec![3.2, -4.1]).max_iters(3000))
+            .run()
+            .unwrap();
+
+        // The global minimum is at the origin, neighbouring local minima have a cost of ~1.
+        assert!(res.state().get_best_cost() < 0.5);
+        let best = res.state().get_best_param().unwrap();
+        for x in best {
+            assert!(x.abs() < 0.1);
+        }
+    }
+}
@@ -8,7 +8,8 @@
 //! # Particle Swarm Optimization (PSO)
 //!
 //! Canonical implementation of the particle swarm optimization method as outlined in \[0\] in
-//! chapter II, section A.
+//! chapter II, section A, with selectable neighbourhood topologies, constriction coefficients
+//! \[2\], velocity clamping, time-varying inertia and several boundary handling strategies.
 //!
 //! For details see [`ParticleSwarm`].
 //!
@@ -19,12 +20,20 @@
 //! Computation. <https://doi.org/10.1109/CEC.2013.6557848>
 //!
 //! \[1\] <https://en.wikipedia.org/wiki/Particle_swarm_optimization>
+//!
+//! \[2\] Clerc, M. and Kennedy, J. (2002): The particle swarm - explosion, stability, and
+//! convergence in a multidimensional complex space. IEEE Transactions on Evolutionary
+//! Computation 6(1), 58-73. <https://doi.org/10.1109/4235.985692>
 
 use crate::core::{
-    ArgminFloat, CostFunction, Error, PopulationState, Problem, SerializeAlias, Solver, SyncAlias,
-    KV,
+    ArgminFloat, CostFunction, Error, PopulationState, Problem, SerializeAlias, Solver, State,
+    SyncAlias, KV,
+};
+use argmin_math::{
+    ArgminAdd, ArgminMinMax, ArgminMul, ArgminOutOfBounds, ArgminRandom, ArgminSub, ArgminZeroLike,
 };
-use argmin_math::{ArgminAdd, ArgminMinMax, ArgminMul, ArgminRandom, ArgminSub, ArgminZeroLike};
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -33,10 +42,25 @@ use serde::{Deserialize, Serialize};
 /// Canonical implementation of the particle swarm optimization method as outlined in \[0\] in
 /// chapter II, section A.
 ///
+/// By default, all particles are attracted by the best position found by the entire swarm. Other
+/// neighbourhood topologies can be chosen via [`with_topology`](`ParticleSwarm::with_topology`)
+/// (see [`Topology`]). Instead of the default inertia weight and acceleration factors, Clerc's
+/// constriction coefficients \[2\] can be used via
+/// [`with_constriction`](`ParticleSwarm::with_constriction`), and the inertia weight can be
+/// decreased linearly over the course of the optimization via
+/// [`with_linear_inertia`](`ParticleSwarm::with_linear_inertia`). Velocities can be limited per
+/// dimension via [`with_velocity_limit`](`ParticleSwarm::with_velocity_limit`). How particles
+/// which leave the search space are treated is determined by [`BoundaryHandling`], which can be
+/// set via [`with_boundary_handling`](`ParticleSwarm::with_boundary_handling`).
+///
 /// The `rayon` feature enables parallel computation of the cost function. This can be beneficial
 /// for expensive cost functions, but may cause a drop in performance for cheap cost functions. Be
 /// sure to benchmark both parallel and sequential computation.
 ///
+/// The random number generator is owned by the solver (by default `Xoshiro256PlusPlus`) and, with
+/// the `serde1` feature, is part of checkpoints. Runs can be made reproducible by providing a
+/// seeded RNG via [`ParticleSwarm::new_with_rng`].
+///
 /// ## Requirements on the optimization problem
 ///
 /// The optimization problem is required to implement [`CostFunction`].
@@ -48,11 +72,17 @@ use serde::{Deserialize, Serialize};
 /// Computation. <https://doi.org/10.1109/CEC.2013.6557848>
 ///
 /// \[1\] <https://en.wikipedia.org/wiki/Particle_swarm_optimization>
+///
+/// \[2\] Clerc, M. and Kennedy, J. (2002): The particle swarm - explosion, stability, and
+/// convergence in a multidimensional complex space. IEEE Transactions on Evolutionary
+/// Computation 6(1), 58-73. <https://doi.org/10.1109/4235.985692>
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct ParticleSwarm<P, F> {
+pub struct ParticleSwarm<P, F, R = Xoshiro256PlusPlus> {
     /// Inertia weight
     weight_inertia: F,
+    /// Inertia weight at the final iteration (linearly decreasing inertia)
+    weight_inertia_final: Option<F>,
     /// Cognitive acceleration coefficient
     weight_cognitive: F,
     /// Social acceleration coefficient
@@ -61,9 +91,106 @@ pub struct ParticleSwarm<P, F> {
     bounds: (P, P),
     /// Number of particles
     num_particles: usize,
+    /// Neighbourhood topology
+    topology: Topology,
+    /// Indices of the particles informing each particle (not used for the global topology)
+    informants: Vec<Vec<usize>>,
+    /// Per-dimension limit on the absolute velocity
+    velocity_limit: Option<P>,
+    /// Treatment of particles leaving the search space
+    boundary_handling: BoundaryHandling,
+    /// Random number generator
+    rng: R,
+}
+
+/// Neighbourhood topology of a [`ParticleSwarm`]
+///
+/// The topology determines which particles inform a particle about their best positions. Each
+/// particle is attracted by the best position found in its neighbourhood (including itself).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum Topology {
+    /// All particles are informed by the entire swarm
+    #[default]
+    Global,
+    /// Particle `i` is informed by particles `i - 1` and `i + 1` (wrapping around)
+    Ring,
+    /// Particles are arranged row by row on a toroidal grid with `round(sqrt(num_particles))`
+    /// columns and are informed by their north, south, east and west neighbours. If the number of
+    /// particles is not a multiple of the number of columns, the last row is incomplete and the
+    /// affected rows and columns wrap around at their actual lengths.
+    VonNeumann,
+    /// Adaptive random topology of SPSO-2011: Each particle informs itself and `informants`
+    /// randomly chosen particles. The topology is redrawn after each iteration in which the best
+    /// cost found by the swarm did not improve.
+    RandomAdaptive {
+        /// Number of particles informed by each particle
+        informants: usize,
+    },
 }
 
-impl<P, F> ParticleSwarm<P, F>
+/// Treatment of particles which leave the search space of a [`ParticleSwarm`]
+///
+/// Independent of the chosen strategy, positions are finally clamped to the bounds such that they
+/// never leave the search space.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum BoundaryHandling {
+    /// Positions are clamped to the bounds, velocities are left unchanged
+    #[default]
+    Clamp,
+    /// Positions are clamped to the bounds and velocities are set to zero in the violated
+    /// dimensions
+    Absorb,
+    /// Positions are reflected at the bounds and velocities are reversed in the violated
+    /// dimensions
+    Reflect,
+    /// Positions are redrawn uniformly within the bounds and velocities are set to zero in the
+    /// violated dimensions
+    RandomReinit,
+}
+
+impl BoundaryHandling {
+    /// Moves a particle which left the search space back into the bounds and adapts its velocity
+    fn apply<P, F, R>(&self, bounds: &(P, P), position: P, velocity: P, rng: &mut R) -> (P, P)
+    where
+        P: ArgminAdd<P, P>
+            + ArgminSub<P, P>
+            + ArgminMul<P, P>
+            + ArgminMul<F, P>
+            + ArgminMinMax
+            + ArgminOutOfBounds
+            + ArgminRandom,
+        F: ArgminFloat,
+        R: Rng,
+    {
+        let (lower, upper) = bounds;
+        let (position, velocity) = match self {
+            BoundaryHandling::Clamp => (position, velocity),
+            BoundaryHandling::Absorb => {
+                let violated = position.out_of_bounds(lower, upper);
+                (position, velocity.sub(&velocity.mul(&violated)))
+            }
+            BoundaryHandling::Reflect => {
+                let violated = position.out_of_bounds(lower, upper);
+                let two: F = float!(2.0);
+                let position = P::max(&position, &lower.mul(&two).sub(&position));
+                let position = P::min(&position, &upper.mul(&two).sub(&position));
+                let velocity = velocity.sub(&velocity.mul(&violated).mul(&two));
+                (position, velocity)
+            }
+            BoundaryHandling::RandomReinit => {
+                let violated = position.out_of_bounds(lower, upper);
+                let random = P::rand_from_range_with_rng(lower, upper, rng);
+                let position = position.add(&random.sub(&position).mul(&violated));
+                (position, velocity.sub(&velocity.mul(&violated)))
+            }
+        };
+        (P::min(&P::max(&position, lower), upper), velocity)
+    }
+}
+
+impl<P, F> ParticleSwarm<P, F, Xoshiro256PlusPlus>
 where
     P: Clone + SyncAlias + ArgminSub<P, P> + ArgminMul<F, P> + ArgminRandom + ArgminZeroLike,
     F: ArgminFloat,
@@ -85,6 +212,12 @@ where
     /// * cognitive: `0.5 + ln(2)`
     /// * social: `0.5 + ln(2)`
     ///
+    /// By default, the global topology is used, velocities are not limited and positions are
+    /// clamped to the bounds.
+    ///
+    /// Uses the `Xoshiro256PlusPlus` RNG internally. For use of another (or a seeded) RNG,
+    /// consider using [`ParticleSwarm::new_with_rng`].
+    ///
     /// # Example
     ///
     /// ```
@@ -94,12 +227,47 @@ where
     /// let pso: ParticleSwarm<_, f64> = ParticleSwarm::new((lower_bound, upper_bound), 40);
     /// ```
     pub fn new(bounds: (P, P), num_particles: usize) -> Self {
+        ParticleSwarm::new_with_rng(bounds, num_particles, Xoshiro256PlusPlus::from_entropy())
+    }
+}
+
+impl<P, F, R> ParticleSwarm<P, F, R>
+where
+    P: Clone + SyncAlias + ArgminSub<P, P> + ArgminMul<F, P> + ArgminRandom + ArgminZeroLike,
+    F: ArgminFloat,
+    R: Rng,
+{
+    /// Construct a new instance of `ParticleSwarm` with a given random number generator
+    ///
+    /// Same as [`ParticleSwarm::new`], but requires a RNG which must implement `rand::Rng` (and
+    /// `serde::Serialize` if the `serde1` feature is enabled). Providing a seeded RNG makes runs
+    /// reproducible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::particleswarm::ParticleSwarm;
+    /// use rand::SeedableRng;
+    /// use rand_xoshiro::Xoshiro256PlusPlus;
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let rng = Xoshiro256PlusPlus::seed_from_u64(42);
+    /// let pso: ParticleSwarm<_, f64, _> =
+    ///     ParticleSwarm::new_with_rng((lower_bound, upper_bound), 40, rng);
+    /// ```
+    pub fn new_with_rng(bounds: (P, P), num_particles: usize, rng: R) -> Self {
         ParticleSwarm {
             weight_inertia: float!(1.0f64 / (2.0 * 2.0f64.ln())),
+            weight_inertia_final: None,
             weight_cognitive: float!(0.5 + 2.0f64.ln()),
             weight_social: float!(0.5 + 2.0f64.ln()),
             bounds,
             num_particles,
+            topology: Topology::Global,
+            informants: vec![],
+            velocity_limit: None,
+            boundary_handling: BoundaryHandling::Clamp,
+            rng,
         }
     }
 
@@ -128,6 +296,89 @@ where
             ));
         }
         self.weight_inertia = factor;
+        self.weight_inertia_final = None;
+        Ok(self)
+    }
+
+    /// Decrease the inertia factor linearly from `start` to `end`
+    ///
+    /// The inertia factor equals `start` in the first iteration and reaches `end` at the maximum
+    /// number of iterations, which therefore needs to be set. Typical values are `start = 0.9`
+    /// and `end = 0.4`. Overrides the inertia factor set via
+    /// [`with_inertia_factor`](`ParticleSwarm::with_inertia_factor`) or
+    /// [`with_constriction`](`ParticleSwarm::with_constriction`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::particleswarm::ParticleSwarm;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let pso: ParticleSwarm<_, f64> =
+    ///     ParticleSwarm::new((lower_bound, upper_bound), 40).with_linear_inertia(0.9, 0.4)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_linear_inertia(mut self, start: F, end: F) -> Result<Self, Error> {
+        if start < float!(0.0) || end < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ParticleSwarm`: inertia factors must be >=0."
+            ));
+        }
+        self.weight_inertia = start;
+        self.weight_inertia_final = Some(end);
+        Ok(self)
+    }
+
+    /// Use Clerc's constriction coefficients
+    ///
+    /// Given the acceleration coefficients `phi_cognitive` and `phi_social`, the constriction
+    /// factor `chi = 2 / (phi - 2 + sqrt(phi^2 - 4 * phi))` with `phi = phi_cognitive +
+    /// phi_social` is computed. The inertia factor is set to `chi` and the cognitive and social
+    /// acceleration factors are set to `chi * phi_cognitive` and `chi * phi_social`, respectively.
+    /// `phi` must be larger than 4. The common choice `phi_cognitive = phi_social = 2.05` results
+    /// in `chi ≈ 0.7298`.
+    ///
+    /// Overrides the factors set via [`with_inertia_factor`](`ParticleSwarm::with_inertia_factor`),
+    /// [`with_linear_inertia`](`ParticleSwarm::with_linear_inertia`),
+    /// [`with_cognitive_factor`](`ParticleSwarm::with_cognitive_factor`) and
+    /// [`with_social_factor`](`ParticleSwarm::with_social_factor`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::particleswarm::ParticleSwarm;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let pso: ParticleSwarm<_, f64> =
+    ///     ParticleSwarm::new((lower_bound, upper_bound), 40).with_constriction(2.05, 2.05)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_constriction(mut self, phi_cognitive: F, phi_social: F) -> Result<Self, Error> {
+        if phi_cognitive < float!(0.0) || phi_social < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ParticleSwarm`: acceleration coefficients must be >=0."
+            ));
+        }
+        let phi = phi_cognitive + phi_social;
+        if phi <= float!(4.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ParticleSwarm`: sum of acceleration coefficients must be >4."
+            ));
+        }
+        let chi = float!(2.0) / (phi - float!(2.0) + (phi * phi - float!(4.0) * phi).sqrt());
+        self.weight_inertia = chi;
+        self.weight_inertia_final = None;
+        self.weight_cognitive = chi * phi_cognitive;
+        self.weight_social = chi * phi_social;
         Ok(self)
     }
 
@@ -187,6 +438,143 @@ where
         Ok(self)
     }
 
+    /// Set neighbourhood topology
+    ///
+    /// Defaults to [`Topology::Global`]. For [`Topology::RandomAdaptive`], the number of
+    /// informants must be larger than 0; SPSO-2011 uses 3.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::particleswarm::{ParticleSwarm, Topology};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let pso: ParticleSwarm<_, f64> = ParticleSwarm::new((lower_bound, upper_bound), 40)
+    ///     .with_topology(Topology::RandomAdaptive { informants: 3 })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_topology(mut self, topology: Topology) -> Result<Self, Error> {
+        if let Topology::RandomAdaptive { informants: 0 } = topology {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ParticleSwarm`: number of informants must be >0."
+            ));
+        }
+        self.topology = topology;
+        Ok(self)
+    }
+
+    /// Limit the absolute velocity of the particles per dimension
+    ///
+    /// After each velocity update, each element of the velocity is clamped to
+    /// `[-velocity_limit, velocity_limit]`. All elements of `velocity_limit` must be
+    /// non-negative. A common choice is a fraction (for instance 20%) of the size of the search
+    /// space in each dimension. By default, velocities are not limited.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::particleswarm::ParticleSwarm;
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let pso: ParticleSwarm<_, f64> =
+    ///     ParticleSwarm::new((lower_bound, upper_bound), 40).with_velocity_limit(vec![0.4, 0.4]);
+    /// ```
+    #[must_use]
+    pub fn with_velocity_limit(mut self, velocity_limit: P) -> Self {
+        self.velocity_limit = Some(velocity_limit);
+        self
+    }
+
+    /// Set the treatment of particles which leave the search space
+    ///
+    /// Defaults to [`BoundaryHandling::Clamp`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::particleswarm::{BoundaryHandling, ParticleSwarm};
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let pso: ParticleSwarm<_, f64> = ParticleSwarm::new((lower_bound, upper_bound), 40)
+    ///     .with_boundary_handling(BoundaryHandling::Reflect);
+    /// ```
+    #[must_use]
+    pub fn with_boundary_handling(mut self, boundary_handling: BoundaryHandling) -> Self {
+        self.boundary_handling = boundary_handling;
+        self
+    }
+
+    /// Inertia factor in iteration `iter` of at most `max_iters` iterations
+    fn inertia(&self, iter: u64, max_iters: u64) -> F {
+        match self.weight_inertia_final {
+            Some(end) if max_iters > 0 => {
+                let progress = float!(iter.min(max_iters) as f64 / max_iters as f64);
+                self.weight_inertia + (end - self.weight_inertia) * progress
+            }
+            Some(end) => end,
+            None => self.weight_inertia,
+        }
+    }
+
+    /// Computes the indices of the particles informing each particle according to the topology
+    fn update_informants(&mut self) {
+        let n = self.num_particles;
+        self.informants = match self.topology {
+            Topology::Global => vec![],
+            Topology::Ring => (0..n)
+                .map(|i| vec![(i + n - 1) % n, i, (i + 1) % n])
+                .collect(),
+            Topology::VonNeumann => {
+                let cols = ((n as f64).sqrt().round() as usize).max(1);
+                let rows = n.div_ceil(cols);
+                // If `n` is not a multiple of `cols`, the last row is incomplete. Rows and columns
+                // wrap around according to their actual lengths.
+                let row_len = |row: usize| cols.min(n - row * cols);
+                let col_len = |col: usize| {
+                    if col < row_len(rows - 1) {
+                        rows
+                    } else {
+                        rows - 1
+                    }
+                };
+                (0..n)
+                    .map(|i| {
+                        let (row, col) = (i / cols, i % cols);
+                        let (row_len, col_len) = (row_len(row), col_len(col));
+                        let mut neighbours = vec![
+                            i,
+                            row * cols + (col + row_len - 1) % row_len,
+                            row * cols + (col + 1) % row_len,
+                            ((row + col_len - 1) % col_len) * cols + col,
+                            ((row + 1) % col_len) * cols + col,
+                        ];
+                        // Small grids may contain a particle more than once
+                        let mut seen = vec![];
+                        neighbours.retain(|&j| {
+                            let new = !seen.contains(&j);
+                            seen.push(j);
+                            new
+                        });
+                        neighbours
+                    })
+                    .collect()
+            }
+            Topology::RandomAdaptive { informants } => {
+                let mut neighbourhoods: Vec<Vec<usize>> = (0..n).map(|i| vec![i]).collect();
+                for i in 0..n {
+                    for _ in 0..informants {
+                        neighbourhoods[self.rng.gen_range(0..n)].push(i);
+                    }
+                }
+                neighbourhoods
+            }
+        };
+    }
+
     /// Initializes all particles randomly and sorts them by their cost function values
     fn initialize_particles<O: CostFunction<Param = P, Output = F> + SyncAlias>(
         &mut self,
@@ -214,23 +602,24 @@ where
     }
 
     /// Initializes positions and velocities for all particles
-    fn initialize_positions_and_velocities(&self) -> (Vec<P>, Vec<P>) {
+    fn initialize_positions_and_velocities(&mut self) -> (Vec<P>, Vec<P>) {
         let (min, max) = &self.bounds;
         let delta = max.sub(min);
         let delta_neg = delta.mul(&float!(-1.0));
+        let rng = &mut self.rng;
 
         (
             (0..self.num_particles)
-                .map(|_| P::rand_from_range(min, max))
+                .map(|_| P::rand_from_range_with_rng(min, max, rng))
                 .collect(),
             (0..self.num_particles)
-                .map(|_| P::rand_from_range(&delta_neg, &delta))
+                .map(|_| P::rand_from_range_with_rng(&delta_neg, &delta, rng))
                 .collect(),
         )
     }
 }
 
-impl<O, P, F> Solver<O, PopulationState<Particle<P, F>, F>> for ParticleSwarm<P, F>
+impl<O, P, F, R> Solver<O, PopulationState<Particle<P, F>, F>> for ParticleSwarm<P, F, R>
 where
     O: CostFunction<Param = P, Output = F> + SyncAlias,
     P: SerializeAlias
@@ -239,10 +628,13 @@ where
         + ArgminAdd<P, P>
         + ArgminSub<P, P>
         + ArgminMul<F, P>
+        + ArgminMul<P, P>
         + ArgminZeroLike
         + ArgminRandom
-        + ArgminMinMax,
+        + ArgminMinMax
+        + ArgminOutOfBounds,
     F: ArgminFloat,
+    R: Rng + SerializeAlias,
 {
     const NAME: &'static str = "Particle Swarm Optimization";
 
@@ -251,6 +643,13 @@ where
         problem: &mut Problem<O>,
         mut state: PopulationState<Particle<P, F>, F>,
     ) -> Result<(PopulationState<Particle<P, F>, F>, Option<KV>), Error> {
+        if self.weight_inertia_final.is_some() && state.get_max_iters() == std::u64::MAX {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ParticleSwarm`: linearly decreasing inertia requires a maximum number of iterations."
+            ));
+        }
+
         // Users can provide a population or it will be randomly created.
         let particles = match state.take_population() {
             Some(mut particles) if particles.len() == self.num_particles => {
@@ -275,6 +674,8 @@ where
             None => self.initialize_particles(problem)?,
         };
 
+        self.update_informants();
+
         Ok((
             state
                 .individual(particles[0].clone())
@@ -301,40 +702,81 @@ where
         ))?;
 
         let zero = P::zero_like(&best_particle.position);
+        let weight_inertia = self.inertia(state.get_iter(), state.get_max_iters());
+
+        // Best positions in the neighbourhood of each particle. For the global topology, this is
+        // the best position of the entire swarm.
+        let neighbourhood_best: Option<Vec<P>> = match self.topology {
+            Topology::Global => None,
+            _ => Some(
+                self.informants
+                    .iter()
+                    .map(|informants| {
+                        let best = informants
+                            .iter()
+                            .map(|&j| &particles[j])
+                            .min_by(|a, b| {
+                                a.best_cost
+                                    .partial_cmp(&b.best_cost)
+                                    .unwrap_or(std::cmp::Ordering::Equal)
+                            })
+                            .unwrap();
+                        best.best_position.clone()
+                    })
+                    .collect(),
+            ),
+        };
 
         let positions: Vec<_> = particles
             .iter_mut()
-            .map(|p| {
+            .enumerate()
+            .map(|(i, p)| {
                 // New velocity is composed of
                 // 1) previous velocity (momentum),
                 // 2) motion toward particle optimum and
-                // 3) motion toward global optimum.
+                // 3) motion toward neighbourhood optimum.
 
                 // ad 1)
-                let momentum = p.velocity.mul(&self.weight_inertia);
+                let momentum = p.velocity.mul(&weight_inertia);
 
                 // ad 2)
                 let to_optimum = p.best_position.sub(&p.position);
-                let pull_to_optimum = P::rand_from_range(&zero, &to_optimum);
+                let pull_to_optimum =
+                    P::rand_from_range_with_rng(&zero, &to_optimum, &mut self.rng);
                 let pull_to_optimum = pull_to_optimum.mul(&self.weight_cognitive);
 
                 // ad 3)
-                let to_global_optimum = best_particle.position.sub(&p.position);
-                let pull_to_global_optimum =
-                    P::rand_from_range(&zero, &to_global_optimum).mul(&self.weight_social);
-
-                p.velocity = momentum.add(&pull_to_optimum).add(&pull_to_global_optimum);
-                let new_position = p.position.add(&p.velocity);
+                let neighbourhood_optimum = neighbourhood_best
+                    .as_ref()
+                    .map_or(&best_particle.position, |best| &best[i]);
+                let to_neighbourhood_optimum = neighbourhood_optimum.sub(&p.position);
+                let pull_to_neighbourhood_optimum =
+                    P::rand_from_range_with_rng(&zero, &to_neighbourhood_optimum, &mut self.rng)
+                        .mul(&self.weight_social);
+
+                let mut velocity = momentum
+                    .add(&pull_to_optimum)
+                    .add(&pull_to_neighbourhood_optimum);
+                if let Some(limit) = &self.velocity_limit {
+                    velocity = P::min(&P::max(&velocity, &limit.mul(&float!(-1.0))), limit);
+                }
+                let new_position = p.position.add(&velocity);
 
                 // Limit to search window
-                p.position = P::min(&P::max(&new_position, &self.bounds.0), &self.bounds.1);
+                (p.position, p.velocity) = self.boundary_handling.apply::<_, F, _>(
+                    &self.bounds,
+                    new_position,
+                    velocity,
+                    &mut self.rng,
+                );
                 &p.position
             })
             .collect();
 
         let costs = problem.bulk_cost(&positions)?;
 
-        for (p, c) in particles.iter_mut().zip(costs.into_iter()) {
+        let mut improved = false;
+        for (p, c) in particles.iter_mut().zip(costs) {
             p.cost = c;
 
             if p.cost < p.best_cost {
@@ -347,10 +789,17 @@ where
                     best_particle.cost = p.cost;
                     best_particle.best_cost = p.cost;
                     best_cost = p.cost;
+                    improved = true;
                 }
             }
         }
 
+        if !improved {
+            if let Topology::RandomAdaptive { .. } = self.topology {
+                self.update_informants();
+            }
+        }
+
         Ok((
             state
                 .individual(best_particle)
@@ -404,7 +853,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::{test_utils::TestProblem, ArgminError, State};
+    use crate::core::{test_utils::TestProblem, ArgminError, Executor, State};
     use crate::test_trait_impl;
     use approx::assert_relative_eq;
 
@@ -418,10 +867,16 @@ mod tests {
             ParticleSwarm::new((lower_bound.clone(), upper_bound.clone()), 40);
         let ParticleSwarm {
             weight_inertia,
+            weight_inertia_final,
             weight_cognitive,
             weight_social,
             bounds,
             num_particles,
+            topology,
+            informants,
+            velocity_limit,
+            boundary_handling,
+            rng: _rng,
         } = pso;
 
         assert_relative_eq!(
@@ -444,6 +899,11 @@ mod tests {
         assert_eq!(upper_bound[0].to_ne_bytes(), bounds.1[0].to_ne_bytes());
         assert_eq!(upper_bound[1].to_ne_bytes(), bounds.1[1].to_ne_bytes());
         assert_eq!(num_particles, 40);
+        assert!(weight_inertia_final.is_none());
+        assert_eq!(topology, Topology::Global);
+        assert!(informants.is_empty());
+        assert!(velocity_limit.is_none());
+        assert_eq!(boundary_handling, BoundaryHandling::Clamp);
     }
 
     #[test]
@@ -475,6 +935,86 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_with_linear_inertia() {
+        let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+        let upper_bound: Vec<f64> = vec![1.0, 1.0];
+
+        let pso = ParticleSwarm::new((lower_bound.clone(), upper_bound.clone()), 40)
+            .with_linear_inertia(0.9, 0.4)
+            .unwrap();
+        assert_eq!(pso.weight_inertia.to_ne_bytes(), 0.9f64.to_ne_bytes());
+        assert_eq!(
+            pso.weight_inertia_final.unwrap().to_ne_bytes(),
+            0.4f64.to_ne_bytes()
+        );
+        assert_relative_eq!(pso.inertia(0, 100), 0.9, epsilon = f64::EPSILON);
+        assert_relative_eq!(pso.inertia(50, 100), 0.65, epsilon = f64::EPSILON);
+        assert_relative_eq!(pso.inertia(100, 100), 0.4, epsilon = f64::EPSILON);
+        assert_relative_eq!(pso.inertia(200, 100), 0.4, epsilon = f64::EPSILON);
+        assert_relative_eq!(pso.inertia(0, 0), 0.4, epsilon = f64::EPSILON);
+
+        // a constant inertia factor replaces the schedule
+        let pso = pso.with_inertia_factor(0.5).unwrap();
+        assert!(pso.weight_inertia_final.is_none());
+        assert_relative_eq!(pso.inertia(50, 100), 0.5, epsilon = f64::EPSILON);
+
+        for (start, end) in [(-0.1, 0.4), (0.9, -0.1), (-1.0, -1.0)] {
+            let res = ParticleSwarm::new((lower_bound.clone(), upper_bound.clone()), 40)
+                .with_linear_inertia(start, end);
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`ParticleSwarm`: ",
+                    "inertia factors must be >=0.\""
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_constriction() {
+        let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+        let upper_bound: Vec<f64> = vec![1.0, 1.0];
+
+        let pso = ParticleSwarm::new((lower_bound.clone(), upper_bound.clone()), 40)
+            .with_linear_inertia(0.9, 0.4)
+            .unwrap()
+            .with_constriction(2.05, 2.05)
+            .unwrap();
+        assert_relative_eq!(pso.weight_inertia, 0.729_843_788, epsilon = 1e-9);
+        assert_relative_eq!(pso.weight_cognitive, 1.496_179_765, epsilon = 1e-9);
+        assert_relative_eq!(pso.weight_social, 1.496_179_765, epsilon = 1e-9);
+        assert!(pso.weight_inertia_final.is_none());
+
+        for (c1, c2) in [(2.0, 2.0), (1.0, 1.0), (0.0, 0.0)] {
+            let res = ParticleSwarm::new((lower_bound.clone(), upper_bound.clone()), 40)
+                .with_constriction(c1, c2);
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`ParticleSwarm`: ",
+                    "sum of acceleration coefficients must be >4.\""
+                )
+            );
+        }
+
+        for (c1, c2) in [(-1.0, 6.0), (6.0, -1.0)] {
+            let res = ParticleSwarm::new((lower_bound.clone(), upper_bound.clone()), 40)
+                .with_constriction(c1, c2);
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`ParticleSwarm`: ",
+                    "acceleration coefficients must be >=0.\""
+                )
+            );
+        }
+    }
+
     #[test]
     fn test_with_cognitive_factor() {
         let lower_bound: Vec<f64> = vec![-1.0, -1.0];
@@ -533,12 +1073,154 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_with_topology() {
+        let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+        let upper_bound: Vec<f64> = vec![1.0, 1.0];
+
+        for topology in [
+            Topology::Global,
+            Topology::Ring,
+            Topology::VonNeumann,
+            Topology::RandomAdaptive { informants: 3 },
+        ] {
+            let pso: ParticleSwarm<_, f64> =
+                ParticleSwarm::new((lower_bound.clone(), upper_bound.clone()), 40)
+                    .with_topology(topology)
+                    .unwrap();
+            assert_eq!(pso.topology, topology);
+        }
+
+        let res: Result<ParticleSwarm<_, f64>, _> =
+            ParticleSwarm::new((lower_bound, upper_bound), 40)
+                .with_topology(Topology::RandomAdaptive { informants: 0 });
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`ParticleSwarm`: ",
+                "number of informants must be >0.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_with_velocity_limit_and_boundary_handling() {
+        let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+        let upper_bound: Vec<f64> = vec![1.0, 1.0];
+        let pso: ParticleSwarm<_, f64> = ParticleSwarm::new((lower_bound, upper_bound), 40)
+            .with_velocity_limit(vec![0.1, 0.2])
+            .with_boundary_handling(BoundaryHandling::Reflect);
+        assert_eq!(pso.velocity_limit, Some(vec![0.1, 0.2]));
+        assert_eq!(pso.boundary_handling, BoundaryHandling::Reflect);
+    }
+
+    #[test]
+    fn test_update_informants() {
+        let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+        let upper_bound: Vec<f64> = vec![1.0, 1.0];
+        let pso: ParticleSwarm<_, f64> = ParticleSwarm::new((lower_bound, upper_bound), 9);
+
+        let mut global = pso.clone();
+        global.update_informants();
+        assert!(global.informants.is_empty());
+
+        let mut ring = pso.clone().with_topology(Topology::Ring).unwrap();
+        ring.update_informants();
+        assert_eq!(ring.informants[0], vec![8, 0, 1]);
+        assert_eq!(ring.informants[4], vec![3, 4, 5]);
+        assert_eq!(ring.informants[8], vec![7, 8, 0]);
+
+        let mut von_neumann = pso.clone().with_topology(Topology::VonNeumann).unwrap();
+        von_neumann.update_informants();
+        // 3x3 grid: west, east, north and south neighbours on the torus
+        assert_eq!(von_neumann.informants[0], vec![0, 2, 1, 6, 3]);
+        assert_eq!(von_neumann.informants[3], vec![3, 5, 4, 0, 6]);
+        assert_eq!(von_neumann.informants[4], vec![4, 3, 5, 1, 7]);
+        assert_eq!(von_neumann.informants[8], vec![8, 7, 6, 5, 2]);
+
+        // 7 particles on a grid with 3 columns, where the last row only contains particle 6
+        let mut incomplete = ParticleSwarm::<_, f64>::new((vec![-1.0], vec![1.0]), 7)
+            .with_topology(Topology::VonNeumann)
+            .unwrap();
+        incomplete.update_informants();
+        assert_eq!(
+            incomplete.informants,
+            vec![
+                vec![0, 2, 1, 6, 3],
+                vec![1, 0, 2, 4],
+                vec![2, 1, 0, 5],
+                vec![3, 5, 4, 0, 6],
+                vec![4, 3, 5, 1],
+                vec![5, 4, 3, 2],
+                vec![6, 3, 0],
+            ]
+        );
+        // The neighbourhood relation is symmetric
+        for (i, informants) in incomplete.informants.iter().enumerate() {
+            for &j in informants {
+                assert!(incomplete.informants[j].contains(&i));
+            }
+        }
+
+        let mut random = pso
+            .with_topology(Topology::RandomAdaptive { informants: 3 })
+            .unwrap();
+        random.update_informants();
+        assert_eq!(random.informants.len(), 9);
+        // every particle informs itself and three others
+        assert_eq!(random.informants.iter().map(Vec::len).sum::<usize>(), 9 * 4);
+        for (i, informants) in random.informants.iter().enumerate() {
+            assert_eq!(informants[0], i);
+            assert!(informants.iter().all(|&j| j < 9));
+        }
+    }
+
+    #[test]
+    fn test_boundary_handling() {
+        let bounds = (vec![-1.0, -1.0, -1.0], vec![1.0, 1.0, 1.0]);
+        let position = vec![1.5, 0.5, -1.25];
+        let velocity = vec![1.0, 0.5, -0.5];
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+
+        let apply = |handling: BoundaryHandling, rng: &mut Xoshiro256PlusPlus| {
+            handling.apply::<_, f64, _>(&bounds, position.clone(), velocity.clone(), rng)
+        };
+
+        let (x, v) = apply(BoundaryHandling::Clamp, &mut rng);
+        assert_eq!(x, vec![1.0, 0.5, -1.0]);
+        assert_eq!(v, velocity);
+
+        let (x, v) = apply(BoundaryHandling::Absorb, &mut rng);
+        assert_eq!(x, vec![1.0, 0.5, -1.0]);
+        assert_eq!(v, vec![0.0, 0.5, 0.0]);
+
+        let (x, v) = apply(BoundaryHandling::Reflect, &mut rng);
+        assert_eq!(x, vec![0.5, 0.5, -0.75]);
+        assert_eq!(v, vec![-1.0, 0.5, 0.5]);
+
+        let (x, v) = apply(BoundaryHandling::RandomReinit, &mut rng);
+        assert!(x[0] >= -1.0 && x[0] <= 1.0);
+        assert_eq!(x[1].to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert!(x[2] >= -1.0 && x[2] <= 1.0);
+        assert_eq!(v, vec![0.0, 0.5, 0.0]);
+
+        // reflections which would still leave the search space are clamped
+        let (x, _) = BoundaryHandling::Reflect.apply::<_, f64, _>(
+            &bounds,
+            vec![4.0, 0.0, 0.0],
+            velocity.clone(),
+            &mut rng,
+        );
+        assert_eq!(x, vec![-1.0, 0.0, 0.0]);
+    }
+
     #[test]
     fn test_initialize_positions_and_velocities() {
         let lower_bound: Vec<f64> = vec![-1.0, -1.0];
         let upper_bound: Vec<f64> = vec![1.0, 1.0];
         let num_particles = 100;
-        let pso: ParticleSwarm<_, f64> =
+        let mut pso: ParticleSwarm<_, f64> =
             ParticleSwarm::new((lower_bound, upper_bound), num_particles);
 
         let (positions, velocities) = pso.initialize_positions_and_velocities();
@@ -679,6 +1361,25 @@ mod tests {
         assert_eq!(population.len(), 40);
     }
 
+    #[test]
+    fn test_init_linear_inertia_without_max_iters() {
+        let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+        let upper_bound: Vec<f64> = vec![1.0, 1.0];
+        let mut pso: ParticleSwarm<_, f64> = ParticleSwarm::new((lower_bound, upper_bound), 40)
+            .with_linear_inertia(0.9, 0.4)
+            .unwrap();
+        let state: PopulationState<Particle<Vec<f64>, f64>, f64> = PopulationState::new();
+        let res = pso.init(&mut Problem::new(TestProblem::new()), state);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`ParticleSwarm`: ",
+                "linearly decreasing inertia requires a maximum number of iterations.\""
+            )
+        );
+    }
+
     #[test]
     fn test_next_iter() {
         struct PsoProblem {
@@ -727,4 +1428,109 @@ mod tests {
             assert_eq!(state.get_cost().to_ne_bytes(), (-3.0f64).to_ne_bytes());
         }
     }
+
+    #[test]
+    fn test_reproducible_with_seeded_rng() {
+        let run = || {
+            let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+            let upper_bound: Vec<f64> = vec![1.0, 1.0];
+            let mut pso = ParticleSwarm::new_with_rng(
+                (lower_bound, upper_bound),
+                10,
+                Xoshiro256PlusPlus::seed_from_u64(42),
+            );
+            let mut problem = Problem::new(TestProblem::new());
+            let state: PopulationState<Particle<Vec<f64>, f64>, f64> = PopulationState::new();
+            let (mut state, _) = pso.init(&mut problem, state).unwrap();
+            for _ in 0..10 {
+                (state, _) = pso.next_iter(&mut problem, state).unwrap();
+            }
+            state.take_population().unwrap()
+        };
+
+        assert_eq!(run(), run());
+    }
+
+    #[test]
+    fn test_next_iter_velocity_limit() {
+        let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+        let upper_bound: Vec<f64> = vec![1.0, 1.0];
+        let mut pso = ParticleSwarm::new_with_rng(
+            (lower_bound, upper_bound),
+            20,
+            Xoshiro256PlusPlus::seed_from_u64(42),
+        )
+        .with_velocity_limit(vec![0.1, 0.05]);
+        let mut problem = Problem::new(SphereProblem {});
+        let state: PopulationState<Particle<Vec<f64>, f64>, f64> = PopulationState::new();
+        let (mut state, _) = pso.init(&mut problem, state).unwrap();
+        for _ in 0..20 {
+            (state, _) = pso.next_iter(&mut problem, state).unwrap();
+            for particle in state.get_population().unwrap() {
+                assert!(particle.velocity[0].abs() <= 0.1);
+                assert!(particle.velocity[1].abs() <= 0.05);
+            }
+        }
+    }
+
+    struct SphereProblem {}
+
+    impl CostFunction for SphereProblem {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p.iter().map(|x| (x - 0.5).powi(2)).sum())
+        }
+    }
+
+    #[test]
+    fn test_solver_variants() {
+        let topologies = [
+            Topology::Global,
+            Topology::Ring,
+            Topology::VonNeumann,
+            Topology::RandomAdaptive { informants: 3 },
+        ];
+        let handlings = [
+            BoundaryHandling::Clamp,
+            BoundaryHandling::Absorb,
+            BoundaryHandling::Reflect,
+            BoundaryHandling::RandomReinit,
+        ];
+
+        for (topology, handling) in topologies.into_iter().zip(handlings) {
+            let pso = ParticleSwarm::new_with_rng(
+                (vec![-2.0; 4], vec![2.0; 4]),
+                30,
+                Xoshiro256PlusPlus::seed_from_u64(42),
+            )
+            .with_constriction(2.05, 2.05)
+            .unwrap()
+            .with_topology(topology)
+            .unwrap()
+            .with_velocity_limit(vec![1.0; 4])
+            .with_boundary_handling(handling);
+            let res = Executor::new(SphereProblem {}, pso)
+                .configure(|state| state.max_iters(300))
+                .run()
+                .unwrap();
+            assert!(res.state.get_best_cost() < 1e-8);
+        }
+
+        let pso = ParticleSwarm::new_with_rng(
+            (vec![-2.0; 4], vec![2.0; 4]),
+            30,
+            Xoshiro256PlusPlus::seed_from_u64(42),
+        )
+        .with_linear_inertia(0.9, 0.4)
+        .unwrap()
+        .with_topology(Topology::Ring)
+        .unwrap();
+        let res = Executor::new(SphereProblem {}, pso)
+            .configure(|state| state.max_iters(300))
+            .run()
+            .unwrap();
+        assert!(res.state.get_best_cost() < 1e-8);
+    }
 }
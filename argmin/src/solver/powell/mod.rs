@@ -0,0 +1,665 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Powell's conjugate direction method
+//!
+//! Derivative-free minimization by successive line searches along a set of directions, which is
+//! updated in each iteration such that the directions tend to become mutually conjugate \[0\].
+//!
+//! For details see [`Powell`].
+//!
+//! ## References
+//!
+//! \[0\] Powell, M.J.D. (1964): An efficient method for finding the minimum of a function of
+//! several variables without calculating derivatives. The Computer Journal 7(2), 155-162.
+//! <https://doi.org/10.1093/comjnl/7.2.155>
+//!
+//! \[1\] Press, W.H. et.al. (2007): Numerical Recipes: The Art of Scientific Computing. Third
+//! Edition. Cambridge University Press. Section 10.7.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Executor, IterState, OptimizationResult, Problem,
+    SerializeAlias, Solver, State, TerminationReason, TerminationStatus, KV,
+};
+use crate::solver::brent::BrentOpt;
+use argmin_math::{ArgminBasis, ArgminL2Norm, ArgminScaledAdd, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Golden ratio, used for expanding the bracketing interval
+const GOLDEN_RATIO: f64 = 1.618_033_988_749_895;
+
+/// Maximum number of expansions of the bracketing interval
+const MAX_BRACKET_EXPANSIONS: usize = 50;
+
+/// Maximum number of iterations of Brent's method per line search
+const MAX_LINE_SEARCH_ITERS: u64 = 100;
+
+/// # Powell's conjugate direction method
+///
+/// In each iteration, the cost function is minimized successively along each direction of a set
+/// of directions (initially the coordinate axes). The overall displacement of the iteration then
+/// replaces the direction of largest decrease, unless Powell's heuristic indicates that this
+/// would make the set of directions linearly dependent \[1\].
+///
+/// Each line search first brackets a minimum by expanding the search interval with the golden
+/// ratio and then locates it with Brent's method ([`BrentOpt`]). The length of the first
+/// bracketing step equals the initial step length (see
+/// [`with_initial_step`](`Powell::with_initial_step`)) in the first iteration and the length of
+/// the displacement of the previous iteration afterwards.
+///
+/// The algorithm terminates when the relative decrease of the cost function value in an
+/// iteration falls below a tolerance (see [`with_cost_tolerance`](`Powell::with_cost_tolerance`)).
+///
+/// Requires an initial parameter vector.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`].
+///
+/// ## References
+///
+/// \[0\] Powell, M.J.D. (1964): An efficient method for finding the minimum of a function of
+/// several variables without calculating derivatives. The Computer Journal 7(2), 155-162.
+/// <https://doi.org/10.1093/comjnl/7.2.155>
+///
+/// \[1\] Press, W.H. et.al. (2007): Numerical Recipes: The Art of Scientific Computing. Third
+/// Edition. Cambridge University Press. Section 10.7.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Powell<P, F> {
+    /// Current set of search directions
+    directions: Vec<P>,
+    /// Length of the first bracketing step in the first iteration
+    initial_step: F,
+    /// Length of the first bracketing step
+    step: F,
+    /// Absolute tolerance of the line searches
+    line_tolerance: F,
+    /// Relative tolerance on the decrease of the cost function value
+    cost_tolerance: F,
+}
+
+impl<P, F> Powell<P, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`Powell`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::powell::Powell;
+    /// let powell: Powell<Vec<f64>, f64> = Powell::new();
+    /// ```
+    pub fn new() -> Self {
+        Powell {
+            directions: vec![],
+            initial_step: float!(1.0),
+            step: float!(1.0),
+            line_tolerance: F::epsilon().sqrt(),
+            cost_tolerance: F::epsilon().sqrt(),
+        }
+    }
+
+    /// Set the initial search directions
+    ///
+    /// The directions should be linearly independent and span the parameter space. Defaults to
+    /// the coordinate axes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::powell::Powell;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let powell: Powell<Vec<f64>, f64> =
+    ///     Powell::new().with_directions(vec![vec![1.0, 1.0], vec![1.0, -1.0]])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_directions(mut self, directions: Vec<P>) -> Result<Self, Error> {
+        if directions.is_empty() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Powell`: at least one search direction is required."
+            ));
+        }
+        self.directions = directions;
+        Ok(self)
+    }
+
+    /// Set the length of the first bracketing step of the line searches in the first iteration
+    ///
+    /// Should be of the order of the distance between the initial guess and the minimum. Must be
+    /// larger than 0 and defaults to 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::powell::Powell;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let powell: Powell<Vec<f64>, f64> = Powell::new().with_initial_step(0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_initial_step(mut self, step: F) -> Result<Self, Error> {
+        if step <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Powell`: initial step must be > 0."
+            ));
+        }
+        self.initial_step = step;
+        self.step = step;
+        Ok(self)
+    }
+
+    /// Set the absolute tolerance of the line searches
+    ///
+    /// Must be larger than 0 and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::powell::Powell;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let powell: Powell<Vec<f64>, f64> = Powell::new().with_line_tolerance(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_line_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Powell`: line tolerance must be > 0."
+            ));
+        }
+        self.line_tolerance = tol;
+        Ok(self)
+    }
+
+    /// Set the relative tolerance on the decrease of the cost function value
+    ///
+    /// The algorithm terminates if `2 * (f_prev - f) <= tol * (|f_prev| + |f|)`. Must be
+    /// non-negative and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::powell::Powell;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let powell: Powell<Vec<f64>, f64> = Powell::new().with_cost_tolerance(1e-12)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_cost_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Powell`: cost tolerance must be >= 0."
+            ));
+        }
+        self.cost_tolerance = tol;
+        Ok(self)
+    }
+}
+
+impl<P, F> Default for Powell<P, F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        Powell::new()
+    }
+}
+
+impl<P, F> Powell<P, F>
+where
+    P: Clone + ArgminScaledAdd<P, F, P> + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    /// Finds an interval along `direction` which contains a minimum, starting with a step of
+    /// length `h` from `param` with cost `cost` and expanding it by the golden ratio.
+    ///
+    /// Returns the bounds of the interval and the best step found so far with its cost.
+    fn bracket<O>(
+        problem: &mut Problem<O>,
+        param: &P,
+        cost: F,
+        direction: &P,
+        h: F,
+    ) -> Result<(F, F, F, F), Error>
+    where
+        O: CostFunction<Param = P, Output = F>,
+    {
+        let mut cost_at = |t: F| problem.cost(&param.scaled_add(&t, direction));
+        let mut a = F::zero();
+        let (mut b, mut fb) = (h, cost_at(h)?);
+        if fb >= cost {
+            let fc = cost_at(-h)?;
+            if fc >= cost {
+                return Ok((-h, h, F::zero(), cost));
+            }
+            (b, fb) = (-h, fc);
+        }
+        for _ in 0..MAX_BRACKET_EXPANSIONS {
+            let c = b + float!(GOLDEN_RATIO) * (b - a);
+            let fc = cost_at(c)?;
+            if fc > fb {
+                return Ok((a.min(c), a.max(c), b, fb));
+            }
+            a = b;
+            (b, fb) = (c, fc);
+        }
+        // No bracket was found within the maximum number of expansions.
+        Ok((a.min(b), a.max(b), b, fb))
+    }
+
+    /// Minimizes the cost function along `direction` starting from `param` with cost `cost`.
+    ///
+    /// Returns the new parameter vector and its cost.
+    fn line_search<O>(
+        &self,
+        problem: &mut Problem<O>,
+        param: &P,
+        cost: F,
+        direction: &P,
+    ) -> Result<(P, F), Error>
+    where
+        O: CostFunction<Param = P, Output = F>,
+    {
+        let norm = direction.l2_norm();
+        if norm <= F::zero() {
+            return Ok((param.clone(), cost));
+        }
+
+        let (lower, upper, mut best_t, mut best_cost) =
+            Self::bracket(problem, param, cost, direction, self.step / norm)?;
+
+        let line_problem = LineProblem {
+            problem: problem.take_problem().ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`Powell`: Failed to take `problem` for line search"
+            ))?,
+            origin: param.clone(),
+            direction: direction.clone(),
+        };
+        let brent = BrentOpt::new(lower, upper)
+            .set_tolerance(F::epsilon().sqrt(), self.line_tolerance / norm);
+        let OptimizationResult {
+            problem: mut line_problem,
+            state: line_state,
+            ..
+        } = Executor::new(line_problem, brent)
+            .configure(|state| state.max_iters(MAX_LINE_SEARCH_ITERS))
+            .ctrlc(false)
+            .run()?;
+
+        // The user defined problem is hidden inside a `LineProblem`, therefore the problem and the
+        // function evaluation counts need to be taken back separately.
+        problem.problem = Some(
+            line_problem
+                .take_problem()
+                .ok_or_else(argmin_error_closure!(
+                    PotentialBug,
+                    "`Powell`: Failed to take `problem` from line search"
+                ))?
+                .problem,
+        );
+        problem.consume_func_counts(line_problem);
+
+        if line_state.get_best_cost() < best_cost {
+            best_cost = line_state.get_best_cost();
+            best_t = *line_state
+                .get_best_param()
+                .ok_or_else(argmin_error_closure!(
+                    PotentialBug,
+                    "`Powell`: No best parameter in line search state"
+                ))?;
+        }
+        Ok((param.scaled_add(&best_t, direction), best_cost))
+    }
+}
+
+impl<O, P, F> Solver<O, IterState<P, (), (), (), F>> for Powell<P, F>
+where
+    O: CostFunction<Param = P, Output = F>,
+    P: Clone + SerializeAlias + ArgminSub<P, P> + ArgminScaledAdd<P, F, P> + ArgminL2Norm<F>,
+    P: ArgminBasis,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Powell";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`Powell` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let cost = state.get_cost();
+        let cost = if cost.is_infinite() {
+            problem.cost(&param)?
+        } else {
+            cost
+        };
+        if self.directions.is_empty() {
+            self.directions = param.basis_like();
+        }
+        self.step = self.initial_step;
+        Ok((state.param(param).cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let start = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`Powell`: No parameter vector in state."
+        ))?;
+        let start_cost = state.get_cost();
+
+        // Minimize along all directions and remember the direction of largest decrease.
+        let mut param = start.clone();
+        let mut cost = start_cost;
+        let mut largest_decrease = F::zero();
+        let mut largest_idx = 0;
+        for (i, direction) in self.directions.iter().enumerate() {
+            let (new_param, new_cost) = self.line_search(problem, &param, cost, direction)?;
+            if cost - new_cost > largest_decrease {
+                largest_decrease = cost - new_cost;
+                largest_idx = i;
+            }
+            param = new_param;
+            cost = new_cost;
+        }
+
+        let displacement = param.sub(&start);
+        let mut replaced = false;
+        if cost < start_cost {
+            // Powell's heuristic decides whether the displacement replaces the direction of
+            // largest decrease, based on the cost function value at the extrapolated point.
+            let extrapolated_cost = problem.cost(&param.scaled_add(&F::one(), &displacement))?;
+            if extrapolated_cost < start_cost {
+                let two = float!(2.0);
+                let t = two
+                    * (start_cost - two * cost + extrapolated_cost)
+                    * (start_cost - cost - largest_decrease).powi(2)
+                    - largest_decrease * (start_cost - extrapolated_cost).powi(2);
+                if t < F::zero() {
+                    (param, cost) = self.line_search(problem, &param, cost, &displacement)?;
+                    self.directions.remove(largest_idx);
+                    self.directions.push(displacement.clone());
+                    replaced = true;
+                }
+            }
+            self.step = param.sub(&start).l2_norm().max(self.line_tolerance);
+        }
+
+        Ok((
+            state.param(param).cost(cost),
+            Some(kv!("replaced_direction" => replaced;)),
+        ))
+    }
+
+    fn terminate(&mut self, state: &IterState<P, (), (), (), F>) -> TerminationStatus {
+        let prev_cost = state.get_prev_cost();
+        let cost = state.get_cost();
+        if prev_cost.is_finite()
+            && float!(2.0) * (prev_cost - cost)
+                <= self.cost_tolerance * (prev_cost.abs() + cost.abs()) + F::min_positive_value()
+        {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+/// Restriction of a cost function to the line `origin + t * direction`
+#[derive(Clone)]
+struct LineProblem<O, P> {
+    problem: O,
+    origin: P,
+    direction: P,
+}
+
+impl<O, P, F> CostFunction for LineProblem<O, P>
+where
+    O: CostFunction<Param = P, Output = F>,
+    P: ArgminScaledAdd<P, F, P>,
+{
+    type Param = F;
+    type Output = F;
+
+    fn cost(&self, t: &Self::Param) -> Result<Self::Output, Error> {
+        self.problem
+            .cost(&self.origin.scaled_add(t, &self.direction))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{test_utils::TestProblem, ArgminError};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(powell, Powell<Vec<f64>, f64>);
+
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((1.0 - p[0]).powi(2) + 100.0 * (p[1] - p[0].powi(2)).powi(2))
+        }
+    }
+
+    struct Quadratic {}
+
+    impl CostFunction for Quadratic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0] - 1.0).powi(2)
+                + 2.0 * (p[1] + 2.0).powi(2)
+                + (p[0] - 1.0) * (p[1] + 2.0)
+                + 3.0 * (p[2] - 0.5).powi(2))
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let powell: Powell<Vec<f64>, f64> = Powell::new();
+        let Powell {
+            directions,
+            initial_step,
+            step,
+            line_tolerance,
+            cost_tolerance,
+        } = powell;
+        assert!(directions.is_empty());
+        assert_eq!(initial_step.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(step.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(
+            line_tolerance.to_ne_bytes(),
+            f64::EPSILON.sqrt().to_ne_bytes()
+        );
+        assert_eq!(
+            cost_tolerance.to_ne_bytes(),
+            f64::EPSILON.sqrt().to_ne_bytes()
+        );
+    }
+
+    #[test]
+    fn test_with_directions() {
+        let directions = vec![vec![1.0, 1.0], vec![1.0, -1.0]];
+        let powell: Powell<Vec<f64>, f64> =
+            Powell::new().with_directions(directions.clone()).unwrap();
+        assert_eq!(powell.directions, directions);
+
+        let res: Result<Powell<Vec<f64>, f64>, _> = Powell::new().with_directions(vec![]);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`Powell`: at least one search direction is required.\""
+        );
+    }
+
+    #[test]
+    fn test_with_initial_step() {
+        let powell: Powell<Vec<f64>, f64> = Powell::new().with_initial_step(0.1).unwrap();
+        assert_eq!(powell.initial_step.to_ne_bytes(), 0.1f64.to_ne_bytes());
+
+        for step in [0.0, -1.0] {
+            let res: Result<Powell<Vec<f64>, f64>, _> = Powell::new().with_initial_step(step);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`Powell`: initial step must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_line_tolerance() {
+        let powell: Powell<Vec<f64>, f64> = Powell::new().with_line_tolerance(1e-10).unwrap();
+        assert_eq!(powell.line_tolerance.to_ne_bytes(), 1e-10f64.to_ne_bytes());
+
+        for tol in [0.0, -1.0] {
+            let res: Result<Powell<Vec<f64>, f64>, _> = Powell::new().with_line_tolerance(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`Powell`: line tolerance must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_cost_tolerance() {
+        for tol in [0.0, 1e-10] {
+            let powell: Powell<Vec<f64>, f64> = Powell::new().with_cost_tolerance(tol).unwrap();
+            assert_eq!(powell.cost_tolerance.to_ne_bytes(), tol.to_ne_bytes());
+        }
+
+        let res: Result<Powell<Vec<f64>, f64>, _> = Powell::new().with_cost_tolerance(-1.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`Powell`: cost tolerance must be >= 0.\""
+        );
+    }
+
+    #[test]
+    fn test_init_param_not_initialized() {
+        let mut powell: Powell<Vec<f64>, f64> = Powell::new();
+        let res = powell.init(&mut Problem::new(TestProblem::new()), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`Powell` requires an initial parameter vector. Please ",
+                "provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_init() {
+        let mut powell: Powell<Vec<f64>, f64> = Powell::new();
+        let (state, kv) = powell
+            .init(
+                &mut Problem::new(Quadratic {}),
+                IterState::new().param(vec![1.0, -2.0, 0.5]),
+            )
+            .unwrap();
+        assert!(kv.is_none());
+        assert_relative_eq!(state.get_cost(), 0.0, epsilon = f64::EPSILON);
+        assert_eq!(
+            powell.directions,
+            vec![
+                vec![1.0, 0.0, 0.0],
+                vec![0.0, 1.0, 0.0],
+                vec![0.0, 0.0, 1.0]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_line_search() {
+        let powell: Powell<Vec<f64>, f64> = Powell::new();
+        let mut problem = Problem::new(Quadratic {});
+        let param = vec![4.0, -2.0, 0.5];
+        let cost = problem.cost(&param).unwrap();
+
+        // the minimum along both the direction and its opposite is found
+        for direction in [vec![1.0, 0.0, 0.0], vec![-2.0, 0.0, 0.0]] {
+            let (new_param, new_cost) = powell
+                .line_search(&mut problem, &param, cost, &direction)
+                .unwrap();
+            assert_relative_eq!(new_param[0], 1.0, epsilon = 1e-6);
+            assert_relative_eq!(new_param[1], -2.0, epsilon = f64::EPSILON);
+            assert_relative_eq!(new_param[2], 0.5, epsilon = f64::EPSILON);
+            assert!(new_cost < 1e-10);
+        }
+
+        // a zero direction leaves the parameter vector untouched
+        let (new_param, new_cost) = powell
+            .line_search(&mut problem, &param, cost, &vec![0.0, 0.0, 0.0])
+            .unwrap();
+        assert_eq!(new_param, param);
+        assert_eq!(new_cost.to_ne_bytes(), cost.to_ne_bytes());
+
+        // function evaluations of the line search are counted
+        assert!(problem.counts["cost_count"] > 10);
+    }
+
+    #[test]
+    fn test_solver_quadratic() {
+        let res = Executor::new(Quadratic {}, Powell::new())
+            .configure(|state| state.param(vec![-3.0, 4.0, 2.0]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(param[1], -2.0, epsilon = 1e-6);
+        assert_relative_eq!(param[2], 0.5, epsilon = 1e-6);
+        // conjugate directions minimize a quadratic in a few iterations
+        assert!(res.state.get_iter() <= 5);
+    }
+
+    #[test]
+    fn test_solver_rosenbrock() {
+        let powell = Powell::new().with_cost_tolerance(1e-14).unwrap();
+        let res = Executor::new(Rosenbrock {}, powell)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(200))
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-4);
+        assert_relative_eq!(param[1], 1.0, epsilon = 1e-4);
+    }
+}
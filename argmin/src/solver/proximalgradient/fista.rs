@@ -0,0 +1,477 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, IterState, Problem, Proximal, SerializeAlias,
+    Solver, State, TerminationReason, KV,
+};
+use crate::solver::proximalgradient::proximal_gradient_step;
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminScaledAdd, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Adaptive restart schemes of [`FISTA`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum Restart {
+    /// Never restart
+    None,
+    /// Restart if the objective increases
+    FunctionValue,
+    /// Restart if the momentum points in a direction in which the objective increases
+    Gradient,
+}
+
+/// # Fast iterative shrinkage-thresholding algorithm (FISTA)
+///
+/// Accelerated proximal gradient method for minimizing `f(x) + g(x)`, where `f` is smooth and
+/// `g` is convex but possibly non-smooth. In contrast to [`ISTA`](`super::ISTA`), the proximal
+/// gradient step is taken from an extrapolated point:
+///
+/// `y_k = x_k + (t_{k-1} - 1) / t_k (x_k - x_{k-1})`
+///
+/// `x_{k+1} = prox(y_k - s_k grad f(y_k), s_k)`
+///
+/// with `t_k = (1 + sqrt(1 + 4 t_{k-1}^2)) / 2` and `t_0 = 1`. The step length `s_k` is obtained
+/// by backtracking as in [`ISTA`](`super::ISTA`).
+///
+/// Optionally, the momentum is reset adaptively ([`Restart`]): either if the objective increases
+/// (`Restart::FunctionValue`) or if the momentum `x_{k+1} - x_k` forms an acute angle with the
+/// gradient mapping `y_k - x_{k+1}` (`Restart::Gradient`). This avoids the oscillations of FISTA
+/// and often considerably speeds up convergence. By default, no restarts are performed.
+///
+/// The algorithm terminates once the norm of the gradient mapping `(y_k - x_{k+1}) / s_k` falls
+/// below a tolerance. The cost stored in the state is `f(x) + g(x)`.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`] for the
+/// smooth part and [`Proximal`] for the non-smooth part of the objective.
+///
+/// ## References
+///
+/// Amir Beck and Marc Teboulle (2009). A Fast Iterative Shrinkage-Thresholding Algorithm for
+/// Linear Inverse Problems. SIAM Journal on Imaging Sciences 2(1), 183-202.
+/// <https://doi.org/10.1137/080716542>
+///
+/// Brendan O'Donoghue and Emmanuel Candès (2015). Adaptive Restart for Accelerated Gradient
+/// Schemes. Foundations of Computational Mathematics 15, 715-732.
+/// <https://doi.org/10.1007/s10208-013-9150-3>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct FISTA<P, F> {
+    /// Step length
+    step: F,
+    /// Factor by which the step length is reduced during backtracking
+    backtracking_factor: F,
+    /// Tolerance for the norm of the gradient mapping
+    tol: F,
+    /// Restart scheme
+    restart: Restart,
+    /// Momentum parameter `t_{k-1}`
+    momentum: F,
+    /// Previous parameter vector `x_{k-1}`
+    prev_param: Option<P>,
+}
+
+impl<P, F> FISTA<P, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`FISTA`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::proximalgradient::FISTA;
+    /// let fista: FISTA<Vec<f64>, f64> = FISTA::new();
+    /// ```
+    pub fn new() -> Self {
+        FISTA {
+            step: F::one(),
+            backtracking_factor: float!(0.5),
+            tol: F::epsilon().sqrt(),
+            restart: Restart::None,
+            momentum: F::one(),
+            prev_param: None,
+        }
+    }
+
+    /// Set the (initial) step length
+    ///
+    /// Must be larger than zero and defaults to `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::proximalgradient::FISTA;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let fista: FISTA<Vec<f64>, f64> = FISTA::new().with_step_size(0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_step_size(mut self, step: F) -> Result<Self, Error> {
+        if step <= F::zero() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`FISTA`: step size must be > 0."
+            ));
+        }
+        self.step = step;
+        Ok(self)
+    }
+
+    /// Set the factor by which the step length is reduced during backtracking
+    ///
+    /// Must be in `(0, 1]` and defaults to `0.5`. A factor of `1` disables backtracking.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::proximalgradient::FISTA;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let fista: FISTA<Vec<f64>, f64> = FISTA::new().with_backtracking_factor(0.8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_backtracking_factor(mut self, factor: F) -> Result<Self, Error> {
+        if factor <= F::zero() || factor > F::one() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`FISTA`: backtracking factor must be in (0, 1]."
+            ));
+        }
+        self.backtracking_factor = factor;
+        Ok(self)
+    }
+
+    /// Set the tolerance for the norm of the gradient mapping
+    ///
+    /// Must be larger than zero and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::proximalgradient::FISTA;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let fista: FISTA<Vec<f64>, f64> = FISTA::new().with_tolerance(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= F::zero() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`FISTA`: tolerance must be > 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+
+    /// Set the adaptive restart scheme
+    ///
+    /// Defaults to [`Restart::None`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::proximalgradient::{FISTA, Restart};
+    /// let fista: FISTA<Vec<f64>, f64> = FISTA::new().with_restart(Restart::Gradient);
+    /// ```
+    #[must_use]
+    pub fn with_restart(mut self, restart: Restart) -> Self {
+        self.restart = restart;
+        self
+    }
+}
+
+impl<P, F> Default for FISTA<P, F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        FISTA::new()
+    }
+}
+
+impl<O, P, F> Solver<O, IterState<P, P, (), (), F>> for FISTA<P, F>
+where
+    O: CostFunction<Param = P, Output = F>
+        + Gradient<Param = P, Gradient = P>
+        + Proximal<Param = P, Float = F>,
+    P: Clone
+        + SerializeAlias
+        + ArgminScaledAdd<P, F, P>
+        + ArgminSub<P, P>
+        + ArgminDot<P, F>
+        + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "FISTA";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, P, (), (), F>,
+    ) -> Result<(IterState<P, P, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`FISTA` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let cost = problem.cost(&param)? + problem.regularization(&param)?;
+        self.momentum = F::one();
+        self.prev_param = Some(param.clone());
+        Ok((state.param(param).cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, P, (), (), F>,
+    ) -> Result<(IterState<P, P, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`FISTA`: Parameter vector in state not set."
+        ))?;
+        let prev_param = self.prev_param.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`FISTA`: Previous parameter vector not set."
+        ))?;
+        let cost = state.get_cost();
+
+        let momentum = (F::one() + (F::one() + float!(4.0) * self.momentum * self.momentum).sqrt())
+            / float!(2.0);
+        let extrapolated = param.scaled_add(
+            &((self.momentum - F::one()) / momentum),
+            &param.sub(&prev_param),
+        );
+        let extrapolated_cost = problem.cost(&extrapolated)?;
+        let gradient = problem.gradient(&extrapolated)?;
+
+        let (new_param, new_smooth_cost, step) = proximal_gradient_step(
+            problem,
+            &extrapolated,
+            extrapolated_cost,
+            &gradient,
+            self.step,
+            self.backtracking_factor,
+        )?;
+        self.step = step;
+        let new_cost = new_smooth_cost + problem.regularization(&new_param)?;
+        let mapping = extrapolated.sub(&new_param);
+        let residual = mapping.l2_norm() / step;
+
+        let restart = match self.restart {
+            Restart::None => false,
+            Restart::FunctionValue => new_cost > cost,
+            Restart::Gradient => mapping.dot(&new_param.sub(&param)) > F::zero(),
+        };
+        self.momentum = if restart { F::one() } else { momentum };
+        self.prev_param = Some(param);
+
+        let state = state.param(new_param).cost(new_cost);
+        let state = if residual <= self.tol {
+            state.terminate_with(TerminationReason::SolverConverged)
+        } else {
+            state
+        };
+        Ok((
+            state,
+            Some(kv!(
+                "step_length" => step;
+                "gradient_mapping_norm" => residual;
+                "restart" => restart;
+            )),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::solver::proximalgradient::{ElasticNet, ProximalOperator, ISTA};
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(fista, FISTA<Vec<f64>, f64>);
+
+    /// Ill-conditioned least squares problem `1/2 ||A x - b||^2` with elastic net regularization
+    struct ElasticNetProblem {
+        a: Vec<Vec<f64>>,
+        b: Vec<f64>,
+        reg: ElasticNet<f64>,
+    }
+
+    impl ElasticNetProblem {
+        fn new() -> Self {
+            ElasticNetProblem {
+                a: vec![
+                    vec![1.0, 0.9, 0.0],
+                    vec![0.9, 1.0, 0.1],
+                    vec![0.0, 0.1, 0.05],
+                    vec![0.5, 0.0, 0.2],
+                ],
+                b: vec![1.0, 2.0, -1.0, 0.5],
+                reg: ElasticNet::new(0.1, 0.01).unwrap(),
+            }
+        }
+
+        fn residual(&self, p: &[f64]) -> Vec<f64> {
+            self.a
+                .iter()
+                .zip(self.b.iter())
+                .map(|(row, b)| row.iter().zip(p).map(|(a, x)| a * x).sum::<f64>() - b)
+                .collect()
+        }
+    }
+
+    impl CostFunction for ElasticNetProblem {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(0.5 * self.residual(p).iter().map(|r| r * r).sum::<f64>())
+        }
+    }
+
+    impl Gradient for ElasticNetProblem {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            let r = self.residual(p);
+            Ok((0..p.len())
+                .map(|j| self.a.iter().zip(r.iter()).map(|(row, r)| row[j] * r).sum())
+                .collect())
+        }
+    }
+
+    impl Proximal for ElasticNetProblem {
+        type Param = Vec<f64>;
+        type Float = f64;
+
+        fn prox(&self, p: &Self::Param, step: Self::Float) -> Result<Self::Param, Error> {
+            Ok(self.reg.prox(p, step))
+        }
+
+        fn regularization(&self, p: &Self::Param) -> Result<Self::Float, Error> {
+            Ok(self.reg.value(p))
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let fista: FISTA<Vec<f64>, f64> = FISTA::new();
+        let FISTA {
+            step,
+            backtracking_factor,
+            tol,
+            restart,
+            momentum,
+            prev_param,
+        } = fista;
+        assert_eq!(step.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(backtracking_factor.to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(restart, Restart::None);
+        assert_eq!(momentum.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert!(prev_param.is_none());
+    }
+
+    #[test]
+    fn test_builders() {
+        let fista: FISTA<Vec<f64>, f64> = FISTA::new()
+            .with_step_size(0.1)
+            .unwrap()
+            .with_backtracking_factor(0.9)
+            .unwrap()
+            .with_tolerance(1e-10)
+            .unwrap()
+            .with_restart(Restart::FunctionValue);
+        assert_eq!(fista.step.to_ne_bytes(), 0.1f64.to_ne_bytes());
+        assert_eq!(
+            fista.backtracking_factor.to_ne_bytes(),
+            0.9f64.to_ne_bytes()
+        );
+        assert_eq!(fista.tol.to_ne_bytes(), 1e-10f64.to_ne_bytes());
+        assert_eq!(fista.restart, Restart::FunctionValue);
+
+        assert_error!(
+            FISTA::<Vec<f64>, f64>::new().with_step_size(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`FISTA`: step size must be > 0.\""
+        );
+        assert_error!(
+            FISTA::<Vec<f64>, f64>::new().with_backtracking_factor(0.0),
+            ArgminError,
+            "Invalid parameter: \"`FISTA`: backtracking factor must be in (0, 1].\""
+        );
+        assert_error!(
+            FISTA::<Vec<f64>, f64>::new().with_tolerance(0.0),
+            ArgminError,
+            "Invalid parameter: \"`FISTA`: tolerance must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_init_param_not_initialized() {
+        let mut fista: FISTA<Vec<f64>, f64> = FISTA::new();
+        let res = fista.init(
+            &mut Problem::new(ElasticNetProblem::new()),
+            IterState::new(),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`FISTA` requires an initial parameter vector. Please ",
+                "provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let reference = Executor::new(ElasticNetProblem::new(), ISTA::new())
+            .configure(|state| state.param(vec![0.0; 3]).max_iters(100000))
+            .run()
+            .unwrap();
+        assert_eq!(
+            reference.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let expected = reference.state.get_best_param().unwrap();
+
+        for restart in [Restart::None, Restart::FunctionValue, Restart::Gradient] {
+            let res = Executor::new(ElasticNetProblem::new(), FISTA::new().with_restart(restart))
+                .configure(|state| state.param(vec![0.0; 3]).max_iters(10000))
+                .run()
+                .unwrap();
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::SolverConverged)
+            );
+            assert!(res.state.get_iter() < reference.state.get_iter());
+            let x = res.state.get_best_param().unwrap();
+            for (x, e) in x.iter().zip(expected.iter()) {
+                assert_relative_eq!(*x, *e, epsilon = 1e-6);
+            }
+            assert_relative_eq!(
+                res.state.get_best_cost(),
+                reference.state.get_best_cost(),
+                epsilon = 1e-10
+            );
+        }
+    }
+}
@@ -0,0 +1,397 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, IterState, Problem, Proximal, SerializeAlias,
+    Solver, State, TerminationReason, KV,
+};
+use crate::solver::proximalgradient::proximal_gradient_step;
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminScaledAdd, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Iterative shrinkage-thresholding algorithm (ISTA)
+///
+/// Proximal gradient method for minimizing `f(x) + g(x)`, where `f` is smooth and `g` is convex
+/// but possibly non-smooth. In each iteration, a gradient step on `f` is followed by the
+/// proximal operator of `g`:
+///
+/// `x_{k+1} = prox(x_k - t_k grad f(x_k), t_k)`
+///
+/// The step length `t_k` is reduced by backtracking until the quadratic upper bound of `f`
+/// around `x_k` with curvature `1/t_k` majorizes `f` at `x_{k+1}`. Backtracking can be disabled
+/// by setting the backtracking factor to one, in which case the step length should not exceed
+/// `1/L`, where `L` is the Lipschitz constant of the gradient of `f`.
+///
+/// The algorithm terminates once the norm of the gradient mapping `(x_k - x_{k+1}) / t_k` falls
+/// below a tolerance. The cost stored in the state is `f(x) + g(x)`, the gradient is the gradient
+/// of `f`.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`] for the
+/// smooth part and [`Proximal`] for the non-smooth part of the objective.
+///
+/// ## Reference
+///
+/// Amir Beck and Marc Teboulle (2009). A Fast Iterative Shrinkage-Thresholding Algorithm for
+/// Linear Inverse Problems. SIAM Journal on Imaging Sciences 2(1), 183-202.
+/// <https://doi.org/10.1137/080716542>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ISTA<F> {
+    /// Step length
+    step: F,
+    /// Factor by which the step length is reduced during backtracking
+    backtracking_factor: F,
+    /// Tolerance for the norm of the gradient mapping
+    tol: F,
+    /// Value of the smooth part of the objective at the current parameter vector
+    smooth_cost: F,
+}
+
+impl<F> ISTA<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`ISTA`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::proximalgradient::ISTA;
+    /// let ista: ISTA<f64> = ISTA::new();
+    /// ```
+    pub fn new() -> Self {
+        ISTA {
+            step: F::one(),
+            backtracking_factor: float!(0.5),
+            tol: F::epsilon().sqrt(),
+            smooth_cost: F::infinity(),
+        }
+    }
+
+    /// Set the (initial) step length
+    ///
+    /// Must be larger than zero and defaults to `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::proximalgradient::ISTA;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let ista = ISTA::new().with_step_size(0.1f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_step_size(mut self, step: F) -> Result<Self, Error> {
+        if step <= F::zero() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ISTA`: step size must be > 0."
+            ));
+        }
+        self.step = step;
+        Ok(self)
+    }
+
+    /// Set the factor by which the step length is reduced during backtracking
+    ///
+    /// Must be in `(0, 1]` and defaults to `0.5`. A factor of `1` disables backtracking.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::proximalgradient::ISTA;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let ista = ISTA::new().with_backtracking_factor(0.8f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_backtracking_factor(mut self, factor: F) -> Result<Self, Error> {
+        if factor <= F::zero() || factor > F::one() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ISTA`: backtracking factor must be in (0, 1]."
+            ));
+        }
+        self.backtracking_factor = factor;
+        Ok(self)
+    }
+
+    /// Set the tolerance for the norm of the gradient mapping
+    ///
+    /// Must be larger than zero and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::proximalgradient::ISTA;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let ista = ISTA::new().with_tolerance(1e-10f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= F::zero() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ISTA`: tolerance must be > 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+}
+
+impl<F> Default for ISTA<F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        ISTA::new()
+    }
+}
+
+impl<O, P, F> Solver<O, IterState<P, P, (), (), F>> for ISTA<F>
+where
+    O: CostFunction<Param = P, Output = F>
+        + Gradient<Param = P, Gradient = P>
+        + Proximal<Param = P, Float = F>,
+    P: Clone
+        + SerializeAlias
+        + ArgminScaledAdd<P, F, P>
+        + ArgminSub<P, P>
+        + ArgminDot<P, F>
+        + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "ISTA";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, P, (), (), F>,
+    ) -> Result<(IterState<P, P, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`ISTA` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        self.smooth_cost = problem.cost(&param)?;
+        let gradient = problem.gradient(&param)?;
+        let cost = self.smooth_cost + problem.regularization(&param)?;
+        Ok((state.param(param).cost(cost).gradient(gradient), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, P, (), (), F>,
+    ) -> Result<(IterState<P, P, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`ISTA`: Parameter vector in state not set."
+        ))?;
+        let gradient = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`ISTA`: Gradient in state not set."
+        ))?;
+
+        let (new_param, new_smooth_cost, step) = proximal_gradient_step(
+            problem,
+            &param,
+            self.smooth_cost,
+            &gradient,
+            self.step,
+            self.backtracking_factor,
+        )?;
+        self.step = step;
+        self.smooth_cost = new_smooth_cost;
+        let residual = new_param.sub(&param).l2_norm() / step;
+        let new_gradient = problem.gradient(&new_param)?;
+        let new_cost = new_smooth_cost + problem.regularization(&new_param)?;
+
+        let state = state.param(new_param).cost(new_cost).gradient(new_gradient);
+        let state = if residual <= self.tol {
+            state.terminate_with(TerminationReason::SolverConverged)
+        } else {
+            state
+        };
+        Ok((
+            state,
+            Some(kv!(
+                "step_length" => step;
+                "gradient_mapping_norm" => residual;
+            )),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::solver::proximalgradient::{ProximalOperator, L1};
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(ista, ISTA<f64>);
+
+    /// `1/2 sum_i d_i (x_i - b_i)^2 + ||x||_1`, minimized by `x_i = soft(b_i, 1 / d_i)`
+    struct Lasso {
+        d: Vec<f64>,
+        b: Vec<f64>,
+        l1: L1<f64>,
+    }
+
+    impl Lasso {
+        fn new() -> Self {
+            Lasso {
+                d: vec![1.0, 4.0, 10.0],
+                b: vec![3.0, -0.5, 1.5],
+                l1: L1::new(1.0).unwrap(),
+            }
+        }
+    }
+
+    impl CostFunction for Lasso {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p.iter()
+                .zip(self.d.iter().zip(self.b.iter()))
+                .map(|(x, (d, b))| 0.5 * d * (x - b).powi(2))
+                .sum())
+        }
+    }
+
+    impl Gradient for Lasso {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(p.iter()
+                .zip(self.d.iter().zip(self.b.iter()))
+                .map(|(x, (d, b))| d * (x - b))
+                .collect())
+        }
+    }
+
+    impl Proximal for Lasso {
+        type Param = Vec<f64>;
+        type Float = f64;
+
+        fn prox(&self, p: &Self::Param, step: Self::Float) -> Result<Self::Param, Error> {
+            Ok(self.l1.prox(p, step))
+        }
+
+        fn regularization(&self, p: &Self::Param) -> Result<Self::Float, Error> {
+            Ok(self.l1.value(p))
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let ista: ISTA<f64> = ISTA::new();
+        let ISTA {
+            step,
+            backtracking_factor,
+            tol,
+            smooth_cost,
+        } = ista;
+        assert_eq!(step.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(backtracking_factor.to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(smooth_cost.is_infinite());
+    }
+
+    #[test]
+    fn test_builders() {
+        let ista: ISTA<f64> = ISTA::new()
+            .with_step_size(0.1)
+            .unwrap()
+            .with_backtracking_factor(1.0)
+            .unwrap()
+            .with_tolerance(1e-10)
+            .unwrap();
+        assert_eq!(ista.step.to_ne_bytes(), 0.1f64.to_ne_bytes());
+        assert_eq!(ista.backtracking_factor.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(ista.tol.to_ne_bytes(), 1e-10f64.to_ne_bytes());
+
+        assert_error!(
+            ISTA::new().with_step_size(0.0f64),
+            ArgminError,
+            "Invalid parameter: \"`ISTA`: step size must be > 0.\""
+        );
+        for factor in [0.0f64, 1.5] {
+            assert_error!(
+                ISTA::new().with_backtracking_factor(factor),
+                ArgminError,
+                "Invalid parameter: \"`ISTA`: backtracking factor must be in (0, 1].\""
+            );
+        }
+        assert_error!(
+            ISTA::new().with_tolerance(-1.0f64),
+            ArgminError,
+            "Invalid parameter: \"`ISTA`: tolerance must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_init_param_not_initialized() {
+        let mut ista: ISTA<f64> = ISTA::new();
+        let res = ista.init(&mut Problem::new(Lasso::new()), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`ISTA` requires an initial parameter vector. Please ",
+                "provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        for (solver, param) in [
+            (ISTA::new(), vec![0.0, 0.0, 0.0]),
+            (ISTA::new(), vec![10.0, -10.0, 5.0]),
+            (
+                ISTA::new()
+                    .with_step_size(0.1)
+                    .unwrap()
+                    .with_backtracking_factor(1.0)
+                    .unwrap(),
+                vec![0.0, 0.0, 0.0],
+            ),
+        ] {
+            let res = Executor::new(Lasso::new(), solver)
+                .configure(|state| state.param(param).max_iters(1000))
+                .run()
+                .unwrap();
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::SolverConverged)
+            );
+            let x = res.state.get_best_param().unwrap();
+            assert_relative_eq!(x[0], 2.0, epsilon = 1e-6);
+            assert_relative_eq!(x[1], -0.25, epsilon = 1e-6);
+            assert_relative_eq!(x[2], 1.4, epsilon = 1e-6);
+            assert_relative_eq!(
+                res.state.get_best_cost(),
+                0.5 + 0.125 + 0.05 + 3.65,
+                epsilon = 1e-6
+            );
+        }
+    }
+}
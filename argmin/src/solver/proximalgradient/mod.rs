@@ -0,0 +1,81 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Proximal and projected gradient methods
+//!
+//! Methods for minimizing composite objectives `f(x) + g(x)`, where `f` is smooth and `g` is
+//! convex but possibly non-smooth (see [`Proximal`](`crate::core::Proximal`)), and for
+//! minimizing smooth functions over convex sets (see [`Projection`](`crate::core::Projection`)).
+//!
+//! * [`ISTA`]
+//! * [`FISTA`]
+//! * [`ProjectedGradient`]
+//!
+//! Common proximal operators ([`L1`], [`ElasticNet`], [`GroupLasso`]) and projections
+//! ([`BoxConstraint`], [`NonNegative`], [`Simplex`]) are provided as well. They implement
+//! [`ProximalOperator`] and [`ProjectionOperator`] and can be used to implement the
+//! [`Proximal`](`crate::core::Proximal`) and [`Projection`](`crate::core::Projection`) traits.
+//!
+//! ## References
+//!
+//! Amir Beck and Marc Teboulle (2009). A Fast Iterative Shrinkage-Thresholding Algorithm for
+//! Linear Inverse Problems. SIAM Journal on Imaging Sciences 2(1), 183-202.
+//! <https://doi.org/10.1137/080716542>
+
+mod fista;
+mod ista;
+mod operators;
+mod projectedgradient;
+
+pub use self::fista::{Restart, FISTA};
+pub use self::ista::ISTA;
+pub use self::operators::*;
+pub use self::projectedgradient::{BarzilaiBorwein, ProjectedGradient};
+
+use crate::core::{ArgminFloat, CostFunction, Error, Problem, Proximal};
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminScaledAdd, ArgminSub};
+
+/// Proximal gradient step from `param` with backtracking on the step length
+///
+/// Starting from `step`, the step length is multiplied by `backtracking_factor` until the
+/// quadratic upper bound of `f` around `param` majorizes `f` at the new point. A factor of one
+/// keeps the step length fixed. Returns the new parameter vector, the value of `f` at the new
+/// parameter vector and the accepted step length.
+fn proximal_gradient_step<O, P, F>(
+    problem: &mut Problem<O>,
+    param: &P,
+    cost: F,
+    gradient: &P,
+    mut step: F,
+    backtracking_factor: F,
+) -> Result<(P, F, F), Error>
+where
+    O: CostFunction<Param = P, Output = F> + Proximal<Param = P, Float = F>,
+    P: ArgminScaledAdd<P, F, P> + ArgminSub<P, P> + ArgminDot<P, F> + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    loop {
+        let new_param = problem.prox(&param.scaled_add(&(-step), gradient), step)?;
+        let new_cost = problem.cost(&new_param)?;
+        if backtracking_factor >= F::one() {
+            return Ok((new_param, new_cost, step));
+        }
+        let diff = new_param.sub(param);
+        let norm = diff.l2_norm();
+        let bound = cost + gradient.dot(&diff) + norm * norm / (float!(2.0) * step);
+        if new_cost <= bound + F::epsilon() * cost.abs() {
+            return Ok((new_param, new_cost, step));
+        }
+        step = step * backtracking_factor;
+        if step <= F::min_positive_value() {
+            return Err(argmin_error!(
+                ConditionViolated,
+                "Proximal gradient step: backtracking failed to find a step length."
+            ));
+        }
+    }
+}
@@ -0,0 +1,671 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Common proximal operators and projections
+//!
+//! All operators are implemented in terms of `argmin-math` traits and as such work with all
+//! vector types of `argmin-math` which implement the required traits, in particular `Vec`,
+//! `ndarray` and `nalgebra` vectors.
+
+use crate::core::{ArgminFloat, Error};
+use argmin_math::{
+    ArgminAdd, ArgminBasis, ArgminDot, ArgminL1Norm, ArgminL2Norm, ArgminMinMax, ArgminMul,
+    ArgminScaledAdd, ArgminSignum, ArgminSub, ArgminZeroLike,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Proximal operator of a convex function `g`
+///
+/// Can be used to implement [`Proximal`](`crate::core::Proximal`) for a problem.
+pub trait ProximalOperator<P, F> {
+    /// Computes `argmin_x g(x) + 1/(2 step) ||x - param||^2`
+    fn prox(&self, param: &P, step: F) -> P;
+
+    /// Computes `g(param)`
+    fn value(&self, param: &P) -> F;
+}
+
+/// Projection onto a closed convex set
+///
+/// Can be used to implement [`Projection`](`crate::core::Projection`) for a problem.
+pub trait ProjectionOperator<P> {
+    /// Computes the point of the set closest to `param`
+    fn project(&self, param: &P) -> P;
+}
+
+/// Soft thresholding: shrinks all entries of `param` towards zero by `threshold`
+fn soft_threshold<P, F>(param: &P, threshold: F) -> P
+where
+    P: ArgminAdd<F, P> + ArgminSub<F, P> + ArgminAdd<P, P> + ArgminMinMax + ArgminZeroLike,
+{
+    let zero = param.zero_like();
+    P::max(&param.sub(&threshold), &zero).add(&P::min(&param.add(&threshold), &zero))
+}
+
+/// # L1 regularization
+///
+/// `g(x) = weight * ||x||_1`. The proximal operator is soft thresholding.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::proximalgradient::{L1, ProximalOperator};
+/// # use argmin::core::Error;
+/// # fn main() -> Result<(), Error> {
+/// let l1 = L1::new(0.5)?;
+/// let x = l1.prox(&vec![2.0f64, -0.2, -1.0], 1.0);
+/// # assert_eq!(x, vec![1.5, 0.0, -0.5]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct L1<F> {
+    /// Weight of the regularization term
+    weight: F,
+}
+
+impl<F> L1<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`L1`]
+    ///
+    /// The weight must be non-negative.
+    pub fn new(weight: F) -> Result<Self, Error> {
+        if weight < F::zero() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`L1`: weight must be >= 0."
+            ));
+        }
+        Ok(L1 { weight })
+    }
+}
+
+impl<P, F> ProximalOperator<P, F> for L1<F>
+where
+    P: ArgminAdd<F, P>
+        + ArgminSub<F, P>
+        + ArgminAdd<P, P>
+        + ArgminMinMax
+        + ArgminZeroLike
+        + ArgminL1Norm<F>,
+    F: ArgminFloat,
+{
+    fn prox(&self, param: &P, step: F) -> P {
+        soft_threshold(param, step * self.weight)
+    }
+
+    fn value(&self, param: &P) -> F {
+        self.weight * param.l1_norm()
+    }
+}
+
+/// # Elastic net regularization
+///
+/// `g(x) = l1_weight * ||x||_1 + l2_weight / 2 * ||x||_2^2`. The proximal operator is soft
+/// thresholding followed by scaling.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::proximalgradient::{ElasticNet, ProximalOperator};
+/// # use argmin::core::Error;
+/// # fn main() -> Result<(), Error> {
+/// let elastic_net = ElasticNet::new(0.5, 1.0)?;
+/// let x = elastic_net.prox(&vec![2.5f64, -0.2, -1.0], 1.0);
+/// # assert_eq!(x, vec![1.0, 0.0, -0.25]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ElasticNet<F> {
+    /// Weight of the L1 term
+    l1_weight: F,
+    /// Weight of the squared L2 term
+    l2_weight: F,
+}
+
+impl<F> ElasticNet<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`ElasticNet`]
+    ///
+    /// Both weights must be non-negative.
+    pub fn new(l1_weight: F, l2_weight: F) -> Result<Self, Error> {
+        if l1_weight < F::zero() || l2_weight < F::zero() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ElasticNet`: weights must be >= 0."
+            ));
+        }
+        Ok(ElasticNet {
+            l1_weight,
+            l2_weight,
+        })
+    }
+}
+
+impl<P, F> ProximalOperator<P, F> for ElasticNet<F>
+where
+    P: ArgminAdd<F, P>
+        + ArgminSub<F, P>
+        + ArgminAdd<P, P>
+        + ArgminMul<F, P>
+        + ArgminMinMax
+        + ArgminZeroLike
+        + ArgminL1Norm<F>
+        + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    fn prox(&self, param: &P, step: F) -> P {
+        soft_threshold(param, step * self.l1_weight)
+            .mul(&(F::one() / (F::one() + step * self.l2_weight)))
+    }
+
+    fn value(&self, param: &P) -> F {
+        let norm = param.l2_norm();
+        self.l1_weight * param.l1_norm() + float!(0.5) * self.l2_weight * norm * norm
+    }
+}
+
+/// # Group lasso regularization
+///
+/// `g(x) = weight * sum_g ||x_g||_2`, where `x_g` are the entries of `x` belonging to group `g`.
+/// Groups must not overlap. The proximal operator shrinks each group towards zero.
+///
+/// Groups are represented by indicator vectors, which have entries equal to one for the indices
+/// belonging to the group and zero otherwise. They can be constructed from lists of indices via
+/// [`GroupLasso::from_indices`].
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::proximalgradient::{GroupLasso, ProximalOperator};
+/// # use argmin::core::Error;
+/// # fn main() -> Result<(), Error> {
+/// let param = vec![3.0f64, 4.0, 0.1];
+/// let group_lasso = GroupLasso::from_indices(&param, &[vec![0, 1], vec![2]], 1.0)?;
+/// let x = group_lasso.prox(&param, 1.0);
+/// # assert_eq!(x, vec![2.4, 3.2, 0.0]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct GroupLasso<P, F> {
+    /// Indicator vectors of the groups
+    groups: Vec<P>,
+    /// Weight of the regularization term
+    weight: F,
+}
+
+impl<P, F> GroupLasso<P, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`GroupLasso`] from indicator vectors of the groups
+    ///
+    /// The weight must be non-negative.
+    pub fn new(groups: Vec<P>, weight: F) -> Result<Self, Error> {
+        if weight < F::zero() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`GroupLasso`: weight must be >= 0."
+            ));
+        }
+        Ok(GroupLasso { groups, weight })
+    }
+
+    /// Construct a new instance of [`GroupLasso`] from lists of indices
+    ///
+    /// `param` is only used to determine the shape of the parameter vectors. The groups must not
+    /// overlap and the weight must be non-negative.
+    pub fn from_indices(param: &P, groups: &[Vec<usize>], weight: F) -> Result<Self, Error>
+    where
+        P: ArgminBasis + ArgminAdd<P, P> + ArgminZeroLike,
+    {
+        let basis = param.basis_like();
+        let mut seen = vec![false; basis.len()];
+        for &idx in groups.iter().flatten() {
+            if idx >= basis.len() {
+                return Err(argmin_error!(
+                    InvalidParameter,
+                    format!("`GroupLasso`: index {} out of bounds.", idx)
+                ));
+            }
+            if seen[idx] {
+                return Err(argmin_error!(
+                    InvalidParameter,
+                    format!("`GroupLasso`: index {} is part of several groups.", idx)
+                ));
+            }
+            seen[idx] = true;
+        }
+        let groups = groups
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .fold(param.zero_like(), |acc, &idx| acc.add(&basis[idx]))
+            })
+            .collect();
+        GroupLasso::new(groups, weight)
+    }
+}
+
+impl<P, F> ProximalOperator<P, F> for GroupLasso<P, F>
+where
+    P: Clone + ArgminMul<P, P> + ArgminScaledAdd<P, F, P> + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    fn prox(&self, param: &P, step: F) -> P {
+        let threshold = step * self.weight;
+        self.groups.iter().fold(param.clone(), |acc, group| {
+            let part = param.mul(group);
+            let norm = part.l2_norm();
+            let shrinkage = if norm > threshold {
+                threshold / norm
+            } else {
+                F::one()
+            };
+            acc.scaled_add(&(-shrinkage), &part)
+        })
+    }
+
+    fn value(&self, param: &P) -> F {
+        self.weight
+            * self
+                .groups
+                .iter()
+                .fold(F::zero(), |acc, group| acc + param.mul(group).l2_norm())
+    }
+}
+
+/// # Nonnegativity constraints
+///
+/// Projection onto the nonnegative orthant `x >= 0`. As a proximal operator, this is the
+/// indicator function of the nonnegative orthant, which is zero for feasible parameter vectors
+/// and infinite otherwise.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::proximalgradient::{NonNegative, ProjectionOperator};
+/// let x = NonNegative::new().project(&vec![2.0f64, -0.2]);
+/// # assert_eq!(x, vec![2.0, 0.0]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct NonNegative {}
+
+impl NonNegative {
+    /// Construct a new instance of [`NonNegative`]
+    pub fn new() -> Self {
+        NonNegative {}
+    }
+}
+
+impl<P> ProjectionOperator<P> for NonNegative
+where
+    P: ArgminMinMax + ArgminZeroLike,
+{
+    fn project(&self, param: &P) -> P {
+        P::max(param, &param.zero_like())
+    }
+}
+
+impl<P, F> ProximalOperator<P, F> for NonNegative
+where
+    P: ArgminMinMax + ArgminZeroLike + ArgminL1Norm<F>,
+    F: ArgminFloat,
+{
+    fn prox(&self, param: &P, _step: F) -> P {
+        self.project(param)
+    }
+
+    fn value(&self, param: &P) -> F {
+        if P::min(param, &param.zero_like()).l1_norm() > F::zero() {
+            F::infinity()
+        } else {
+            F::zero()
+        }
+    }
+}
+
+/// # Box constraints
+///
+/// Projection onto the box `lower <= x <= upper`. Infinite entries of the bounds denote
+/// unbounded parameters. As a proximal operator, this is the indicator function of the box,
+/// which is zero for feasible parameter vectors and infinite otherwise.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::proximalgradient::{BoxConstraint, ProjectionOperator};
+/// let bounds = BoxConstraint::new(vec![0.0f64, -1.0], vec![1.0, f64::INFINITY]);
+/// let x = bounds.project(&vec![2.0f64, -3.0]);
+/// # assert_eq!(x, vec![1.0, -1.0]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct BoxConstraint<P> {
+    /// Lower bounds
+    lower: P,
+    /// Upper bounds
+    upper: P,
+}
+
+impl<P> BoxConstraint<P> {
+    /// Construct a new instance of [`BoxConstraint`]
+    ///
+    /// All entries of `lower` must be less than or equal to the corresponding entries of `upper`.
+    pub fn new(lower: P, upper: P) -> Self {
+        BoxConstraint { lower, upper }
+    }
+}
+
+impl<P> ProjectionOperator<P> for BoxConstraint<P>
+where
+    P: ArgminMinMax,
+{
+    fn project(&self, param: &P) -> P {
+        P::min(&P::max(param, &self.lower), &self.upper)
+    }
+}
+
+impl<P, F> ProximalOperator<P, F> for BoxConstraint<P>
+where
+    P: ArgminMinMax + ArgminSub<P, P> + ArgminL1Norm<F>,
+    F: ArgminFloat,
+{
+    fn prox(&self, param: &P, _step: F) -> P {
+        self.project(param)
+    }
+
+    fn value(&self, param: &P) -> F {
+        if param.sub(&self.project(param)).l1_norm() > F::zero() {
+            F::infinity()
+        } else {
+            F::zero()
+        }
+    }
+}
+
+/// # Simplex constraints
+///
+/// Projection onto the simplex `{x : x >= 0, sum_i x_i = radius}`, computed with the algorithm
+/// of Michelot. As a proximal operator, this is the indicator function of the simplex, which is
+/// zero for feasible parameter vectors and infinite otherwise.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::proximalgradient::{Simplex, ProjectionOperator};
+/// # use argmin::core::Error;
+/// # fn main() -> Result<(), Error> {
+/// let x = Simplex::new(1.0)?.project(&vec![0.5f64, 1.0, -0.5]);
+/// # assert_eq!(x, vec![0.25, 0.75, 0.0]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # References
+///
+/// Michelot, C. (1986): A finite algorithm for finding the projection of a point onto the
+/// canonical simplex of R^n. Journal of Optimization Theory and Applications 50, 195-200.
+/// <https://doi.org/10.1007/BF00938486>
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Simplex<F> {
+    /// Sum of the entries
+    radius: F,
+}
+
+impl<F> Simplex<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`Simplex`]
+    ///
+    /// The radius (sum of the entries) must be larger than zero.
+    pub fn new(radius: F) -> Result<Self, Error> {
+        if radius <= F::zero() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Simplex`: radius must be > 0."
+            ));
+        }
+        Ok(Simplex { radius })
+    }
+}
+
+impl<P, F> ProjectionOperator<P> for Simplex<F>
+where
+    P: ArgminAdd<F, P>
+        + ArgminSub<F, P>
+        + ArgminDot<P, F>
+        + ArgminMinMax
+        + ArgminSignum
+        + ArgminZeroLike,
+    F: ArgminFloat,
+{
+    fn project(&self, param: &P) -> P {
+        let zero = param.zero_like();
+        let ones = zero.add(&F::one());
+        let n = ones.dot(&ones);
+        // The projection is `max(param - tau, 0)`, where `tau` solves
+        // `sum_i max(param_i - tau, 0) = radius`. Starting from a lower bound, Newton's method
+        // increases `tau` monotonically and terminates after at most `n` iterations.
+        let mut tau = (param.dot(&ones) - self.radius) / n;
+        loop {
+            let shifted = param.sub(&tau);
+            let excess = P::max(&shifted, &zero).dot(&ones) - self.radius;
+            let support =
+                float!(0.5) * (shifted.sub(&F::min_positive_value()).signum().dot(&ones) + n);
+            if excess <= F::zero() || support < F::one() {
+                break;
+            }
+            let new_tau = tau + excess / support;
+            if new_tau <= tau {
+                break;
+            }
+            tau = new_tau;
+        }
+        P::max(&param.sub(&tau), &zero)
+    }
+}
+
+impl<P, F> ProximalOperator<P, F> for Simplex<F>
+where
+    P: ArgminAdd<F, P>
+        + ArgminSub<F, P>
+        + ArgminDot<P, F>
+        + ArgminMinMax
+        + ArgminSignum
+        + ArgminZeroLike
+        + ArgminL1Norm<F>,
+    F: ArgminFloat,
+{
+    fn prox(&self, param: &P, _step: F) -> P {
+        self.project(param)
+    }
+
+    fn value(&self, param: &P) -> F {
+        let zero = param.zero_like();
+        let ones = zero.add(&F::one());
+        let violation = P::min(param, &zero).l1_norm() + (param.dot(&ones) - self.radius).abs();
+        if violation > F::epsilon().sqrt() * (F::one() + self.radius) {
+            F::infinity()
+        } else {
+            F::zero()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_l1() {
+        let l1 = L1::new(0.5).unwrap();
+        let x = l1.prox(&vec![2.0f64, -0.2, -1.0, 0.5], 2.0);
+        assert_eq!(x, vec![1.0, 0.0, 0.0, 0.0]);
+        assert_relative_eq!(
+            ProximalOperator::<Vec<f64>, f64>::value(&l1, &vec![2.0, -1.0]),
+            1.5
+        );
+
+        assert_error!(
+            L1::new(-1.0f64),
+            ArgminError,
+            "Invalid parameter: \"`L1`: weight must be >= 0.\""
+        );
+    }
+
+    #[test]
+    fn test_elastic_net() {
+        let elastic_net = ElasticNet::new(0.5, 1.0).unwrap();
+        let x = elastic_net.prox(&vec![2.5f64, -0.2, -1.0], 1.0);
+        assert_eq!(x, vec![1.0, 0.0, -0.25]);
+        assert_relative_eq!(
+            ProximalOperator::<Vec<f64>, f64>::value(&elastic_net, &vec![3.0, -4.0]),
+            3.5 + 12.5
+        );
+
+        assert_error!(
+            ElasticNet::new(1.0f64, -1.0),
+            ArgminError,
+            "Invalid parameter: \"`ElasticNet`: weights must be >= 0.\""
+        );
+    }
+
+    #[test]
+    fn test_group_lasso() {
+        let param = vec![3.0f64, 0.1, 4.0, 0.5];
+        let group_lasso = GroupLasso::from_indices(&param, &[vec![0, 2], vec![1]], 1.0).unwrap();
+        let x = group_lasso.prox(&param, 2.0);
+        assert_relative_eq!(x[0], 1.8, epsilon = 1e-12);
+        assert_relative_eq!(x[1], 0.0, epsilon = 1e-12);
+        assert_relative_eq!(x[2], 2.4, epsilon = 1e-12);
+        assert_relative_eq!(x[3], 0.5, epsilon = 1e-12);
+        assert_relative_eq!(group_lasso.value(&param), 5.1, epsilon = 1e-12);
+
+        assert_error!(
+            GroupLasso::from_indices(&param, &[vec![0, 4]], 1.0),
+            ArgminError,
+            "Invalid parameter: \"`GroupLasso`: index 4 out of bounds.\""
+        );
+        assert_error!(
+            GroupLasso::from_indices(&param, &[vec![0, 1], vec![1]], 1.0),
+            ArgminError,
+            "Invalid parameter: \"`GroupLasso`: index 1 is part of several groups.\""
+        );
+    }
+
+    #[test]
+    fn test_nonnegative() {
+        let op = NonNegative::new();
+        assert_eq!(op.project(&vec![2.0f64, -0.2]), vec![2.0, 0.0]);
+        let value: f64 = op.value(&vec![2.0f64, -0.2]);
+        assert!(value.is_infinite());
+        let value: f64 = op.value(&vec![2.0f64, 0.0]);
+        assert_relative_eq!(value, 0.0);
+    }
+
+    #[test]
+    fn test_box_constraint() {
+        let op = BoxConstraint::new(vec![0.0f64, -1.0], vec![1.0, f64::INFINITY]);
+        assert_eq!(op.project(&vec![2.0f64, -3.0]), vec![1.0, -1.0]);
+        assert_eq!(op.project(&vec![0.5f64, 1e10]), vec![0.5, 1e10]);
+        let value: f64 = op.value(&vec![2.0f64, 0.0]);
+        assert!(value.is_infinite());
+        let value: f64 = op.value(&vec![0.5f64, 1e10]);
+        assert_relative_eq!(value, 0.0);
+    }
+
+    #[test]
+    fn test_simplex() {
+        let op = Simplex::new(1.0).unwrap();
+        for (param, expected) in [
+            (vec![0.5f64, 1.0, -0.5], vec![0.25, 0.75, 0.0]),
+            (vec![0.5, 0.8, -0.2], vec![0.35, 0.65, 0.0]),
+            (vec![0.2, 0.3, 0.5], vec![0.2, 0.3, 0.5]),
+            (vec![-1.0, -2.0, -3.0], vec![1.0, 0.0, 0.0]),
+            (vec![0.0, 0.0, 0.0], vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0]),
+            (vec![10.0, 9.0, 1.0, 0.0], vec![1.0, 0.0, 0.0, 0.0]),
+        ] {
+            let x = op.project(&param);
+            for (x, e) in x.iter().zip(expected.iter()) {
+                assert_relative_eq!(*x, *e, epsilon = 1e-12);
+            }
+            let value: f64 = op.value(&x);
+            assert_relative_eq!(value, 0.0);
+        }
+
+        let op = Simplex::new(3.0).unwrap();
+        let x = op.project(&vec![4.0f64, 2.0, 0.0]);
+        assert_relative_eq!(x[0], 2.5, epsilon = 1e-12);
+        assert_relative_eq!(x[1], 0.5, epsilon = 1e-12);
+        assert_relative_eq!(x[2], 0.0, epsilon = 1e-12);
+        let value: f64 = op.value(&vec![4.0f64, 2.0, 0.0]);
+        assert!(value.is_infinite());
+
+        assert_error!(
+            Simplex::new(0.0f64),
+            ArgminError,
+            "Invalid parameter: \"`Simplex`: radius must be > 0.\""
+        );
+    }
+
+    #[cfg(feature = "_ndarrayl")]
+    #[test]
+    fn test_ndarray() {
+        use ndarray::array;
+
+        let x = L1::new(0.5).unwrap().prox(&array![2.0f64, -0.2, -1.0], 1.0);
+        assert_eq!(x, array![1.5, 0.0, -0.5]);
+        let x = Simplex::new(1.0)
+            .unwrap()
+            .project(&array![0.5f64, 1.0, -0.5]);
+        assert_eq!(x, array![0.25, 0.75, 0.0]);
+        let param = array![3.0f64, 4.0, 0.1];
+        let x = GroupLasso::from_indices(&param, &[vec![0, 1], vec![2]], 1.0)
+            .unwrap()
+            .prox(&param, 1.0);
+        assert_relative_eq!(x[0], 2.4, epsilon = 1e-12);
+        assert_relative_eq!(x[1], 3.2, epsilon = 1e-12);
+        assert_relative_eq!(x[2], 0.0, epsilon = 1e-12);
+    }
+
+    #[cfg(feature = "_nalgebral")]
+    #[test]
+    fn test_nalgebra() {
+        use nalgebra::Vector3;
+
+        let x = L1::new(0.5)
+            .unwrap()
+            .prox(&Vector3::new(2.0f64, -0.2, -1.0), 1.0);
+        assert_eq!(x, Vector3::new(1.5, 0.0, -0.5));
+        let x = Simplex::new(1.0)
+            .unwrap()
+            .project(&Vector3::new(0.5f64, 1.0, -0.5));
+        assert_eq!(x, Vector3::new(0.25, 0.75, 0.0));
+        let param = Vector3::new(3.0f64, 4.0, 0.1);
+        let x = GroupLasso::from_indices(&param, &[vec![0, 1], vec![2]], 1.0)
+            .unwrap()
+            .prox(&param, 1.0);
+        assert_relative_eq!(x, Vector3::new(2.4, 3.2, 0.0), epsilon = 1e-12);
+    }
+}
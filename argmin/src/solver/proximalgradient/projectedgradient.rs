@@ -0,0 +1,595 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, IterState, Problem, Projection, SerializeAlias,
+    Solver, State, TerminationReason, KV,
+};
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminScaledAdd, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Barzilai-Borwein step lengths of [`ProjectedGradient`]
+///
+/// The step lengths are given in terms of `s = x_{k+1} - x_k` and
+/// `y = grad f(x_{k+1}) - grad f(x_k)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum BarzilaiBorwein {
+    /// `s^T s / s^T y`
+    Long,
+    /// `s^T y / y^T y`
+    Short,
+    /// Alternate between `Long` and `Short` in subsequent iterations
+    Alternating,
+}
+
+/// # Spectral projected gradient method
+///
+/// Minimizes a smooth function over a closed convex set, given via its projection. In each
+/// iteration, the search direction `d_k = P(x_k - a_k grad f(x_k)) - x_k` is computed from a
+/// projected gradient step with a Barzilai-Borwein step length `a_k` (see
+/// [`BarzilaiBorwein`]), which is safeguarded by lower and upper bounds. Since these step lengths
+/// do not decrease the objective monotonically, a non-monotone line search along `d_k` accepts
+/// steps which decrease the objective sufficiently with respect to the maximum of the last `M`
+/// objective values. With `M = 1`, the line search is monotone.
+///
+/// All iterates are feasible: if the initial parameter vector is not, it is projected onto the
+/// set first. The algorithm terminates once the norm of the projected gradient
+/// `P(x_k - grad f(x_k)) - x_k` falls below a tolerance.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`], [`Gradient`] and
+/// [`Projection`].
+///
+/// ## References
+///
+/// Ernesto G. Birgin, José Mario Martínez and Marcos Raydan (2000). Nonmonotone Spectral
+/// Projected Gradient Methods on Convex Sets. SIAM Journal on Optimization 10(4), 1196-1211.
+/// <https://doi.org/10.1137/S1052623497330963>
+///
+/// Jonathan Barzilai and Jonathan M. Borwein (1988). Two-Point Step Size Gradient Methods.
+/// IMA Journal of Numerical Analysis 8(1), 141-148. <https://doi.org/10.1093/imanum/8.1.141>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ProjectedGradient<F> {
+    /// Current Barzilai-Borwein step length
+    step: F,
+    /// Lower bound of the step length
+    min_step: F,
+    /// Upper bound of the step length
+    max_step: F,
+    /// Barzilai-Borwein step length variant
+    bb: BarzilaiBorwein,
+    /// Number of previous objective values considered by the line search
+    memory: usize,
+    /// Sufficient decrease parameter of the line search
+    sufficient_decrease: F,
+    /// Tolerance for the norm of the projected gradient
+    tol: F,
+    /// Previous objective values
+    history: VecDeque<F>,
+}
+
+impl<F> ProjectedGradient<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`ProjectedGradient`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::proximalgradient::ProjectedGradient;
+    /// let solver: ProjectedGradient<f64> = ProjectedGradient::new();
+    /// ```
+    pub fn new() -> Self {
+        ProjectedGradient {
+            step: F::one(),
+            min_step: float!(1e-30),
+            max_step: float!(1e30),
+            bb: BarzilaiBorwein::Long,
+            memory: 10,
+            sufficient_decrease: float!(1e-4),
+            tol: F::epsilon().sqrt(),
+            history: VecDeque::new(),
+        }
+    }
+
+    /// Set lower and upper bounds for the Barzilai-Borwein step length
+    ///
+    /// Must satisfy `0 < min_step < max_step`. Defaults to `1e-30` and `1e30`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::proximalgradient::ProjectedGradient;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let solver = ProjectedGradient::new().with_step_bounds(1e-10f64, 1e10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_step_bounds(mut self, min_step: F, max_step: F) -> Result<Self, Error> {
+        if min_step <= F::zero() || min_step >= max_step {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ProjectedGradient`: step bounds must satisfy 0 < min_step < max_step."
+            ));
+        }
+        self.min_step = min_step;
+        self.max_step = max_step;
+        Ok(self)
+    }
+
+    /// Set the Barzilai-Borwein step length variant
+    ///
+    /// Defaults to [`BarzilaiBorwein::Long`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::proximalgradient::{BarzilaiBorwein, ProjectedGradient};
+    /// let solver: ProjectedGradient<f64> =
+    ///     ProjectedGradient::new().with_barzilai_borwein(BarzilaiBorwein::Alternating);
+    /// ```
+    #[must_use]
+    pub fn with_barzilai_borwein(mut self, bb: BarzilaiBorwein) -> Self {
+        self.bb = bb;
+        self
+    }
+
+    /// Set the number of previous objective values considered by the non-monotone line search
+    ///
+    /// Must be at least `1` and defaults to `10`. A value of `1` results in a monotone line
+    /// search.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::proximalgradient::ProjectedGradient;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let solver: ProjectedGradient<f64> = ProjectedGradient::new().with_memory(5)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_memory(mut self, memory: usize) -> Result<Self, Error> {
+        if memory < 1 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ProjectedGradient`: memory must be >= 1."
+            ));
+        }
+        self.memory = memory;
+        Ok(self)
+    }
+
+    /// Set the sufficient decrease parameter of the line search
+    ///
+    /// Must be in `(0, 1)` and defaults to `1e-4`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::proximalgradient::ProjectedGradient;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let solver = ProjectedGradient::new().with_sufficient_decrease(1e-3f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_sufficient_decrease(mut self, sufficient_decrease: F) -> Result<Self, Error> {
+        if sufficient_decrease <= F::zero() || sufficient_decrease >= F::one() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ProjectedGradient`: sufficient decrease parameter must be in (0, 1)."
+            ));
+        }
+        self.sufficient_decrease = sufficient_decrease;
+        Ok(self)
+    }
+
+    /// Set the tolerance for the norm of the projected gradient
+    ///
+    /// Must be larger than zero and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::proximalgradient::ProjectedGradient;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let solver = ProjectedGradient::new().with_tolerance(1e-10f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= F::zero() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ProjectedGradient`: tolerance must be > 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+
+    /// Clamps a step length to the bounds
+    fn safeguard(&self, step: F) -> F {
+        step.max(self.min_step).min(self.max_step)
+    }
+}
+
+impl<F> Default for ProjectedGradient<F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        ProjectedGradient::new()
+    }
+}
+
+impl<O, P, F> Solver<O, IterState<P, P, (), (), F>> for ProjectedGradient<F>
+where
+    O: CostFunction<Param = P, Output = F>
+        + Gradient<Param = P, Gradient = P>
+        + Projection<Param = P>,
+    P: Clone
+        + SerializeAlias
+        + ArgminScaledAdd<P, F, P>
+        + ArgminSub<P, P>
+        + ArgminDot<P, F>
+        + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Spectral projected gradient";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, P, (), (), F>,
+    ) -> Result<(IterState<P, P, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`ProjectedGradient` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let param = problem.project(&param)?;
+        let cost = problem.cost(&param)?;
+        let gradient = problem.gradient(&param)?;
+        // Initial step length as proposed by Birgin, Martínez and Raydan
+        let norm = problem
+            .project(&param.scaled_add(&(-F::one()), &gradient))?
+            .sub(&param)
+            .l2_norm();
+        self.step = self.safeguard(F::one() / norm);
+        self.history = VecDeque::from(vec![cost]);
+        Ok((state.param(param).cost(cost).gradient(gradient), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, P, (), (), F>,
+    ) -> Result<(IterState<P, P, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`ProjectedGradient`: Parameter vector in state not set."
+        ))?;
+        let gradient = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`ProjectedGradient`: Gradient in state not set."
+        ))?;
+        let cost = state.get_cost();
+
+        let residual = problem
+            .project(&param.scaled_add(&(-F::one()), &gradient))?
+            .sub(&param)
+            .l2_norm();
+        if residual <= self.tol {
+            return Ok((
+                state
+                    .param(param)
+                    .gradient(gradient)
+                    .terminate_with(TerminationReason::SolverConverged),
+                Some(kv!("projected_gradient_norm" => residual;)),
+            ));
+        }
+
+        let direction = problem
+            .project(&param.scaled_add(&(-self.step), &gradient))?
+            .sub(&param);
+
+        // Non-monotone line search with safeguarded quadratic interpolation
+        let reference = self.history.iter().fold(cost, |acc, &c| acc.max(c));
+        let slope = gradient.dot(&direction);
+        let mut alpha = F::one();
+        let (new_param, new_cost) = loop {
+            let new_param = param.scaled_add(&alpha, &direction);
+            let new_cost = problem.cost(&new_param)?;
+            if new_cost <= reference + self.sufficient_decrease * alpha * slope {
+                break (new_param, new_cost);
+            }
+            let candidate =
+                -float!(0.5) * alpha * alpha * slope / (new_cost - cost - alpha * slope);
+            alpha = if candidate >= float!(0.1) * alpha && candidate <= float!(0.9) * alpha {
+                candidate
+            } else {
+                alpha / float!(2.0)
+            };
+            if alpha <= F::epsilon() {
+                return Err(argmin_error!(
+                    ConditionViolated,
+                    "`ProjectedGradient`: line search failed to find a sufficient decrease."
+                ));
+            }
+        };
+        let new_gradient = problem.gradient(&new_param)?;
+
+        // Barzilai-Borwein step length
+        let s = new_param.sub(&param);
+        let y = new_gradient.sub(&gradient);
+        let sy = s.dot(&y);
+        let long = match self.bb {
+            BarzilaiBorwein::Long => true,
+            BarzilaiBorwein::Short => false,
+            BarzilaiBorwein::Alternating => state.get_iter().is_multiple_of(2),
+        };
+        self.step = if sy <= F::zero() {
+            self.max_step
+        } else if long {
+            self.safeguard(s.dot(&s) / sy)
+        } else {
+            self.safeguard(sy / y.dot(&y))
+        };
+
+        self.history.push_back(new_cost);
+        if self.history.len() > self.memory {
+            self.history.pop_front();
+        }
+
+        Ok((
+            state.param(new_param).cost(new_cost).gradient(new_gradient),
+            Some(kv!(
+                "step_length" => alpha;
+                "bb_step_length" => self.step;
+                "projected_gradient_norm" => residual;
+            )),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::solver::proximalgradient::{BoxConstraint, ProjectionOperator, Simplex};
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(projected_gradient, ProjectedGradient<f64>);
+
+    /// Rosenbrock function on a box which does not contain the unconstrained minimizer `(1, 1)`
+    struct BoxedRosenbrock {
+        bounds: BoxConstraint<Vec<f64>>,
+    }
+
+    impl CostFunction for BoxedRosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((1.0 - p[0]).powi(2) + 100.0 * (p[1] - p[0].powi(2)).powi(2))
+        }
+    }
+
+    impl Gradient for BoxedRosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![
+                -2.0 * (1.0 - p[0]) - 400.0 * p[0] * (p[1] - p[0].powi(2)),
+                200.0 * (p[1] - p[0].powi(2)),
+            ])
+        }
+    }
+
+    impl Projection for BoxedRosenbrock {
+        type Param = Vec<f64>;
+
+        fn project(&self, p: &Self::Param) -> Result<Self::Param, Error> {
+            Ok(self.bounds.project(p))
+        }
+    }
+
+    /// `1/2 sum_i d_i (x_i - c_i)^2` on the unit simplex
+    struct SimplexQuadratic {
+        d: Vec<f64>,
+        c: Vec<f64>,
+        simplex: Simplex<f64>,
+    }
+
+    impl CostFunction for SimplexQuadratic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p.iter()
+                .zip(self.d.iter().zip(self.c.iter()))
+                .map(|(x, (d, c))| 0.5 * d * (x - c).powi(2))
+                .sum())
+        }
+    }
+
+    impl Gradient for SimplexQuadratic {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(p.iter()
+                .zip(self.d.iter().zip(self.c.iter()))
+                .map(|(x, (d, c))| d * (x - c))
+                .collect())
+        }
+    }
+
+    impl Projection for SimplexQuadratic {
+        type Param = Vec<f64>;
+
+        fn project(&self, p: &Self::Param) -> Result<Self::Param, Error> {
+            Ok(self.simplex.project(p))
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let solver: ProjectedGradient<f64> = ProjectedGradient::new();
+        let ProjectedGradient {
+            step,
+            min_step,
+            max_step,
+            bb,
+            memory,
+            sufficient_decrease,
+            tol,
+            history,
+        } = solver;
+        assert_eq!(step.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(min_step.to_ne_bytes(), 1e-30f64.to_ne_bytes());
+        assert_eq!(max_step.to_ne_bytes(), 1e30f64.to_ne_bytes());
+        assert_eq!(bb, BarzilaiBorwein::Long);
+        assert_eq!(memory, 10);
+        assert_eq!(sufficient_decrease.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(history.is_empty());
+    }
+
+    #[test]
+    fn test_builders() {
+        let solver: ProjectedGradient<f64> = ProjectedGradient::new()
+            .with_step_bounds(1e-10, 1e10)
+            .unwrap()
+            .with_barzilai_borwein(BarzilaiBorwein::Short)
+            .with_memory(1)
+            .unwrap()
+            .with_sufficient_decrease(0.1)
+            .unwrap()
+            .with_tolerance(1e-10)
+            .unwrap();
+        assert_eq!(solver.min_step.to_ne_bytes(), 1e-10f64.to_ne_bytes());
+        assert_eq!(solver.max_step.to_ne_bytes(), 1e10f64.to_ne_bytes());
+        assert_eq!(solver.bb, BarzilaiBorwein::Short);
+        assert_eq!(solver.memory, 1);
+        assert_eq!(
+            solver.sufficient_decrease.to_ne_bytes(),
+            0.1f64.to_ne_bytes()
+        );
+        assert_eq!(solver.tol.to_ne_bytes(), 1e-10f64.to_ne_bytes());
+
+        for (min, max) in [(0.0f64, 1.0), (1.0, 1.0), (2.0, 1.0)] {
+            assert_error!(
+                ProjectedGradient::new().with_step_bounds(min, max),
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`ProjectedGradient`: step bounds must satisfy ",
+                    "0 < min_step < max_step.\""
+                )
+            );
+        }
+        assert_error!(
+            ProjectedGradient::<f64>::new().with_memory(0),
+            ArgminError,
+            "Invalid parameter: \"`ProjectedGradient`: memory must be >= 1.\""
+        );
+        for c in [0.0f64, 1.0] {
+            assert_error!(
+                ProjectedGradient::new().with_sufficient_decrease(c),
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`ProjectedGradient`: sufficient decrease parameter ",
+                    "must be in (0, 1).\""
+                )
+            );
+        }
+        assert_error!(
+            ProjectedGradient::new().with_tolerance(0.0f64),
+            ArgminError,
+            "Invalid parameter: \"`ProjectedGradient`: tolerance must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_init_param_not_initialized() {
+        let mut solver: ProjectedGradient<f64> = ProjectedGradient::new();
+        let problem = BoxedRosenbrock {
+            bounds: BoxConstraint::new(vec![-2.0, -2.0], vec![0.5, 2.0]),
+        };
+        let res = solver.init(&mut Problem::new(problem), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`ProjectedGradient` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_box_constrained_rosenbrock() {
+        // The minimizer on the box [-2, 0.5] x [-2, 2] lies on the upper bound of the first
+        // parameter, where `x_2 = x_1^2`.
+        for bb in [
+            BarzilaiBorwein::Long,
+            BarzilaiBorwein::Short,
+            BarzilaiBorwein::Alternating,
+        ] {
+            let problem = BoxedRosenbrock {
+                bounds: BoxConstraint::new(vec![-2.0, -2.0], vec![0.5, 2.0]),
+            };
+            let res = Executor::new(problem, ProjectedGradient::new().with_barzilai_borwein(bb))
+                .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(10000))
+                .run()
+                .unwrap();
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::SolverConverged)
+            );
+            let x = res.state.get_best_param().unwrap();
+            assert_relative_eq!(x[0], 0.5, epsilon = 1e-8);
+            assert_relative_eq!(x[1], 0.25, epsilon = 1e-6);
+            assert_relative_eq!(res.state.get_best_cost(), 0.25, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_simplex() {
+        // KKT conditions: x_i = max(c_i - tau / d_i, 0) with sum_i x_i = 1, here tau = 0.2
+        let problem = SimplexQuadratic {
+            d: vec![1.0, 2.0, 4.0],
+            c: vec![0.8, 0.5, 0.0],
+            simplex: Simplex::new(1.0).unwrap(),
+        };
+        let res = Executor::new(problem, ProjectedGradient::new())
+            .configure(|state| state.param(vec![5.0, 5.0, 5.0]).max_iters(1000))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let x = res.state.get_best_param().unwrap();
+        assert_relative_eq!(x[0], 0.6, epsilon = 1e-8);
+        assert_relative_eq!(x[1], 0.4, epsilon = 1e-8);
+        assert_relative_eq!(x[2], 0.0, epsilon = 1e-8);
+    }
+}
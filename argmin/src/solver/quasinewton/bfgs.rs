@@ -6,12 +6,12 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{
-    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState,
-    LineSearch, OptimizationResult, Problem, SerializeAlias, Solver, TerminationReason,
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState, L2Norm,
+    LineSearch, Norm, OptimizationResult, Problem, SerializeAlias, Solver, TerminationReason,
     TerminationStatus, KV,
 };
 use argmin_math::{
-    ArgminAdd, ArgminDot, ArgminEye, ArgminL2Norm, ArgminMul, ArgminSub, ArgminTranspose,
+    ArgminDot, ArgminMulAssign, ArgminRankOneUpdate, ArgminRankTwoUpdate, ArgminSub,
 };
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
@@ -34,6 +34,8 @@ use serde::{Deserialize, Serialize};
 /// One is a tolerance on the gradient (set with
 /// [`with_tolerance_grad`](`BFGS::with_tolerance_grad`)): If the norm of the gradient is below
 /// said tolerance, the algorithm stops. It defaults to `sqrt(EPSILON)`.
+/// The norm used in this comparison can be chosen via
+/// [`with_gradient_norm`](`BFGS::with_gradient_norm`) and defaults to the l2-norm.
 /// The other one is a tolerance on the change of the cost function from one iteration to the
 /// other. If the change is below this tolerance (default: `EPSILON`), the algorithm stops. This
 /// parameter can be set via [`with_tolerance_cost`](`BFGS::with_tolerance_cost`).
@@ -48,11 +50,13 @@ use serde::{Deserialize, Serialize};
 /// Springer. ISBN 0-387-30303-0.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct BFGS<L, F> {
+pub struct BFGS<L, F, N = L2Norm> {
     /// line search
     linesearch: L,
     /// Tolerance for the stopping criterion based on the change of the norm on the gradient
     tol_grad: F,
+    /// Norm used in the stopping criterion based on the gradient
+    grad_norm: N,
     /// Tolerance for the stopping criterion based on the change of the cost stopping criterion
     tol_cost: F,
 }
@@ -74,10 +78,16 @@ where
         BFGS {
             linesearch,
             tol_grad: F::epsilon().sqrt(),
+            grad_norm: L2Norm,
             tol_cost: F::epsilon(),
         }
     }
+}
 
+impl<L, F, N> BFGS<L, F, N>
+where
+    F: ArgminFloat,
+{
     /// The algorithm stops if the norm of the gradient is below `tol_grad`.
     ///
     /// The provided value must be non-negative. Defaults to `sqrt(EPSILON)`.
@@ -104,6 +114,30 @@ where
         Ok(self)
     }
 
+    /// Sets the norm used in the stopping criterion based on the gradient.
+    ///
+    /// Any [`Norm`] which is implemented for the gradient can be used, for instance
+    /// [`LInfNorm`](`crate::core::LInfNorm`) or [`WeightedL2Norm`](`crate::core::WeightedL2Norm`).
+    /// Defaults to [`L2Norm`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::BFGS;
+    /// # use argmin::core::LInfNorm;
+    /// # let linesearch = ();
+    /// let bfgs: BFGS<_, f64, LInfNorm> = BFGS::new(linesearch).with_gradient_norm(LInfNorm);
+    /// ```
+    #[must_use]
+    pub fn with_gradient_norm<N2>(self, norm: N2) -> BFGS<L, F, N2> {
+        BFGS {
+            linesearch: self.linesearch,
+            tol_grad: self.tol_grad,
+            grad_norm: norm,
+            tol_cost: self.tol_cost,
+        }
+    }
+
     /// Sets tolerance for the stopping criterion based on the change of the cost stopping criterion
     ///
     /// The provided value must be non-negative. Defaults to `EPSILON`.
@@ -131,32 +165,18 @@ where
     }
 }
 
-impl<O, L, P, G, H, F> Solver<O, IterState<P, G, (), H, F>> for BFGS<L, F>
+impl<O, L, P, G, H, F, N> Solver<O, IterState<P, G, (), H, F>> for BFGS<L, F, N>
 where
     O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
-    P: Clone
-        + SerializeAlias
-        + DeserializeOwnedAlias
-        + ArgminSub<P, P>
-        + ArgminDot<G, H>
-        + ArgminDot<P, H>,
-    G: Clone
-        + SerializeAlias
-        + DeserializeOwnedAlias
-        + ArgminL2Norm<F>
-        + ArgminMul<F, P>
-        + ArgminDot<P, F>
-        + ArgminSub<G, G>,
+    P: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminSub<P, P> + ArgminMulAssign<F>,
+    G: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminDot<P, F> + ArgminSub<G, G>,
     H: SerializeAlias
         + DeserializeOwnedAlias
-        + ArgminSub<H, H>
-        + ArgminDot<G, G>
-        + ArgminDot<H, H>
-        + ArgminAdd<H, H>
-        + ArgminMul<F, H>
-        + ArgminTranspose<H>
-        + ArgminEye,
+        + ArgminDot<G, P>
+        + ArgminRankOneUpdate<P, F>
+        + ArgminRankTwoUpdate<P, F>,
     L: Clone + LineSearch<P, F> + Solver<O, IterState<P, G, (), (), F>>,
+    N: Norm<G, F>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "BFGS";
@@ -226,7 +246,8 @@ where
             "`BFGS`: Inverse Hessian in state not set."
         ))?;
 
-        let p = inv_hessian.dot(&prev_grad).mul(&float!(-1.0));
+        let mut p: P = inv_hessian.dot(&prev_grad);
+        p.mul_assign(&float!(-1.0));
 
         self.linesearch.search_direction(p);
 
@@ -264,25 +285,14 @@ where
         let yksk: F = yk.dot(&sk);
         let rhok = float!(1.0) / yksk;
 
-        let e = inv_hessian.eye_like();
-        let mat1: H = sk.dot(&yk);
-        let mat1 = mat1.mul(&rhok);
-
-        let tmp1 = e.sub(&mat1);
-
-        let mat2 = mat1.t();
-        let tmp2 = e.sub(&mat2);
-
-        let sksk: H = sk.dot(&sk);
-        let sksk = sksk.mul(&rhok);
-
-        // if state.get_iter() == 0 {
-        //     let ykyk: f64 = yk.dot(&yk);
-        //     self.inv_hessian = self.inv_hessian.eye_like().mul(&(yksk / ykyk));
-        //     println!("{:?}", self.inv_hessian);
-        // }
-
-        let inv_hessian = tmp1.dot(&inv_hessian.dot(&tmp2)).add(&sksk);
+        // The update `(I - rho * s * y^T) * H * (I - rho * y * s^T) + rho * s * s^T` is expanded
+        // into a symmetric rank-two and a rank-one update, which avoids computing the products of
+        // full matrices. This relies on the inverse Hessian being symmetric.
+        let hy: P = inv_hessian.dot(&yk);
+        let yhy: F = yk.dot(&hy);
+        let mut inv_hessian = inv_hessian;
+        inv_hessian.rank_two_update(&(-rhok), &sk, &hy);
+        inv_hessian.rank_one_update(&(rhok + rhok * rhok * yhy), &sk, &sk);
 
         Ok((
             state
@@ -295,7 +305,7 @@ where
     }
 
     fn terminate(&mut self, state: &IterState<P, G, (), H, F>) -> TerminationStatus {
-        if state.get_gradient().unwrap().l2_norm() < self.tol_grad {
+        if self.grad_norm.norm(state.get_gradient().unwrap()) < self.tol_grad {
             return TerminationStatus::Terminated(TerminationReason::SolverConverged);
         }
         if (state.get_prev_cost() - state.cost).abs() < self.tol_cost {
@@ -326,11 +336,13 @@ mod tests {
         let BFGS {
             linesearch,
             tol_grad,
+            grad_norm,
             tol_cost,
         } = bfgs;
 
         assert_eq!(linesearch, MyFakeLineSearch {});
         assert_eq!(tol_grad.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(grad_norm, L2Norm);
         assert_eq!(tol_cost.to_ne_bytes(), f64::EPSILON.to_ne_bytes());
     }
 
@@ -507,4 +519,49 @@ mod tests {
             assert_eq!(s.to_ne_bytes(), g.to_ne_bytes());
         }
     }
+
+    #[test]
+    fn test_solver_array() {
+        use crate::core::{CostFunction, Executor, Gradient};
+        use approx::assert_relative_eq;
+
+        struct Rosenbrock {}
+
+        impl CostFunction for Rosenbrock {
+            type Param = [f64; 2];
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok((1.0 - p[0]).powi(2) + 100.0 * (p[1] - p[0].powi(2)).powi(2))
+            }
+        }
+
+        impl Gradient for Rosenbrock {
+            type Param = [f64; 2];
+            type Gradient = [f64; 2];
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok([
+                    -2.0 * (1.0 - p[0]) - 400.0 * p[0] * (p[1] - p[0].powi(2)),
+                    200.0 * (p[1] - p[0].powi(2)),
+                ])
+            }
+        }
+
+        let linesearch = MoreThuenteLineSearch::new().with_c(1e-4, 0.9).unwrap();
+        let bfgs: BFGS<_, f64> = BFGS::new(linesearch);
+        let res = Executor::new(Rosenbrock {}, bfgs)
+            .configure(|state| {
+                state
+                    .param([-1.2, 1.0])
+                    .inv_hessian([[1.0, 0.0], [0.0, 1.0]])
+                    .max_iters(100)
+            })
+            .run()
+            .unwrap();
+
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0f64, epsilon = 1e-6);
+        assert_relative_eq!(param[1], 1.0f64, epsilon = 1e-6);
+    }
 }
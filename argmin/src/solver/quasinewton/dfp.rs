@@ -6,11 +6,11 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{
-    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState,
-    LineSearch, OptimizationResult, Problem, SerializeAlias, Solver, TerminationReason,
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState, L2Norm,
+    LineSearch, Norm, OptimizationResult, Problem, SerializeAlias, Solver, TerminationReason,
     TerminationStatus, KV,
 };
-use argmin_math::{ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminSub};
+use argmin_math::{ArgminDot, ArgminMul, ArgminRankOneUpdate, ArgminSub};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -31,6 +31,8 @@ use serde::{Deserialize, Serialize};
 /// A tolerance on the gradient can be configured with
 /// [`with_tolerance_grad`](`DFP::with_tolerance_grad`): If the norm of the gradient is below
 /// said tolerance, the algorithm stops. It defaults to `sqrt(EPSILON)`.
+/// The norm used in this comparison can be chosen via
+/// [`with_gradient_norm`](`DFP::with_gradient_norm`) and defaults to the l2-norm.
 ///
 /// ## Requirements on the optimization problem
 ///
@@ -42,11 +44,13 @@ use serde::{Deserialize, Serialize};
 /// Springer. ISBN 0-387-30303-0.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct DFP<L, F> {
+pub struct DFP<L, F, N = L2Norm> {
     /// line search
     linesearch: L,
     /// Tolerance for the stopping criterion based on the change of the norm on the gradient
     tol_grad: F,
+    /// Norm used in the stopping criterion based on the gradient
+    grad_norm: N,
 }
 
 impl<L, F> DFP<L, F>
@@ -66,9 +70,15 @@ where
         DFP {
             linesearch,
             tol_grad: F::epsilon().sqrt(),
+            grad_norm: L2Norm,
         }
     }
+}
 
+impl<L, F, N> DFP<L, F, N>
+where
+    F: ArgminFloat,
+{
     /// The algorithm stops if the norm of the gradient is below `tol_grad`.
     ///
     /// The provided value must be non-negative. Defaults to `sqrt(EPSILON)`.
@@ -94,9 +104,32 @@ where
         self.tol_grad = tol_grad;
         Ok(self)
     }
+
+    /// Sets the norm used in the stopping criterion based on the gradient.
+    ///
+    /// Any [`Norm`] which is implemented for the gradient can be used, for instance
+    /// [`LInfNorm`](`crate::core::LInfNorm`) or [`WeightedL2Norm`](`crate::core::WeightedL2Norm`).
+    /// Defaults to [`L2Norm`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::DFP;
+    /// # use argmin::core::LInfNorm;
+    /// # let linesearch = ();
+    /// let dfp: DFP<_, f64, LInfNorm> = DFP::new(linesearch).with_gradient_norm(LInfNorm);
+    /// ```
+    #[must_use]
+    pub fn with_gradient_norm<N2>(self, norm: N2) -> DFP<L, F, N2> {
+        DFP {
+            linesearch: self.linesearch,
+            tol_grad: self.tol_grad,
+            grad_norm: norm,
+        }
+    }
 }
 
-impl<O, L, P, G, H, F> Solver<O, IterState<P, G, (), H, F>> for DFP<L, F>
+impl<O, L, P, G, H, F, N> Solver<O, IterState<P, G, (), H, F>> for DFP<L, F, N>
 where
     O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
     P: Clone
@@ -104,22 +137,11 @@ where
         + DeserializeOwnedAlias
         + ArgminSub<P, P>
         + ArgminDot<G, F>
-        + ArgminDot<P, H>
         + ArgminMul<F, P>,
-    G: Clone
-        + SerializeAlias
-        + DeserializeOwnedAlias
-        + ArgminSub<G, G>
-        + ArgminL2Norm<F>
-        + ArgminDot<P, F>,
-    H: Clone
-        + SerializeAlias
-        + DeserializeOwnedAlias
-        + ArgminSub<H, H>
-        + ArgminDot<G, P>
-        + ArgminAdd<H, H>
-        + ArgminMul<F, H>,
+    G: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminSub<G, G> + ArgminDot<P, F>,
+    H: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminDot<G, P> + ArgminRankOneUpdate<P, F>,
     L: Clone + LineSearch<P, F> + Solver<O, IterState<P, G, (), (), F>>,
+    N: Norm<G, F>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "DFP";
@@ -225,14 +247,12 @@ where
 
         let yksk: F = yk.dot(&sk);
 
-        let sksk: H = sk.dot(&sk);
-
-        let tmp3: P = inv_hessian.dot(&yk);
-        let tmp4: F = tmp3.dot(&yk);
-        let tmp3: H = tmp3.dot(&tmp3);
-        let tmp3: H = tmp3.mul(&(float!(1.0) / tmp4));
+        let hy: P = inv_hessian.dot(&yk);
+        let yhy: F = hy.dot(&yk);
 
-        let inv_hessian = inv_hessian.sub(&tmp3).add(&sksk.mul(&(float!(1.0) / yksk)));
+        let mut inv_hessian = inv_hessian;
+        inv_hessian.rank_one_update(&(float!(-1.0) / yhy), &hy, &hy);
+        inv_hessian.rank_one_update(&(float!(1.0) / yksk), &sk, &sk);
 
         Ok((
             state
@@ -245,7 +265,7 @@ where
     }
 
     fn terminate(&mut self, state: &IterState<P, G, (), H, F>) -> TerminationStatus {
-        if state.get_gradient().unwrap().l2_norm() < self.tol_grad {
+        if self.grad_norm.norm(state.get_gradient().unwrap()) < self.tol_grad {
             return TerminationStatus::Terminated(TerminationReason::SolverConverged);
         }
         TerminationStatus::NotTerminated
@@ -273,10 +293,12 @@ mod tests {
         let DFP {
             linesearch,
             tol_grad,
+            grad_norm,
         } = dfp;
 
         assert_eq!(linesearch, MyFakeLineSearch {});
         assert_eq!(tol_grad.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(grad_norm, L2Norm);
     }
 
     #[test]
@@ -6,13 +6,13 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{
-    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState,
-    LineSearch, OptimizationResult, Problem, SerializeAlias, Solver, State, TerminationReason,
-    TerminationStatus, KV,
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState, L2Norm,
+    LineSearch, Norm, OptimizationResult, Problem, SerializeAlias, Solver, State,
+    TerminationReason, TerminationStatus, KV,
 };
 use argmin_math::{
-    ArgminAdd, ArgminDot, ArgminL1Norm, ArgminL2Norm, ArgminMinMax, ArgminMul, ArgminSignum,
-    ArgminSub, ArgminZeroLike,
+    ArgminAdd, ArgminDot, ArgminL1Norm, ArgminMinMax, ArgminMul, ArgminMulAssign,
+    ArgminScaledAddAssign, ArgminScaledSubAssign, ArgminSignum, ArgminSub, ArgminZeroLike,
 };
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
@@ -50,6 +50,8 @@ where
 /// One is a tolerance on the gradient (set with
 /// [`with_tolerance_grad`](`LBFGS::with_tolerance_grad`)): If the norm of the gradient is below
 /// said tolerance, the algorithm stops. It defaults to `sqrt(EPSILON)`.
+/// The norm used in this comparison can be chosen via
+/// [`with_gradient_norm`](`LBFGS::with_gradient_norm`) and defaults to the l2-norm.
 /// The other one is a tolerance on the change of the cost function from one iteration to the
 /// other. If the change is below this tolerance (default: `EPSILON`), the algorithm stops. This
 /// parameter can be set via [`with_tolerance_cost`](`LBFGS::with_tolerance_cost`).
@@ -76,7 +78,7 @@ where
 /// International Conference on Machine Learning.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct LBFGS<L, P, G, F> {
+pub struct LBFGS<L, P, G, F, N = L2Norm> {
     /// line search
     linesearch: L,
     /// m
@@ -87,6 +89,8 @@ pub struct LBFGS<L, P, G, F> {
     y: VecDeque<G>,
     /// Tolerance for the stopping criterion based on the change of the norm on the gradient
     tol_grad: F,
+    /// Norm used in the stopping criterion based on the gradient
+    grad_norm: N,
     /// Tolerance for the stopping criterion based on the change of the cost stopping criterion
     tol_cost: F,
     /// Coefficient of L1-regularization
@@ -115,12 +119,18 @@ where
             s: VecDeque::with_capacity(m),
             y: VecDeque::with_capacity(m),
             tol_grad: F::epsilon().sqrt(),
+            grad_norm: L2Norm,
             tol_cost: F::epsilon(),
             l1_coeff: None,
             l1_prev_unreg_grad: None,
         }
     }
+}
 
+impl<L, P, G, F, N> LBFGS<L, P, G, F, N>
+where
+    F: ArgminFloat,
+{
     /// The algorithm stops if the norm of the gradient is below `tol_grad`.
     ///
     /// The provided value must be non-negative. Defaults to `sqrt(EPSILON)`.
@@ -147,6 +157,35 @@ where
         Ok(self)
     }
 
+    /// Sets the norm used in the stopping criterion based on the gradient.
+    ///
+    /// Any [`Norm`] which is implemented for the gradient can be used, for instance
+    /// [`LInfNorm`](`crate::core::LInfNorm`) or [`WeightedL2Norm`](`crate::core::WeightedL2Norm`).
+    /// Defaults to [`L2Norm`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::LBFGS;
+    /// # use argmin::core::LInfNorm;
+    /// # let linesearch = ();
+    /// let lbfgs: LBFGS<_, Vec<f64>, Vec<f64>,  f64, LInfNorm> = LBFGS::new(linesearch, 3).with_gradient_norm(LInfNorm);
+    /// ```
+    #[must_use]
+    pub fn with_gradient_norm<N2>(self, norm: N2) -> LBFGS<L, P, G, F, N2> {
+        LBFGS {
+            linesearch: self.linesearch,
+            m: self.m,
+            s: self.s,
+            y: self.y,
+            tol_grad: self.tol_grad,
+            grad_norm: norm,
+            tol_cost: self.tol_cost,
+            l1_coeff: self.l1_coeff,
+            l1_prev_unreg_grad: self.l1_prev_unreg_grad,
+        }
+    }
+
     /// Sets tolerance for the stopping criterion based on the change of the cost stopping criterion
     ///
     /// The provided value must be non-negative. Defaults to `EPSILON`.
@@ -177,6 +216,9 @@ where
     ///
     /// Parameter `l1_coeff` must be `> 0.0`.
     ///
+    /// Other non-smooth regularization terms are supported by the proximal gradient methods in
+    /// [`proximalgradient`](`crate::solver::proximalgradient`).
+    ///
     /// # Example
     ///
     /// ```
@@ -302,7 +344,7 @@ where
     }
 }
 
-impl<O, L, P, G, F> Solver<O, IterState<P, G, (), (), F>> for LBFGS<L, P, G, F>
+impl<O, L, P, G, F, N> Solver<O, IterState<P, G, (), (), F>> for LBFGS<L, P, G, F, N>
 where
     O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
     P: Clone
@@ -317,6 +359,8 @@ where
         + ArgminMul<F, P>
         + ArgminMul<P, P>
         + ArgminMul<G, P>
+        + ArgminMulAssign<F>
+        + ArgminScaledAddAssign<P, F>
         + ArgminL1Norm<F>
         + ArgminSignum
         + ArgminZeroLike
@@ -325,7 +369,6 @@ where
         + std::fmt::Debug
         + SerializeAlias
         + DeserializeOwnedAlias
-        + ArgminL2Norm<F>
         + ArgminSub<G, G>
         + ArgminAdd<G, G>
         + ArgminAdd<P, G>
@@ -333,9 +376,11 @@ where
         + ArgminDot<P, F>
         + ArgminMul<F, G>
         + ArgminMul<F, P>
+        + ArgminScaledSubAssign<G, F>
         + ArgminZeroLike
         + ArgminMinMax,
     L: Clone + LineSearch<P, F> + Solver<LineSearchProblem<O, P, G, F>, IterState<P, G, (), (), F>>,
+    N: Norm<G, F>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "L-BFGS";
@@ -412,7 +457,7 @@ where
             let rho_t = float!(1.0) / yksk;
             let skq: F = sk.dot(&q);
             let alpha_t = skq.mul(rho_t);
-            q = q.sub(&yk.mul(&alpha_t));
+            q.scaled_sub_assign(&alpha_t, yk);
             rho[cur_m - i - 1] = rho_t;
             alpha[cur_m - i - 1] = alpha_t;
         }
@@ -420,7 +465,7 @@ where
         for (i, (sk, yk)) in self.s.iter().zip(self.y.iter()).enumerate() {
             let beta: F = yk.dot(&r);
             let beta = beta.mul(rho[i]);
-            r = r.add(&sk.mul(&(alpha[i] - beta)));
+            r.scaled_add_assign(&(alpha[i] - beta), sk);
         }
 
         let mut line_problem = LineSearchProblem::new(problem.take_problem().unwrap());
@@ -434,7 +479,8 @@ where
             .mul(&r)
             .mul(&float!(-1.0))
         } else {
-            r.mul(&float!(-1.0))
+            r.mul_assign(&float!(-1.0));
+            r
         };
 
         self.linesearch.search_direction(d);
@@ -494,7 +540,7 @@ where
     }
 
     fn terminate(&mut self, state: &IterState<P, G, (), (), F>) -> TerminationStatus {
-        if state.get_gradient().unwrap().l2_norm() < self.tol_grad {
+        if self.grad_norm.norm(state.get_gradient().unwrap()) < self.tol_grad {
             return TerminationStatus::Terminated(TerminationReason::SolverConverged);
         }
         if (state.get_prev_cost() - state.get_cost()).abs() < self.tol_cost {
@@ -509,7 +555,7 @@ mod tests {
     use super::*;
     use crate::core::{
         test_utils::{TestProblem, TestSparseProblem},
-        ArgminError, IterState, State,
+        ArgminError, IterState, L1Norm, LInfNorm, State, WeightedL2Norm,
     };
     use crate::solver::linesearch::MoreThuenteLineSearch;
     use crate::test_trait_impl;
@@ -528,6 +574,7 @@ mod tests {
         let LBFGS {
             linesearch,
             tol_grad,
+            grad_norm,
             tol_cost,
             m,
             s,
@@ -538,6 +585,7 @@ mod tests {
 
         assert_eq!(linesearch, MyFakeLineSearch {});
         assert_eq!(tol_grad.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(grad_norm, L2Norm);
         assert_eq!(tol_cost.to_ne_bytes(), f64::EPSILON.to_ne_bytes());
         assert_eq!(m, 3);
         assert!(s.capacity() >= 3);
@@ -600,6 +648,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_with_gradient_norm() {
+        #[derive(Eq, PartialEq, Debug, Clone, Copy)]
+        struct MyFakeLineSearch {}
+
+        let lbfgs: LBFGS<_, Vec<f64>, Vec<f64>, f64, LInfNorm> =
+            LBFGS::new(MyFakeLineSearch {}, 3).with_gradient_norm(LInfNorm);
+        assert_eq!(lbfgs.grad_norm, LInfNorm);
+
+        let weights = vec![1.0, 2.0];
+        let lbfgs: LBFGS<_, Vec<f64>, Vec<f64>, f64, WeightedL2Norm<Vec<f64>>> =
+            LBFGS::new(MyFakeLineSearch {}, 3).with_gradient_norm(WeightedL2Norm::new(weights));
+        assert_eq!(lbfgs.grad_norm, WeightedL2Norm::new(vec![1.0, 2.0]));
+    }
+
+    #[test]
+    fn test_terminate_gradient_norm() {
+        type LbfgsState = IterState<Vec<f64>, Vec<f64>, (), (), f64>;
+
+        type Linesearch = MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>;
+
+        fn terminate<N: Norm<Vec<f64>, f64>>(norm: N, state: &LbfgsState) -> TerminationStatus {
+            let mut lbfgs: LBFGS<Linesearch, Vec<f64>, Vec<f64>, f64, N> =
+                LBFGS::new(MoreThuenteLineSearch::new(), 3)
+                    .with_tolerance_grad(0.045)
+                    .unwrap()
+                    .with_gradient_norm(norm);
+            Solver::<TestProblem, LbfgsState>::terminate(&mut lbfgs, state)
+        }
+
+        // l1-norm of the gradient is 0.07, l2-norm is 0.05, l-infinity-norm is 0.04 and the
+        // weighted l2-norm is 0.03
+        let state = LbfgsState::new().gradient(vec![0.03, -0.04]);
+        let converged = TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        assert_eq!(terminate(L1Norm, &state), TerminationStatus::NotTerminated);
+        assert_eq!(terminate(L2Norm, &state), TerminationStatus::NotTerminated);
+        assert_eq!(terminate(LInfNorm, &state), converged);
+        let weighted = WeightedL2Norm::new(vec![1.0, 0.0]);
+        assert_eq!(terminate(weighted, &state), converged);
+    }
+
     #[test]
     fn test_init() {
         let linesearch = MoreThuenteLineSearch::new().with_c(1e-4, 0.9).unwrap();
@@ -6,11 +6,11 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{
-    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState,
-    LineSearch, OptimizationResult, Problem, SerializeAlias, Solver, TerminationReason,
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState, L2Norm,
+    LineSearch, Norm, OptimizationResult, Problem, SerializeAlias, Solver, TerminationReason,
     TerminationStatus, KV,
 };
-use argmin_math::{ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminSub};
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminMul, ArgminRankOneUpdate, ArgminSub};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -28,13 +28,15 @@ use serde::{Deserialize, Serialize};
 /// Springer. ISBN 0-387-30303-0.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct SR1<L, F> {
+pub struct SR1<L, F, N = L2Norm> {
     /// parameter for skipping rule
     denominator_factor: F,
     /// line search
     linesearch: L,
     /// Tolerance for the stopping criterion based on the change of the norm on the gradient
     tol_grad: F,
+    /// Norm used in the stopping criterion based on the gradient
+    grad_norm: N,
     /// Tolerance for the stopping criterion based on the change of the cost stopping criterion
     tol_cost: F,
 }
@@ -57,10 +59,16 @@ where
             denominator_factor: float!(1e-8),
             linesearch,
             tol_grad: F::epsilon().sqrt(),
+            grad_norm: L2Norm,
             tol_cost: F::epsilon(),
         }
     }
+}
 
+impl<L, F, N> SR1<L, F, N>
+where
+    F: ArgminFloat,
+{
     /// Set denominator factor
     ///
     /// If the denominator of the update is below the `denominator_factor` (scaled with other
@@ -118,6 +126,31 @@ where
         Ok(self)
     }
 
+    /// Sets the norm used in the stopping criterion based on the gradient.
+    ///
+    /// Any [`Norm`] which is implemented for the gradient can be used, for instance
+    /// [`LInfNorm`](`crate::core::LInfNorm`) or [`WeightedL2Norm`](`crate::core::WeightedL2Norm`).
+    /// Defaults to [`L2Norm`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::SR1;
+    /// # use argmin::core::LInfNorm;
+    /// # let linesearch = ();
+    /// let sr1: SR1<_, f64, LInfNorm> = SR1::new(linesearch).with_gradient_norm(LInfNorm);
+    /// ```
+    #[must_use]
+    pub fn with_gradient_norm<N2>(self, norm: N2) -> SR1<L, F, N2> {
+        SR1 {
+            denominator_factor: self.denominator_factor,
+            linesearch: self.linesearch,
+            tol_grad: self.tol_grad,
+            grad_norm: norm,
+            tol_cost: self.tol_cost,
+        }
+    }
+
     /// Sets tolerance for the stopping criterion based on the change of the cost stopping criterion
     ///
     /// The provided value must be non-negative. Defaults to `EPSILON`.
@@ -145,7 +178,7 @@ where
     }
 }
 
-impl<O, L, P, G, H, F> Solver<O, IterState<P, G, (), H, F>> for SR1<L, F>
+impl<O, L, P, G, H, F, N> Solver<O, IterState<P, G, (), H, F>> for SR1<L, F, N>
 where
     O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
     P: Clone
@@ -154,22 +187,16 @@ where
         + ArgminSub<P, P>
         + ArgminDot<G, F>
         + ArgminDot<P, F>
-        + ArgminDot<P, H>
         + ArgminL2Norm<F>
         + ArgminMul<F, P>,
-    G: Clone
-        + SerializeAlias
-        + DeserializeOwnedAlias
-        + ArgminSub<P, P>
-        + ArgminL2Norm<F>
-        + ArgminSub<G, G>,
+    G: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminSub<P, P> + ArgminSub<G, G>,
     H: SerializeAlias
         + DeserializeOwnedAlias
         + ArgminDot<G, P>
         + ArgminDot<P, P>
-        + ArgminAdd<H, H>
-        + ArgminMul<F, H>,
+        + ArgminRankOneUpdate<P, F>,
     L: Clone + LineSearch<P, F> + Solver<O, IterState<P, G, (), (), F>>,
+    N: Norm<G, F>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "SR1";
@@ -271,14 +298,13 @@ where
         // let a: H = skmhkyk.dot(&skmhkyk);
         // let b: F = skmhkyk.dot(&yk);
         let ykmbksk: P = yk.sub(&inv_hessian.dot(&sk));
-        let a: H = ykmbksk.dot(&ykmbksk);
         let b: F = ykmbksk.dot(&sk);
 
         // let hessian_update = b.abs() >= self.r * yk.l2_norm() * skmhkyk.l2_norm();
         let hessian_update = b.abs() >= self.denominator_factor * sk.l2_norm() * ykmbksk.l2_norm();
 
         if hessian_update {
-            inv_hessian = inv_hessian.add(&a.mul(&(float!(1.0) / b)));
+            inv_hessian.rank_one_update(&(float!(1.0) / b), &ykmbksk, &ykmbksk);
         }
 
         Ok((
@@ -292,7 +318,7 @@ where
     }
 
     fn terminate(&mut self, state: &IterState<P, G, (), H, F>) -> TerminationStatus {
-        if state.get_gradient().unwrap().l2_norm() < self.tol_grad {
+        if self.grad_norm.norm(state.get_gradient().unwrap()) < self.tol_grad {
             return TerminationStatus::Terminated(TerminationReason::SolverConverged);
         }
         if (state.get_prev_cost() - state.cost).abs() < self.tol_cost {
@@ -324,11 +350,13 @@ mod tests {
             denominator_factor,
             linesearch,
             tol_grad,
+            grad_norm,
             tol_cost,
         } = sr1;
 
         assert_eq!(linesearch, MyFakeLineSearch {});
         assert_eq!(tol_grad.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(grad_norm, L2Norm);
         assert_eq!(tol_cost.to_ne_bytes(), f64::EPSILON.to_ne_bytes());
         assert_eq!(denominator_factor.to_ne_bytes(), 1e-8f64.to_ne_bytes());
     }
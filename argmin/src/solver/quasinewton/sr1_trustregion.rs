@@ -7,11 +7,12 @@
 
 use crate::core::{
     ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, Hessian,
-    IterState, OptimizationResult, Problem, SerializeAlias, Solver, TerminationReason,
-    TerminationStatus, TrustRegionRadius, KV,
+    IterState, L2Norm, Norm, OptimizationResult, Problem, SerializeAlias, Solver,
+    TerminationReason, TerminationStatus, TrustRegionRadius, KV,
 };
 use argmin_math::{
-    ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminSub, ArgminWeightedDot, ArgminZeroLike,
+    ArgminAdd, ArgminDot, ArgminL2Norm, ArgminRankOneUpdate, ArgminSub, ArgminWeightedDot,
+    ArgminZeroLike,
 };
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
@@ -34,7 +35,7 @@ use serde::{Deserialize, Serialize};
 /// Springer. ISBN 0-387-30303-0.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct SR1TrustRegion<R, F> {
+pub struct SR1TrustRegion<R, F, N = L2Norm> {
     /// parameter for skipping rule
     denominator_factor: F,
     /// subproblem
@@ -45,6 +46,8 @@ pub struct SR1TrustRegion<R, F> {
     eta: F,
     /// Tolerance for the stopping criterion based on the change of the norm on the gradient
     tol_grad: F,
+    /// Norm used in the stopping criterion based on the gradient
+    grad_norm: N,
 }
 
 impl<R, F> SR1TrustRegion<R, F>
@@ -72,9 +75,15 @@ where
             radius: float!(1.0),
             eta: float!(0.5 * 1e-3),
             tol_grad: float!(1e-3),
+            grad_norm: L2Norm,
         }
     }
+}
 
+impl<R, F, N> SR1TrustRegion<R, F, N>
+where
+    F: ArgminFloat,
+{
     /// Set denominator factor
     ///
     /// If the denominator of the update is below the `denominator_factor` (scaled with other
@@ -178,9 +187,35 @@ where
         self.tol_grad = tol_grad;
         Ok(self)
     }
+
+    /// Sets the norm used in the stopping criterion based on the gradient.
+    ///
+    /// Any [`Norm`] which is implemented for the gradient can be used, for instance
+    /// [`LInfNorm`](`crate::core::LInfNorm`) or [`WeightedL2Norm`](`crate::core::WeightedL2Norm`).
+    /// Defaults to [`L2Norm`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::SR1TrustRegion;
+    /// # use argmin::core::LInfNorm;
+    /// # let subproblem = ();
+    /// let sr1: SR1TrustRegion<_, f64, LInfNorm> = SR1TrustRegion::new(subproblem).with_gradient_norm(LInfNorm);
+    /// ```
+    #[must_use]
+    pub fn with_gradient_norm<N2>(self, norm: N2) -> SR1TrustRegion<R, F, N2> {
+        SR1TrustRegion {
+            denominator_factor: self.denominator_factor,
+            subproblem: self.subproblem,
+            radius: self.radius,
+            eta: self.eta,
+            tol_grad: self.tol_grad,
+            grad_norm: norm,
+        }
+    }
 }
 
-impl<O, R, P, G, B, F> Solver<O, IterState<P, G, (), B, F>> for SR1TrustRegion<R, F>
+impl<O, R, P, G, B, F, N> Solver<O, IterState<P, G, (), B, F>> for SR1TrustRegion<R, F, N>
 where
     O: CostFunction<Param = P, Output = F>
         + Gradient<Param = P, Gradient = G>
@@ -191,22 +226,12 @@ where
         + ArgminSub<P, P>
         + ArgminAdd<P, P>
         + ArgminDot<P, F>
-        + ArgminDot<P, B>
         + ArgminL2Norm<F>
         + ArgminZeroLike,
-    G: Clone
-        + SerializeAlias
-        + DeserializeOwnedAlias
-        + ArgminL2Norm<F>
-        + ArgminDot<P, F>
-        + ArgminSub<G, P>,
-    B: Clone
-        + SerializeAlias
-        + DeserializeOwnedAlias
-        + ArgminDot<P, P>
-        + ArgminAdd<B, B>
-        + ArgminMul<F, B>,
+    G: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminDot<P, F> + ArgminSub<G, P>,
+    B: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminDot<P, P> + ArgminRankOneUpdate<P, F>,
     R: Clone + TrustRegionRadius<F> + Solver<O, IterState<P, G, (), B, F>>,
+    N: Norm<G, F>,
     F: ArgminFloat + ArgminL2Norm<F>,
 {
     const NAME: &'static str = "SR1 trust region";
@@ -333,13 +358,11 @@ where
 
         let hessian_update =
             skykbksk.abs() >= self.denominator_factor * sk.l2_norm() * skykbksk.l2_norm();
-        let hessian = if hessian_update {
-            let a: B = ykbksk.dot(&ykbksk);
+        let mut hessian = hessian;
+        if hessian_update {
             let b: F = sk.dot(&ykbksk);
-            hessian.add(&a.mul(&(float!(1.0) / b)))
-        } else {
-            hessian
-        };
+            hessian.rank_one_update(&(float!(1.0) / b), &ykbksk, &ykbksk);
+        }
 
         Ok((
             state.param(xk1).cost(fk1).gradient(dfk1).hessian(hessian),
@@ -352,7 +375,7 @@ where
     }
 
     fn terminate(&mut self, state: &IterState<P, G, (), B, F>) -> TerminationStatus {
-        if state.get_gradient().unwrap().l2_norm() < self.tol_grad {
+        if self.grad_norm.norm(state.get_gradient().unwrap()) < self.tol_grad {
             return TerminationStatus::Terminated(TerminationReason::SolverConverged);
         }
         TerminationStatus::NotTerminated
@@ -380,6 +403,7 @@ mod tests {
             radius,
             eta,
             tol_grad,
+            grad_norm,
         } = sr1;
 
         assert_eq!(denominator_factor.to_ne_bytes(), 1e-8f64.to_ne_bytes());
@@ -387,6 +411,7 @@ mod tests {
         assert_eq!(radius.to_ne_bytes(), 1.0f64.to_ne_bytes());
         assert_eq!(eta.to_ne_bytes(), (0.5f64 * 1e-3f64).to_ne_bytes());
         assert_eq!(tol_grad.to_ne_bytes(), 1e-3f64.to_ne_bytes());
+        assert_eq!(grad_norm, L2Norm);
     }
 
     #[test]
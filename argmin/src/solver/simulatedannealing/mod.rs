@@ -18,6 +18,10 @@
 //! Science 13 May 1983, Vol. 220, Issue 4598, pp. 671-680
 //! DOI: 10.1126/science.220.4598.671
 
+mod schedule;
+
+pub use schedule::*;
+
 use crate::core::{
     ArgminFloat, CostFunction, Error, IterState, Problem, SerializeAlias, Solver,
     TerminationReason, TerminationStatus, KV,
@@ -97,9 +101,6 @@ pub enum SATempFunc<F> {
     Boltzmann,
     /// `t_i = t_init * x^i`
     Exponential(F),
-    // /// User-provided temperature function. The first parameter must be the current temperature and
-    // /// the second parameter must be the iteration number.
-    // Custom(Box<dyn Fn(f64, u64) -> f64 + 'static>),
 }
 
 /// # Simulated Annealing
@@ -119,8 +120,10 @@ pub enum SATempFunc<F> {
 /// vector (via [`configure`](`crate::core::Executor::configure`) of
 /// [`Executor`](`crate::core::Executor`).
 ///
-/// The cooling schedule can be set with [`SimulatedAnnealing::with_temp_func`]. For the available
-/// choices please see [`SATempFunc`].
+/// The cooling schedule can be set with [`SimulatedAnnealing::with_temp_func`], which accepts any
+/// implementation of [`TemperatureSchedule`]. Besides the variants of [`SATempFunc`], the
+/// schedules [`Logarithmic`], [`Linear`], [`LundyMees`] and [`AdaptiveAcceptance`] are available.
+/// User-defined closures can be used via [`TemperatureFn`], however, this prevents checkpointing.
 ///
 /// Reannealing can be performed if no new best solution was found for `N` iterations
 /// ([`SimulatedAnnealing::with_reannealing_best`]), or if no new accepted solution was found for
@@ -144,11 +147,11 @@ pub enum SATempFunc<F> {
 /// DOI: 10.1126/science.220.4598.671
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct SimulatedAnnealing<F, R> {
+pub struct SimulatedAnnealing<F, R, S = SATempFunc<F>> {
     /// Initial temperature
     init_temp: F,
-    /// Temperature function used for decreasing the temperature
-    temp_func: SATempFunc<F>,
+    /// Temperature schedule used for decreasing the temperature
+    temp_func: S,
     /// Number of iterations used for the calculation of temperature. Needed for reannealing
     temp_iter: u64,
     /// Number of iterations since the last accepted solution
@@ -250,27 +253,53 @@ where
             })
         }
     }
+}
 
+impl<F, R, S> SimulatedAnnealing<F, R, S>
+where
+    F: ArgminFloat,
+{
     /// Set temperature function
     ///
     /// The temperature function defines how the temperature is decreased over the course of the
-    /// iterations.
-    /// See [`SATempFunc`] for the available options. Defaults to [`SATempFunc::TemperatureFast`].
+    /// iterations. Any implementation of [`TemperatureSchedule`] can be used, see
+    /// [`SATempFunc`], [`Logarithmic`], [`Linear`], [`LundyMees`], [`AdaptiveAcceptance`] and
+    /// [`TemperatureFn`]. Defaults to [`SATempFunc::TemperatureFast`].
     ///
     /// # Example
     ///
     /// ```
-    /// # use argmin::solver::simulatedannealing::{SimulatedAnnealing, SATempFunc};
+    /// # use argmin::solver::simulatedannealing::{SimulatedAnnealing, SATempFunc, LundyMees};
     /// # use argmin::core::Error;
     /// # fn main() -> Result<(), Error> {
     /// let sa = SimulatedAnnealing::new(100.0f64)?.with_temp_func(SATempFunc::Boltzmann);
+    ///
+    /// let sa = SimulatedAnnealing::new(100.0f64)?.with_temp_func(LundyMees::new(0.01));
     /// # Ok(())
     /// # }
     /// ```
     #[must_use]
-    pub fn with_temp_func(mut self, temperature_func: SATempFunc<F>) -> Self {
-        self.temp_func = temperature_func;
-        self
+    pub fn with_temp_func<S2>(self, temperature_func: S2) -> SimulatedAnnealing<F, R, S2>
+    where
+        S2: TemperatureSchedule<F>,
+    {
+        SimulatedAnnealing {
+            init_temp: self.init_temp,
+            temp_func: temperature_func,
+            temp_iter: self.temp_iter,
+            stall_iter_accepted: self.stall_iter_accepted,
+            stall_iter_accepted_limit: self.stall_iter_accepted_limit,
+            stall_iter_best: self.stall_iter_best,
+            stall_iter_best_limit: self.stall_iter_best_limit,
+            reanneal_fixed: self.reanneal_fixed,
+            reanneal_iter_fixed: self.reanneal_iter_fixed,
+            reanneal_accepted: self.reanneal_accepted,
+            reanneal_iter_accepted: self.reanneal_iter_accepted,
+            reanneal_best: self.reanneal_best,
+            reanneal_iter_best: self.reanneal_iter_best,
+            cur_temp: self.cur_temp,
+            rng: self.rng,
+        }
     }
 
     /// If there are no accepted solutions for `iter` iterations, the algorithm stops.
@@ -386,21 +415,21 @@ where
 
     /// Update the temperature based on the current iteration number.
     ///
-    /// Updates are performed based on specific update functions. See `SATempFunc` for details.
-    fn update_temperature(&mut self) {
-        self.cur_temp = match self.temp_func {
-            SATempFunc::TemperatureFast => {
-                self.init_temp / F::from_u64(self.temp_iter + 1).unwrap()
-            }
-            SATempFunc::Boltzmann => self.init_temp / F::from_u64(self.temp_iter + 1).unwrap().ln(),
-            SATempFunc::Exponential(x) => {
-                self.init_temp * x.powf(F::from_u64(self.temp_iter + 1).unwrap())
-            }
-        };
+    /// Updates are performed by the temperature schedule. See [`TemperatureSchedule`] for details.
+    fn update_temperature(&mut self, accepted: bool)
+    where
+        S: TemperatureSchedule<F>,
+    {
+        self.cur_temp =
+            self.temp_func
+                .temperature(self.init_temp, self.cur_temp, self.temp_iter, accepted);
     }
 
     /// Perform reannealing
-    fn reanneal(&mut self) -> (bool, bool, bool) {
+    fn reanneal(&mut self) -> (bool, bool, bool)
+    where
+        S: TemperatureSchedule<F>,
+    {
         let out = (
             self.reanneal_iter_fixed >= self.reanneal_fixed,
             self.reanneal_iter_accepted >= self.reanneal_accepted,
@@ -412,6 +441,7 @@ where
             self.reanneal_iter_best = 0;
             self.cur_temp = self.init_temp;
             self.temp_iter = 0;
+            self.temp_func.reset();
         }
         out
     }
@@ -435,12 +465,13 @@ where
     }
 }
 
-impl<O, P, F, R> Solver<O, IterState<P, (), (), (), F>> for SimulatedAnnealing<F, R>
+impl<O, P, F, R, S> Solver<O, IterState<P, (), (), (), F>> for SimulatedAnnealing<F, R, S>
 where
     O: CostFunction<Param = P, Output = F> + Anneal<Param = P, Output = P, Float = F>,
     P: Clone,
     F: ArgminFloat,
     R: Rng + SerializeAlias,
+    S: TemperatureSchedule<F>,
 {
     const NAME: &'static str = "Simulated Annealing";
     fn init(
@@ -528,7 +559,7 @@ where
         // better readability.
         self.reanneal_iter_fixed += 1;
 
-        self.update_temperature();
+        self.update_temperature(accepted);
 
         Ok((
             if accepted {
@@ -689,6 +720,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_with_temp_func_schedule() {
+        let sa = SimulatedAnnealing::new(100.0f64)
+            .unwrap()
+            .with_stall_best(10)
+            .with_temp_func(LundyMees::new(0.5));
+
+        assert_eq!(sa.temp_func, LundyMees::new(0.5));
+        assert_eq!(sa.stall_iter_best_limit, 10);
+    }
+
+    #[test]
+    fn test_update_temperature_schedule() {
+        let mut sa = SimulatedAnnealing::new(100.0f64)
+            .unwrap()
+            .with_temp_func(LundyMees::new(0.01));
+
+        sa.update_temperature(true);
+        assert_relative_eq!(sa.cur_temp, 50.0, epsilon = f64::EPSILON);
+        sa.update_temperature(false);
+        assert_relative_eq!(sa.cur_temp, 50.0 / 1.5, epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_reanneal_resets_schedule() {
+        let mut sa = SimulatedAnnealing::new(100.0f64)
+            .unwrap()
+            .with_temp_func(AdaptiveAcceptance::new().with_target(0.5))
+            .with_reannealing_fixed(0);
+
+        sa.update_temperature(true);
+        assert!(sa.temp_func.acceptance_rate() > 0.5);
+
+        assert_eq!(sa.reanneal(), (true, false, false));
+        assert_relative_eq!(sa.temp_func.acceptance_rate(), 0.5, epsilon = f64::EPSILON);
+        assert_eq!(sa.cur_temp.to_ne_bytes(), 100.0f64.to_ne_bytes());
+    }
+
     #[test]
     fn test_with_stall_accepted() {
         for iter in [0, 1, 5, 10, 100, 100000] {
@@ -751,7 +820,7 @@ mod tests {
                 .with_temp_func(func);
             sa.temp_iter = 1;
 
-            sa.update_temperature();
+            sa.update_temperature(true);
 
             assert_relative_eq!(sa.cur_temp, val, epsilon = f64::EPSILON);
         }
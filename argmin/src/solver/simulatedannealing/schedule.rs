@@ -0,0 +1,364 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Temperature schedules for Simulated Annealing.
+//!
+//! ## References
+//!
+//! M Lundy, A Mees. (1986). "Convergence of an annealing algorithm".
+//! Mathematical Programming 34, pp. 111-124
+//! DOI: 10.1007/BF01582166
+
+use super::SATempFunc;
+use crate::core::ArgminFloat;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Defines how the temperature of [`SimulatedAnnealing`](`super::SimulatedAnnealing`) evolves
+/// over the course of the iterations.
+///
+/// The schedule is queried once per iteration, after the acceptance decision of the iteration has
+/// been made. When reannealing is performed, the temperature is reset to the initial temperature,
+/// the iteration counter is reset to zero and [`TemperatureSchedule::reset`] is called.
+///
+/// Implementations which implement `serde::Serialize` (and `serde::Deserialize`) allow the solver
+/// to be checkpointed.
+pub trait TemperatureSchedule<F> {
+    /// Compute the temperature of the next iteration.
+    ///
+    /// `init_temp` is the initial temperature, `cur_temp` the temperature of the current
+    /// iteration, `iter` the number of iterations since the start or the last reannealing (at
+    /// least 1) and `accepted` indicates whether the candidate of the current iteration was
+    /// accepted.
+    fn temperature(&mut self, init_temp: F, cur_temp: F, iter: u64, accepted: bool) -> F;
+
+    /// Reset the internal state of the schedule. Called when reannealing is performed.
+    fn reset(&mut self) {}
+}
+
+impl<F: ArgminFloat> TemperatureSchedule<F> for SATempFunc<F> {
+    fn temperature(&mut self, init_temp: F, _cur_temp: F, iter: u64, _accepted: bool) -> F {
+        let i = F::from_u64(iter + 1).unwrap();
+        match *self {
+            SATempFunc::TemperatureFast => init_temp / i,
+            SATempFunc::Boltzmann => init_temp / i.ln(),
+            SATempFunc::Exponential(x) => init_temp * x.powf(i),
+        }
+    }
+}
+
+/// Logarithmic cooling schedule
+///
+/// `t_i = t_init / (1 + alpha * ln(1 + i))`
+///
+/// The temperature decreases very slowly, which is the classical schedule for which convergence
+/// to the global optimum can be shown. `alpha` must be > 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Logarithmic<F> {
+    /// Cooling rate
+    pub alpha: F,
+}
+
+impl<F> Logarithmic<F> {
+    /// Construct a new instance of [`Logarithmic`]
+    pub fn new(alpha: F) -> Self {
+        Logarithmic { alpha }
+    }
+}
+
+impl<F: ArgminFloat> TemperatureSchedule<F> for Logarithmic<F> {
+    fn temperature(&mut self, init_temp: F, _cur_temp: F, iter: u64, _accepted: bool) -> F {
+        init_temp / (float!(1.0) + self.alpha * F::from_u64(iter).unwrap().ln_1p())
+    }
+}
+
+/// Linear cooling schedule
+///
+/// `t_i = max(t_init - rate * i, min_temp)`
+///
+/// The temperature decreases by a constant amount in each iteration until it reaches `min_temp`,
+/// which must be > 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Linear<F> {
+    /// Decrease of the temperature per iteration
+    pub rate: F,
+    /// Lower bound on the temperature
+    pub min_temp: F,
+}
+
+impl<F> Linear<F> {
+    /// Construct a new instance of [`Linear`]
+    pub fn new(rate: F, min_temp: F) -> Self {
+        Linear { rate, min_temp }
+    }
+}
+
+impl<F: ArgminFloat> TemperatureSchedule<F> for Linear<F> {
+    fn temperature(&mut self, init_temp: F, _cur_temp: F, iter: u64, _accepted: bool) -> F {
+        (init_temp - self.rate * F::from_u64(iter).unwrap()).max(self.min_temp)
+    }
+}
+
+/// Lundy–Mees cooling schedule
+///
+/// `t_{i+1} = t_i / (1 + beta * t_i)`
+///
+/// Fast at high temperatures and increasingly slow at low temperatures. `beta` must be > 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct LundyMees<F> {
+    /// Cooling parameter
+    pub beta: F,
+}
+
+impl<F> LundyMees<F> {
+    /// Construct a new instance of [`LundyMees`]
+    pub fn new(beta: F) -> Self {
+        LundyMees { beta }
+    }
+}
+
+impl<F: ArgminFloat> TemperatureSchedule<F> for LundyMees<F> {
+    fn temperature(&mut self, _init_temp: F, cur_temp: F, _iter: u64, _accepted: bool) -> F {
+        cur_temp / (float!(1.0) + self.beta * cur_temp)
+    }
+}
+
+/// Acceptance-rate-adaptive schedule
+///
+/// Keeps an exponential moving average of the acceptance rate,
+///
+/// `r_{i+1} = (1 - smoothing) * r_i + smoothing * accepted`,
+///
+/// and cools (`t_{i+1} = factor * t_i`) while the rate is above `target`, otherwise heats
+/// (`t_{i+1} = t_i / factor`). The temperature therefore settles at a level where roughly a
+/// fraction of `target` of all candidates is accepted.
+///
+/// Defaults: `target = 0.44`, `factor = 0.95`, `smoothing = 0.05`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct AdaptiveAcceptance<F> {
+    /// Desired acceptance rate
+    target: F,
+    /// Factor by which the temperature is multiplied (divided) when cooling (heating)
+    factor: F,
+    /// Weight of the latest acceptance decision in the moving average
+    smoothing: F,
+    /// Current estimate of the acceptance rate
+    rate: F,
+}
+
+impl<F: ArgminFloat> AdaptiveAcceptance<F> {
+    /// Construct a new instance of [`AdaptiveAcceptance`]
+    pub fn new() -> Self {
+        AdaptiveAcceptance {
+            target: float!(0.44),
+            factor: float!(0.95),
+            smoothing: float!(0.05),
+            rate: float!(0.44),
+        }
+    }
+
+    /// Set the desired acceptance rate. Must be in (0, 1). Defaults to 0.44.
+    #[must_use]
+    pub fn with_target(mut self, target: F) -> Self {
+        self.target = target;
+        self.rate = target;
+        self
+    }
+
+    /// Set the cooling factor. Must be in (0, 1). Defaults to 0.95.
+    #[must_use]
+    pub fn with_factor(mut self, factor: F) -> Self {
+        self.factor = factor;
+        self
+    }
+
+    /// Set the weight of the latest acceptance decision in the moving average of the acceptance
+    /// rate. Must be in (0, 1]. Defaults to 0.05.
+    #[must_use]
+    pub fn with_smoothing(mut self, smoothing: F) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Returns the current estimate of the acceptance rate
+    pub fn acceptance_rate(&self) -> F {
+        self.rate
+    }
+}
+
+impl<F: ArgminFloat> Default for AdaptiveAcceptance<F> {
+    fn default() -> Self {
+        AdaptiveAcceptance::new()
+    }
+}
+
+impl<F: ArgminFloat> TemperatureSchedule<F> for AdaptiveAcceptance<F> {
+    fn temperature(&mut self, _init_temp: F, cur_temp: F, _iter: u64, accepted: bool) -> F {
+        let accepted = if accepted { float!(1.0) } else { float!(0.0) };
+        self.rate = (float!(1.0) - self.smoothing) * self.rate + self.smoothing * accepted;
+        if self.rate > self.target {
+            cur_temp * self.factor
+        } else {
+            cur_temp / self.factor
+        }
+    }
+
+    fn reset(&mut self) {
+        self.rate = self.target;
+    }
+}
+
+/// User-provided temperature schedule
+///
+/// Wraps a closure which is called with the initial temperature, the current temperature and the
+/// iteration number and returns the temperature of the next iteration.
+///
+/// Since closures cannot be serialized, a solver using this schedule cannot be checkpointed.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::simulatedannealing::{SimulatedAnnealing, TemperatureFn};
+/// # use argmin::core::Error;
+/// # fn main() -> Result<(), Error> {
+/// let sa = SimulatedAnnealing::new(100.0f64)?
+///     .with_temp_func(TemperatureFn::new(|t_init: f64, _t, i| t_init / (i as f64).sqrt()));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct TemperatureFn<G> {
+    func: G,
+}
+
+impl<G> TemperatureFn<G> {
+    /// Construct a new instance of [`TemperatureFn`]
+    pub fn new<F>(func: G) -> Self
+    where
+        G: FnMut(F, F, u64) -> F,
+    {
+        TemperatureFn { func }
+    }
+}
+
+impl<G> std::fmt::Debug for TemperatureFn<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TemperatureFn").finish_non_exhaustive()
+    }
+}
+
+impl<F, G> TemperatureSchedule<F> for TemperatureFn<G>
+where
+    G: FnMut(F, F, u64) -> F,
+{
+    fn temperature(&mut self, init_temp: F, cur_temp: F, iter: u64, _accepted: bool) -> F {
+        (self.func)(init_temp, cur_temp, iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_satempfunc() {
+        for (mut func, val) in [
+            (SATempFunc::TemperatureFast, 100.0f64 / 2.0),
+            (SATempFunc::Boltzmann, 100.0f64 / 2.0f64.ln()),
+            (SATempFunc::Exponential(3.0), 100.0 * 3.0f64.powi(2)),
+        ] {
+            let t = func.temperature(100.0, 50.0, 1, true);
+            assert_relative_eq!(t, val, epsilon = f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_logarithmic() {
+        let mut sched = Logarithmic::new(2.0f64);
+        assert_relative_eq!(
+            sched.temperature(100.0, 1.0, 0, true),
+            100.0,
+            epsilon = f64::EPSILON
+        );
+        assert_relative_eq!(
+            sched.temperature(100.0, 1.0, 3, true),
+            100.0 / (1.0 + 2.0 * 4.0f64.ln()),
+            epsilon = f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_linear() {
+        let mut sched = Linear::new(10.0f64, 1.0);
+        for (iter, val) in [(1, 90.0), (5, 50.0), (9, 10.0), (10, 1.0), (100, 1.0)] {
+            assert_relative_eq!(
+                sched.temperature(100.0, 1.0, iter, false),
+                val,
+                epsilon = f64::EPSILON
+            );
+        }
+    }
+
+    #[test]
+    fn test_lundy_mees() {
+        let mut sched = LundyMees::new(0.01f64);
+        let mut t = 100.0;
+        for _ in 0..3 {
+            let t_new = sched.temperature(100.0, t, 1, true);
+            assert_relative_eq!(t_new, t / (1.0 + 0.01 * t), epsilon = f64::EPSILON);
+            assert!(t_new < t);
+            t = t_new;
+        }
+    }
+
+    #[test]
+    fn test_adaptive_acceptance() {
+        let mut sched = AdaptiveAcceptance::new()
+            .with_target(0.5f64)
+            .with_factor(0.5)
+            .with_smoothing(0.5);
+
+        // rate: 0.5 -> 0.75, cool down
+        assert_relative_eq!(
+            sched.temperature(100.0, 10.0, 1, true),
+            5.0,
+            epsilon = f64::EPSILON
+        );
+        assert_relative_eq!(sched.acceptance_rate(), 0.75, epsilon = f64::EPSILON);
+
+        // rate: 0.75 -> 0.375, heat up
+        assert_relative_eq!(
+            sched.temperature(100.0, 5.0, 2, false),
+            10.0,
+            epsilon = f64::EPSILON
+        );
+        assert_relative_eq!(sched.acceptance_rate(), 0.375, epsilon = f64::EPSILON);
+
+        sched.reset();
+        assert_relative_eq!(sched.acceptance_rate(), 0.5, epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_temperature_fn() {
+        let mut calls = 0;
+        let mut sched = TemperatureFn::new(|t_init: f64, t_cur: f64, i: u64| {
+            calls += 1;
+            t_init + t_cur + i as f64
+        });
+        assert_relative_eq!(
+            sched.temperature(100.0, 10.0, 3, true),
+            113.0,
+            epsilon = f64::EPSILON
+        );
+        assert_eq!(calls, 1);
+    }
+}
@@ -0,0 +1,1060 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Sequential quadratic programming
+//!
+//! Solves smooth equality and inequality constrained problems by a sequence of quadratic
+//! subproblems.
+//!
+//! For details see [`SQP`].
+//!
+//! ## References
+//!
+//! \[0\] Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+//! Springer. ISBN 0-387-30303-0.
+//!
+//! \[1\] Goldfarb, D., Idnani, A. (1983): A numerically stable dual method for solving strictly
+//! convex quadratic programs. Mathematical Programming 27, 1-33.
+//! <https://doi.org/10.1007/BF02591962>
+
+use crate::core::{
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, EqualityConstraint,
+    EqualityConstraintJacobian, Error, Executor, Gradient, InequalityConstraint,
+    InequalityConstraintJacobian, IterState, LineSearch, OptimizationResult, Problem,
+    SerializeAlias, Solver, State, TerminationReason, KV,
+};
+use crate::solver::dense::{
+    dot, identity, mat_vec, norm, param_from_coords, solve_qp, to_coords, Cholesky, QpSolution,
+};
+use argmin_math::{ArgminBasis, ArgminDot, ArgminScaledAdd, ArgminZeroLike};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Sequential quadratic programming
+///
+/// Minimizes `f(x)` subject to equality constraints `h(x) = 0` and inequality constraints
+/// `g(x) >= 0`. In each iteration, the quadratic subproblem
+///
+/// `min_d grad f(x)^T d + 1/2 d^T B d`
+///
+/// subject to the linearized constraints `h(x) + grad h(x)^T d = 0` and
+/// `g(x) + grad g(x)^T d >= 0` is solved by the dual active set method of Goldfarb and Idnani
+/// \[1\], which also yields estimates of the Lagrange multipliers. `B` is an approximation of
+/// the Hessian of the Lagrangian `f(x) - lambda^T h(x) - mu^T g(x)`, which is updated by the
+/// damped BFGS formula of Powell and therefore remains positive definite. If the linearized
+/// constraints are inconsistent, the subproblem is solved in elastic mode, where violations of
+/// the linearized constraints are penalized instead.
+///
+/// The step length along `d` is determined by a line search on the l1 merit function
+///
+/// `phi(x) = f(x) + nu * (sum_i |h_i(x)| + sum_j max(0, -g_j(x)))`,
+///
+/// where the penalty parameter `nu` is increased whenever it is not sufficiently larger than the
+/// magnitude of the multipliers. The line search is provided with the gradient of `phi` wherever
+/// it is differentiable.
+/// [`BacktrackingLineSearch`](`crate::solver::linesearch::BacktrackingLineSearch`) with the
+/// Armijo condition is a suitable choice.
+///
+/// In each iteration, the KKT residuals at the new parameter vector are reported: `stationarity`
+/// (norm of the gradient of the Lagrangian), `feasibility` (greatest constraint violation) and
+/// `complementarity` (`max_j |min(g_j(x), mu_j)|`). The algorithm terminates once stationarity
+/// is below the optimality tolerance and feasibility and complementarity are below the
+/// feasibility tolerance (see [`with_tolerances`](`SQP::with_tolerances`)). The multipliers of
+/// the equality constraints followed by those of the inequality constraints are stored in the
+/// state (see [`IterState::get_lagrange_multipliers`]).
+///
+/// The cost stored in the state is the value of the l1 merit function `phi` if the greatest
+/// constraint violation is below the feasibility tolerance and infinity otherwise. Therefore the
+/// best parameter vector of the state is the feasible parameter vector with the lowest merit, or
+/// the current parameter vector if no feasible one was found yet. The value of `f` is reported as
+/// `objective`.
+///
+/// Requires an initial parameter vector. The quadratic subproblems are solved in dense form,
+/// which makes the method suitable for problems with a moderate number of parameters.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`], [`Gradient`],
+/// [`EqualityConstraint`], [`InequalityConstraint`], [`EqualityConstraintJacobian`] and
+/// [`InequalityConstraintJacobian`]. Constraint values are returned as `Vec<F>` and Jacobians as
+/// `Vec<P>`, holding the gradient of each constraint. For problems without equality or inequality
+/// constraints, the corresponding traits can be implemented without any methods, which yields empty
+/// vectors.
+///
+/// ## References
+///
+/// \[0\] Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0.
+///
+/// \[1\] Goldfarb, D., Idnani, A. (1983): A numerically stable dual method for solving strictly
+/// convex quadratic programs. Mathematical Programming 27, 1-33.
+/// <https://doi.org/10.1007/BF02591962>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct SQP<L, P, F> {
+    /// Line search
+    linesearch: L,
+    /// Tolerance on stationarity
+    optimality_tol: F,
+    /// Tolerance on feasibility and complementarity
+    feasibility_tol: F,
+    /// Penalty parameter of the merit function
+    penalty: F,
+    /// Standard basis of the parameter space
+    basis: Vec<P>,
+    /// Approximation of the Hessian of the Lagrangian in coordinates
+    hessian: Vec<Vec<F>>,
+    /// Evaluation of the problem at the current parameter vector
+    current: Option<Evaluation<F>>,
+}
+
+/// Values and derivatives of the cost function and the constraints at a parameter vector, in
+/// coordinates
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+struct Evaluation<F> {
+    /// Cost function value
+    cost: F,
+    /// Gradient of the cost function
+    gradient: Vec<F>,
+    /// Values of the equality constraints
+    equality: Vec<F>,
+    /// Gradients of the equality constraints
+    equality_jacobian: Vec<Vec<F>>,
+    /// Values of the inequality constraints
+    inequality: Vec<F>,
+    /// Gradients of the inequality constraints
+    inequality_jacobian: Vec<Vec<F>>,
+}
+
+impl<F: ArgminFloat> Evaluation<F> {
+    /// Gradient of the Lagrangian for the given multipliers of the equality constraints
+    /// followed by those of the inequality constraints
+    fn lagrangian_gradient(&self, multipliers: &[F]) -> Vec<F> {
+        self.equality_jacobian
+            .iter()
+            .chain(&self.inequality_jacobian)
+            .zip(multipliers)
+            .fold(self.gradient.clone(), |mut acc, (grad, &multiplier)| {
+                for (a, &g) in acc.iter_mut().zip(grad) {
+                    *a = *a - multiplier * g;
+                }
+                acc
+            })
+    }
+
+    /// Greatest constraint violation
+    fn max_violation(&self) -> F {
+        self.equality
+            .iter()
+            .map(|h| h.abs())
+            .chain(self.inequality.iter().map(|&g| -g))
+            .fold(F::zero(), |acc, v| acc.max(v))
+    }
+
+    /// Sum of the constraint violations
+    fn violation(&self) -> F {
+        self.equality
+            .iter()
+            .map(|h| h.abs())
+            .chain(self.inequality.iter().map(|&g| (-g).max(F::zero())))
+            .fold(F::zero(), |acc, v| acc + v)
+    }
+
+    /// Gradient of the l1 merit function where it is differentiable
+    fn merit_gradient(&self, penalty: F) -> Vec<F> {
+        let equality = self
+            .equality
+            .iter()
+            .zip(&self.equality_jacobian)
+            .map(|(&h, grad)| (sign(h) * penalty, grad));
+        let inequality = self
+            .inequality
+            .iter()
+            .zip(&self.inequality_jacobian)
+            .filter(|(&g, _)| g < F::zero())
+            .map(|(_, grad)| (-penalty, grad));
+        equality
+            .chain(inequality)
+            .fold(self.gradient.clone(), |mut acc, (weight, grad)| {
+                for (a, &g) in acc.iter_mut().zip(grad) {
+                    *a = *a + weight * g;
+                }
+                acc
+            })
+    }
+}
+
+impl<L, P, F> SQP<L, P, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`SQP`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::sqp::SQP;
+    /// # let linesearch = ();
+    /// let sqp: SQP<_, Vec<f64>, f64> = SQP::new(linesearch);
+    /// ```
+    pub fn new(linesearch: L) -> Self {
+        SQP {
+            linesearch,
+            optimality_tol: float!(1e-6),
+            feasibility_tol: float!(1e-8),
+            penalty: float!(1.0),
+            basis: vec![],
+            hessian: vec![],
+            current: None,
+        }
+    }
+
+    /// Set the tolerances on stationarity and on feasibility and complementarity
+    ///
+    /// Both must be non-negative. Default to `1e-6` and `1e-8`, respectively.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::sqp::SQP;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let linesearch = ();
+    /// let sqp: SQP<_, Vec<f64>, f64> = SQP::new(linesearch).with_tolerances(1e-5, 1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerances(mut self, optimality: F, feasibility: F) -> Result<Self, Error> {
+        if optimality < F::zero() || feasibility < F::zero() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SQP`: tolerances must be >= 0."
+            ));
+        }
+        self.optimality_tol = optimality;
+        self.feasibility_tol = feasibility;
+        Ok(self)
+    }
+
+    /// Set the initial penalty parameter of the merit function
+    ///
+    /// Must be positive. Defaults to `1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::sqp::SQP;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let linesearch = ();
+    /// let sqp: SQP<_, Vec<f64>, f64> = SQP::new(linesearch).with_penalty(10.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_penalty(mut self, penalty: F) -> Result<Self, Error> {
+        if penalty <= F::zero() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SQP`: penalty parameter must be > 0."
+            ));
+        }
+        self.penalty = penalty;
+        Ok(self)
+    }
+}
+
+impl<L, P, F> SQP<L, P, F>
+where
+    P: Clone + ArgminDot<P, F> + ArgminScaledAdd<P, F, P> + ArgminZeroLike,
+    F: ArgminFloat,
+{
+    /// Evaluates the cost function, the constraints and their derivatives at `param`
+    fn evaluate<O>(&self, problem: &mut Problem<O>, param: &P) -> Result<Evaluation<F>, Error>
+    where
+        O: CostFunction<Param = P, Output = F>
+            + Gradient<Param = P, Gradient = P>
+            + EqualityConstraint<Param = P, Output = Vec<F>>
+            + InequalityConstraint<Param = P, Output = Vec<F>>
+            + EqualityConstraintJacobian<Param = P, Jacobian = Vec<P>>
+            + InequalityConstraintJacobian<Param = P, Jacobian = Vec<P>>,
+    {
+        Ok(Evaluation {
+            cost: problem.cost(param)?,
+            gradient: to_coords(&self.basis, &problem.gradient(param)?),
+            equality: problem.equality_constraint(param)?,
+            equality_jacobian: problem
+                .equality_constraint_jacobian(param)?
+                .iter()
+                .map(|grad| to_coords(&self.basis, grad))
+                .collect(),
+            inequality: problem.inequality_constraint(param)?,
+            inequality_jacobian: problem
+                .inequality_constraint_jacobian(param)?
+                .iter()
+                .map(|grad| to_coords(&self.basis, grad))
+                .collect(),
+        })
+    }
+}
+
+impl<L, P, F> SQP<L, P, F>
+where
+    F: ArgminFloat,
+{
+    /// Cost stored in the state for `eval`: the l1 merit function if `eval` is feasible up to the
+    /// feasibility tolerance and infinity otherwise, such that infeasible parameter vectors never
+    /// replace a feasible best parameter vector
+    fn state_cost(&self, eval: &Evaluation<F>) -> F {
+        if eval.max_violation() <= self.feasibility_tol {
+            eval.cost + self.penalty * eval.violation()
+        } else {
+            F::infinity()
+        }
+    }
+
+    /// Solves the quadratic subproblem at `eval`. Returns the step, the multipliers and whether
+    /// the subproblem had to be solved in elastic mode.
+    fn search_direction(&mut self, eval: &Evaluation<F>) -> (Vec<F>, Vec<F>, bool) {
+        let n = eval.gradient.len();
+        let num_eq = eval.equality.len();
+        let num_ineq = eval.inequality.len();
+        let hessian = match Cholesky::new(&self.hessian) {
+            Some(factorization) => factorization,
+            None => {
+                self.hessian = identity(n);
+                Cholesky::identity(n)
+            }
+        };
+
+        let constraints: Vec<(Vec<F>, F)> = eval
+            .equality_jacobian
+            .iter()
+            .zip(&eval.equality)
+            .chain(eval.inequality_jacobian.iter().zip(&eval.inequality))
+            .map(|(grad, &value)| (grad.clone(), -value))
+            .collect();
+        if let Ok(QpSolution { x, multipliers, .. }) =
+            solve_qp(&hessian, &eval.gradient, &constraints, num_eq)
+        {
+            return (x, multipliers, false);
+        }
+
+        // Elastic mode: the violations `v - w` of the linearized equality constraints and `t` of
+        // the linearized inequality constraints are penalized by `penalty * (v + w + t)` and,
+        // to keep the subproblem strictly convex, by `1/2 (v^2 + w^2 + t^2)`.
+        let num_slacks = 2 * num_eq + num_ineq;
+        let dim = n + num_slacks;
+        let mut hessian_elastic = identity(dim);
+        for (row, hessian_row) in hessian_elastic.iter_mut().zip(&self.hessian) {
+            row[..n].copy_from_slice(hessian_row);
+        }
+        // Block diagonal with the positive definite blocks `B` and `I`
+        let hessian_elastic =
+            Cholesky::new(&hessian_elastic).unwrap_or_else(|| Cholesky::identity(dim));
+        let mut gradient = eval.gradient.clone();
+        gradient.extend(vec![self.penalty; num_slacks]);
+        let slack = |idx: usize, value: F| {
+            let mut a = vec![F::zero(); num_slacks];
+            a[idx] = value;
+            a
+        };
+        let mut constraints: Vec<(Vec<F>, F)> = constraints
+            .into_iter()
+            .enumerate()
+            .map(|(i, (mut a, b))| {
+                if i < num_eq {
+                    let mut elastic = slack(i, -F::one());
+                    elastic[num_eq + i] = F::one();
+                    a.extend(elastic);
+                } else {
+                    a.extend(slack(num_eq + i, F::one()));
+                }
+                (a, b)
+            })
+            .collect();
+        constraints.extend((0..num_slacks).map(|i| {
+            let mut a = vec![F::zero(); n];
+            a.extend(slack(i, F::one()));
+            (a, F::zero())
+        }));
+        // The elastic subproblem is always feasible.
+        let (mut step, mut multipliers) =
+            solve_qp(&hessian_elastic, &gradient, &constraints, num_eq)
+                .map(|solution| (solution.x, solution.multipliers))
+                .unwrap_or_else(|_| (vec![F::zero(); dim], vec![F::zero(); constraints.len()]));
+        step.truncate(n);
+        multipliers.truncate(num_eq + num_ineq);
+        (step, multipliers, true)
+    }
+
+    /// Updates the approximation of the Hessian of the Lagrangian by the damped BFGS formula for
+    /// the step `s` and the change `y` of the gradient of the Lagrangian
+    fn update_hessian(&mut self, s: &[F], y: &[F]) {
+        let bs = mat_vec(&self.hessian, s);
+        let sbs = dot(s, &bs);
+        if sbs <= F::epsilon() * dot(s, s) {
+            return;
+        }
+        let sy = dot(s, y);
+        let theta = if sy >= float!(0.2) * sbs {
+            F::one()
+        } else {
+            float!(0.8) * sbs / (sbs - sy)
+        };
+        let r: Vec<F> = y
+            .iter()
+            .zip(&bs)
+            .map(|(&y, &bs)| theta * y + (F::one() - theta) * bs)
+            .collect();
+        let sr = dot(s, &r);
+        for (i, row) in self.hessian.iter_mut().enumerate() {
+            for (j, b) in row.iter_mut().enumerate() {
+                *b = *b - bs[i] * bs[j] / sbs + r[i] * r[j] / sr;
+            }
+        }
+    }
+}
+
+/// Counts of the evaluations of the optimization problem caused by one evaluation of the merit
+/// function and its gradient, respectively
+const EVALUATIONS: [(&str, [u64; 2]); 6] = [
+    ("cost_count", [1, 0]),
+    ("gradient_count", [0, 1]),
+    ("equality_constraint_count", [1, 1]),
+    ("inequality_constraint_count", [1, 1]),
+    ("equality_constraint_jacobian_count", [0, 1]),
+    ("inequality_constraint_jacobian_count", [0, 1]),
+];
+
+impl<O, L, P, F> Solver<O, IterState<P, P, (), (), F>> for SQP<L, P, F>
+where
+    O: CostFunction<Param = P, Output = F>
+        + Gradient<Param = P, Gradient = P>
+        + EqualityConstraint<Param = P, Output = Vec<F>>
+        + InequalityConstraint<Param = P, Output = Vec<F>>
+        + EqualityConstraintJacobian<Param = P, Jacobian = Vec<P>>
+        + InequalityConstraintJacobian<Param = P, Jacobian = Vec<P>>,
+    L: Clone + LineSearch<P, F> + Solver<MeritProblem<O, F>, IterState<P, P, (), (), F>>,
+    P: Clone
+        + SerializeAlias
+        + DeserializeOwnedAlias
+        + ArgminBasis
+        + ArgminDot<P, F>
+        + ArgminScaledAdd<P, F, P>
+        + ArgminZeroLike,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "SQP";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, P, (), (), F>,
+    ) -> Result<(IterState<P, P, (), (), F>, Option<KV>), Error> {
+        let param = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`SQP` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        self.basis = param.basis_like();
+        self.hessian = identity(self.basis.len());
+        let eval = self.evaluate(problem, param)?;
+        let cost = self.state_cost(&eval);
+        let gradient = param_from_coords(&self.basis, &eval.gradient);
+        let objective = eval.cost;
+        self.current = Some(eval);
+        Ok((
+            state.cost(cost).gradient(gradient),
+            Some(kv!("objective" => objective;)),
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, P, (), (), F>,
+    ) -> Result<(IterState<P, P, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`SQP` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let eval = match self.current.take() {
+            Some(eval) => eval,
+            None => self.evaluate(problem, &param)?,
+        };
+
+        let (step, multipliers, elastic) = self.search_direction(&eval);
+        let max_multiplier = multipliers
+            .iter()
+            .fold(F::zero(), |acc, m| acc.max(m.abs()));
+        self.penalty = self.penalty.max(float!(1.5) * max_multiplier);
+
+        // Line search on the merit function
+        let x = to_coords(&self.basis, &param);
+        let (new_param, step_length) = if norm(&step) <= F::epsilon() * (F::one() + norm(&x)) {
+            (param, F::zero())
+        } else {
+            self.linesearch
+                .search_direction(param_from_coords(&self.basis, &step));
+            self.linesearch.initial_step_length(F::one())?;
+            let merit = eval.cost + self.penalty * eval.violation();
+            let merit_gradient = param_from_coords(&self.basis, &eval.merit_gradient(self.penalty));
+            let OptimizationResult {
+                problem: mut line_problem,
+                state: mut linesearch_state,
+                ..
+            } = Executor::new(
+                MeritProblem {
+                    problem: problem.take_problem().ok_or_else(argmin_error_closure!(
+                        PotentialBug,
+                        "`SQP`: Failed to take `problem` for line search"
+                    ))?,
+                    penalty: self.penalty,
+                },
+                self.linesearch.clone(),
+            )
+            .configure(|config| config.param(param).gradient(merit_gradient).cost(merit))
+            .ctrlc(false)
+            .run()?;
+
+            problem.problem = Some(
+                line_problem
+                    .take_problem()
+                    .ok_or_else(argmin_error_closure!(
+                        PotentialBug,
+                        "`SQP`: Failed to take `problem` from line search"
+                    ))?
+                    .problem,
+            );
+            let calls = [
+                line_problem.counts.get("cost_count"),
+                line_problem.counts.get("gradient_count"),
+            ]
+            .map(|count| count.copied().unwrap_or(0));
+            for (name, evaluations) in EVALUATIONS {
+                let count: u64 = calls.iter().zip(evaluations).map(|(c, e)| c * e).sum();
+                if count > 0 {
+                    *problem.counts.entry(name).or_insert(0) += count;
+                }
+            }
+
+            let new_param = linesearch_state
+                .take_param()
+                .ok_or_else(argmin_error_closure!(
+                    PotentialBug,
+                    "`SQP`: Failed to take `param` from line search state"
+                ))?;
+            let new_x = to_coords(&self.basis, &new_param);
+            let step_length = new_x
+                .iter()
+                .zip(&x)
+                .fold(F::zero(), |acc, (&a, &b)| acc + (a - b) * (a - b))
+                .sqrt();
+            (new_param, step_length)
+        };
+
+        // Update the approximation of the Hessian of the Lagrangian
+        let new_eval = self.evaluate(problem, &new_param)?;
+        if step_length > F::zero() {
+            let s: Vec<F> = to_coords(&self.basis, &new_param)
+                .iter()
+                .zip(&x)
+                .map(|(&a, &b)| a - b)
+                .collect();
+            let y: Vec<F> = new_eval
+                .lagrangian_gradient(&multipliers)
+                .iter()
+                .zip(&eval.lagrangian_gradient(&multipliers))
+                .map(|(&a, &b)| a - b)
+                .collect();
+            self.update_hessian(&s, &y);
+        }
+
+        // KKT residuals
+        let stationarity = norm(&new_eval.lagrangian_gradient(&multipliers));
+        let feasibility = new_eval.max_violation();
+        let complementarity = new_eval
+            .inequality
+            .iter()
+            .zip(&multipliers[new_eval.equality.len()..])
+            .fold(F::zero(), |acc, (&g, &mu)| acc.max(g.min(mu).abs()));
+
+        let cost = self.state_cost(&new_eval);
+        let objective = new_eval.cost;
+        let gradient = param_from_coords(&self.basis, &new_eval.gradient);
+        self.current = Some(new_eval);
+        let state = state
+            .param(new_param)
+            .cost(cost)
+            .gradient(gradient)
+            .lagrange_multipliers(multipliers);
+        let state = if stationarity <= self.optimality_tol
+            && feasibility <= self.feasibility_tol
+            && complementarity <= self.feasibility_tol
+        {
+            state.terminate_with(TerminationReason::SolverConverged)
+        } else {
+            state
+        };
+        Ok((
+            state,
+            Some(kv!(
+                "objective" => objective;
+                "penalty" => self.penalty;
+                "step_length" => step_length;
+                "elastic" => elastic;
+                "stationarity" => stationarity;
+                "feasibility" => feasibility;
+                "complementarity" => complementarity;
+            )),
+        ))
+    }
+}
+
+/// Sign of `x`, which is zero for `x = 0`
+fn sign<F: ArgminFloat>(x: F) -> F {
+    if x > F::zero() {
+        F::one()
+    } else if x < F::zero() {
+        -F::one()
+    } else {
+        F::zero()
+    }
+}
+
+/// l1 merit function of [`SQP`]
+#[doc(hidden)]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+struct MeritProblem<O, F> {
+    /// Constrained optimization problem
+    problem: O,
+    /// Penalty parameter
+    penalty: F,
+}
+
+impl<O, P, F> CostFunction for MeritProblem<O, F>
+where
+    O: CostFunction<Param = P, Output = F>
+        + EqualityConstraint<Param = P, Output = Vec<F>>
+        + InequalityConstraint<Param = P, Output = Vec<F>>,
+    F: ArgminFloat,
+{
+    type Param = P;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        let violation = self
+            .problem
+            .equality_constraint(param)?
+            .iter()
+            .map(|h| h.abs())
+            .chain(
+                self.problem
+                    .inequality_constraint(param)?
+                    .iter()
+                    .map(|&g| (-g).max(F::zero())),
+            )
+            .fold(F::zero(), |acc, v| acc + v);
+        Ok(self.problem.cost(param)? + self.penalty * violation)
+    }
+}
+
+impl<O, P, F> Gradient for MeritProblem<O, F>
+where
+    O: Gradient<Param = P, Gradient = P>
+        + EqualityConstraint<Param = P, Output = Vec<F>>
+        + InequalityConstraint<Param = P, Output = Vec<F>>
+        + EqualityConstraintJacobian<Param = P, Jacobian = Vec<P>>
+        + InequalityConstraintJacobian<Param = P, Jacobian = Vec<P>>,
+    P: ArgminScaledAdd<P, F, P>,
+    F: ArgminFloat,
+{
+    type Param = P;
+    type Gradient = P;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        let penalty = self.penalty;
+        let equality = self.problem.equality_constraint(param)?;
+        let inequality = self.problem.inequality_constraint(param)?;
+        let equality_jacobian = self.problem.equality_constraint_jacobian(param)?;
+        let inequality_jacobian = self.problem.inequality_constraint_jacobian(param)?;
+        let equality = equality
+            .iter()
+            .zip(&equality_jacobian)
+            .map(|(&h, grad)| (sign(h) * penalty, grad));
+        let inequality = inequality
+            .iter()
+            .zip(&inequality_jacobian)
+            .filter(|(&g, _)| g < F::zero())
+            .map(|(_, grad)| (-penalty, grad));
+        Ok(equality
+            .chain(inequality)
+            .fold(self.problem.gradient(param)?, |acc, (weight, grad)| {
+                acc.scaled_add(&weight, grad)
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use crate::solver::linesearch::{condition::ArmijoCondition, BacktrackingLineSearch};
+    use approx::assert_relative_eq;
+
+    type Linesearch = BacktrackingLineSearch<Vec<f64>, Vec<f64>, ArmijoCondition<f64>, f64>;
+
+    fn linesearch() -> Linesearch {
+        BacktrackingLineSearch::new(ArmijoCondition::new(1e-4).unwrap())
+    }
+
+    /// Minimize `(x - 2)^2 + (y - 1)^2` subject to `x = 2y` and `x + y <= 2`. The solution is
+    /// `(4/3, 2/3)` with multipliers `lambda = -2/9` and `mu = 10/9`.
+    struct Problem1 {}
+
+    impl CostFunction for Problem1 {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0] - 2.0).powi(2) + (p[1] - 1.0).powi(2))
+        }
+    }
+
+    impl Gradient for Problem1 {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![2.0 * (p[0] - 2.0), 2.0 * (p[1] - 1.0)])
+        }
+    }
+
+    impl EqualityConstraint for Problem1 {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn equality_constraint(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![p[0] - 2.0 * p[1]])
+        }
+    }
+
+    impl EqualityConstraintJacobian for Problem1 {
+        type Param = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn equality_constraint_jacobian(&self, _p: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(vec![vec![1.0, -2.0]])
+        }
+    }
+
+    impl InequalityConstraint for Problem1 {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn inequality_constraint(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![2.0 - p[0] - p[1]])
+        }
+    }
+
+    impl InequalityConstraintJacobian for Problem1 {
+        type Param = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn inequality_constraint_jacobian(
+            &self,
+            _p: &Self::Param,
+        ) -> Result<Self::Jacobian, Error> {
+            Ok(vec![vec![-1.0, -1.0]])
+        }
+    }
+
+    /// Minimize `(x - 2)^2 + (y - 1)^2` subject to `x + y <= 2` only. The solution is `(3/2, 1/2)`
+    /// with multiplier `mu = 1`.
+    struct Problem2 {}
+
+    impl CostFunction for Problem2 {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0] - 2.0).powi(2) + (p[1] - 1.0).powi(2))
+        }
+    }
+
+    impl Gradient for Problem2 {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![2.0 * (p[0] - 2.0), 2.0 * (p[1] - 1.0)])
+        }
+    }
+
+    impl EqualityConstraint for Problem2 {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+    }
+
+    impl EqualityConstraintJacobian for Problem2 {
+        type Param = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+    }
+
+    impl InequalityConstraint for Problem2 {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn inequality_constraint(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![2.0 - p[0] - p[1]])
+        }
+    }
+
+    impl InequalityConstraintJacobian for Problem2 {
+        type Param = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn inequality_constraint_jacobian(
+            &self,
+            _p: &Self::Param,
+        ) -> Result<Self::Jacobian, Error> {
+            Ok(vec![vec![-1.0, -1.0]])
+        }
+    }
+
+    /// Hock-Schittkowski problem 71: minimize `x1 x4 (x1 + x2 + x3) + x3` subject to
+    /// `x1 x2 x3 x4 >= 25`, `x1^2 + x2^2 + x3^2 + x4^2 = 40` and `1 <= xi <= 5`.
+    struct Hs71 {}
+
+    impl CostFunction for Hs71 {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(x[0] * x[3] * (x[0] + x[1] + x[2]) + x[2])
+        }
+    }
+
+    impl Gradient for Hs71 {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![
+                x[3] * (2.0 * x[0] + x[1] + x[2]),
+                x[0] * x[3],
+                x[0] * x[3] + 1.0,
+                x[0] * (x[0] + x[1] + x[2]),
+            ])
+        }
+    }
+
+    impl EqualityConstraint for Hs71 {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn equality_constraint(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![x.iter().map(|xi| xi * xi).sum::<f64>() - 40.0])
+        }
+    }
+
+    impl EqualityConstraintJacobian for Hs71 {
+        type Param = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn equality_constraint_jacobian(&self, x: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(vec![x.iter().map(|xi| 2.0 * xi).collect()])
+        }
+    }
+
+    impl InequalityConstraint for Hs71 {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn inequality_constraint(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            let mut g = vec![x[0] * x[1] * x[2] * x[3] - 25.0];
+            g.extend(x.iter().map(|xi| xi - 1.0));
+            g.extend(x.iter().map(|xi| 5.0 - xi));
+            Ok(g)
+        }
+    }
+
+    impl InequalityConstraintJacobian for Hs71 {
+        type Param = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn inequality_constraint_jacobian(&self, x: &Self::Param) -> Result<Self::Jacobian, Error> {
+            let mut jacobian = vec![vec![
+                x[1] * x[2] * x[3],
+                x[0] * x[2] * x[3],
+                x[0] * x[1] * x[3],
+                x[0] * x[1] * x[2],
+            ]];
+            for sign in [1.0, -1.0] {
+                jacobian.extend((0..4).map(|i| {
+                    let mut grad = vec![0.0; 4];
+                    grad[i] = sign;
+                    grad
+                }));
+            }
+            Ok(jacobian)
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let sqp: SQP<_, Vec<f64>, f64> = SQP::new(linesearch());
+        assert_eq!(sqp.optimality_tol.to_ne_bytes(), 1e-6f64.to_ne_bytes());
+        assert_eq!(sqp.feasibility_tol.to_ne_bytes(), 1e-8f64.to_ne_bytes());
+        assert_eq!(sqp.penalty.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert!(sqp.basis.is_empty());
+        assert!(sqp.hessian.is_empty());
+        assert!(sqp.current.is_none());
+    }
+
+    #[test]
+    fn test_builders() {
+        let sqp: SQP<_, Vec<f64>, f64> = SQP::new(linesearch())
+            .with_tolerances(1e-4, 1e-5)
+            .unwrap()
+            .with_penalty(3.0)
+            .unwrap();
+        assert_eq!(sqp.optimality_tol.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_eq!(sqp.feasibility_tol.to_ne_bytes(), 1e-5f64.to_ne_bytes());
+        assert_eq!(sqp.penalty.to_ne_bytes(), 3.0f64.to_ne_bytes());
+
+        let res: Result<SQP<_, Vec<f64>, f64>, _> =
+            SQP::new(linesearch()).with_tolerances(0.0, -1.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`SQP`: tolerances must be >= 0.\""
+        );
+        let res: Result<SQP<_, Vec<f64>, f64>, _> = SQP::new(linesearch()).with_penalty(0.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`SQP`: penalty parameter must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_init_param_not_initialized() {
+        let mut sqp: SQP<_, Vec<f64>, f64> = SQP::new(linesearch());
+        let res = sqp.init(&mut Problem::new(Problem1 {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`SQP` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_state_cost() {
+        let sqp: SQP<Linesearch, Vec<f64>, f64> = SQP::new(linesearch());
+        let mut eval = Evaluation {
+            cost: 2.0,
+            gradient: vec![0.0],
+            equality: vec![1e-9],
+            equality_jacobian: vec![vec![1.0]],
+            inequality: vec![1.0],
+            inequality_jacobian: vec![vec![1.0]],
+        };
+        assert_relative_eq!(sqp.state_cost(&eval), 2.0 + 1e-9, epsilon = 1e-15);
+        eval.inequality = vec![-1.0];
+        let cost = sqp.state_cost(&eval);
+        assert!(cost.is_infinite() && cost > 0.0);
+    }
+
+    #[test]
+    fn test_update_hessian() {
+        let mut sqp: SQP<Linesearch, Vec<f64>, f64> = SQP::new(linesearch());
+        sqp.hessian = identity(2);
+        // curvature condition holds: plain BFGS update satisfies the secant equation
+        sqp.update_hessian(&[1.0, 0.0], &[2.0, 1.0]);
+        let bs = mat_vec(&sqp.hessian, &[1.0, 0.0]);
+        assert_relative_eq!(bs[0], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(bs[1], 1.0, epsilon = 1e-12);
+        // negative curvature: damping keeps the matrix positive definite
+        sqp.hessian = identity(2);
+        sqp.update_hessian(&[1.0, 0.0], &[-1.0, 0.0]);
+        assert!(sqp.hessian[0][0] > 0.0);
+        assert_relative_eq!(sqp.hessian[0][0], 0.2, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_solver() {
+        let sqp = SQP::new(linesearch());
+        let res = Executor::new(Problem1 {}, sqp)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_param().unwrap();
+        assert_relative_eq!(param[0], 4.0 / 3.0, epsilon = 1e-8);
+        assert_relative_eq!(param[1], 2.0 / 3.0, epsilon = 1e-8);
+        let multipliers = res.state.get_lagrange_multipliers().unwrap();
+        assert_relative_eq!(multipliers[0], -2.0 / 9.0, epsilon = 1e-6);
+        assert_relative_eq!(multipliers[1], 10.0 / 9.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_solver_inequality_only() {
+        let sqp = SQP::new(linesearch());
+        let res = Executor::new(Problem2 {}, sqp)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_param().unwrap();
+        assert_relative_eq!(param[0], 1.5, epsilon = 1e-8);
+        assert_relative_eq!(param[1], 0.5, epsilon = 1e-8);
+        let multipliers = res.state.get_lagrange_multipliers().unwrap();
+        assert_eq!(multipliers.len(), 1);
+        assert_relative_eq!(multipliers[0], 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_solver_hs71() {
+        let sqp = SQP::new(linesearch());
+        let res = Executor::new(Hs71 {}, sqp)
+            .configure(|state| state.param(vec![1.0, 5.0, 5.0, 1.0]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_param().unwrap();
+        let expected = [1.0, 4.742_999_64, 3.821_149_98, 1.379_408_29];
+        for (p, e) in param.iter().zip(expected) {
+            assert_relative_eq!(*p, e, epsilon = 1e-6);
+        }
+        assert_relative_eq!(res.state.get_cost(), 17.014_017_3, epsilon = 1e-6);
+        let best_param = res.state.get_best_param().unwrap();
+        for (p, e) in best_param.iter().zip(expected) {
+            assert_relative_eq!(*p, e, epsilon = 1e-6);
+        }
+        assert!(res.problem.counts["inequality_constraint_jacobian_count"] > 0);
+    }
+}
@@ -10,7 +10,7 @@ use crate::core::{
     TerminationStatus, TrustRegionRadius, KV,
 };
 use argmin_math::{
-    ArgminAdd, ArgminDot, ArgminInv, ArgminL2Norm, ArgminMul, ArgminSub, ArgminWeightedDot,
+    ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminSolve, ArgminSub, ArgminWeightedDot,
 };
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
@@ -62,7 +62,7 @@ where
         + ArgminDot<P, F>
         + ArgminAdd<P, P>
         + ArgminSub<P, P>,
-    H: ArgminInv<H> + ArgminDot<P, P>,
+    H: ArgminSolve<P, P> + ArgminDot<P, P>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "Dogleg";
@@ -93,7 +93,7 @@ where
         let pstar;
 
         // pb = -H^-1g
-        let pb = (h.inv()?).dot(&g).mul(&float!(-1.0));
+        let pb = h.solve(&g)?.mul(&float!(-1.0));
 
         if pb.l2_norm() <= self.radius {
             pstar = pb;
@@ -234,7 +234,14 @@ mod tests {
 
         let s_param = state_out.take_param().unwrap();
 
-        assert_relative_eq!(s_param[0], -0.9730617585026127, epsilon = f64::EPSILON);
-        assert_relative_eq!(s_param[1], 0.2305446033629983, epsilon = f64::EPSILON);
+        // `pb = -H^-1 g` is computed with the linear solver of the backend, which may differ from
+        // the explicit inverse used in the past by about one ulp. Such a change of `pb` changes the
+        // result by up to `2 * f64::EPSILON`.
+        assert_relative_eq!(
+            s_param[0],
+            -0.9730617585026127,
+            epsilon = 4.0 * f64::EPSILON
+        );
+        assert_relative_eq!(s_param[1], 0.2305446033629983, epsilon = 4.0 * f64::EPSILON);
     }
 }
@@ -10,7 +10,8 @@ use crate::core::{
     TerminationStatus, TrustRegionRadius, KV,
 };
 use argmin_math::{
-    ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminWeightedDot, ArgminZeroLike,
+    ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminMulAssign, ArgminScaledAddAssign,
+    ArgminSubAssign, ArgminWeightedDot, ArgminZeroLike,
 };
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
@@ -186,6 +187,9 @@ where
         + ArgminL2Norm<F>
         + ArgminDot<P, F>
         + ArgminAdd<P, P>
+        + ArgminMulAssign<F>
+        + ArgminSubAssign<P>
+        + ArgminScaledAddAssign<P, F>
         + ArgminZeroLike,
     H: ArgminDot<P, P>,
     F: ArgminFloat,
@@ -251,30 +255,35 @@ where
         let p = self.p.as_ref().unwrap();
         if dhd <= float!(0.0) {
             let tau = self.tau(|_| true, true, &grad, &h);
+            let mut p_tau = p.clone();
+            p_tau.scaled_add_assign(&tau, d);
             return Ok((
                 state
-                    .param(p.add(&d.mul(&tau)))
+                    .param(p_tau)
                     .terminate_with(TerminationReason::SolverConverged),
                 None,
             ));
         }
 
         let alpha = self.rtr / dhd;
-        let p_n = p.add(&d.mul(&alpha));
+        let mut p_n = p.clone();
+        p_n.scaled_add_assign(&alpha, d);
 
         // new p violates trust region bound
         if p_n.l2_norm() >= self.radius {
             let tau = self.tau(|x| x >= float!(0.0), false, &grad, &h);
+            let mut p_tau = p.clone();
+            p_tau.scaled_add_assign(&tau, d);
             return Ok((
                 state
-                    .param(p.add(&d.mul(&tau)))
+                    .param(p_tau)
                     .terminate_with(TerminationReason::SolverConverged),
                 None,
             ));
         }
 
-        let r = self.r.as_ref().unwrap();
-        let r_n = r.add(&h.dot(d).mul(&alpha));
+        let mut r_n = self.r.take().unwrap();
+        r_n.scaled_add_assign(&alpha, &h.dot(d));
 
         if r_n.l2_norm() < self.epsilon * self.r_0_norm {
             return Ok((
@@ -287,7 +296,9 @@ where
 
         let rjtrj = r_n.dot(&r_n);
         let beta = rjtrj / self.rtr;
-        self.d = Some(r_n.mul(&float!(-1.0)).add(&d.mul(&beta)));
+        let d = self.d.as_mut().unwrap();
+        d.mul_assign(&beta);
+        d.sub_assign(&r_n);
         self.r = Some(r_n);
         self.p = Some(p_n.clone());
         self.rtr = rjtrj;